@@ -0,0 +1,523 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`FileIndex`]: the files of a table from a manifest instead of listing
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, SchemaRef};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use datafusion_catalog::Session;
+use datafusion_common::not_impl_err;
+use datafusion_common::stats::Precision;
+use datafusion_common::{
+    exec_datafusion_err, ColumnStatistics, DataFusionError, Result, ScalarValue,
+    Statistics,
+};
+use datafusion_datasource::{ListingTableUrl, PartitionedFile};
+use datafusion_expr::{Expr, TableVersion};
+use object_store::path::Path;
+use object_store::ObjectMeta;
+use serde_json::{Map, Value};
+
+/// The files of a table, used by a table instead of listing the files of
+/// its directories.
+///
+/// Listing the files of a table with [`ListingTableUrl::list_all_files`] is
+/// slow for tables with many files, and does not observe a consistent
+/// snapshot of a table while the table is written. A [`FileIndex`] returns
+/// the files of a table from a manifest instead, such as the manifests of
+/// table formats like Iceberg or Delta Lake, or the [`JsonManifestIndex`].
+#[async_trait]
+pub trait FileIndex: Debug + Send + Sync {
+    /// Returns the index as [`Any`] so that it can be downcast to a specific
+    /// implementation
+    fn as_any(&self) -> &dyn Any;
+
+    /// The files of the table that may contain rows matching `filters`.
+    ///
+    /// `filters` only reference the partition columns `partition_cols`, and
+    /// the files may include files whose partition values do not match the
+    /// filters, which are pruned by the caller. The partition values of the
+    /// files must have the order and types of `partition_cols`.
+    ///
+    /// The files may have [`PartitionedFile::statistics`] for the columns of
+    /// `file_schema`, which are then used instead of reading the statistics
+    /// from the files.
+    async fn list_files(
+        &self,
+        ctx: &dyn Session,
+        filters: &[Expr],
+        file_schema: &SchemaRef,
+        partition_cols: &[(String, DataType)],
+    ) -> Result<Vec<PartitionedFile>>;
+
+    /// Return an index of the files of the previous `version` of the table
+    fn at_version(&self, version: &TableVersion) -> Result<Arc<dyn FileIndex>> {
+        not_impl_err!("Reading the {version} of the table is not supported")
+    }
+}
+
+/// The name of the manifest file of a [`JsonManifestIndex`]
+pub const JSON_MANIFEST_FILE_NAME: &str = "_manifest.json";
+
+/// The snapshot of a table read by a [`JsonManifestIndex`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SnapshotSelector {
+    /// The current snapshot of the table
+    Current,
+    /// The snapshot with an id
+    Id(u64),
+    /// The latest snapshot committed at or before a timestamp, in
+    /// milliseconds since the epoch
+    AsOf(i64),
+}
+
+/// [`FileIndex`] reading the files of a table from a JSON manifest file,
+/// named [`JSON_MANIFEST_FILE_NAME`] in the directory of the table.
+///
+/// The manifest lists the snapshots of the table, each with the files of the
+/// table after a commit, with the paths of the files relative to the
+/// directory of the table:
+///
+/// ```json
+/// {
+///   "current_snapshot_id": 2,
+///   "snapshots": [
+///     {
+///       "snapshot_id": 1,
+///       "timestamp_ms": 1700000000000,
+///       "files": [
+///         {
+///           "path": "year=2024/part-0.parquet",
+///           "size": 1024,
+///           "partition_values": { "year": "2024" },
+///           "num_rows": 100,
+///           "column_statistics": {
+///             "id": { "min": "1", "max": "100", "null_count": 0 }
+///           }
+///         }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// `current_snapshot_id` defaults to the last snapshot, and the partition
+/// values and statistics of the files are optional. The values of the
+/// partitions and the minimum and maximum values are parsed as the types of
+/// their columns.
+///
+/// The index reads the current snapshot, unless it reads a previous snapshot
+/// of the table with [`Self::with_snapshot_id`] or [`Self::as_of`].
+#[derive(Debug, Clone)]
+pub struct JsonManifestIndex {
+    /// The directory of the table
+    table_path: ListingTableUrl,
+    /// The snapshot to read
+    snapshot: SnapshotSelector,
+}
+
+impl JsonManifestIndex {
+    /// Create a new index of the table in the directory `table_path`,
+    /// reading the current snapshot of the table
+    pub fn new(table_path: ListingTableUrl) -> Self {
+        Self {
+            table_path,
+            snapshot: SnapshotSelector::Current,
+        }
+    }
+
+    /// Read the snapshot with id `snapshot_id` instead of the current
+    /// snapshot
+    pub fn with_snapshot_id(mut self, snapshot_id: u64) -> Self {
+        self.snapshot = SnapshotSelector::Id(snapshot_id);
+        self
+    }
+
+    /// Read the latest snapshot committed at or before `timestamp`, instead
+    /// of the current snapshot
+    pub fn as_of(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.snapshot = SnapshotSelector::AsOf(timestamp.timestamp_millis());
+        self
+    }
+
+    /// The directory of the table
+    pub fn table_path(&self) -> &ListingTableUrl {
+        &self.table_path
+    }
+
+    /// The path of the manifest file
+    pub fn manifest_path(&self) -> Path {
+        self.table_path.prefix().child(JSON_MANIFEST_FILE_NAME)
+    }
+
+    /// Read and parse the manifest file
+    async fn read_manifest(&self, ctx: &dyn Session) -> Result<Value> {
+        let store = ctx.runtime_env().object_store(&self.table_path)?;
+        let bytes = store.get(&self.manifest_path()).await?.bytes().await?;
+        serde_json::from_slice(&bytes).map_err(|e| self.invalid(e))
+    }
+
+    /// The snapshot of `manifest` read by this index, or `None` if the table
+    /// has no snapshots
+    fn select_snapshot<'a>(
+        &self,
+        manifest: &'a Value,
+    ) -> Result<Option<&'a Map<String, Value>>> {
+        let snapshots = manifest
+            .get("snapshots")
+            .and_then(Value::as_array)
+            .ok_or_else(|| self.invalid("missing snapshots"))?
+            .iter()
+            .map(|snapshot| {
+                let snapshot = snapshot
+                    .as_object()
+                    .ok_or_else(|| self.invalid("snapshot"))?;
+                let id = snapshot
+                    .get("snapshot_id")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| self.invalid("missing snapshot_id"))?;
+                Ok((id, snapshot))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let id = match self.snapshot {
+            SnapshotSelector::Current => {
+                match manifest.get("current_snapshot_id").and_then(Value::as_u64) {
+                    Some(id) => id,
+                    None if snapshots.is_empty() => return Ok(None),
+                    None => snapshots[snapshots.len() - 1].0,
+                }
+            }
+            SnapshotSelector::Id(id) => id,
+            SnapshotSelector::AsOf(timestamp) => snapshots
+                .iter()
+                .filter_map(|(id, snapshot)| {
+                    let committed = snapshot.get("timestamp_ms")?.as_i64()?;
+                    (committed <= timestamp).then_some((committed, *id))
+                })
+                .max()
+                .map(|(_, id)| id)
+                .ok_or_else(|| {
+                    exec_datafusion_err!(
+                        "Table {} has no snapshot at or before {}",
+                        self.table_path,
+                        DateTime::from_timestamp_millis(timestamp)
+                            .map(|timestamp| timestamp.to_rfc3339())
+                            .unwrap_or_else(|| timestamp.to_string())
+                    )
+                })?,
+        };
+        let snapshot = snapshots
+            .into_iter()
+            .find_map(|(snapshot_id, snapshot)| (snapshot_id == id).then_some(snapshot))
+            .ok_or_else(|| {
+                exec_datafusion_err!("Table {} has no snapshot {id}", self.table_path)
+            })?;
+        Ok(Some(snapshot))
+    }
+
+    /// Create the [`PartitionedFile`] of the manifest entry `file`
+    fn parse_file(
+        &self,
+        file: &Value,
+        last_modified: DateTime<Utc>,
+        file_schema: &SchemaRef,
+        partition_cols: &[(String, DataType)],
+    ) -> Result<PartitionedFile> {
+        let path = file
+            .get("path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| self.invalid("missing path of file"))?;
+        let size = file
+            .get("size")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| self.invalid(format!("missing size of file {path}")))?;
+        let relative_path = Path::parse(path).map_err(|e| self.invalid(e))?;
+        let location = self
+            .table_path
+            .prefix()
+            .parts()
+            .chain(relative_path.parts())
+            .collect();
+
+        let partition_values = partition_cols
+            .iter()
+            .map(|(name, data_type)| {
+                let value = file
+                    .get("partition_values")
+                    .and_then(|values| values.get(name))
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| {
+                        self.invalid(format!("missing partition {name} of file {path}"))
+                    })?;
+                ScalarValue::try_from_string(value.to_string(), data_type)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let statistics = file
+            .get("num_rows")
+            .and_then(Value::as_u64)
+            .map(|num_rows| {
+                let column_statistics = file_schema
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        let Some(stats) = file
+                            .get("column_statistics")
+                            .and_then(|stats| stats.get(field.name()))
+                        else {
+                            return Ok(ColumnStatistics::new_unknown());
+                        };
+                        let value =
+                            |key: &str| match stats.get(key).and_then(Value::as_str) {
+                                Some(value) => {
+                                    Ok(Precision::Exact(ScalarValue::try_from_string(
+                                        value.to_string(),
+                                        field.data_type(),
+                                    )?))
+                                }
+                                None => Ok::<_, DataFusionError>(Precision::Absent),
+                            };
+                        Ok(ColumnStatistics {
+                            null_count: stats
+                                .get("null_count")
+                                .and_then(Value::as_u64)
+                                .map_or(Precision::Absent, |count| {
+                                    Precision::Exact(count as usize)
+                                }),
+                            max_value: value("max")?,
+                            min_value: value("min")?,
+                            sum_value: Precision::Absent,
+                            distinct_count: Precision::Absent,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok::<_, DataFusionError>(Statistics {
+                    num_rows: Precision::Exact(num_rows as usize),
+                    total_byte_size: Precision::Inexact(size as usize),
+                    column_statistics,
+                })
+            })
+            .transpose()?;
+
+        Ok(PartitionedFile {
+            object_meta: ObjectMeta {
+                location,
+                last_modified,
+                size: size as usize,
+                e_tag: None,
+                version: None,
+            },
+            partition_values,
+            range: None,
+            statistics,
+            extensions: None,
+            deletes: None,
+            metadata_size_hint: None,
+        })
+    }
+
+    /// The error of an invalid manifest
+    fn invalid(&self, reason: impl std::fmt::Display) -> DataFusionError {
+        exec_datafusion_err!("Invalid manifest {}: {reason}", self.manifest_path())
+    }
+}
+
+#[async_trait]
+impl FileIndex for JsonManifestIndex {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn list_files(
+        &self,
+        ctx: &dyn Session,
+        _filters: &[Expr],
+        file_schema: &SchemaRef,
+        partition_cols: &[(String, DataType)],
+    ) -> Result<Vec<PartitionedFile>> {
+        let manifest = self.read_manifest(ctx).await?;
+        self.files_of_manifest(&manifest, file_schema, partition_cols)
+    }
+
+    fn at_version(&self, version: &TableVersion) -> Result<Arc<dyn FileIndex>> {
+        let index = match *version {
+            TableVersion::Version(snapshot_id) => {
+                self.clone().with_snapshot_id(snapshot_id)
+            }
+            TableVersion::Timestamp(nanos) => {
+                self.clone().as_of(DateTime::from_timestamp_nanos(nanos))
+            }
+        };
+        Ok(Arc::new(index))
+    }
+}
+
+impl JsonManifestIndex {
+    /// The files of the snapshot of `manifest` read by this index
+    fn files_of_manifest(
+        &self,
+        manifest: &Value,
+        file_schema: &SchemaRef,
+        partition_cols: &[(String, DataType)],
+    ) -> Result<Vec<PartitionedFile>> {
+        let Some(snapshot) = self.select_snapshot(manifest)? else {
+            // a table without snapshots has no files
+            return Ok(vec![]);
+        };
+        let last_modified = snapshot
+            .get("timestamp_ms")
+            .and_then(Value::as_i64)
+            .and_then(DateTime::from_timestamp_millis)
+            .unwrap_or_default();
+        let Some(files) = snapshot.get("files") else {
+            return Ok(vec![]);
+        };
+        files
+            .as_array()
+            .ok_or_else(|| self.invalid("files"))?
+            .iter()
+            .map(|file| self.parse_file(file, last_modified, file_schema, partition_cols))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::datatypes::{Field, Schema};
+    use datafusion_expr::{col, lit};
+
+    use crate::helpers::prune_files_by_partition_values;
+
+    const MANIFEST: &str = r#"{
+        "current_snapshot_id": 2,
+        "snapshots": [
+            {
+                "snapshot_id": 1,
+                "timestamp_ms": 1000,
+                "files": [
+                    { "path": "a=1/f1.parquet", "size": 10, "partition_values": { "a": "1" } }
+                ]
+            },
+            {
+                "snapshot_id": 2,
+                "timestamp_ms": 2000,
+                "files": [
+                    { "path": "a=1/f1.parquet", "size": 10, "partition_values": { "a": "1" } },
+                    {
+                        "path": "a=2/f2.parquet",
+                        "size": 20,
+                        "partition_values": { "a": "2" },
+                        "num_rows": 5,
+                        "column_statistics": { "x": { "min": "3", "max": "7", "null_count": 1 } }
+                    }
+                ]
+            },
+            { "snapshot_id": 3, "timestamp_ms": 3000, "files": [] }
+        ]
+    }"#;
+
+    fn paths(files: &[PartitionedFile]) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| file.object_meta.location.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn json_manifest_index() {
+        let manifest: Value = serde_json::from_str(MANIFEST).unwrap();
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("x", DataType::Int32, true)]));
+        let partition_cols = vec![("a".to_string(), DataType::Int64)];
+        let index =
+            JsonManifestIndex::new(ListingTableUrl::parse("file:///table/").unwrap());
+        assert_eq!(index.manifest_path().as_ref(), "table/_manifest.json");
+        let list = |index: JsonManifestIndex| {
+            index.files_of_manifest(&manifest, &file_schema, &partition_cols)
+        };
+
+        let files = list(index.clone()).unwrap();
+        assert_eq!(
+            paths(&files),
+            vec!["table/a=1/f1.parquet", "table/a=2/f2.parquet"]
+        );
+        assert_eq!(files[1].object_meta.size, 20);
+        assert_eq!(files[1].partition_values, vec![ScalarValue::Int64(Some(2))]);
+        assert_eq!(files[0].statistics, None);
+        let statistics = files[1].statistics.as_ref().unwrap();
+        assert_eq!(statistics.num_rows, Precision::Exact(5));
+        let x = &statistics.column_statistics[0];
+        assert_eq!(x.min_value, Precision::Exact(ScalarValue::Int32(Some(3))));
+        assert_eq!(x.max_value, Precision::Exact(ScalarValue::Int32(Some(7))));
+        assert_eq!(x.null_count, Precision::Exact(1));
+
+        let filters = [col("a").gt(lit(1i64))];
+        let pruned =
+            prune_files_by_partition_values(files, &filters, &partition_cols).unwrap();
+        assert_eq!(paths(&pruned), vec!["table/a=2/f2.parquet"]);
+
+        // previous snapshots
+        let files = list(index.clone().with_snapshot_id(1)).unwrap();
+        assert_eq!(paths(&files), vec!["table/a=1/f1.parquet"]);
+        let as_of = DateTime::from_timestamp_millis(1500).unwrap();
+        let files = list(index.clone().as_of(as_of)).unwrap();
+        assert_eq!(paths(&files), vec!["table/a=1/f1.parquet"]);
+        let as_of = DateTime::from_timestamp_millis(3500).unwrap();
+        assert!(list(index.clone().as_of(as_of)).unwrap().is_empty());
+
+        let at_version = |version| {
+            let index = index.at_version(&version).unwrap();
+            let index = index.as_any().downcast_ref::<JsonManifestIndex>().unwrap();
+            list(index.clone()).unwrap()
+        };
+        let files = at_version(TableVersion::Version(1));
+        assert_eq!(paths(&files), vec!["table/a=1/f1.parquet"]);
+        let files = at_version(TableVersion::Timestamp(2_500_000_000));
+        assert_eq!(files.len(), 2);
+
+        let err = list(index.clone().with_snapshot_id(4)).unwrap_err();
+        assert!(err.to_string().contains("has no snapshot 4"), "{err}");
+        let as_of = DateTime::from_timestamp_millis(500).unwrap();
+        let err = list(index.clone().as_of(as_of)).unwrap_err();
+        assert!(
+            err.to_string().contains("has no snapshot at or before"),
+            "{err}"
+        );
+
+        let manifest = serde_json::json!({ "snapshots": [] });
+        assert!(index
+            .files_of_manifest(&manifest, &file_schema, &partition_cols)
+            .unwrap()
+            .is_empty());
+        let manifest = serde_json::json!({
+            "snapshots": [{ "snapshot_id": 1, "files": [{ "path": "f.parquet", "size": 1 }] }]
+        });
+        let err = index
+            .files_of_manifest(&manifest, &file_schema, &partition_cols)
+            .map(|_| ())
+            .unwrap_err();
+        assert!(err.to_string().contains("missing partition a"), "{err}");
+    }
+}
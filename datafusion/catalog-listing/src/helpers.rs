@@ -21,8 +21,9 @@ use std::mem;
 use std::sync::Arc;
 
 use datafusion_catalog::Session;
-use datafusion_common::internal_err;
+use datafusion_common::{exec_datafusion_err, internal_err};
 use datafusion_common::{HashMap, Result, ScalarValue};
+use datafusion_datasource::write::orchestration::CommitMarkers;
 use datafusion_datasource::ListingTableUrl;
 use datafusion_datasource::PartitionedFile;
 use datafusion_expr::{BinaryExpr, Operator};
@@ -30,12 +31,12 @@ use datafusion_expr::{BinaryExpr, Operator};
 use arrow::{
     array::{Array, ArrayRef, AsArray, StringBuilder},
     compute::{and, cast, prep_null_mask_filter},
-    datatypes::{DataType, Field, Fields, Schema},
+    datatypes::{DataType, Field, Fields, Schema, TimeUnit},
     record_batch::RecordBatch,
 };
 use datafusion_expr::execution_props::ExecutionProps;
 use futures::stream::FuturesUnordered;
-use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use futures::{stream::BoxStream, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use log::{debug, trace};
 
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
@@ -187,6 +188,24 @@ pub async fn list_partitions(
     table_path: &ListingTableUrl,
     max_depth: usize,
     partition_prefix: Option<Path>,
+) -> Result<Vec<Partition>> {
+    list_pruned_partitions(store, table_path, max_depth, partition_prefix, &[], &[]).await
+}
+
+/// Returns a recursive list of the partitions in `table_path` up to
+/// `max_depth`, like [`list_partitions`], but without listing the partitions
+/// whose values of the leading `partition_cols` do not match `filters`.
+///
+/// The partitions are pruned level by level: after listing the directories
+/// `col1=x` of the first level, the filters on `col1` only are evaluated to
+/// prune them before their directories `col2=y` are listed, and so on.
+async fn list_pruned_partitions(
+    store: &dyn ObjectStore,
+    table_path: &ListingTableUrl,
+    max_depth: usize,
+    partition_prefix: Option<Path>,
+    filters: &[Expr],
+    partition_cols: &[(String, DataType)],
 ) -> Result<Vec<Partition>> {
     let partition = Partition {
         path: match partition_prefix {
@@ -217,6 +236,15 @@ pub async fn list_partitions(
 
         let depth = partition.depth;
         out.push(partition);
+        let num_paths = paths.len();
+        let paths = prune_paths(table_path, paths, filters, partition_cols)?;
+        if paths.len() < num_paths {
+            debug!(
+                "Pruned {} of {num_paths} partitions at depth {}",
+                num_paths - paths.len(),
+                depth + 1
+            );
+        }
         for path in paths {
             let child = Partition {
                 path,
@@ -235,7 +263,57 @@ pub async fn list_partitions(
     Ok(out)
 }
 
-async fn prune_partitions(
+/// Retain the child directories `paths` of a partition whose values of the
+/// leading partition columns may match `filters`.
+///
+/// Only the filters that reference the partition columns of the levels
+/// above and including `paths` are evaluated, the others are evaluated once
+/// the directories of their columns are listed.
+fn prune_paths(
+    table_path: &ListingTableUrl,
+    paths: Vec<Path>,
+    filters: &[Expr],
+    partition_cols: &[(String, DataType)],
+) -> Result<Vec<Path>> {
+    let Some(first) = paths.first() else {
+        return Ok(paths);
+    };
+    // the number of partition columns with a value in the paths, which
+    // are all children of the same directory
+    let num_cols = table_path
+        .strip_prefix(first)
+        .map(|parts| parts.count())
+        .unwrap_or_default()
+        .min(partition_cols.len());
+    let partition_cols = &partition_cols[..num_cols];
+    let col_names = partition_cols
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    let filters = filters
+        .iter()
+        .filter(|filter| {
+            !col_names.is_empty() && expr_applicable_for_cols(&col_names, filter)
+        })
+        .collect::<Vec<_>>();
+    if filters.is_empty() {
+        return Ok(paths);
+    }
+
+    let matches = match_partition_paths(
+        table_path,
+        &paths.iter().collect::<Vec<_>>(),
+        &filters,
+        partition_cols,
+    )?;
+    Ok(paths
+        .into_iter()
+        .zip(matches)
+        .filter_map(|(path, matches)| matches.then_some(path))
+        .collect())
+}
+
+fn prune_partitions(
     table_path: &ListingTableUrl,
     partitions: Vec<Partition>,
     filters: &[Expr],
@@ -245,14 +323,42 @@ async fn prune_partitions(
         return Ok(partitions);
     }
 
+    let paths = partitions.iter().map(|p| &p.path).collect::<Vec<_>>();
+    let matches = match_partition_paths(
+        table_path,
+        &paths,
+        &filters.iter().collect::<Vec<_>>(),
+        partition_cols,
+    )?;
+
+    let filtered = partitions
+        .into_iter()
+        .zip(matches)
+        .filter_map(|(p, f)| f.then_some(p))
+        .collect();
+
+    Ok(filtered)
+}
+
+/// Evaluates the conjunction of `filters` on the values of `partition_cols`
+/// parsed from each of `paths`, returning whether each path matches.
+///
+/// The paths whose values can not be parsed, or for which the filters
+/// evaluate to null, do not match.
+fn match_partition_paths(
+    table_path: &ListingTableUrl,
+    paths: &[&Path],
+    filters: &[&Expr],
+    partition_cols: &[(String, DataType)],
+) -> Result<Vec<bool>> {
     let mut builders: Vec<_> = (0..partition_cols.len())
-        .map(|_| StringBuilder::with_capacity(partitions.len(), partitions.len() * 10))
+        .map(|_| StringBuilder::with_capacity(paths.len(), paths.len() * 10))
         .collect();
 
-    for partition in &partitions {
+    for path in paths {
         let cols = partition_cols.iter().map(|x| x.0.as_str());
-        let parsed = parse_partitions_for_path(table_path, &partition.path, cols)
-            .unwrap_or_default();
+        let parsed =
+            parse_partitions_for_path(table_path, path, cols).unwrap_or_default();
 
         let mut builders = builders.iter_mut();
         for (p, b) in parsed.iter().zip(&mut builders) {
@@ -270,6 +376,57 @@ async fn prune_partitions(
         })
         .collect::<Result<_, _>>()?;
 
+    evaluate_partition_filters(arrays, paths.len(), filters, partition_cols)
+}
+
+/// Retain the `files` whose partition values match all the `filters`, which
+/// must only reference the partition columns `partition_cols`.
+///
+/// This is used to prune the files that are not listed from the directories
+/// of the partitions, such as the files of a [`FileIndex`].
+///
+/// [`FileIndex`]: crate::file_index::FileIndex
+pub fn prune_files_by_partition_values(
+    files: Vec<PartitionedFile>,
+    filters: &[Expr],
+    partition_cols: &[(String, DataType)],
+) -> Result<Vec<PartitionedFile>> {
+    if filters.is_empty() || files.is_empty() {
+        return Ok(files);
+    }
+    let arrays = partition_cols
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, _))| {
+            let values = files.iter().map(|file| {
+                file.partition_values.get(idx).cloned().ok_or_else(|| {
+                    exec_datafusion_err!(
+                        "Missing value of partition column {name} for file {}",
+                        file.object_meta.location
+                    )
+                })
+            });
+            ScalarValue::iter_to_array(values.collect::<Result<Vec<_>>>()?)
+        })
+        .collect::<Result<_>>()?;
+
+    let filters = filters.iter().collect::<Vec<_>>();
+    let mask = evaluate_partition_filters(arrays, files.len(), &filters, partition_cols)?;
+    Ok(files
+        .into_iter()
+        .zip(mask)
+        .filter_map(|(file, keep)| keep.then_some(file))
+        .collect())
+}
+
+/// Evaluate the conjunction of `filters` on the `arrays` of the values of
+/// `num_rows` partitions, returning whether each partition matches
+fn evaluate_partition_filters(
+    arrays: Vec<ArrayRef>,
+    num_rows: usize,
+    filters: &[&Expr],
+    partition_cols: &[(String, DataType)],
+) -> Result<Vec<bool>> {
     let fields: Fields = partition_cols
         .iter()
         .map(|(n, d)| Field::new(n, d.clone(), true))
@@ -292,19 +449,19 @@ async fn prune_partitions(
     // Applies `filter` to `batch` returning `None` on error
     let do_filter = |filter| -> Result<ArrayRef> {
         let expr = create_physical_expr(filter, &df_schema, &props)?;
-        expr.evaluate(&batch)?.into_array(partitions.len())
+        expr.evaluate(&batch)?.into_array(num_rows)
     };
 
     //.Compute the conjunction of the filters
     let mask = filters
         .iter()
-        .map(|f| do_filter(f).map(|a| a.as_boolean().clone()))
+        .map(|f| do_filter(*f).map(|a| a.as_boolean().clone()))
         .reduce(|a, b| Ok(and(&a?, &b?)?));
 
     let mask = match mask {
         Some(Ok(mask)) => mask,
         Some(Err(err)) => return Err(err),
-        None => return Ok(partitions),
+        None => return Ok(vec![true; num_rows]),
     };
 
     // Don't retain partitions that evaluated to null
@@ -314,15 +471,9 @@ async fn prune_partitions(
     };
 
     // Sanity check
-    assert_eq!(prepared.len(), partitions.len());
+    assert_eq!(prepared.len(), num_rows);
 
-    let filtered = partitions
-        .into_iter()
-        .zip(prepared.values())
-        .filter_map(|(p, f)| f.then_some(p))
-        .collect();
-
-    Ok(filtered)
+    Ok(prepared.values().iter().collect())
 }
 
 #[derive(Debug)]
@@ -343,8 +494,12 @@ fn populate_partition_values<'a>(
     {
         match op {
             Operator::Eq => match (left.as_ref(), right.as_ref()) {
+                // the directory names of integer values can not be derived
+                // from the values, as they may be zero padded like `hour=07`
                 (Expr::Column(Column { ref name, .. }), Expr::Literal(val))
-                | (Expr::Literal(val), Expr::Column(Column { ref name, .. })) => {
+                | (Expr::Literal(val), Expr::Column(Column { ref name, .. }))
+                    if !val.data_type().is_integer() =>
+                {
                     if partition_values
                         .insert(name, PartitionValue::Single(val.to_string()))
                         .is_some()
@@ -429,56 +584,88 @@ pub async fn pruned_partition_list<'a>(
     }
 
     let partition_prefix = evaluate_partition_prefix(partition_cols, filters);
-    let partitions =
-        list_partitions(store, table_path, partition_cols.len(), partition_prefix)
-            .await?;
+    // the commit markers of the writes to the table are at its root, which
+    // the partitions are listed from unless they are listed from a prefix
+    let list_root = match partition_prefix {
+        Some(_) => {
+            let prefix = Some(table_path.prefix()).filter(|p| !p.as_ref().is_empty());
+            store.list_with_delimiter(prefix).map_ok(Some).boxed()
+        }
+        None => futures::future::ready(Ok(None)).boxed(),
+    };
+    let list_partitions = list_pruned_partitions(
+        store,
+        table_path,
+        partition_cols.len(),
+        partition_prefix,
+        filters,
+        partition_cols,
+    );
+    let (partitions, root) =
+        futures::try_join!(list_partitions, list_root.map_err(DataFusionError::from))?;
     debug!("Listed {} partitions", partitions.len());
 
-    let pruned =
-        prune_partitions(table_path, partitions, filters, partition_cols).await?;
+    let root = match &root {
+        Some(root) => Some(&root.objects),
+        None => partitions
+            .iter()
+            .find(|partition| partition.depth == 0)
+            .and_then(|partition| partition.files.as_ref()),
+    };
+    let markers = match root {
+        Some(files) => CommitMarkers::load(store, files).await?,
+        None => CommitMarkers::default(),
+    };
+    let markers = Arc::new(markers);
+
+    let pruned = prune_partitions(table_path, partitions, filters, partition_cols)?;
 
     debug!("Pruning yielded {} partitions", pruned.len());
 
     let stream = futures::stream::iter(pruned)
-        .map(move |partition: Partition| async move {
-            let cols = partition_cols.iter().map(|x| x.0.as_str());
-            let parsed = parse_partitions_for_path(table_path, &partition.path, cols);
-
-            let partition_values = parsed
-                .into_iter()
-                .flatten()
-                .zip(partition_cols)
-                .map(|(parsed, (_, datatype))| {
-                    ScalarValue::try_from_string(parsed.to_string(), datatype)
-                })
-                .collect::<Result<Vec<_>>>()?;
-
-            let files = match partition.files {
-                Some(files) => files,
-                None => {
-                    trace!("Recursively listing partition {}", partition.path);
-                    store.list(Some(&partition.path)).try_collect().await?
-                }
-            };
-            let files = files.into_iter().filter(move |o| {
-                let extension_match = o.location.as_ref().ends_with(file_extension);
-                // here need to scan subdirectories(`listing_table_ignore_subdirectory` = false)
-                let glob_match = table_path.contains(&o.location, false);
-                extension_match && glob_match
-            });
-
-            let stream = futures::stream::iter(files.map(move |object_meta| {
-                Ok(PartitionedFile {
-                    object_meta,
-                    partition_values: partition_values.clone(),
-                    range: None,
-                    statistics: None,
-                    extensions: None,
-                    metadata_size_hint: None,
-                })
-            }));
-
-            Ok::<_, DataFusionError>(stream)
+        .map(move |partition: Partition| {
+            let markers = Arc::clone(&markers);
+            async move {
+                let cols = partition_cols.iter().map(|x| x.0.as_str());
+                let parsed = parse_partitions_for_path(table_path, &partition.path, cols);
+
+                let partition_values = parsed
+                    .into_iter()
+                    .flatten()
+                    .zip(partition_cols)
+                    .map(|(parsed, (_, datatype))| {
+                        ScalarValue::try_from_string(parsed.to_string(), datatype)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let files = match partition.files {
+                    Some(files) => files,
+                    None => {
+                        trace!("Recursively listing partition {}", partition.path);
+                        store.list(Some(&partition.path)).try_collect().await?
+                    }
+                };
+                let files = files.into_iter().filter(move |o| {
+                    let extension_match = o.location.as_ref().ends_with(file_extension);
+                    // here need to scan subdirectories(`listing_table_ignore_subdirectory` = false)
+                    let glob_match = table_path.contains(&o.location, false);
+                    extension_match && glob_match && markers.is_visible(&o.location)
+                });
+
+                let stream = futures::stream::iter(files.map(move |object_meta| {
+                    Ok(PartitionedFile {
+                        object_meta,
+                        partition_values: partition_values.clone(),
+                        range: None,
+                        statistics: None,
+                        extensions: None,
+                        deletes: None,
+                        metadata_size_hint: None,
+                    })
+                }));
+
+                Ok::<_, DataFusionError>(stream)
+            }
         })
         .buffer_unordered(CONCURRENCY_LIMIT)
         .try_flatten()
@@ -486,6 +673,45 @@ pub async fn pruned_partition_list<'a>(
     Ok(stream)
 }
 
+/// Infer the type of a partition column from all its distinct `values` in
+/// the directory names, such as `2024-01-01` for `dt=2024-01-01`.
+///
+/// Returns `Int64` if all the values are integers, `Date32` if they are all
+/// dates, `Timestamp(Nanosecond, None)` if they are all timestamps, and a
+/// dictionary encoded `Utf8` otherwise.
+///
+/// Integers are inferred if every value parses as an integer, including zero
+/// padded values such as `hour=07`: the partitions of integer values are
+/// pruned by parsing the values of the directory names, rather than by
+/// listing the directory of the formatted value (see
+/// [`evaluate_partition_prefix`]). Dates and timestamps are only inferred if
+/// every value is formatted as the value of that type would be, so that the
+/// directory of a value can be found from the value.
+pub fn infer_partition_data_type<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+) -> DataType {
+    let values = values.into_iter().collect::<Vec<_>>();
+    let infer = |data_type: DataType, check_formatted: bool| {
+        let all_parsed = !values.is_empty()
+            && values.iter().all(|value| {
+                let scalar = ScalarValue::try_from_string(value.to_string(), &data_type);
+                if !check_formatted {
+                    return scalar.is_ok_and(|scalar| !scalar.is_null());
+                }
+                scalar
+                    .and_then(|scalar| scalar.cast_to(&DataType::Utf8))
+                    .is_ok_and(|formatted| formatted == ScalarValue::from(*value))
+            });
+        all_parsed.then_some(data_type)
+    };
+    infer(DataType::Int64, false)
+        .or_else(|| infer(DataType::Date32, true))
+        .or_else(|| infer(DataType::Timestamp(TimeUnit::Nanosecond, None), true))
+        .unwrap_or_else(|| {
+            DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8))
+        })
+}
+
 /// Extract the partition values for the given `file_path` (in the given `table_path`)
 /// associated to the partitions defined by `table_partition_cols`
 pub fn parse_partitions_for_path<'a, I>(
@@ -542,7 +768,7 @@ mod tests {
 
     use super::*;
     use datafusion_expr::{
-        case, col, lit, AggregateUDF, Expr, LogicalPlan, ScalarUDF, WindowUDF,
+        case, cast, col, lit, AggregateUDF, Expr, LogicalPlan, ScalarUDF, WindowUDF,
     };
     use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
     use datafusion_physical_plan::ExecutionPlan;
@@ -649,6 +875,55 @@ mod tests {
         assert_eq!(f2.partition_values, &[ScalarValue::from("val1"),]);
     }
 
+    #[tokio::test]
+    async fn test_pruned_partition_list_commit_markers() {
+        use datafusion_datasource::write::orchestration::CommitMarker;
+
+        let (store, state) = make_test_store_and_state(&[
+            ("tablepath/mypartition=val1/file.parquet", 100),
+            ("tablepath/mypartition=val1/pending.parquet", 100),
+            ("tablepath/mypartition=val2/file.parquet", 100),
+        ]);
+        let marker = CommitMarker {
+            committed: false,
+            added: vec![Path::from("tablepath/mypartition=val1/pending.parquet")],
+            replaced: vec![],
+        };
+        store
+            .put(
+                &Path::from("tablepath/.staging-abc.commit"),
+                marker.to_bytes().into(),
+            )
+            .await
+            .unwrap();
+
+        // the marker at the root is found whether the partitions are listed
+        // from the root or from the prefix of the filtered partition
+        let filters = [vec![], vec![Expr::eq(col("mypartition"), lit("val1"))]];
+        for (filters, expected) in filters.iter().zip([2, 1]) {
+            let files = pruned_partition_list(
+                state.as_ref(),
+                store.as_ref(),
+                &ListingTableUrl::parse("file:///tablepath/").unwrap(),
+                filters,
+                ".parquet",
+                &[(String::from("mypartition"), DataType::Utf8)],
+            )
+            .await
+            .expect("partition pruning failed")
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+            assert_eq!(files.len(), expected);
+            assert!(files.iter().all(|file| !file
+                .object_meta
+                .location
+                .as_ref()
+                .ends_with("pending.parquet")));
+        }
+    }
+
     #[tokio::test]
     async fn test_pruned_partition_list_multi() {
         let (store, state) = make_test_store_and_state(&[
@@ -698,6 +973,139 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_pruned_partition_list_typed() {
+        let (store, state) = make_test_store_and_state(&[
+            ("tablepath/dt=2024-01-01/hour=07/file.parquet", 100),
+            ("tablepath/dt=2024-01-01/hour=08/file.parquet", 100),
+            ("tablepath/dt=2024-01-02/hour=07/file.parquet", 100),
+            ("tablepath/dt=2024-01-02/hour=09/file.parquet", 100),
+            ("tablepath/dt=2024-02-01/hour=07/file.parquet", 100),
+        ]);
+        let table_path = ListingTableUrl::parse("file:///tablepath/").unwrap();
+        let partition_cols = [
+            (String::from("dt"), DataType::Date32),
+            (String::from("hour"), DataType::Int64),
+        ];
+        let date = |days| Expr::Literal(ScalarValue::Date32(Some(days)));
+        // 2024-01-02 is day 19724
+        let filters = [
+            col("dt").gt_eq(date(19724)),
+            col("hour").in_list(vec![lit(7i64), lit(9i64)], false),
+            cast(col("dt"), DataType::Utf8).like(lit("2024-01-%")),
+        ];
+
+        // the directories of the first level are pruned before being listed
+        let partitions = list_pruned_partitions(
+            store.as_ref(),
+            &table_path,
+            2,
+            None,
+            &filters,
+            &partition_cols,
+        )
+        .await
+        .unwrap();
+        let mut paths = partitions
+            .iter()
+            .map(|p| p.path.as_ref())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "tablepath",
+                "tablepath/dt=2024-01-02",
+                "tablepath/dt=2024-01-02/hour=07",
+                "tablepath/dt=2024-01-02/hour=09",
+            ]
+        );
+
+        let mut pruned = pruned_partition_list(
+            state.as_ref(),
+            store.as_ref(),
+            &table_path,
+            &filters,
+            ".parquet",
+            &partition_cols,
+        )
+        .await
+        .expect("partition pruning failed")
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+        pruned.sort_by(|a, b| a.object_meta.location.cmp(&b.object_meta.location));
+
+        assert_eq!(pruned.len(), 2);
+        assert_eq!(
+            &pruned[0].partition_values,
+            &[
+                ScalarValue::Date32(Some(19724)),
+                ScalarValue::Int64(Some(7))
+            ]
+        );
+        assert_eq!(
+            &pruned[1].partition_values,
+            &[
+                ScalarValue::Date32(Some(19724)),
+                ScalarValue::Int64(Some(9))
+            ]
+        );
+
+        // zero padded integer values are matched by value
+        let pruned = pruned_partition_list(
+            state.as_ref(),
+            store.as_ref(),
+            &table_path,
+            &[col("dt").eq(date(19724)), col("hour").eq(lit(7i64))],
+            ".parquet",
+            &partition_cols,
+        )
+        .await
+        .expect("partition pruning failed")
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(
+            pruned[0].object_meta.location.as_ref(),
+            "tablepath/dt=2024-01-02/hour=07/file.parquet"
+        );
+    }
+
+    #[test]
+    fn test_infer_partition_data_type() {
+        let dictionary =
+            DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8));
+        assert_eq!(
+            infer_partition_data_type(["7", "12", "-1"]),
+            DataType::Int64
+        );
+        // zero padded integers are integers
+        assert_eq!(infer_partition_data_type(["07", "12"]), DataType::Int64);
+        assert_eq!(infer_partition_data_type(["+1"]), DataType::Int64);
+        assert_eq!(
+            infer_partition_data_type(["2024-01-01", "2023-12-31"]),
+            DataType::Date32
+        );
+        assert_eq!(
+            infer_partition_data_type(["2024-01-01T07:00:00", "2024-01-02T00:00:00"]),
+            DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        // the values are not formatted as dates or timestamps
+        assert_eq!(
+            infer_partition_data_type(["2024-01-01T07:00:00", "2024-01-02"]),
+            dictionary
+        );
+        assert_eq!(infer_partition_data_type(["2024-1-1"]), dictionary);
+        assert_eq!(infer_partition_data_type(["1", "a"]), dictionary);
+        assert_eq!(
+            infer_partition_data_type(["1", "__HIVE_DEFAULT_PARTITION__"]),
+            dictionary
+        );
+        assert_eq!(infer_partition_data_type([]), dictionary);
+    }
+
     #[tokio::test]
     async fn test_list_partition() {
         let (store, _) = make_test_store_and_state(&[
@@ -975,6 +1383,15 @@ mod tests {
             evaluate_partition_prefix(partitions, &[col("b").lt(lit(5))],),
             None,
         );
+
+        // integer values may be zero padded in the directory names
+        assert_eq!(
+            evaluate_partition_prefix(
+                partitions,
+                &[col("a").eq(lit("foo")).and(col("b").eq(lit(1)))],
+            ),
+            Some(Path::from("a=foo")),
+        );
     }
 
     #[test]
@@ -21,4 +21,5 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+pub mod file_index;
 pub mod helpers;
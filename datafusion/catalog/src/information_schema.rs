@@ -21,16 +21,21 @@
 
 use crate::streaming::StreamingTable;
 use crate::{CatalogProviderList, SchemaProvider, TableProvider};
-use arrow::array::builder::{BooleanBuilder, UInt8Builder};
+use arrow::array::builder::{BooleanBuilder, TimestampMillisecondBuilder, UInt8Builder};
 use arrow::{
     array::{StringBuilder, UInt64Builder},
-    datatypes::{DataType, Field, Schema, SchemaRef},
+    datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit},
     record_batch::RecordBatch,
 };
 use async_trait::async_trait;
 use datafusion_common::config::{ConfigEntry, ConfigOptions};
 use datafusion_common::error::Result;
-use datafusion_common::DataFusionError;
+use datafusion_common::{exec_err, DataFusionError};
+use datafusion_execution::admission::AdmissionEntry;
+use datafusion_execution::memory_pool::MemoryConsumer;
+use datafusion_execution::query_registry::{
+    QueryRecord, QueryRegistry, RunningQueryInfo,
+};
 use datafusion_execution::TaskContext;
 use datafusion_expr::{AggregateUDF, ScalarUDF, Signature, TypeSignature, WindowUDF};
 use datafusion_expr::{TableType, Volatility};
@@ -39,6 +44,7 @@ use datafusion_physical_plan::streaming::PartitionStream;
 use datafusion_physical_plan::SendableRecordBatchStream;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{any::Any, sync::Arc};
 
 pub const INFORMATION_SCHEMA: &str = "information_schema";
@@ -49,6 +55,11 @@ pub(crate) const DF_SETTINGS: &str = "df_settings";
 pub(crate) const SCHEMATA: &str = "schemata";
 pub(crate) const ROUTINES: &str = "routines";
 pub(crate) const PARAMETERS: &str = "parameters";
+pub(crate) const ADMISSION_QUEUE: &str = "admission_queue";
+pub(crate) const RUNNING_QUERIES: &str = "running_queries";
+pub(crate) const QUERY_HISTORY: &str = "query_history";
+pub(crate) const MEMORY_CONSUMERS: &str = "memory_consumers";
+pub(crate) const CACHES: &str = "caches";
 
 /// All information schema tables
 pub const INFORMATION_SCHEMA_TABLES: &[&str] = &[
@@ -59,6 +70,11 @@ pub const INFORMATION_SCHEMA_TABLES: &[&str] = &[
     SCHEMATA,
     ROUTINES,
     PARAMETERS,
+    ADMISSION_QUEUE,
+    RUNNING_QUERIES,
+    QUERY_HISTORY,
+    MEMORY_CONSUMERS,
+    CACHES,
 ];
 
 /// Implements the `information_schema` virtual schema and tables
@@ -496,6 +512,11 @@ impl SchemaProvider for InformationSchemaProvider {
             SCHEMATA => Arc::new(InformationSchemata::new(config)),
             ROUTINES => Arc::new(InformationSchemaRoutines::new(config)),
             PARAMETERS => Arc::new(InformationSchemaParameters::new(config)),
+            ADMISSION_QUEUE => Arc::new(InformationSchemaAdmissionQueue::new()),
+            RUNNING_QUERIES => Arc::new(InformationSchemaRunningQueries::new()),
+            QUERY_HISTORY => Arc::new(InformationSchemaQueryHistory::new()),
+            MEMORY_CONSUMERS => Arc::new(InformationSchemaMemoryConsumers::new()),
+            CACHES => Arc::new(InformationSchemaCaches::new()),
             _ => return Ok(None),
         };
 
@@ -1348,3 +1369,503 @@ impl PartitionStream for InformationSchemaParameters {
         ))
     }
 }
+
+/// The queries of the admission controller of the runtime, see
+/// [`AdmissionController`](datafusion_execution::admission::AdmissionController)
+#[derive(Debug)]
+struct InformationSchemaAdmissionQueue {
+    schema: SchemaRef,
+}
+
+impl InformationSchemaAdmissionQueue {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("query_id", DataType::UInt64, false),
+            Field::new("session_id", DataType::Utf8, false),
+            Field::new("queue", DataType::Utf8, false),
+            Field::new("priority", DataType::UInt64, false),
+            Field::new("cpu_slots", DataType::UInt64, false),
+            Field::new("state", DataType::Utf8, false),
+            Field::new("wait_time_ms", DataType::UInt64, false),
+        ]));
+
+        Self { schema }
+    }
+
+    fn builder(&self) -> InformationSchemaAdmissionQueueBuilder {
+        InformationSchemaAdmissionQueueBuilder {
+            query_ids: UInt64Builder::new(),
+            session_ids: StringBuilder::new(),
+            queues: StringBuilder::new(),
+            priorities: UInt64Builder::new(),
+            cpu_slots: UInt64Builder::new(),
+            states: StringBuilder::new(),
+            wait_times: UInt64Builder::new(),
+            schema: Arc::clone(&self.schema),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaAdmissionQueue {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::once(async move {
+                if let Some(controller) = &ctx.runtime_env().admission_controller {
+                    for entry in controller.entries() {
+                        builder.add_entry(entry);
+                    }
+                }
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+struct InformationSchemaAdmissionQueueBuilder {
+    schema: SchemaRef,
+    query_ids: UInt64Builder,
+    session_ids: StringBuilder,
+    queues: StringBuilder,
+    priorities: UInt64Builder,
+    cpu_slots: UInt64Builder,
+    states: StringBuilder,
+    wait_times: UInt64Builder,
+}
+
+impl InformationSchemaAdmissionQueueBuilder {
+    fn add_entry(&mut self, entry: AdmissionEntry) {
+        self.query_ids.append_value(entry.query_id);
+        self.session_ids.append_value(entry.request.session_id);
+        self.queues.append_value(entry.request.queue);
+        self.priorities.append_value(entry.request.priority as u64);
+        self.cpu_slots.append_value(entry.request.cpu_slots as u64);
+        self.states.append_value(entry.state.to_string());
+        self.wait_times
+            .append_value(entry.wait_time.as_millis() as u64);
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::clone(&self.schema),
+            vec![
+                Arc::new(self.query_ids.finish()),
+                Arc::new(self.session_ids.finish()),
+                Arc::new(self.queues.finish()),
+                Arc::new(self.priorities.finish()),
+                Arc::new(self.cpu_slots.finish()),
+                Arc::new(self.states.finish()),
+                Arc::new(self.wait_times.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+/// The timezone of the timestamps of the runtime tables
+const UTC: &str = "+00:00";
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Millisecond, Some(UTC.into()))
+}
+
+fn timestamp_builder() -> TimestampMillisecondBuilder {
+    TimestampMillisecondBuilder::new().with_timezone(UTC)
+}
+
+fn timestamp_millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// The running queries of the [`QueryRegistry`] of the session
+#[derive(Debug)]
+struct InformationSchemaRunningQueries {
+    schema: SchemaRef,
+}
+
+impl InformationSchemaRunningQueries {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("query_id", DataType::UInt64, false),
+            Field::new("session_id", DataType::Utf8, false),
+            Field::new("sql", DataType::Utf8, false),
+            Field::new("start_time", timestamp_type(), false),
+            Field::new("elapsed_ms", DataType::UInt64, false),
+            Field::new("output_rows", DataType::UInt64, false),
+        ]));
+
+        Self { schema }
+    }
+
+    fn builder(&self) -> InformationSchemaRunningQueriesBuilder {
+        InformationSchemaRunningQueriesBuilder {
+            query_ids: UInt64Builder::new(),
+            session_ids: StringBuilder::new(),
+            sqls: StringBuilder::new(),
+            start_times: timestamp_builder(),
+            elapsed: UInt64Builder::new(),
+            output_rows: UInt64Builder::new(),
+            schema: Arc::clone(&self.schema),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaRunningQueries {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::once(async move {
+                if let Some(registry) =
+                    ctx.session_config().get_extension::<QueryRegistry>()
+                {
+                    let now = SystemTime::now();
+                    for query in registry.running() {
+                        builder.add_query(query, now);
+                    }
+                }
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+struct InformationSchemaRunningQueriesBuilder {
+    schema: SchemaRef,
+    query_ids: UInt64Builder,
+    session_ids: StringBuilder,
+    sqls: StringBuilder,
+    start_times: TimestampMillisecondBuilder,
+    elapsed: UInt64Builder,
+    output_rows: UInt64Builder,
+}
+
+impl InformationSchemaRunningQueriesBuilder {
+    fn add_query(&mut self, query: RunningQueryInfo, now: SystemTime) {
+        let elapsed = now.duration_since(query.start_time).unwrap_or_default();
+        self.query_ids.append_value(query.query_id);
+        self.session_ids.append_value(query.session_id);
+        self.sqls.append_value(query.sql);
+        self.start_times
+            .append_value(timestamp_millis(query.start_time));
+        self.elapsed.append_value(elapsed.as_millis() as u64);
+        self.output_rows.append_value(query.output_rows as u64);
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::clone(&self.schema),
+            vec![
+                Arc::new(self.query_ids.finish()),
+                Arc::new(self.session_ids.finish()),
+                Arc::new(self.sqls.finish()),
+                Arc::new(self.start_times.finish()),
+                Arc::new(self.elapsed.finish()),
+                Arc::new(self.output_rows.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+/// The completed queries of the [`QueryRegistry`] of the session
+#[derive(Debug)]
+struct InformationSchemaQueryHistory {
+    schema: SchemaRef,
+}
+
+impl InformationSchemaQueryHistory {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("query_id", DataType::UInt64, false),
+            Field::new("session_id", DataType::Utf8, false),
+            Field::new("sql", DataType::Utf8, false),
+            Field::new("start_time", timestamp_type(), false),
+            Field::new("end_time", timestamp_type(), false),
+            Field::new("status", DataType::Utf8, false),
+            Field::new("error", DataType::Utf8, true),
+            Field::new("output_rows", DataType::UInt64, false),
+            Field::new("elapsed_compute_ns", DataType::UInt64, false),
+            Field::new("spill_count", DataType::UInt64, false),
+            Field::new("spilled_bytes", DataType::UInt64, false),
+        ]));
+
+        Self { schema }
+    }
+
+    fn builder(&self) -> InformationSchemaQueryHistoryBuilder {
+        InformationSchemaQueryHistoryBuilder {
+            query_ids: UInt64Builder::new(),
+            session_ids: StringBuilder::new(),
+            sqls: StringBuilder::new(),
+            start_times: timestamp_builder(),
+            end_times: timestamp_builder(),
+            statuses: StringBuilder::new(),
+            errors: StringBuilder::new(),
+            output_rows: UInt64Builder::new(),
+            elapsed_compute: UInt64Builder::new(),
+            spill_counts: UInt64Builder::new(),
+            spilled_bytes: UInt64Builder::new(),
+            schema: Arc::clone(&self.schema),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaQueryHistory {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::once(async move {
+                if let Some(registry) =
+                    ctx.session_config().get_extension::<QueryRegistry>()
+                {
+                    for query in registry.history() {
+                        builder.add_query(query);
+                    }
+                }
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+struct InformationSchemaQueryHistoryBuilder {
+    schema: SchemaRef,
+    query_ids: UInt64Builder,
+    session_ids: StringBuilder,
+    sqls: StringBuilder,
+    start_times: TimestampMillisecondBuilder,
+    end_times: TimestampMillisecondBuilder,
+    statuses: StringBuilder,
+    errors: StringBuilder,
+    output_rows: UInt64Builder,
+    elapsed_compute: UInt64Builder,
+    spill_counts: UInt64Builder,
+    spilled_bytes: UInt64Builder,
+}
+
+impl InformationSchemaQueryHistoryBuilder {
+    fn add_query(&mut self, query: QueryRecord) {
+        self.query_ids.append_value(query.query_id);
+        self.session_ids.append_value(query.session_id);
+        self.sqls.append_value(query.sql);
+        self.start_times
+            .append_value(timestamp_millis(query.start_time));
+        self.end_times
+            .append_value(timestamp_millis(query.end_time));
+        self.statuses.append_value(query.status.to_string());
+        self.errors.append_option(query.error);
+        self.output_rows.append_value(query.output_rows as u64);
+        self.elapsed_compute
+            .append_value(query.metrics.elapsed_compute as u64);
+        self.spill_counts
+            .append_value(query.metrics.spill_count as u64);
+        self.spilled_bytes
+            .append_value(query.metrics.spilled_bytes as u64);
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::clone(&self.schema),
+            vec![
+                Arc::new(self.query_ids.finish()),
+                Arc::new(self.session_ids.finish()),
+                Arc::new(self.sqls.finish()),
+                Arc::new(self.start_times.finish()),
+                Arc::new(self.end_times.finish()),
+                Arc::new(self.statuses.finish()),
+                Arc::new(self.errors.finish()),
+                Arc::new(self.output_rows.finish()),
+                Arc::new(self.elapsed_compute.finish()),
+                Arc::new(self.spill_counts.finish()),
+                Arc::new(self.spilled_bytes.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+/// The consumers of the memory pool of the runtime, see
+/// [`MemoryPool::consumers`](datafusion_execution::memory_pool::MemoryPool::consumers)
+///
+/// Querying the table fails if the pool does not track its consumers.
+#[derive(Debug)]
+struct InformationSchemaMemoryConsumers {
+    schema: SchemaRef,
+}
+
+impl InformationSchemaMemoryConsumers {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("can_spill", DataType::Boolean, false),
+            Field::new("reserved", DataType::UInt64, false),
+        ]));
+
+        Self { schema }
+    }
+
+    fn builder(&self) -> InformationSchemaMemoryConsumersBuilder {
+        InformationSchemaMemoryConsumersBuilder {
+            names: StringBuilder::new(),
+            can_spill: BooleanBuilder::new(),
+            reserved: UInt64Builder::new(),
+            schema: Arc::clone(&self.schema),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaMemoryConsumers {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::once(async move {
+                let Some(mut consumers) = ctx.runtime_env().memory_pool.consumers()
+                else {
+                    return exec_err!(
+                        "The memory pool of the runtime does not track its consumers, \
+                         use a TrackConsumersPool to list them in memory_consumers"
+                    );
+                };
+                consumers.sort_by(|a, b| b.1.cmp(&a.1));
+                for (consumer, reserved) in consumers {
+                    builder.add_consumer(&consumer, reserved);
+                }
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+struct InformationSchemaMemoryConsumersBuilder {
+    schema: SchemaRef,
+    names: StringBuilder,
+    can_spill: BooleanBuilder,
+    reserved: UInt64Builder,
+}
+
+impl InformationSchemaMemoryConsumersBuilder {
+    fn add_consumer(&mut self, consumer: &MemoryConsumer, reserved: usize) {
+        self.names.append_value(consumer.name());
+        self.can_spill.append_value(consumer.can_spill());
+        self.reserved.append_value(reserved as u64);
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::clone(&self.schema),
+            vec![
+                Arc::new(self.names.finish()),
+                Arc::new(self.can_spill.finish()),
+                Arc::new(self.reserved.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+/// The entries of the list files and file statistics caches of the runtime
+#[derive(Debug)]
+struct InformationSchemaCaches {
+    schema: SchemaRef,
+}
+
+impl InformationSchemaCaches {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("cache_type", DataType::Utf8, false),
+            Field::new("cache_name", DataType::Utf8, false),
+            Field::new("path", DataType::Utf8, false),
+        ]));
+
+        Self { schema }
+    }
+
+    fn builder(&self) -> InformationSchemaCachesBuilder {
+        InformationSchemaCachesBuilder {
+            cache_types: StringBuilder::new(),
+            cache_names: StringBuilder::new(),
+            paths: StringBuilder::new(),
+            schema: Arc::clone(&self.schema),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaCaches {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::once(async move {
+                let cache_manager = &ctx.runtime_env().cache_manager;
+                if let Some(cache) = cache_manager.get_list_files_cache() {
+                    builder.add_cache("list_files", cache.name(), cache.keys());
+                }
+                if let Some(cache) = cache_manager.get_file_statistic_cache() {
+                    builder.add_cache("file_statistics", cache.name(), cache.keys());
+                }
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+struct InformationSchemaCachesBuilder {
+    schema: SchemaRef,
+    cache_types: StringBuilder,
+    cache_names: StringBuilder,
+    paths: StringBuilder,
+}
+
+impl InformationSchemaCachesBuilder {
+    fn add_cache<P: AsRef<str> + Ord>(
+        &mut self,
+        cache_type: &str,
+        cache_name: String,
+        mut paths: Vec<P>,
+    ) {
+        paths.sort();
+        for path in paths {
+            self.cache_types.append_value(cache_type);
+            self.cache_names.append_value(&cache_name);
+            self.paths.append_value(path.as_ref());
+        }
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::clone(&self.schema),
+            vec![
+                Arc::new(self.cache_types.finish()),
+                Arc::new(self.cache_names.finish()),
+                Arc::new(self.paths.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
@@ -30,6 +30,7 @@ use datafusion_expr::Expr;
 use datafusion_expr::dml::InsertOp;
 use datafusion_expr::{
     CreateExternalTable, LogicalPlan, TableProviderFilterPushDown, TableType,
+    TableVersion,
 };
 use datafusion_physical_plan::ExecutionPlan;
 
@@ -297,6 +298,22 @@ pub trait TableProvider: Debug + Sync + Send {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         not_impl_err!("Insert into not implemented for this table")
     }
+
+    /// Return a provider reading the previous `version` of this table, such
+    /// as a snapshot of a table whose files are read from a manifest.
+    ///
+    /// This is used to plan `FOR SYSTEM_TIME AS OF` in SQL, where an integer
+    /// is a [`TableVersion::Version`] and a timestamp is a
+    /// [`TableVersion::Timestamp`].
+    fn at_version(&self, _version: &TableVersion) -> Result<Arc<dyn TableProvider>> {
+        not_impl_err!("Versions are not supported for this table")
+    }
+
+    /// Get the version of the table read by this provider, if it reads a
+    /// previous version of the table, see [`Self::at_version`]
+    fn table_version(&self) -> Option<&TableVersion> {
+        None
+    }
 }
 
 /// A factory which creates [`TableProvider`]s at runtime given a URL.
@@ -18,11 +18,16 @@
 use std::future::Future;
 
 use tokio::task::{JoinError, JoinSet};
+use tracing::{Instrument, Span};
 
 /// Helper that  provides a simple API to spawn a single task and join it.
 /// Provides guarantees of aborting on `Drop` to keep it cancel-safe.
 ///
 /// Technically, it's just a wrapper of `JoinSet` (with size=1).
+///
+/// The task runs in the [`tracing`] span that was current when it was
+/// spawned, so spans it creates are nested under the spawning operator
+/// rather than appearing as new roots.
 #[derive(Debug)]
 pub struct SpawnedTask<R> {
     inner: JoinSet<R>,
@@ -36,7 +41,7 @@ impl<R: 'static> SpawnedTask<R> {
         R: Send,
     {
         let mut inner = JoinSet::new();
-        inner.spawn(task);
+        inner.spawn(task.in_current_span());
         Self { inner }
     }
 
@@ -46,8 +51,9 @@ impl<R: 'static> SpawnedTask<R> {
         T: Send + 'static,
         R: Send,
     {
+        let span = Span::current();
         let mut inner = JoinSet::new();
-        inner.spawn_blocking(task);
+        inner.spawn_blocking(move || span.in_scope(task));
         Self { inner }
     }
 
@@ -81,8 +87,13 @@ mod tests {
     use super::*;
 
     use std::future::{pending, Pending};
+    use std::sync::{Arc, Mutex};
 
     use tokio::runtime::Runtime;
+    use tracing::span::{Attributes, Id};
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    use tracing_subscriber::registry::{LookupSpan, Registry};
 
     #[tokio::test]
     async fn runtime_shutdown() {
@@ -118,4 +129,51 @@ mod tests {
             .await
             .ok();
     }
+
+    /// Records every new span as `parent/name`, or just `name` for roots
+    #[derive(Debug, Clone, Default)]
+    struct SpanParents(Arc<Mutex<Vec<String>>>);
+
+    impl<S> Layer<S> for SpanParents
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let span = ctx.span(id).unwrap();
+            let path = match span.parent() {
+                Some(parent) => format!("{}/{}", parent.name(), span.name()),
+                None => span.name().to_string(),
+            };
+            self.0.lock().unwrap().push(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn propagates_current_span() {
+        let parents = SpanParents::default();
+        // blocking tasks run on another thread, so a thread local default
+        // subscriber would not see their spans
+        tracing::subscriber::set_global_default(
+            Registry::default().with(parents.clone()),
+        )
+        .unwrap();
+
+        let outer = tracing::info_span!("outer");
+        let task = outer.in_scope(|| {
+            SpawnedTask::spawn(async {
+                let _span = tracing::info_span!("async_child");
+            })
+        });
+        task.join().await.unwrap();
+        let task = outer.in_scope(|| {
+            SpawnedTask::spawn_blocking(|| {
+                let _span = tracing::info_span!("blocking_child");
+            })
+        });
+        task.join().await.unwrap();
+
+        let parents = parents.0.lock().unwrap();
+        assert!(parents.contains(&"outer/async_child".to_string()));
+        assert!(parents.contains(&"outer/blocking_child".to_string()));
+    }
 }
@@ -32,7 +32,8 @@ use crate::{DataFusionError, Result};
 /// [`Default`] and [`ConfigField`] for it, allowing it to be used
 /// in the [`ConfigOptions`] configuration tree.
 ///
-/// `transform` is used to normalize values before parsing.
+/// `transform` is used to normalize values before parsing, and `validate`
+/// to reject values, by returning an error, before they are set.
 ///
 /// For example,
 ///
@@ -122,6 +123,7 @@ macro_rules! config_namespace {
                 $field_vis:vis $field_name:ident : $field_type:ty,
                 $(warn = $warn:expr,)?
                 $(transform = $transform:expr,)?
+                $(validate = $validate:expr,)?
                 default = $default:expr
             )*$(,)*
         }
@@ -149,6 +151,7 @@ macro_rules! config_namespace {
                             // $(#[allow(deprecated)])?
                             {
                                 $(let value = $transform(value);)? // Apply transformation if specified
+                                $($validate(value.as_ref())?;)? // Reject invalid values if specified
                                 $(log::warn!($warn);)? // Log warning if specified
                                 #[allow(deprecated)]
                                 self.$field_name.set(rem, value.as_ref())
@@ -324,6 +327,13 @@ config_namespace! {
         /// (i.e., if there's no `DiskManager` configured).
         pub sort_spill_reservation_bytes: usize, default = 10 * 1024 * 1024
 
+        /// Sets the compression codec used when spilling data to disk.
+        ///
+        /// Valid values are: `uncompressed`, `lz4_frame` and `zstd`. Compressed
+        /// spill files use less disk space and I/O at the cost of the CPU time
+        /// to compress and decompress the spilled data.
+        pub spill_compression: SpillCompression, default = SpillCompression::Uncompressed
+
         /// When sorting, below what size should data be concatenated
         /// and sorted in a single RecordBatch rather than sorted in
         /// batches and merged.
@@ -350,12 +360,49 @@ config_namespace! {
         /// memory consumption
         pub max_buffered_batches_per_output_file: usize, default = 2
 
+        /// When inserting an unbounded input into a table, each file being
+        /// written is committed, and a new file started, once it contains at
+        /// least this many rows. The limit applies to each file on its own,
+        /// such as the file of each partition of a table partitioned with
+        /// `PARTITIONED BY`. At least one of the `streaming_write_*` options
+        /// must be set to insert an unbounded input into a table.
+        pub streaming_write_max_rows_per_file: Option<usize>, default = None
+
+        /// When inserting an unbounded input into a table, each file being
+        /// written is committed, and a new file started, once the in-memory
+        /// size of its rows reaches this many bytes
+        pub streaming_write_max_bytes_per_file: Option<usize>, default = None
+
+        /// When inserting an unbounded input into a table, each file being
+        /// written is committed, and a new file started, this many
+        /// milliseconds after its first row is received
+        pub streaming_write_rollover_interval_ms: Option<u64>, default = None
+
         /// Should sub directories be ignored when scanning directories for data
         /// files. Defaults to true (ignores subdirectories), consistent with
         /// Hive. Note that this setting does not affect reading partitioned
         /// tables (e.g. `/table/year=2021/month=01/data.parquet`).
         pub listing_table_ignore_subdirectory: bool, default = true
 
+        /// Should the sort order of the files of a listing table be inferred
+        /// from the sort order recorded in their metadata, such as by the
+        /// Parquet files written by DataFusion, when the files are read to
+        /// infer the schema of the table and it does not declare a sort order
+        /// with `WITH ORDER`. The inferred sort order is only used by the
+        /// scans of files that all record it in their metadata
+        pub listing_table_infer_file_sort_order: bool, default = true
+
+        /// Should the types of partition columns be inferred from their values
+        /// in the directory names when inferring the partitions of a listing
+        /// table from its path, such as for `SELECT * FROM '/table/'`. If true,
+        /// the directories of all the files of the table are listed, and
+        /// partition values that are all integers, dates or timestamps are typed
+        /// as such, e.g. `Date32` for `/table/dt=2024-01-01/data.parquet` and
+        /// `Int64` for `/table/hour=07/data.parquet`. Dates and timestamps must
+        /// be formatted as such, e.g. `dt=2024-1-1` is a string. If false,
+        /// partition columns are typed as dictionary encoded strings.
+        pub listing_table_infer_partition_types: bool, default = true
+
         /// Should DataFusion support recursive CTEs
         pub enable_recursive_ctes: bool, default = true
 
@@ -389,6 +436,21 @@ config_namespace! {
         /// in joins can reduce memory usage when joining large
         /// tables with a highly-selective join filter, but is also slightly slower.
         pub enforce_batch_size_in_joins: bool, default = false
+
+        /// Maximum time in milliseconds a query may run before it is cancelled
+        /// with a timeout error. Operators check for the timeout at batch
+        /// boundaries, and output streams waiting for their input end once it
+        /// elapses. `0` means no timeout
+        pub statement_timeout: usize, default = 0
+
+        /// Name of the admission queue of the queries of this session. If the
+        /// runtime has an admission controller, the number of running queries
+        /// of each queue can be limited separately
+        pub admission_queue: String, default = "default".to_string()
+
+        /// Priority of the queries of this session in the admission queue.
+        /// Queued queries with a higher priority are admitted first
+        pub admission_priority: usize, default = 0
     }
 }
 
@@ -683,6 +745,14 @@ config_namespace! {
         /// then the output will be coerced to a non-view.
         /// Coerces `Utf8View` to `LargeUtf8`, and `BinaryView` to `LargeBinary`.
         pub expand_views_at_output: bool, default = false
+
+        /// When set to true, the optimizer will answer queries, or parts of
+        /// queries, from the results of a materialized view whose definition
+        /// subsumes them. Before planning a query, the files of the tables
+        /// read by the views are listed to detect changes since the views
+        /// were refreshed, and views reading tables other than listing or
+        /// memory tables are not used
+        pub enable_materialized_view_rewrite: bool, default = false
     }
 }
 
@@ -708,6 +778,46 @@ config_namespace! {
 
         /// When set to true, the explain statement will print schema information
         pub show_schema: bool, default = false
+
+        /// Display format of explain. Default is "indent". "indent" prints one line
+        /// per node, "tree" prints a box per node with its key properties and
+        /// "json" prints a JSON document. `EXPLAIN FORMAT <format>` overrides this
+        /// for a single statement
+        pub format: String, default = "indent".to_string()
+    }
+}
+
+config_namespace! {
+    /// Options related to the resources used by queries at runtime
+    ///
+    /// See also: [`SessionConfig`]
+    ///
+    /// [`SessionConfig`]: https://docs.rs/datafusion/latest/datafusion/prelude/struct.SessionConfig.html
+    pub struct RuntimeOptions {
+        /// Maximum memory each query of the session may reserve, such as `512M`
+        /// or `2G`. Each query reserves its memory from its own child of the
+        /// runtime's memory pool, so the runtime's limit still applies to all
+        /// queries together. If not set or `0`, there is no per-query limit.
+        pub memory_limit: Option<String>, validate = parse_memory_limit, default = None
+    }
+}
+
+/// Parses a memory size such as `1024`, `512K`, `100M` or `1.5G` into a
+/// number of bytes, as used by `datafusion.runtime.memory_limit`
+pub fn parse_memory_limit(limit: &str) -> Result<usize> {
+    let limit = limit.trim();
+    let (number, multiplier) = match limit.chars().last().map(|c| c.to_ascii_uppercase())
+    {
+        Some('K') => (&limit[..limit.len() - 1], 1024),
+        Some('M') => (&limit[..limit.len() - 1], 1024 * 1024),
+        Some('G') => (&limit[..limit.len() - 1], 1024 * 1024 * 1024),
+        _ => (limit, 1),
+    };
+    match number.parse::<f64>() {
+        Ok(number) if number.is_finite() && number >= 0.0 => Ok((number * multiplier as f64) as usize),
+        _ => _config_err!(
+            "Invalid value '{limit}' for datafusion.runtime.memory_limit, expected a number of bytes optionally followed by one of 'K', 'M' or 'G'"
+        ),
     }
 }
 
@@ -738,6 +848,8 @@ pub struct ConfigOptions {
     pub sql_parser: SqlParserOptions,
     /// Explain options
     pub explain: ExplainOptions,
+    /// Runtime options
+    pub runtime: RuntimeOptions,
     /// Optional extensions registered using [`Extensions::insert`]
     pub extensions: Extensions,
 }
@@ -752,6 +864,7 @@ impl ConfigField for ConfigOptions {
             "optimizer" => self.optimizer.set(rem, value),
             "explain" => self.explain.set(rem, value),
             "sql_parser" => self.sql_parser.set(rem, value),
+            "runtime" => self.runtime.set(rem, value),
             _ => _config_err!("Config value \"{key}\" not found on ConfigOptions"),
         }
     }
@@ -762,6 +875,7 @@ impl ConfigField for ConfigOptions {
         self.optimizer.visit(v, "datafusion.optimizer", "");
         self.explain.visit(v, "datafusion.explain", "");
         self.sql_parser.visit(v, "datafusion.sql_parser", "");
+        self.runtime.visit(v, "datafusion.runtime", "");
     }
 }
 
@@ -1144,6 +1258,55 @@ impl ConfigField for CompressionTypeVariant {
     }
 }
 
+/// Compression codec of spill files, see `datafusion.execution.spill_compression`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SpillCompression {
+    /// Spill files are not compressed
+    #[default]
+    Uncompressed,
+    /// Spill files are compressed with LZ4 frames
+    Lz4Frame,
+    /// Spill files are compressed with ZSTD
+    Zstd,
+}
+
+impl FromStr for SpillCompression {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "uncompressed" | "" => Ok(Self::Uncompressed),
+            "lz4_frame" => Ok(Self::Lz4Frame),
+            "zstd" => Ok(Self::Zstd),
+            _ => _config_err!(
+                "Invalid spill compression: {s}. Expected one of: uncompressed, lz4_frame, zstd"
+            ),
+        }
+    }
+}
+
+impl Display for SpillCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Uncompressed => "uncompressed",
+            Self::Lz4Frame => "lz4_frame",
+            Self::Zstd => "zstd",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl ConfigField for SpillCompression {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = SpillCompression::from_str(value)?;
+        Ok(())
+    }
+}
+
 /// An implementation trait used to recursively walk configuration
 pub trait Visit {
     fn some<V: Display>(&mut self, key: &str, value: V, description: &'static str);
@@ -2134,4 +2297,33 @@ mod tests {
         let parsed_metadata = table_config.parquet.key_value_metadata;
         assert_eq!(parsed_metadata.get("key_dupe"), Some(&Some("B".into())));
     }
+
+    #[test]
+    fn runtime_memory_limit() {
+        use crate::config::{parse_memory_limit, ConfigOptions};
+
+        assert_eq!(parse_memory_limit("1024").unwrap(), 1024);
+        assert_eq!(parse_memory_limit("512k").unwrap(), 512 * 1024);
+        assert_eq!(parse_memory_limit("1.5G").unwrap(), 1536 * 1024 * 1024);
+
+        let mut options = ConfigOptions::new();
+        options
+            .set("datafusion.runtime.memory_limit", "2G")
+            .unwrap();
+        assert_eq!(options.runtime.memory_limit.as_deref(), Some("2G"));
+
+        // invalid limits are rejected rather than ignored
+        for limit in ["10XB", "-1G", "lots", "inf"] {
+            let err = options
+                .set("datafusion.runtime.memory_limit", limit)
+                .unwrap_err();
+            assert!(
+                err.to_string().contains(&format!(
+                    "Invalid value '{limit}' for datafusion.runtime.memory_limit"
+                )),
+                "{err}"
+            );
+        }
+        assert_eq!(options.runtime.memory_limit.as_deref(), Some("2G"));
+    }
 }
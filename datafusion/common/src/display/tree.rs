@@ -0,0 +1,381 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Logic related to drawing plans as trees of boxes.
+
+use std::fmt;
+
+/// Width of the box of a node, including its borders
+const NODE_WIDTH: usize = 29;
+/// Maximum width of a line of text inside the box of a node
+const TEXT_WIDTH: usize = NODE_WIDTH - 4;
+/// Position of the connectors between a node and its parent and children
+const CENTER: usize = NODE_WIDTH / 2;
+
+/// A node of a plan drawn as a box, with the boxes of its children below it.
+///
+/// The [`Display`](fmt::Display) implementation draws the tree rooted at the
+/// node. Nodes of the same depth are drawn next to each other, for example
+///
+/// ```text
+/// ┌───────────────────────────┐
+/// │        HashJoinExec       ├──────────────┐
+/// │    ────────────────────   │              │
+/// │      join_type=Inner      │              │
+/// │      on=[(a@0, b@0)]      │              │
+/// └─────────────┬─────────────┘              │
+/// ┌─────────────┴─────────────┐┌─────────────┴─────────────┐
+/// │       DataSourceExec      ││       DataSourceExec      │
+/// └───────────────────────────┘└───────────────────────────┘
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeRenderNode {
+    /// The name of the node, drawn at the top of its box
+    pub name: String,
+    /// The properties of the node, drawn below its name. Properties that do
+    /// not fit into the box are wrapped
+    pub properties: Vec<String>,
+    /// The inputs of the node
+    pub children: Vec<TreeRenderNode>,
+}
+
+impl TreeRenderNode {
+    /// Create a new node without children
+    pub fn new(name: impl Into<String>, properties: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            properties,
+            children: vec![],
+        }
+    }
+
+    /// Create a new node without children from the single line description
+    /// of a plan node, see [`split_node_description`]
+    pub fn from_description(description: &str, name: Option<&str>) -> Self {
+        let (name, details) = split_node_description(description, name);
+        Self::new(name, details.into_iter().map(str::to_string).collect())
+    }
+
+    /// The lines of text inside the box of this node
+    fn text_lines(&self) -> Vec<String> {
+        let mut lines = wrap(&self.name, TEXT_WIDTH);
+        if !self.properties.is_empty() {
+            lines.push("─".repeat(TEXT_WIDTH - 5));
+            for property in &self.properties {
+                lines.extend(wrap(property, TEXT_WIDTH));
+            }
+        }
+        lines
+    }
+}
+
+/// Splits the single line description of a plan node, such as
+/// `HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, b@0)]`, into
+/// the name of the node and its top level comma separated details, such as
+/// `mode=Partitioned`, `join_type=Inner` and `on=[(a@0, b@0)]`.
+///
+/// `name` is the name of the node if it is known; otherwise the text before
+/// the first `": "` is used as the name.
+pub fn split_node_description<'a>(
+    description: &'a str,
+    name: Option<&'a str>,
+) -> (&'a str, Vec<&'a str>) {
+    let (name, details) =
+        match name.and_then(|name| Some((name, description.strip_prefix(name)?))) {
+            Some((name, details)) => (name, details.trim_start_matches(':')),
+            None => description.split_once(": ").unwrap_or((description, "")),
+        };
+
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in details.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(details[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(details[start..].trim());
+    parts.retain(|part| !part.is_empty());
+
+    (name.trim(), parts)
+}
+
+/// Splits `text` into lines of at most `width` characters, breaking at spaces
+/// where possible
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut line_width = 0;
+    for mut word in text.split(' ').filter(|word| !word.is_empty()) {
+        loop {
+            let word_width = word.chars().count();
+            if line_width == 0 && word_width <= width {
+                line.push_str(word);
+                line_width = word_width;
+                break;
+            } else if line_width > 0 && line_width + 1 + word_width <= width {
+                line.push(' ');
+                line.push_str(word);
+                line_width += 1 + word_width;
+                break;
+            } else if line_width > 0 {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            } else {
+                // the word does not fit into a line of its own: break it
+                // after the last `=` or `,` that fits, if any
+                let (end, _) = word.char_indices().nth(width).unwrap();
+                let split = word[..end].rfind(['=', ',']).map(|i| i + 1).unwrap_or(end);
+                lines.push(word[..split].to_string());
+                word = &word[split..];
+            }
+        }
+    }
+    if line_width > 0 || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// A node of the tree with its position in the drawing
+struct PlacedNode {
+    /// The column of the node
+    x: usize,
+    /// The columns of the children of the node
+    child_xs: Vec<usize>,
+    /// The lines of text inside the box of the node
+    lines: Vec<String>,
+}
+
+/// Places `node` and its children at column `x` and row `depth` and further,
+/// returning the number of columns used
+fn place(
+    node: &TreeRenderNode,
+    x: usize,
+    depth: usize,
+    rows: &mut Vec<Vec<PlacedNode>>,
+) -> usize {
+    let mut child_xs = Vec::with_capacity(node.children.len());
+    let mut next_x = x;
+    for child in &node.children {
+        child_xs.push(next_x);
+        next_x += place(child, next_x, depth + 1, rows);
+    }
+
+    if rows.len() <= depth {
+        rows.resize_with(depth + 1, Vec::new);
+    }
+    rows[depth].push(PlacedNode {
+        x,
+        child_xs,
+        lines: node.text_lines(),
+    });
+
+    (next_x - x).max(1)
+}
+
+/// A border of a box, with an optional connector in the middle
+fn border(left: char, middle: Option<char>, right: char) -> String {
+    let mut border = String::with_capacity(NODE_WIDTH * 3);
+    border.push(left);
+    match middle {
+        Some(middle) => {
+            border.push_str(&"─".repeat(CENTER - 1));
+            border.push(middle);
+            border.push_str(&"─".repeat(NODE_WIDTH - CENTER - 2));
+        }
+        None => border.push_str(&"─".repeat(NODE_WIDTH - 2)),
+    }
+    border.push(right);
+    border
+}
+
+/// A vertical connector in the middle of an otherwise empty cell
+fn vertical_connector() -> String {
+    format!(
+        "{}│{}",
+        " ".repeat(CENTER),
+        " ".repeat(NODE_WIDTH - CENTER - 1)
+    )
+}
+
+impl fmt::Display for TreeRenderNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows = vec![];
+        let width = place(self, 0, 0, &mut rows);
+
+        for (depth, row) in rows.iter_mut().enumerate() {
+            row.sort_by_key(|node| node.x);
+            let height = row.iter().map(|node| node.lines.len()).max().unwrap_or(0);
+
+            // the top border, the lines of text and the bottom border
+            let mut cells = vec![vec![" ".repeat(NODE_WIDTH); width]; height + 2];
+            for node in row.iter() {
+                let x = node.x;
+                let top = if depth == 0 { None } else { Some('┴') };
+                cells[0][x] = border('┌', top, '┐');
+                for line in 0..height {
+                    let text = node.lines.get(line).map(String::as_str).unwrap_or("");
+                    let padding = NODE_WIDTH - 2 - text.chars().count();
+                    cells[line + 1][x] = format!(
+                        "│{}{text}{}│",
+                        " ".repeat(padding - padding / 2),
+                        " ".repeat(padding / 2)
+                    );
+                }
+                let bottom = (!node.child_xs.is_empty()).then_some('┬');
+                cells[height + 1][x] = border('└', bottom, '┘');
+
+                // connect the children after the first one from the right
+                // side of the box
+                if let Some((&last_x, _)) = node.child_xs.split_last() {
+                    if last_x == x {
+                        continue;
+                    }
+                    let first_line = &mut cells[1][x];
+                    first_line.pop();
+                    first_line.push('├');
+                    for column in x + 1..=last_x {
+                        cells[1][column] = if column == last_x {
+                            format!("{}┐{}", "─".repeat(CENTER), " ".repeat(CENTER))
+                        } else if node.child_xs.contains(&column) {
+                            format!("{}┬{}", "─".repeat(CENTER), "─".repeat(CENTER))
+                        } else {
+                            "─".repeat(NODE_WIDTH)
+                        };
+                    }
+                    for line in &mut cells[2..] {
+                        for &child_x in &node.child_xs[1..] {
+                            line[child_x] = vertical_connector();
+                        }
+                    }
+                }
+            }
+
+            for line in cells {
+                writeln!(f, "{}", line.concat().trim_end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_node_description() {
+        assert_eq!(
+            split_node_description(
+                "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, b@0)]",
+                Some("HashJoinExec")
+            ),
+            (
+                "HashJoinExec",
+                vec!["mode=Partitioned", "join_type=Inner", "on=[(a@0, b@0)]"]
+            )
+        );
+        assert_eq!(
+            split_node_description("AnalyzeExec verbose=false", Some("AnalyzeExec")),
+            ("AnalyzeExec", vec!["verbose=false"])
+        );
+        assert_eq!(
+            split_node_description("Filter: t.a = Utf8(\"x, y\")", None),
+            ("Filter", vec!["t.a = Utf8(\"x, y\")"])
+        );
+        assert_eq!(
+            split_node_description("EmptyRelation", None),
+            ("EmptyRelation", vec![])
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("", 5), vec![""]);
+        assert_eq!(wrap("a b c", 3), vec!["a b", "c"]);
+        assert_eq!(wrap("abcdefg hi", 3), vec!["abc", "def", "g", "hi"]);
+        assert_eq!(wrap("ää ää", 5), vec!["ää ää"]);
+        assert_eq!(
+            wrap("preserve_partitioning=[false]", 25),
+            vec!["preserve_partitioning=", "[false]"]
+        );
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let mut join = TreeRenderNode::new(
+            "HashJoinExec",
+            vec!["join_type=Inner".to_string(), "on=[(a@0, b@0)]".to_string()],
+        );
+        join.children = vec![
+            TreeRenderNode::new("DataSourceExec", vec![]),
+            TreeRenderNode::new("DataSourceExec", vec![]),
+        ];
+        let mut root = TreeRenderNode::new("ProjectionExec", vec![]);
+        root.children = vec![join];
+
+        let expected = "\
+┌───────────────────────────┐
+│       ProjectionExec      │
+└─────────────┬─────────────┘
+┌─────────────┴─────────────┐
+│        HashJoinExec       ├──────────────┐
+│    ────────────────────   │              │
+│      join_type=Inner      │              │
+│      on=[(a@0, b@0)]      │              │
+└─────────────┬─────────────┘              │
+┌─────────────┴─────────────┐┌─────────────┴─────────────┐
+│       DataSourceExec      ││       DataSourceExec      │
+└───────────────────────────┘└───────────────────────────┘
+";
+        assert_eq!(root.to_string(), expected);
+    }
+
+    #[test]
+    fn test_render_wide_tree() {
+        let mut union = TreeRenderNode::new("UnionExec", vec![]);
+        for name in ["a", "b", "c"] {
+            let mut child = TreeRenderNode::new("ProjectionExec", vec![]);
+            child.children = vec![TreeRenderNode::new(name, vec![])];
+            union.children.push(child);
+        }
+
+        let expected = "\
+┌───────────────────────────┐
+│         UnionExec         ├──────────────┬────────────────────────────┐
+└─────────────┬─────────────┘              │                            │
+┌─────────────┴─────────────┐┌─────────────┴─────────────┐┌─────────────┴─────────────┐
+│       ProjectionExec      ││       ProjectionExec      ││       ProjectionExec      │
+└─────────────┬─────────────┘└─────────────┬─────────────┘└─────────────┬─────────────┘
+┌─────────────┴─────────────┐┌─────────────┴─────────────┐┌─────────────┴─────────────┐
+│             a             ││             b             ││             c             │
+└───────────────────────────┘└───────────────────────────┘└───────────────────────────┘
+";
+        assert_eq!(union.to_string(), expected);
+    }
+}
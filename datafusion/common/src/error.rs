@@ -26,6 +26,7 @@ use std::fmt::{Display, Formatter};
 use std::io;
 use std::result;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::utils::datafusion_strsim::normalized_levenshtein;
 use crate::utils::quote_identifier;
@@ -124,6 +125,12 @@ pub enum DataFusionError {
     /// This error is thrown when a consumer cannot acquire additional memory
     /// or other resources needed to execute the query from the Memory Manager.
     ResourcesExhausted(String),
+    /// Error when a query is stopped before it completes.
+    ///
+    /// This error is returned by operators that observe that the query was
+    /// cancelled or ran longer than the configured `statement_timeout`.
+    /// The [`CancellationReason`] tells which of the two happened.
+    Cancelled(CancellationReason),
     /// Errors originating from outside DataFusion's core codebase.
     ///
     /// For example, a custom S3Error from the crate datafusion-objectstore-s3
@@ -262,6 +269,26 @@ impl Display for SchemaError {
 
 impl Error for SchemaError {}
 
+/// The reason a query was stopped, see [`DataFusionError::Cancelled`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancellationReason {
+    /// The query was explicitly cancelled
+    Cancelled,
+    /// The query ran longer than the given timeout
+    Timeout(Duration),
+}
+
+impl Display for CancellationReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cancelled => write!(f, "query was cancelled"),
+            Self::Timeout(timeout) => {
+                write!(f, "query exceeded the statement timeout of {timeout:?}")
+            }
+        }
+    }
+}
+
 impl From<std::fmt::Error> for DataFusionError {
     fn from(_e: std::fmt::Error) -> Self {
         DataFusionError::Execution("Fail to format".to_string())
@@ -378,6 +405,7 @@ impl Error for DataFusionError {
             DataFusionError::Execution(_) => None,
             DataFusionError::ExecutionJoin(e) => Some(e),
             DataFusionError::ResourcesExhausted(_) => None,
+            DataFusionError::Cancelled(_) => None,
             DataFusionError::External(e) => Some(e.as_ref()),
             DataFusionError::Context(_, e) => Some(e.as_ref()),
             DataFusionError::Substrait(_) => None,
@@ -512,6 +540,7 @@ impl DataFusionError {
             DataFusionError::ResourcesExhausted(_) => {
                 "Resources exhausted: "
             }
+            DataFusionError::Cancelled(_) => "Query cancelled: ",
             DataFusionError::External(_) => "External error: ",
             DataFusionError::Context(_, _) => "",
             DataFusionError::Substrait(_) => "Substrait error: ",
@@ -553,6 +582,7 @@ impl DataFusionError {
             DataFusionError::Execution(ref desc) => Cow::Owned(desc.to_string()),
             DataFusionError::ExecutionJoin(ref desc) => Cow::Owned(desc.to_string()),
             DataFusionError::ResourcesExhausted(ref desc) => Cow::Owned(desc.to_string()),
+            DataFusionError::Cancelled(ref reason) => Cow::Owned(reason.to_string()),
             DataFusionError::External(ref desc) => Cow::Owned(desc.to_string()),
             #[cfg(feature = "object_store")]
             DataFusionError::ObjectStore(ref desc) => Cow::Owned(desc.to_string()),
@@ -915,8 +945,9 @@ pub fn add_possible_columns_to_diag(
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
+    use std::time::Duration;
 
-    use crate::error::{DataFusionError, GenericError};
+    use crate::error::{CancellationReason, DataFusionError, GenericError};
     use arrow::error::ArrowError;
 
     #[test]
@@ -927,6 +958,20 @@ mod test {
             .starts_with("External error: Error during planning: foo"));
     }
 
+    #[test]
+    fn cancelled_error_message() {
+        let err = DataFusionError::Cancelled(CancellationReason::Cancelled);
+        assert_eq!(err.to_string(), "Query cancelled: query was cancelled");
+
+        let err = DataFusionError::Cancelled(CancellationReason::Timeout(
+            Duration::from_millis(1500),
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Query cancelled: query exceeded the statement timeout of 1.5s"
+        );
+    }
+
     #[test]
     fn arrow_error_to_datafusion() {
         let res = return_datafusion_error().unwrap_err();
@@ -15,9 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
 use arrow::compute::CastOptions;
 use arrow::util::display::{DurationFormat, FormatOptions};
 
+use crate::error::_plan_err;
+use crate::{DataFusionError, Result};
+
 /// The default [`FormatOptions`] to use within DataFusion
 pub const DEFAULT_FORMAT_OPTIONS: FormatOptions<'static> =
     FormatOptions::new().with_duration_format(DurationFormat::Pretty);
@@ -31,3 +37,64 @@ pub const DEFAULT_CAST_OPTIONS: CastOptions<'static> = CastOptions {
 pub const DEFAULT_CLI_FORMAT_OPTIONS: FormatOptions<'static> = FormatOptions::new()
     .with_duration_format(DurationFormat::Pretty)
     .with_null("NULL");
+
+/// The output format of `EXPLAIN`
+///
+/// Set with `EXPLAIN FORMAT <format> ...` or the `datafusion.explain.format`
+/// configuration option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Hash)]
+pub enum ExplainFormat {
+    /// One line per node, with children indented below their parent
+    ///
+    /// ```text
+    /// ProjectionExec: expr=[a@0 as a]
+    ///   FilterExec: a@0 < 5
+    ///     DataSourceExec: partitions=1, partition_sizes=[1]
+    /// ```
+    #[default]
+    Indent,
+    /// One box per node drawn with box-drawing characters, with the key
+    /// properties of each node inside its box
+    ///
+    /// ```text
+    /// ┌───────────────────────────┐
+    /// │       ProjectionExec      │
+    /// │    ────────────────────   │
+    /// │      expr=[a@0 as a]      │
+    /// └─────────────┬─────────────┘
+    /// ┌─────────────┴─────────────┐
+    /// │         FilterExec        │
+    /// │    ────────────────────   │
+    /// │          a@0 < 5          │
+    /// └───────────────────────────┘
+    /// ```
+    Tree,
+    /// A JSON document per plan, for consumption by other programs
+    Json,
+}
+
+impl FromStr for ExplainFormat {
+    type Err = DataFusionError;
+
+    fn from_str(format: &str) -> Result<Self> {
+        match format.to_lowercase().as_str() {
+            "indent" => Ok(Self::Indent),
+            "tree" => Ok(Self::Tree),
+            "json" => Ok(Self::Json),
+            _ => _plan_err!(
+                "Invalid explain format. Expected 'indent', 'tree' or 'json', got '{format}'"
+            ),
+        }
+    }
+}
+
+impl Display for ExplainFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let format = match self {
+            Self::Indent => "indent",
+            Self::Tree => "tree",
+            Self::Json => "json",
+        };
+        write!(f, "{format}")
+    }
+}
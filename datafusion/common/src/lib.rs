@@ -63,8 +63,8 @@ pub use dfschema::{
 };
 pub use diagnostic::Diagnostic;
 pub use error::{
-    field_not_found, unqualified_field_not_found, DataFusionError, Result, SchemaError,
-    SharedResult,
+    field_not_found, unqualified_field_not_found, CancellationReason, DataFusionError,
+    Result, SchemaError, SharedResult,
 };
 pub use file_options::file_type::{
     GetExt, DEFAULT_ARROW_EXTENSION, DEFAULT_AVRO_EXTENSION, DEFAULT_CSV_EXTENSION,
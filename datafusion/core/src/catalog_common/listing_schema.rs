@@ -137,6 +137,8 @@ impl ListingSchemaProvider {
                             location: table_url,
                             file_type: self.format.clone(),
                             table_partition_cols: vec![],
+                            clustered_by: vec![],
+                            num_buckets: None,
                             if_not_exists: false,
                             temporary: false,
                             definition: None,
@@ -29,7 +29,9 @@ use crate::datasource::{
     provider_as_source, DefaultTableSource, MemTable, TableProvider,
 };
 use crate::error::Result;
+use crate::execution::cancellation::CancellationToken;
 use crate::execution::context::{SessionState, TaskContext};
+use crate::execution::query_registry::{QueryMetrics, QueryRegistry, TrackedQuery};
 use crate::execution::FunctionRegistry;
 use crate::logical_expr::utils::find_window_exprs;
 use crate::logical_expr::{
@@ -37,19 +39,22 @@ use crate::logical_expr::{
     Partitioning, TableType,
 };
 use crate::physical_plan::{
-    collect, collect_partitioned, execute_stream, execute_stream_partitioned,
-    ExecutionPlan, SendableRecordBatchStream,
+    collect_partitioned, common, execute_stream, execute_stream_partitioned,
+    ExecutionPlan, RecordBatchStream, SendableRecordBatchStream,
 };
 use crate::prelude::SessionContext;
 use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
 use arrow::array::{Array, ArrayRef, Int64Array, StringArray};
 use arrow::compute::{cast, concat};
 use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
 use datafusion_common::config::{CsvOptions, JsonOptions};
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::{
     exec_err, not_impl_err, plan_err, Column, DFSchema, DataFusionError, ParamValues,
     SchemaError, UnnestOptions,
@@ -66,6 +71,7 @@ use datafusion_functions_aggregate::expr_fn::{
 use async_trait::async_trait;
 use datafusion_catalog::Session;
 use datafusion_sql::TableReference;
+use futures::{Stream, StreamExt};
 
 /// Contains options that control how data is
 /// written out from a DataFrame
@@ -82,6 +88,9 @@ pub struct DataFrameWriteOptions {
     /// Sets which columns should be used for sorting the output by name.
     /// Can be set to empty vec![] for non-sorted writes.
     sort_by: Vec<SortExpr>,
+    /// Sets the expression, such as `zorder` or `hilbert` of some columns,
+    /// that the output is clustered by after sorting it by `sort_by`.
+    cluster_by: Option<Expr>,
 }
 
 impl DataFrameWriteOptions {
@@ -92,6 +101,7 @@ impl DataFrameWriteOptions {
             single_file_output: false,
             partition_by: vec![],
             sort_by: vec![],
+            cluster_by: None,
         }
     }
 
@@ -118,6 +128,28 @@ impl DataFrameWriteOptions {
         self.sort_by = sort_by;
         self
     }
+
+    /// Clusters the output by `cluster_by`, which is usually a call of
+    /// `zorder` or `hilbert` (see [`crate::functions::expr_fn`]) with the
+    /// columns to cluster by.
+    ///
+    /// The rows are sorted by the value of `cluster_by`, after the `sort_by`
+    /// columns if any, so that rows with close values of all the columns are
+    /// written to the same files and row groups. This lets the statistics of
+    /// the files prune more of them when filtering by any of the columns.
+    pub fn with_cluster_by(mut self, cluster_by: Expr) -> Self {
+        self.cluster_by = Some(cluster_by);
+        self
+    }
+
+    /// The expressions to sort the output by
+    fn sort_exprs(&self) -> Vec<SortExpr> {
+        let cluster_by = self
+            .cluster_by
+            .iter()
+            .map(|expr| expr.clone().sort(true, false));
+        self.sort_by.iter().cloned().chain(cluster_by).collect()
+    }
 }
 
 impl Default for DataFrameWriteOptions {
@@ -184,6 +216,11 @@ pub struct DataFrame {
     // Box the (large) SessionState to reduce the size of DataFrame on the stack
     session_state: Box<SessionState>,
     plan: LogicalPlan,
+    // Cancels all executions of this DataFrame, see [`Self::cancellation_token`]
+    cancellation: CancellationToken,
+    // The SQL text `plan` was created from, shown in the `running_queries`
+    // and `query_history` tables of the `information_schema`
+    sql: Option<Arc<str>>,
 }
 
 impl DataFrame {
@@ -196,6 +233,24 @@ impl DataFrame {
         Self {
             session_state: Box::new(session_state),
             plan,
+            cancellation: CancellationToken::new(),
+            sql: None,
+        }
+    }
+
+    /// Create a `DataFrame` of `plan`, derived from a `DataFrame` with
+    /// `session_state` and `cancellation`. The SQL text is not kept, as it
+    /// no longer describes `plan`.
+    pub(crate) fn derived(
+        session_state: Box<SessionState>,
+        cancellation: CancellationToken,
+        plan: LogicalPlan,
+    ) -> Self {
+        Self {
+            session_state,
+            plan,
+            cancellation,
+            sql: None,
         }
     }
 
@@ -330,10 +385,11 @@ impl DataFrame {
         };
         let project_plan = LogicalPlanBuilder::from(plan).project(expr_list)?.build()?;
 
-        Ok(DataFrame {
-            session_state: self.session_state,
-            plan: project_plan,
-        })
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
+            project_plan,
+        ))
     }
 
     /// Returns a new DataFrame containing all columns except the specified columns.
@@ -435,10 +491,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .unnest_columns_with_options(columns, options)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a DataFrame with only rows for which `predicate` evaluates to
@@ -475,10 +532,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .filter(predicate)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a new `DataFrame` that aggregates the rows of the current
@@ -548,10 +606,11 @@ impl DataFrame {
         } else {
             plan
         };
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a new DataFrame that adds the result of evaluating one or more
@@ -560,10 +619,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .window(window_exprs)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Returns a new `DataFrame` with a limited number of rows.
@@ -598,10 +658,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .limit(skip, fetch)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Calculate the union of two [`DataFrame`]s, preserving duplicate rows.
@@ -635,10 +696,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .union(dataframe.plan)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Calculate the distinct union of two [`DataFrame`]s.
@@ -673,10 +735,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .union_distinct(dataframe.plan)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a new `DataFrame` with all duplicated rows removed.
@@ -704,10 +767,11 @@ impl DataFrame {
     /// ```
     pub fn distinct(self) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::from(self.plan).distinct()?.build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a new `DataFrame` with duplicated rows removed as per the specified expression list
@@ -745,10 +809,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .distinct_on(on_expr, select_expr, sort_expr)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a new `DataFrame` that has statistics for a DataFrame.
@@ -945,10 +1010,11 @@ impl DataFrame {
         )?
         .build()?;
 
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Apply a sort by provided expressions with default direction
@@ -994,10 +1060,11 @@ impl DataFrame {
     /// ```
     pub fn sort(self, expr: Vec<SortExpr>) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::from(self.plan).sort(expr)?.build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Join this `DataFrame` with another `DataFrame` using explicitly specified
@@ -1061,10 +1128,11 @@ impl DataFrame {
                 filter,
             )?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Join this `DataFrame` with another `DataFrame` using the specified
@@ -1120,10 +1188,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .join_on(right.plan, join_type, on_exprs)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Repartition a DataFrame based on a logical partitioning scheme.
@@ -1155,10 +1224,11 @@ impl DataFrame {
         let plan = LogicalPlanBuilder::from(self.plan)
             .repartition(partitioning_scheme)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return the total number of rows in this `DataFrame`.
@@ -1202,6 +1272,11 @@ impl DataFrame {
     ///
     /// See [`Self::execute_stream`] to execute a DataFrame without buffering.
     ///
+    /// If the session has a [`ResultCache`], the cached results of a query
+    /// with the same plan are returned without executing the `DataFrame`.
+    ///
+    /// [`ResultCache`]: crate::execution::result_cache::ResultCache
+    ///
     /// # Example
     /// ```
     /// # use datafusion::prelude::*;
@@ -1215,9 +1290,43 @@ impl DataFrame {
     /// # }
     /// ```
     pub async fn collect(self) -> Result<Vec<RecordBatch>> {
+        let result_cache = self.session_state.result_cache().cloned();
+        let cache_key = match &result_cache {
+            Some(cache) => cache.key(&self.session_state, &self.plan).await?,
+            None => None,
+        };
+        if let (Some(cache), Some(key)) = (&result_cache, &cache_key) {
+            if let Some(batches) = cache.get(key) {
+                return Ok(batches);
+            }
+        }
+
+        let _permit = self.session_state.admit(&self.plan).await;
         let task_ctx = Arc::new(self.task_ctx());
-        let plan = self.create_physical_plan().await?;
-        collect(plan, task_ctx).await
+        let sql = self.query_text();
+        let session_state = self.session_state.as_ref();
+        let plan = match &cache_key {
+            // the plan of the key is already optimized
+            Some(key) => {
+                session_state
+                    .query_planner()
+                    .create_physical_plan(key.plan(), session_state)
+                    .await?
+            }
+            None => session_state.create_physical_plan(&self.plan).await?,
+        };
+        let query = start_query(&task_ctx, sql, &plan);
+        let stream = track_stream(query.as_ref(), execute_stream(plan, task_ctx))?;
+        let batches = common::collect(stream).await?;
+
+        if matches!(self.plan, LogicalPlan::Dml(_)) {
+            // Queries that started while the statement ran may have read the old data
+            session_state.invalidate_cached_results(&self.plan);
+        }
+        if let (Some(cache), Some(key)) = (result_cache, cache_key) {
+            cache.put(key, &batches);
+        }
+        Ok(batches)
     }
 
     /// Execute the `DataFrame` and print the results to the console.
@@ -1260,8 +1369,73 @@ impl DataFrame {
     }
 
     /// Return a new [`TaskContext`] which would be used to execute this DataFrame
+    ///
+    /// The task is cancelled when the DataFrame's [`cancellation_token`] is
+    /// cancelled, or after the configured `statement_timeout` has elapsed.
+    ///
+    /// [`cancellation_token`]: Self::cancellation_token
     pub fn task_ctx(&self) -> TaskContext {
-        TaskContext::from(self.session_state.as_ref())
+        let cancellation = match self.session_state.config().statement_timeout() {
+            Some(timeout) => self.cancellation.child_token_with_timeout(timeout),
+            None => self.cancellation.child_token(),
+        };
+        TaskContext::from(self.session_state.as_ref()).with_cancellation(cancellation)
+    }
+
+    /// The text of this DataFrame in the [`QueryRegistry`] of the session:
+    /// the SQL it was created from, or the root of its plan otherwise
+    fn query_text(&self) -> String {
+        match &self.sql {
+            Some(sql) => sql.to_string(),
+            None => self.plan.display().to_string(),
+        }
+    }
+
+    /// The session and plan of this DataFrame if it is a DML statement, whose
+    /// cached results must be invalidated again once it completed
+    fn dml_statement(&self) -> Option<(Arc<SessionState>, Arc<LogicalPlan>)> {
+        matches!(self.plan, LogicalPlan::Dml(_)).then(|| {
+            let session_state = SessionState::clone(&self.session_state);
+            (Arc::new(session_state), Arc::new(self.plan.clone()))
+        })
+    }
+
+    /// Sets the SQL text this DataFrame was created from
+    pub(crate) fn with_sql(mut self, sql: &str) -> Self {
+        self.sql = Some(sql.into());
+        self
+    }
+
+    /// Returns a handle to cancel the execution of this DataFrame.
+    ///
+    /// Cancelling the token stops all running and future executions of this
+    /// DataFrame, and of DataFrames derived from it, with a
+    /// [`DataFusionError::Cancelled`] error. Operators check for cancellation
+    /// between batches, and output streams waiting for their input end as
+    /// soon as the token is cancelled or times out, so a query stops shortly
+    /// after it is cancelled even if it is waiting for its input.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::{DataFusionError, Result};
+    /// # use datafusion_common::CancellationReason;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.sql("SELECT * FROM generate_series(1, 1000000000) ORDER BY 1").await?;
+    /// let cancellation = df.cancellation_token();
+    /// let stream = df.execute_stream().await?;
+    ///
+    /// // e.g. from another task
+    /// cancellation.cancel();
+    /// let err = datafusion::physical_plan::common::collect(stream).await.unwrap_err();
+    /// assert!(matches!(err, DataFusionError::Cancelled(CancellationReason::Cancelled)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
     }
 
     /// Executes this DataFrame and returns a stream over a single partition
@@ -1286,9 +1460,18 @@ impl DataFrame {
     /// Dropping the stream will abort the execution of the query, and free up
     /// any allocated resources
     pub async fn execute_stream(self) -> Result<SendableRecordBatchStream> {
+        let permit = self.session_state.admit(&self.plan).await;
         let task_ctx = Arc::new(self.task_ctx());
+        let sql = self.query_text();
+        let dml = self.dml_statement();
         let plan = self.create_physical_plan().await?;
-        execute_stream(plan, task_ctx)
+        let query = start_query(&task_ctx, sql, &plan);
+        let stream = track_stream(query.as_ref(), execute_stream(plan, task_ctx))?;
+        let stream = invalidate_on_completion(dml, stream);
+        Ok(match permit {
+            Some(permit) => Arc::new(permit).wrap_stream(stream),
+            None => stream,
+        })
     }
 
     /// Executes this DataFrame and collects all results into a vector of vector of RecordBatch
@@ -1307,9 +1490,19 @@ impl DataFrame {
     /// # }
     /// ```
     pub async fn collect_partitioned(self) -> Result<Vec<Vec<RecordBatch>>> {
+        let _permit = self.session_state.admit(&self.plan).await;
         let task_ctx = Arc::new(self.task_ctx());
+        let sql = self.query_text();
+        let dml = self.dml_statement();
         let plan = self.create_physical_plan().await?;
-        collect_partitioned(plan, task_ctx).await
+        let query = start_query(&task_ctx, sql, &plan);
+        let partitions =
+            track_partitions(query, collect_partitioned(plan, task_ctx).await)?;
+        if let Some((session_state, plan)) = dml {
+            // Queries that started while the statement ran may have read the old data
+            session_state.invalidate_cached_results(&plan);
+        }
+        Ok(partitions)
     }
 
     /// Executes this DataFrame and returns one stream per partition.
@@ -1333,9 +1526,28 @@ impl DataFrame {
     pub async fn execute_stream_partitioned(
         self,
     ) -> Result<Vec<SendableRecordBatchStream>> {
+        let permit = self.session_state.admit(&self.plan).await;
         let task_ctx = Arc::new(self.task_ctx());
+        let sql = self.query_text();
+        let dml = self.dml_statement();
         let plan = self.create_physical_plan().await?;
-        execute_stream_partitioned(plan, task_ctx)
+        let query = start_query(&task_ctx, sql, &plan);
+        let streams =
+            track_streams(query.as_ref(), execute_stream_partitioned(plan, task_ctx))?;
+        let streams = streams
+            .into_iter()
+            .map(|stream| invalidate_on_completion(dml.clone(), stream))
+            .collect::<Vec<_>>();
+        Ok(match permit {
+            Some(permit) => {
+                let permit = Arc::new(permit);
+                streams
+                    .into_iter()
+                    .map(|stream| permit.wrap_stream(stream))
+                    .collect()
+            }
+            None => streams,
+        })
     }
 
     /// Returns the `DFSchema` describing the output of this DataFrame.
@@ -1420,16 +1632,45 @@ impl DataFrame {
     /// # }
     /// ```
     pub fn explain(self, verbose: bool, analyze: bool) -> Result<DataFrame> {
+        self.explain_with_format(verbose, analyze, ExplainFormat::Indent)
+    }
+
+    /// Return a DataFrame with the explanation of its plan so far, like
+    /// [`Self::explain`], with the final plans in the given [`ExplainFormat`].
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::common::format::ExplainFormat;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let batches = df
+    ///     .limit(0, Some(100))?
+    ///     .explain_with_format(false, false, ExplainFormat::Tree)?
+    ///     .collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn explain_with_format(
+        self,
+        verbose: bool,
+        analyze: bool,
+        explain_format: ExplainFormat,
+    ) -> Result<DataFrame> {
         if matches!(self.plan, LogicalPlan::Explain(_)) {
             return plan_err!("Nested EXPLAINs are not supported");
         }
         let plan = LogicalPlanBuilder::from(self.plan)
-            .explain(verbose, analyze)?
+            .explain_with_format(verbose, analyze, explain_format)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Return a `FunctionRegistry` used to plan udf's calls
@@ -1478,10 +1719,11 @@ impl DataFrame {
         let left_plan = self.plan;
         let right_plan = dataframe.plan;
         let plan = LogicalPlanBuilder::intersect(left_plan, right_plan, true)?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Calculate the exception of two [`DataFrame`]s.  The two [`DataFrame`]s must have exactly the same schema
@@ -1513,10 +1755,11 @@ impl DataFrame {
         let left_plan = self.plan;
         let right_plan = dataframe.plan;
         let plan = LogicalPlanBuilder::except(left_plan, right_plan, true)?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Execute this `DataFrame` and write the results to `table_name`.
@@ -1533,11 +1776,12 @@ impl DataFrame {
         table_name: &str,
         write_options: DataFrameWriteOptions,
     ) -> Result<Vec<RecordBatch>, DataFusionError> {
-        let plan = if write_options.sort_by.is_empty() {
+        let sort_exprs = write_options.sort_exprs();
+        let plan = if sort_exprs.is_empty() {
             self.plan
         } else {
             LogicalPlanBuilder::from(self.plan)
-                .sort(write_options.sort_by)?
+                .sort(sort_exprs)?
                 .build()?
         };
 
@@ -1558,12 +1802,9 @@ impl DataFrame {
         )?
         .build()?;
 
-        DataFrame {
-            session_state: self.session_state,
-            plan,
-        }
-        .collect()
-        .await
+        DataFrame::derived(self.session_state, self.cancellation, plan)
+            .collect()
+            .await
     }
 
     /// Execute the `DataFrame` and write the results to CSV file(s).
@@ -1610,11 +1851,12 @@ impl DataFrame {
 
         let file_type = format_as_file_type(format);
 
-        let plan = if options.sort_by.is_empty() {
+        let sort_exprs = options.sort_exprs();
+        let plan = if sort_exprs.is_empty() {
             self.plan
         } else {
             LogicalPlanBuilder::from(self.plan)
-                .sort(options.sort_by)?
+                .sort(sort_exprs)?
                 .build()?
         };
 
@@ -1627,12 +1869,9 @@ impl DataFrame {
         )?
         .build()?;
 
-        DataFrame {
-            session_state: self.session_state,
-            plan,
-        }
-        .collect()
-        .await
+        DataFrame::derived(self.session_state, self.cancellation, plan)
+            .collect()
+            .await
     }
 
     /// Execute the `DataFrame` and write the results to JSON file(s).
@@ -1679,11 +1918,12 @@ impl DataFrame {
 
         let file_type = format_as_file_type(format);
 
-        let plan = if options.sort_by.is_empty() {
+        let sort_exprs = options.sort_exprs();
+        let plan = if sort_exprs.is_empty() {
             self.plan
         } else {
             LogicalPlanBuilder::from(self.plan)
-                .sort(options.sort_by)?
+                .sort(sort_exprs)?
                 .build()?
         };
 
@@ -1696,12 +1936,9 @@ impl DataFrame {
         )?
         .build()?;
 
-        DataFrame {
-            session_state: self.session_state,
-            plan,
-        }
-        .collect()
-        .await
+        DataFrame::derived(self.session_state, self.cancellation, plan)
+            .collect()
+            .await
     }
 
     /// Add an additional column to the DataFrame.
@@ -1756,10 +1993,11 @@ impl DataFrame {
 
         let project_plan = LogicalPlanBuilder::from(plan).project(fields)?.build()?;
 
-        Ok(DataFrame {
-            session_state: self.session_state,
-            plan: project_plan,
-        })
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
+            project_plan,
+        ))
     }
 
     /// Rename one column by applying a new projection. This is a no-op if the column to be
@@ -1825,10 +2063,11 @@ impl DataFrame {
         let project_plan = LogicalPlanBuilder::from(self.plan)
             .project(projection)?
             .build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
-            plan: project_plan,
-        })
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
+            project_plan,
+        ))
     }
 
     /// Replace all parameters in logical plan with the specified
@@ -1890,10 +2129,11 @@ impl DataFrame {
     /// ```
     pub fn with_param_values(self, query_values: impl Into<ParamValues>) -> Result<Self> {
         let plan = self.plan.with_param_values(query_values)?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
-        })
+        ))
     }
 
     /// Cache DataFrame as a memory table.
@@ -1911,11 +2151,15 @@ impl DataFrame {
     /// ```
     pub async fn cache(self) -> Result<DataFrame> {
         let context = SessionContext::new_with_state((*self.session_state).clone());
+        let permit = self.session_state.admit(&self.plan).await;
         // The schema is consistent with the output
         let plan = self.clone().create_physical_plan().await?;
         let schema = plan.schema();
         let task_ctx = Arc::new(self.task_ctx());
-        let partitions = collect_partitioned(plan, task_ctx).await?;
+        let query = start_query(&task_ctx, self.query_text(), &plan);
+        let partitions =
+            track_partitions(query, collect_partitioned(plan, task_ctx).await)?;
+        drop(permit);
         let mem_table = MemTable::try_new(schema, partitions)?;
         context.read_table(Arc::new(mem_table))
     }
@@ -1925,10 +2169,149 @@ impl DataFrame {
     /// This method replaces the qualifiers of output columns with the given alias.
     pub fn alias(self, alias: &str) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::from(self.plan).alias(alias)?.build()?;
-        Ok(DataFrame {
-            session_state: self.session_state,
+        Ok(DataFrame::derived(
+            self.session_state,
+            self.cancellation,
             plan,
+        ))
+    }
+}
+
+/// Registers a query running `plan` in the [`QueryRegistry`] of the session
+fn start_query(
+    task_ctx: &TaskContext,
+    sql: String,
+    plan: &Arc<dyn ExecutionPlan>,
+) -> Option<Arc<TrackedQuery>> {
+    let registry = task_ctx.session_config().get_extension::<QueryRegistry>()?;
+    let plan = Arc::clone(plan);
+    let query = registry
+        .start(task_ctx.session_id(), sql)
+        .with_metrics(move || query_metrics(plan.as_ref()));
+    Some(Arc::new(query))
+}
+
+/// Sums the metrics of all operators of `plan`
+fn query_metrics(plan: &dyn ExecutionPlan) -> QueryMetrics {
+    let mut metrics = plan
+        .children()
+        .into_iter()
+        .map(|child| query_metrics(child.as_ref()))
+        .fold(QueryMetrics::default(), |acc, child| QueryMetrics {
+            elapsed_compute: acc.elapsed_compute + child.elapsed_compute,
+            spill_count: acc.spill_count + child.spill_count,
+            spilled_bytes: acc.spilled_bytes + child.spilled_bytes,
+        });
+    if let Some(plan_metrics) = plan.metrics() {
+        metrics.elapsed_compute += plan_metrics.elapsed_compute().unwrap_or_default();
+        metrics.spill_count += plan_metrics.spill_count().unwrap_or_default();
+        metrics.spilled_bytes += plan_metrics.spilled_bytes().unwrap_or_default();
+    }
+    metrics
+}
+
+fn track_stream(
+    query: Option<&Arc<TrackedQuery>>,
+    stream: Result<SendableRecordBatchStream>,
+) -> Result<SendableRecordBatchStream> {
+    let Some(query) = query else {
+        return stream;
+    };
+    stream
+        .map(|stream| query.wrap_stream(stream))
+        .inspect_err(|e| query.fail(e))
+}
+
+fn track_streams(
+    query: Option<&Arc<TrackedQuery>>,
+    streams: Result<Vec<SendableRecordBatchStream>>,
+) -> Result<Vec<SendableRecordBatchStream>> {
+    let Some(query) = query else {
+        return streams;
+    };
+    streams
+        .map(|streams| {
+            streams
+                .into_iter()
+                .map(|stream| query.wrap_stream(stream))
+                .collect()
         })
+        .inspect_err(|e| query.fail(e))
+}
+
+fn track_partitions(
+    query: Option<Arc<TrackedQuery>>,
+    partitions: Result<Vec<Vec<RecordBatch>>>,
+) -> Result<Vec<Vec<RecordBatch>>> {
+    let Some(query) = query else {
+        return partitions;
+    };
+    partitions
+        .inspect(|partitions| {
+            let rows = partitions.iter().flatten().map(|b| b.num_rows()).sum();
+            query.record_output_rows(rows);
+        })
+        .inspect_err(|e| query.fail(e))
+}
+
+/// Invalidates the cached results of the tables modified by the DML
+/// statement `dml` once `stream` is exhausted or dropped, as queries that
+/// ran while the statement was writing may have cached the old data
+fn invalidate_on_completion(
+    dml: Option<(Arc<SessionState>, Arc<LogicalPlan>)>,
+    stream: SendableRecordBatchStream,
+) -> SendableRecordBatchStream {
+    match dml {
+        Some(dml) => Box::pin(InvalidateOnCompletion {
+            inner: stream,
+            dml: Some(dml),
+        }),
+        None => stream,
+    }
+}
+
+/// The stream of a DML statement, see [`invalidate_on_completion`]
+struct InvalidateOnCompletion {
+    inner: SendableRecordBatchStream,
+    dml: Option<(Arc<SessionState>, Arc<LogicalPlan>)>,
+}
+
+impl InvalidateOnCompletion {
+    fn invalidate(&mut self) {
+        if let Some((session_state, plan)) = self.dml.take() {
+            session_state.invalidate_cached_results(&plan);
+        }
+    }
+}
+
+impl Stream for InvalidateOnCompletion {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.inner.poll_next_unpin(cx);
+        if let Poll::Ready(None) = poll {
+            self.invalidate();
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl RecordBatchStream for InvalidateOnCompletion {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+impl Drop for InvalidateOnCompletion {
+    fn drop(&mut self) {
+        self.invalidate();
     }
 }
 
@@ -74,11 +74,12 @@ impl DataFrame {
 
         let file_type = format_as_file_type(format);
 
-        let plan = if options.sort_by.is_empty() {
+        let sort_exprs = options.sort_exprs();
+        let plan = if sort_exprs.is_empty() {
             self.plan
         } else {
             LogicalPlanBuilder::from(self.plan)
-                .sort(options.sort_by)?
+                .sort(sort_exprs)?
                 .build()?
         };
 
@@ -90,12 +91,9 @@ impl DataFrame {
             options.partition_by,
         )?
         .build()?;
-        DataFrame {
-            session_state: self.session_state,
-            plan,
-        }
-        .collect()
-        .await
+        DataFrame::derived(self.session_state, self.cancellation, plan)
+            .collect()
+            .await
     }
 }
 
@@ -107,16 +105,26 @@ mod tests {
     use super::super::Result;
     use super::*;
     use crate::arrow::util::pretty;
+    use crate::datasource::file_format::parquet::CLUSTERING_META_KEY;
+    use crate::datasource::physical_plan::parquet::{
+        ParquetAccessPlan, ParquetFileMetrics, RowGroupAccessPlanFilter,
+    };
     use crate::execution::context::SessionContext;
     use crate::execution::options::ParquetReadOptions;
+    use crate::functions::expr_fn::{hilbert, zorder};
+    use crate::physical_plan::metrics::ExecutionPlanMetricsSet;
     use crate::test_util::{self, register_aggregate_csv};
 
+    use arrow::array::{ArrayRef, Int32Array};
     use datafusion_common::file_options::parquet_writer::parse_compression_string;
     use datafusion_execution::config::SessionConfig;
-    use datafusion_expr::{col, lit};
+    use datafusion_expr::{col, lit, Expr};
+    use datafusion_physical_expr::planner::logical2physical;
+    use datafusion_physical_optimizer::pruning::PruningPredicate;
 
     use object_store::local::LocalFileSystem;
     use parquet::file::reader::FileReader;
+    use serde_json::{json, Value};
     use tempfile::TempDir;
     use url::Url;
 
@@ -243,6 +251,102 @@ mod tests {
             assert_eq!(written_rows as usize, rg_size);
         }
 
+        Ok(())
+    }
+    /// Writes `df` to a Parquet file with row groups of 256 rows, and
+    /// returns the number of row groups whose statistics don't rule out
+    /// each of `predicates`, along with the clustering recorded in the file
+    async fn row_groups_matching(
+        df: DataFrame,
+        write_options: DataFrameWriteOptions,
+        predicates: &[Expr],
+    ) -> Result<(Vec<usize>, Option<Value>)> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("test.parquet");
+        let schema = Arc::clone(df.schema().inner());
+        let mut options = TableParquetOptions::default();
+        options.global.max_row_group_size = 256;
+        df.write_parquet(
+            path.to_str().unwrap(),
+            write_options.with_single_file_output(true),
+            Some(options),
+        )
+        .await?;
+
+        let reader = parquet::file::serialized_reader::SerializedFileReader::new(
+            std::fs::File::open(&path)?,
+        )?;
+        let metadata = reader.metadata();
+        let clustering = metadata
+            .file_metadata()
+            .key_value_metadata()
+            .and_then(|kvs| kvs.iter().find(|kv| kv.key == CLUSTERING_META_KEY))
+            .and_then(|kv| serde_json::from_str(kv.value.as_deref()?).ok());
+
+        let metrics = ExecutionPlanMetricsSet::new();
+        let file_metrics = ParquetFileMetrics::new(0, "test.parquet", &metrics);
+        let row_groups = predicates
+            .iter()
+            .map(|predicate| {
+                let predicate = PruningPredicate::try_new(
+                    logical2physical(predicate, &schema),
+                    Arc::clone(&schema),
+                )?;
+                let mut row_groups = RowGroupAccessPlanFilter::new(
+                    ParquetAccessPlan::new_all(metadata.num_row_groups()),
+                );
+                row_groups.prune_by_statistics(
+                    &schema,
+                    metadata.file_metadata().schema_descr(),
+                    metadata.row_groups(),
+                    &predicate,
+                    &file_metrics,
+                );
+                Ok(row_groups.build().row_group_index_iter().count())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((row_groups, clustering))
+    }
+
+    #[tokio::test]
+    async fn write_parquet_cluster_by() -> Result<()> {
+        // the points of a 64x64 grid, ordered by x
+        let (x, y): (Vec<i32>, Vec<i32>) = (0..64 * 64).map(|i| (i / 64, i % 64)).unzip();
+        let batch = RecordBatch::try_from_iter([
+            ("x", Arc::new(Int32Array::from(x)) as ArrayRef),
+            ("y", Arc::new(Int32Array::from(y)) as ArrayRef),
+        ])?;
+        let df = SessionContext::new().read_batch(batch)?;
+        let predicates = [col("x").eq(lit(10)), col("y").eq(lit(10))];
+
+        // each of the 16 row groups holds 4 values of x and all values of y
+        let (row_groups, clustering) =
+            row_groups_matching(df.clone(), DataFrameWriteOptions::new(), &predicates)
+                .await?;
+        assert_eq!(row_groups, vec![1, 16]);
+        assert_eq!(clustering, None);
+
+        // clustered, each row group holds a 16x16 square of the grid
+        let write_options = DataFrameWriteOptions::new()
+            .with_cluster_by(zorder(vec![col("x"), col("y")]));
+        let (row_groups, clustering) =
+            row_groups_matching(df.clone(), write_options, &predicates).await?;
+        assert_eq!(row_groups, vec![4, 4]);
+        assert_eq!(
+            clustering,
+            Some(json!({ "method": "zorder", "columns": ["x", "y"] }))
+        );
+
+        let write_options = DataFrameWriteOptions::new()
+            .with_cluster_by(hilbert(vec![col("x"), col("y")]));
+        let (row_groups, clustering) =
+            row_groups_matching(df, write_options, &predicates).await?;
+        assert_eq!(row_groups, vec![4, 4]);
+        assert_eq!(
+            clustering,
+            Some(json!({ "method": "hilbert", "columns": ["x", "y"] }))
+        );
+
         Ok(())
     }
 }
@@ -24,7 +24,9 @@ use crate::datasource::TableProvider;
 
 use arrow::datatypes::SchemaRef;
 use datafusion_common::{internal_err, Constraints};
-use datafusion_expr::{Expr, TableProviderFilterPushDown, TableSource, TableType};
+use datafusion_expr::{
+    Expr, TableProviderFilterPushDown, TableSource, TableType, TableVersion,
+};
 
 /// Implements [`TableSource`] for a [`TableProvider`]
 ///
@@ -85,6 +87,10 @@ impl TableSource for DefaultTableSource {
     fn get_column_default(&self, column: &str) -> Option<&Expr> {
         self.table_provider.get_column_default(column)
     }
+
+    fn table_version(&self) -> Option<&TableVersion> {
+        self.table_provider.table_version()
+    }
 }
 
 /// Wrap TableProvider in TableSource
@@ -184,8 +184,11 @@ impl FileFormat for ArrowFormat {
         conf: FileSinkConfig,
         order_requirements: Option<LexRequirement>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        if conf.insert_op != InsertOp::Append {
-            return not_impl_err!("Overwrites are not implemented yet for Arrow format");
+        if conf.insert_op == InsertOp::Replace {
+            return not_impl_err!(
+                "{} not implemented for Arrow format yet",
+                conf.insert_op
+            );
         }
 
         let sink = Arc::new(ArrowFileSink::new(conf));
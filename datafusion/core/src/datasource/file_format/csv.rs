@@ -444,8 +444,8 @@ impl FileFormat for CsvFormat {
         conf: FileSinkConfig,
         order_requirements: Option<LexRequirement>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        if conf.insert_op != InsertOp::Append {
-            return not_impl_err!("Overwrites are not implemented yet for CSV");
+        if conf.insert_op == InsertOp::Replace {
+            return not_impl_err!("{} not implemented for CSV yet", conf.insert_op);
         }
 
         // `has_header` and `newlines_in_values` fields of CsvOptions may inherit
@@ -263,8 +263,8 @@ impl FileFormat for JsonFormat {
         conf: FileSinkConfig,
         order_requirements: Option<LexRequirement>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        if conf.insert_op != InsertOp::Append {
-            return not_impl_err!("Overwrites are not implemented yet for Json");
+        if conf.insert_op == InsertOp::Replace {
+            return not_impl_err!("{} not implemented for Json yet", conf.insert_op);
         }
 
         let writer_options = JsonWriterOptions::try_from(&self.options)?;
@@ -49,7 +49,7 @@ use crate::physical_plan::{ExecutionPlan, Statistics};
 use datafusion_catalog::Session;
 use datafusion_common::file_options::file_type::FileType;
 use datafusion_common::{internal_err, not_impl_err, GetExt};
-use datafusion_expr::Expr;
+use datafusion_expr::{Expr, SortExpr};
 use datafusion_physical_expr::PhysicalExpr;
 
 use async_trait::async_trait;
@@ -125,6 +125,26 @@ pub trait FileFormat: Send + Sync + Debug {
         object: &ObjectMeta,
     ) -> Result<Statistics>;
 
+    /// Infer the sort order of the rows of each of the provided objects, from
+    /// the sort order recorded in their metadata, such as by the writer of
+    /// [`Self::create_writer_physical_plan`].
+    ///
+    /// Returns the longest sort order shared by all the objects, or `None`
+    /// if there is none or the format does not record sort orders.
+    ///
+    /// Formats may reuse the metadata read by previous calls to this method
+    /// and [`Self::infer_schema`] for the objects that did not change since,
+    /// as this method is called by every scan of a listing table whose sort
+    /// order was inferred.
+    async fn infer_sort_order(
+        &self,
+        _state: &dyn Session,
+        _store: &Arc<dyn ObjectStore>,
+        _objects: &[ObjectMeta],
+    ) -> Result<Option<Vec<SortExpr>>> {
+        Ok(None)
+    }
+
     /// Take a list of files and convert it to the appropriate executor
     /// according to this file format.
     async fn create_physical_plan(
@@ -594,6 +614,7 @@ pub(crate) mod test_util {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         }]];
 
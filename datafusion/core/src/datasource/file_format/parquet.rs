@@ -24,6 +24,7 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use super::write::demux::DemuxedStreamReceiver;
+use super::write::orchestration::committed_path;
 use super::write::{create_writer, SharedBuffer};
 use super::{
     coerce_file_schema_to_string_type, coerce_file_schema_to_view_type,
@@ -42,8 +43,8 @@ use crate::error::Result;
 use crate::execution::SessionState;
 use crate::physical_plan::insert::{DataSink, DataSinkExec};
 use crate::physical_plan::{
-    Accumulator, DisplayAs, DisplayFormatType, ExecutionPlan, SendableRecordBatchStream,
-    Statistics,
+    Accumulator, DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties,
+    SendableRecordBatchStream, Statistics,
 };
 
 use arrow::compute::sum;
@@ -63,9 +64,10 @@ use datafusion_datasource::file_scan_config::FileScanConfig;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
 use datafusion_execution::TaskContext;
 use datafusion_expr::dml::InsertOp;
-use datafusion_expr::Expr;
+use datafusion_expr::{Expr, SortExpr};
 use datafusion_functions_aggregate::min_max::{MaxAccumulator, MinAccumulator};
-use datafusion_physical_expr::PhysicalExpr;
+use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_expr::{PhysicalExpr, ScalarFunctionExpr};
 use datafusion_physical_expr_common::sort_expr::LexRequirement;
 
 use async_trait::async_trait;
@@ -84,10 +86,13 @@ use parquet::arrow::arrow_writer::{
 use parquet::arrow::async_reader::MetadataFetch;
 use parquet::arrow::{parquet_to_arrow_schema, ArrowSchemaConverter, AsyncArrowWriter};
 use parquet::errors::ParquetError;
-use parquet::file::metadata::{ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData};
+use parquet::file::metadata::{
+    KeyValue, ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData,
+};
 use parquet::file::properties::{WriterProperties, WriterPropertiesBuilder};
 use parquet::file::writer::SerializedFileWriter;
 use parquet::format::FileMetaData;
+use serde_json::{json, Value};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio::task::JoinSet;
@@ -176,8 +181,17 @@ impl Debug for ParquetFormatFactory {
 #[derive(Debug, Default)]
 pub struct ParquetFormat {
     options: TableParquetOptions,
+    /// The sort orders recorded in the metadata of the files read by
+    /// [`FileFormat::infer_schema`] and [`FileFormat::infer_sort_order`], so
+    /// that [`FileFormat::infer_sort_order`] does not read the metadata of
+    /// the same files again
+    inferred_sort_orders: parking_lot::Mutex<HashMap<Path, InferredSortOrder>>,
 }
 
+/// The sort order recorded in the metadata of a file, if any, and the
+/// [`ObjectMeta`] of the file it was read from
+type InferredSortOrder = (ObjectMeta, Option<Vec<SortExpr>>);
+
 impl ParquetFormat {
     /// Construct a new Format with no local overrides
     pub fn new() -> Self {
@@ -346,6 +360,18 @@ impl FileFormat for ParquetFormat {
             .try_collect()
             .await?;
 
+        // the metadata of the files includes their sort order, see
+        // `Self::infer_sort_order`
+        self.inferred_sort_orders
+            .lock()
+            .extend(objects.iter().zip(&schemas).map(|(object, (_, schema))| {
+                let sort_order = schema
+                    .metadata()
+                    .get(SORT_ORDER_META_KEY)
+                    .and_then(|value| parse_sort_order(value));
+                (object.location.clone(), (object.clone(), sort_order))
+            }));
+
         // Schema inference adds fields based the order they are seen
         // which depends on the order the files are processed. For some
         // object stores (like local file systems) the order returned from list
@@ -397,6 +423,76 @@ impl FileFormat for ParquetFormat {
         Ok(stats)
     }
 
+    async fn infer_sort_order(
+        &self,
+        state: &dyn Session,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<Option<Vec<SortExpr>>> {
+        // only read the metadata of the files whose sort order was not read
+        // before, or that changed since
+        let known = {
+            let inferred = self.inferred_sort_orders.lock();
+            objects
+                .iter()
+                .map(|object| {
+                    let (_, sort_order) =
+                        inferred.get(&object.location).filter(|(inferred, _)| {
+                            inferred.size == object.size
+                                && inferred.last_modified == object.last_modified
+                                && inferred.e_tag == object.e_tag
+                        })?;
+                    Some(sort_order.clone())
+                })
+                .collect::<Vec<_>>()
+        };
+        let read: Vec<_> = futures::stream::iter(objects.iter().zip(known))
+            .map(|(object, known)| async move {
+                let sort_order = match known {
+                    Some(sort_order) => sort_order,
+                    None => {
+                        let metadata = fetch_parquet_metadata(
+                            store.as_ref(),
+                            object,
+                            self.metadata_size_hint(),
+                        )
+                        .await?;
+                        let metadata = metadata.file_metadata().key_value_metadata();
+                        sort_order_from_metadata(metadata)
+                    }
+                };
+                Ok::<_, DataFusionError>(sort_order)
+            })
+            .boxed() // Workaround https://github.com/rust-lang/rust/issues/64552
+            .buffered(state.config_options().execution.meta_fetch_concurrency)
+            .try_collect()
+            .await?;
+        self.inferred_sort_orders
+            .lock()
+            .extend(objects.iter().zip(&read).map(|(object, sort_order)| {
+                (
+                    object.location.clone(),
+                    (object.clone(), sort_order.clone()),
+                )
+            }));
+
+        // the rows of the table are only sorted by the prefix of the sort
+        // orders shared by all of its files
+        let mut sort_orders = read.into_iter().map(Option::unwrap_or_default);
+        let Some(mut sort_order) = sort_orders.next() else {
+            return Ok(None);
+        };
+        for other in sort_orders {
+            let shared = sort_order
+                .iter()
+                .zip(&other)
+                .take_while(|(a, b)| a == b)
+                .count();
+            sort_order.truncate(shared);
+        }
+        Ok((!sort_order.is_empty()).then_some(sort_order))
+    }
+
     async fn create_physical_plan(
         &self,
         _state: &dyn Session,
@@ -420,6 +516,16 @@ impl FileFormat for ParquetFormat {
 
         let mut source = ParquetSource::new(self.options.clone());
 
+        // keep the schema adapter of the scan, such as the adapter of a
+        // `ListingTable` with schema evolution
+        if let Some(factory) = conf
+            .file_source()
+            .as_any()
+            .downcast_ref::<ParquetSource>()
+            .and_then(|source| source.schema_adapter_factory())
+        {
+            source = source.with_schema_adapter_factory(Arc::clone(factory));
+        }
         if let Some(predicate) = predicate {
             source = source.with_predicate(Arc::clone(&conf.file_schema), predicate);
         }
@@ -436,13 +542,25 @@ impl FileFormat for ParquetFormat {
         conf: FileSinkConfig,
         order_requirements: Option<LexRequirement>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        if conf.insert_op != InsertOp::Append {
-            return not_impl_err!("Overwrites are not implemented yet for Parquet");
+        if conf.insert_op == InsertOp::Replace {
+            return not_impl_err!("{} not implemented for Parquet yet", conf.insert_op);
         }
 
-        let sink = Arc::new(ParquetSink::new(conf, self.options.clone()));
+        // The rows of each file are written in the order of the input, so if
+        // the input is sorted, record its sort order in the files
+        let order_requirements = match order_requirements {
+            Some(order_requirements) => Some(order_requirements),
+            None if input.output_partitioning().partition_count() == 1 => {
+                input.output_ordering().cloned().map(LexRequirement::from)
+            }
+            None => None,
+        };
+        let mut sink = ParquetSink::new(conf, self.options.clone());
+        if let Some(sort_order) = &order_requirements {
+            sink = sink.with_sort_order(sort_order);
+        }
 
-        Ok(Arc::new(DataSinkExec::new(input, sink, order_requirements)) as _)
+        Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), order_requirements)) as _)
     }
 
     fn supports_filters_pushdown(
@@ -534,6 +652,90 @@ async fn fetch_schema(
     Ok(schema)
 }
 
+/// Key of the Parquet key-value metadata recording the sort order of the rows
+/// of a file, as written by [`ParquetSink::with_sort_order`], e.g.
+/// `[{"column":"a","descending":false,"nulls_first":true}]`
+pub const SORT_ORDER_META_KEY: &str = "datafusion.sort_order";
+
+/// Key of the Parquet key-value metadata recording the clustering of the rows
+/// of a file along a space filling curve of several columns, as written by
+/// [`ParquetSink::with_sort_order`] for a sort by a `zorder` or `hilbert`
+/// function, e.g. `{"method":"zorder","columns":["a","b"]}`
+pub const CLUSTERING_META_KEY: &str = "datafusion.clustering";
+
+/// Returns the key-value metadata recording `sort_order`: its leading column
+/// sort expressions as [`SORT_ORDER_META_KEY`], and a following `zorder` or
+/// `hilbert` function of columns as [`CLUSTERING_META_KEY`]. The rest of the
+/// sort order is not recorded.
+fn sort_order_metadata(sort_order: &LexRequirement) -> Vec<(String, String)> {
+    let mut columns = vec![];
+    let mut metadata = vec![];
+    for requirement in sort_order.iter() {
+        let expr = requirement.expr.as_any();
+        if let (Some(column), Some(options)) =
+            (expr.downcast_ref::<Column>(), requirement.options)
+        {
+            columns.push(json!({
+                "column": column.name(),
+                "descending": options.descending,
+                "nulls_first": options.nulls_first,
+            }));
+            continue;
+        }
+
+        if let Some(func) = expr.downcast_ref::<ScalarFunctionExpr>() {
+            let clustered_by = func
+                .args()
+                .iter()
+                .map(|arg| arg.as_any().downcast_ref::<Column>().map(Column::name))
+                .collect::<Option<Vec<_>>>();
+            if let (method @ ("zorder" | "hilbert"), Some(clustered_by)) =
+                (func.name(), clustered_by)
+            {
+                let clustering = json!({ "method": method, "columns": clustered_by });
+                metadata.push((CLUSTERING_META_KEY.to_string(), clustering.to_string()));
+            }
+        }
+        break;
+    }
+    if !columns.is_empty() {
+        let sort_order = Value::Array(columns).to_string();
+        metadata.push((SORT_ORDER_META_KEY.to_string(), sort_order));
+    }
+    metadata
+}
+
+/// Returns the sort order recorded as [`SORT_ORDER_META_KEY`] in the
+/// key-value metadata of a Parquet file, if any
+fn sort_order_from_metadata(metadata: Option<&Vec<KeyValue>>) -> Option<Vec<SortExpr>> {
+    let value = metadata?
+        .iter()
+        .find(|kv| kv.key == SORT_ORDER_META_KEY)?
+        .value
+        .as_deref()?;
+    parse_sort_order(value)
+}
+
+/// Parses the sort order recorded as [`SORT_ORDER_META_KEY`]
+fn parse_sort_order(value: &str) -> Option<Vec<SortExpr>> {
+    let sort_order = serde_json::from_str::<Value>(value)
+        .ok()?
+        .as_array()?
+        .iter()
+        .map(|sort| {
+            let column = sort.get("column")?.as_str()?;
+            let descending = sort.get("descending")?.as_bool()?;
+            let nulls_first = sort.get("nulls_first")?.as_bool()?;
+            let column = datafusion_common::Column::new_unqualified(column);
+            Some(Expr::Column(column).sort(!descending, nulls_first))
+        })
+        .collect::<Option<Vec<_>>>();
+    if sort_order.is_none() {
+        debug!("Ignoring invalid {SORT_ORDER_META_KEY} metadata: {value}");
+    }
+    sort_order
+}
+
 /// Read and parse the statistics of the Parquet file at location `path`
 ///
 /// See [`statistics_from_parquet_meta_calc`] for more details
@@ -717,6 +919,22 @@ impl ParquetSink {
         }
     }
 
+    /// Record that the rows of the written files are sorted by `sort_order`
+    /// in their key-value metadata, see [`SORT_ORDER_META_KEY`] and
+    /// [`CLUSTERING_META_KEY`], so that reading the files does not need to
+    /// sort them again.
+    ///
+    /// The rows must be written in this order, such as by requiring it from
+    /// the input of the [`DataSinkExec`] writing to this sink.
+    pub fn with_sort_order(mut self, sort_order: &LexRequirement) -> Self {
+        self.parquet_options.key_value_metadata.extend(
+            sort_order_metadata(sort_order)
+                .into_iter()
+                .map(|(key, value)| (key, Some(value))),
+        );
+        self
+    }
+
     /// Retrieve the file metadata for the written files, keyed to the path
     /// which may be partitioned (in the case of hive style partitioning).
     pub fn written(&self) -> HashMap<Path, FileMetaData> {
@@ -859,6 +1077,7 @@ impl FileSink for ParquetSink {
             match result {
                 Ok(r) => {
                     let (path, file_metadata) = r?;
+                    let path = committed_path(&path)?;
                     row_count += file_metadata.num_rows;
                     let mut written_files = self.written.lock();
                     written_files
@@ -2555,6 +2774,8 @@ mod tests {
             insert_op: InsertOp::Overwrite,
             keep_partition_by_columns: false,
             file_extension: "parquet".into(),
+            bucket_spec: None,
+            rolling_policy: None,
         };
         let parquet_sink = Arc::new(ParquetSink::new(
             file_sink_config,
@@ -2640,6 +2861,8 @@ mod tests {
             insert_op: InsertOp::Overwrite,
             keep_partition_by_columns: false,
             file_extension: "parquet".into(),
+            bucket_spec: None,
+            rolling_policy: None,
         };
         let parquet_sink = Arc::new(ParquetSink::new(
             file_sink_config,
@@ -2723,6 +2946,8 @@ mod tests {
                 insert_op: InsertOp::Overwrite,
                 keep_partition_by_columns: false,
                 file_extension: "parquet".into(),
+                bucket_spec: None,
+                rolling_policy: None,
             };
             let parquet_sink = Arc::new(ParquetSink::new(
                 file_sink_config,
@@ -2808,4 +3033,199 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn write_parquet_sort_order() -> Result<()> {
+        use crate::dataframe::DataFrameWriteOptions;
+        use crate::physical_plan::displayable;
+        use crate::prelude::col;
+        use datafusion_common::assert_contains;
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = format!("{}/sorted/", tmp_dir.path().to_string_lossy());
+
+        let ctx = SessionContext::new();
+        let sort_by = vec![col("a").sort(false, true), col("b").sort(true, false)];
+        ctx.sql("SELECT * FROM (VALUES (3, 'x'), (1, 'y'), (2, 'z')) AS t(a, b)")
+            .await?
+            .write_parquet(
+                &path,
+                DataFrameWriteOptions::new().with_sort_by(sort_by.clone()),
+                None,
+            )
+            .await?;
+
+        // the sort order is recorded in the metadata of the written file
+        let files = std::fs::read_dir(&path)?.collect::<std::io::Result<Vec<_>>>()?;
+        assert_eq!(files.len(), 1);
+        let reader = SerializedFileReader::new(std::fs::File::open(files[0].path())?)?;
+        let sort_order = reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .and_then(|kv| kv.iter().find(|kv| kv.key == SORT_ORDER_META_KEY))
+            .and_then(|kv| kv.value.clone());
+        assert_eq!(
+            sort_order.as_deref(),
+            Some(
+                r#"[{"column":"a","descending":true,"nulls_first":true},{"column":"b","descending":false,"nulls_first":false}]"#
+            )
+        );
+
+        // and inferred when reading it, so that sorting it does not need a sort
+        let df = ctx
+            .read_parquet(&path, ParquetReadOptions::default())
+            .await?
+            .sort(sort_by)?;
+        let plan = df.clone().create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!plan.contains("SortExec"), "{plan}");
+        assert_contains!(plan, "output_ordering=[a@0 DESC, b@1 ASC NULLS LAST]");
+
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 3 | x |",
+            "| 2 | z |",
+            "| 1 | y |",
+            "+---+---+",
+        ];
+        assert_batches_eq!(expected, &df.collect().await?);
+
+        // unless disabled
+        let config = SessionConfig::new().set_bool(
+            "datafusion.execution.listing_table_infer_file_sort_order",
+            false,
+        );
+        let ctx = SessionContext::new_with_config(config);
+        let plan = ctx
+            .read_parquet(&path, ParquetReadOptions::default())
+            .await?
+            .sort(vec![col("a").sort(false, true)])?
+            .create_physical_plan()
+            .await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert_contains!(plan, "SortExec");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn inferred_sort_order_ignores_unsorted_files() -> Result<()> {
+        use crate::dataframe::DataFrameWriteOptions;
+        use crate::physical_plan::displayable;
+        use crate::prelude::col;
+        use datafusion_common::assert_contains;
+
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = format!("{}/sorted/", tmp_dir.path().to_string_lossy());
+
+        let ctx = SessionContext::new();
+        ctx.sql("SELECT * FROM (VALUES (3, 'x'), (1, 'y')) AS t(a, b)")
+            .await?
+            .write_parquet(
+                &path,
+                DataFrameWriteOptions::new()
+                    .with_sort_by(vec![col("a").sort(true, false)]),
+                None,
+            )
+            .await?;
+        ctx.register_parquet("t", &path, ParquetReadOptions::default())
+            .await?;
+
+        async fn plan(ctx: &SessionContext) -> Result<String> {
+            let plan = ctx
+                .sql("SELECT a FROM t ORDER BY a")
+                .await?
+                .create_physical_plan()
+                .await?;
+            let plan = displayable(plan.as_ref()).indent(true).to_string();
+            Ok(plan)
+        }
+        let sorted = plan(&ctx).await?;
+        assert!(!sorted.contains("SortExec"), "{sorted}");
+
+        // a file added to the table without the sort order is sorted
+        ctx.sql("SELECT * FROM (VALUES (4, 'z'), (2, 'w')) AS t(a, b)")
+            .await?
+            .write_parquet(&path, DataFrameWriteOptions::new(), None)
+            .await?;
+        assert_contains!(plan(&ctx).await?, "SortExec");
+
+        let batches = ctx
+            .sql("SELECT a FROM t ORDER BY a")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "| 4 |", "+---+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn infer_sort_order_reuses_schema_metadata() -> Result<()> {
+        use crate::dataframe::DataFrameWriteOptions;
+        use crate::prelude::col;
+
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = format!("{}/sorted/", tmp_dir.path().to_string_lossy());
+
+        let ctx = SessionContext::new();
+        let sort_by = vec![col("a").sort(true, false)];
+        ctx.sql("SELECT * FROM (VALUES (1, 'x'), (2, 'y')) AS t(a, b)")
+            .await?
+            .write_parquet(
+                &path,
+                DataFrameWriteOptions::new().with_sort_by(sort_by.clone()),
+                None,
+            )
+            .await?;
+
+        let store = Arc::new(RequestCountingObjectStore::new(Arc::new(
+            LocalFileSystem::new(),
+        )));
+        let objects = store
+            .inner
+            .list(Some(&Path::from_filesystem_path(&path)?))
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(objects.len(), 1);
+
+        let state = ctx.state();
+        let format = ParquetFormat::default();
+        format
+            .infer_schema(&state, &store.upcast(), &objects)
+            .await?;
+        let request_count = store.request_count();
+        assert!(request_count > 0);
+
+        // the sort order is inferred from the metadata read to infer the schema
+        let sort_order = format
+            .infer_sort_order(&state, &store.upcast(), &objects)
+            .await?;
+        assert_eq!(sort_order, Some(sort_by.clone()));
+        assert_eq!(store.request_count(), request_count);
+
+        // and again, until the file changes
+        let sort_order = format
+            .infer_sort_order(&state, &store.upcast(), &objects)
+            .await?;
+        assert_eq!(sort_order, Some(sort_by.clone()));
+        assert_eq!(store.request_count(), request_count);
+
+        let mut changed = objects.clone();
+        changed[0].e_tag = Some("changed".to_string());
+        let sort_order = format
+            .infer_sort_order(&state, &store.upcast(), &changed)
+            .await?;
+        assert_eq!(sort_order, Some(sort_by));
+        assert!(store.request_count() > request_count);
+
+        Ok(())
+    }
 }
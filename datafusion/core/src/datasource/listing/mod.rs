@@ -19,7 +19,10 @@
 //! to get the list of files to process.
 
 mod table;
+pub use datafusion_catalog_listing::file_index::{FileIndex, JsonManifestIndex};
 pub use datafusion_catalog_listing::helpers;
+pub use datafusion_datasource::bucket::BucketSpec;
+pub use datafusion_datasource::deletes::FileDeletes;
 pub use datafusion_datasource::{
     FileRange, ListingTableUrl, PartitionedFile, PartitionedFileStream,
 };
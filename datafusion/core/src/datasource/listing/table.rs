@@ -20,8 +20,12 @@
 use std::collections::HashMap;
 use std::{any::Any, str::FromStr, sync::Arc};
 
-use super::helpers::{expr_applicable_for_cols, pruned_partition_list, split_files};
-use super::{ListingTableUrl, PartitionedFile};
+use super::helpers::{
+    expr_applicable_for_cols, infer_partition_data_type, prune_files_by_partition_values,
+    pruned_partition_list, split_files,
+};
+use super::FileIndex;
+use super::{BucketSpec, ListingTableUrl, PartitionedFile};
 
 use crate::datasource::{
     create_ordering,
@@ -30,21 +34,25 @@ use crate::datasource::{
     },
     get_statistics_with_limit,
     physical_plan::FileSinkConfig,
+    schema_adapter::SchemaEvolutionAdapterFactory,
 };
 use crate::execution::context::SessionState;
 use datafusion_catalog::TableProvider;
 use datafusion_common::{config_err, DataFusionError, Result};
+use datafusion_datasource::file::FileSource;
 use datafusion_datasource::file_scan_config::FileScanConfig;
+use datafusion_datasource::write::rolling::RollingPolicy;
 use datafusion_expr::dml::InsertOp;
 use datafusion_expr::{utils::conjunction, Expr, TableProviderFilterPushDown};
-use datafusion_expr::{SortExpr, TableType};
+use datafusion_expr::{ColumnarValue, SortExpr, TableType, TableVersion};
 use datafusion_physical_plan::empty::EmptyExec;
 use datafusion_physical_plan::{ExecutionPlan, Statistics};
 
+use arrow::array::RecordBatch;
 use arrow::datatypes::{DataType, Field, Schema, SchemaBuilder, SchemaRef};
 use datafusion_common::{
-    config_datafusion_err, internal_err, plan_err, project_schema, Constraints,
-    SchemaExt, ToDFSchema,
+    config_datafusion_err, internal_err, not_impl_err, plan_err, project_schema,
+    Constraints, DFSchema, ScalarValue, SchemaExt, ToDFSchema,
 };
 use datafusion_execution::cache::{
     cache_manager::FileStatisticsCache, cache_unit::DefaultFileStatisticsCache,
@@ -58,7 +66,7 @@ use datafusion_catalog::Session;
 use datafusion_physical_expr_common::sort_expr::LexRequirement;
 use futures::{future, stream, StreamExt, TryStreamExt};
 use itertools::Itertools;
-use object_store::ObjectStore;
+use object_store::{ObjectMeta, ObjectStore};
 
 /// Configuration for creating a [`ListingTable`]
 #[derive(Debug, Clone)]
@@ -211,30 +219,110 @@ impl ListingTableConfig {
         }
     }
 
-    /// Convenience wrapper for calling `infer_options` and `infer_schema`
+    /// Convenience wrapper for calling `infer_options`, `infer_schema` and
+    /// `infer_file_sort_order`
     pub async fn infer(self, state: &dyn Session) -> Result<Self> {
-        self.infer_options(state).await?.infer_schema(state).await
+        self.infer_options(state)
+            .await?
+            .infer_schema(state)
+            .await?
+            .infer_file_sort_order(state)
+            .await
+    }
+
+    /// Infer the sort order of the files from the sort order recorded in
+    /// their metadata, see [`ListingOptions::infer_file_sort_order`].
+    /// Requires `self.options` to be set prior to using.
+    ///
+    /// Does nothing if the options already declare a sort order, or if
+    /// `datafusion.execution.listing_table_infer_file_sort_order` is false.
+    /// The inferred sort order stops at the first column that is not in the
+    /// schema of the table, if set. It is checked again against the metadata
+    /// of the files of each scan, and not used by the scans of files that do
+    /// not record it, such as files added to the table since.
+    pub async fn infer_file_sort_order(self, state: &dyn Session) -> Result<Self> {
+        let Some(options) = self.options else {
+            return config_err!("No `ListingOptions` set for inferring sort order");
+        };
+        if !options.file_sort_order.is_empty()
+            || !state
+                .config_options()
+                .execution
+                .listing_table_infer_file_sort_order
+        {
+            return Ok(Self {
+                options: Some(options),
+                ..self
+            });
+        }
+        let Some(url) = self.table_paths.first() else {
+            return config_err!("No table path found");
+        };
+
+        let mut sort_order = options.infer_file_sort_order(state, url).await?;
+        if let Some(file_schema) = &self.file_schema {
+            let in_schema = |sort: &SortExpr| match &sort.expr {
+                Expr::Column(column) => {
+                    file_schema.field_with_name(&column.name).is_ok()
+                        || options
+                            .table_partition_cols
+                            .iter()
+                            .any(|(name, _)| name == &column.name)
+                }
+                _ => false,
+            };
+            let valid = sort_order.iter().take_while(|sort| in_schema(sort)).count();
+            sort_order.truncate(valid);
+        }
+        let options = if sort_order.is_empty() {
+            options
+        } else {
+            ListingOptions {
+                file_sort_order_inferred: true,
+                ..options.with_file_sort_order(vec![sort_order])
+            }
+        };
+        Ok(Self {
+            options: Some(options),
+            ..self
+        })
     }
 
     /// Infer the partition columns from the path. Requires `self.options` to be set prior to using.
+    ///
+    /// If `datafusion.execution.listing_table_infer_partition_types` is true,
+    /// the default, the types of the partition columns are inferred from their values in
+    /// the directory names of all the files of the table (see
+    /// [`infer_partition_data_type`]). Otherwise they are dictionary encoded
+    /// strings.
+    ///
+    /// [`infer_partition_data_type`]: super::helpers::infer_partition_data_type
     pub async fn infer_partitions_from_path(self, state: &dyn Session) -> Result<Self> {
         match self.options {
             Some(options) => {
                 let Some(url) = self.table_paths.first() else {
                     return config_err!("No table path found");
                 };
+                let infer_types = state
+                    .config_options()
+                    .execution
+                    .listing_table_infer_partition_types;
+                // all the values are needed to infer the types
+                let max_files = (!infer_types).then_some(10);
                 let partitions = options
-                    .infer_partitions(state, url)
+                    .infer_partition_values(state, url, max_files)
                     .await?
                     .into_iter()
-                    .map(|col_name| {
-                        (
-                            col_name,
+                    .map(|(col_name, values)| {
+                        let data_type = if infer_types {
+                            infer_partition_data_type(values.iter().map(String::as_str))
+                        } else {
                             DataType::Dictionary(
                                 Box::new(DataType::UInt16),
                                 Box::new(DataType::Utf8),
-                            ),
-                        )
+                            )
+                        };
+                        (col_name, data_type)
                     })
                     .collect::<Vec<_>>();
                 let options = options.with_table_partition_cols(partitions);
@@ -282,6 +370,14 @@ pub struct ListingOptions {
     ///       multiple equivalent orderings, the outer `Vec` will have a
     ///       single element.
     pub file_sort_order: Vec<Vec<SortExpr>>,
+    /// Optional bucketed layout of the files.
+    /// See [Self::with_bucket_spec] for details
+    pub bucket_spec: Option<BucketSpec>,
+    /// Whether `file_sort_order` was inferred from the sort order recorded in
+    /// the metadata of the files, see [`ListingTableConfig::infer_file_sort_order`].
+    /// An inferred sort order is only used by the scans of files whose
+    /// metadata records it.
+    pub file_sort_order_inferred: bool,
 }
 
 impl ListingOptions {
@@ -299,6 +395,8 @@ impl ListingOptions {
             collect_stat: true,
             target_partitions: 1,
             file_sort_order: vec![],
+            bucket_spec: None,
+            file_sort_order_inferred: false,
         }
     }
 
@@ -474,6 +572,34 @@ impl ListingOptions {
     /// ```
     pub fn with_file_sort_order(mut self, file_sort_order: Vec<Vec<SortExpr>>) -> Self {
         self.file_sort_order = file_sort_order;
+        self.file_sort_order_inferred = false;
+        self
+    }
+
+    /// Set the bucketed layout of the files on [`ListingOptions`] and
+    /// returns self.
+    ///
+    /// Inserts write the rows of each bucket to separate files. When the
+    /// `target_partitions` of the session divides the number of buckets,
+    /// scans read the files of each bucket in the same partition and report
+    /// a hash partitioning on the bucket columns, so that aggregations on
+    /// these columns, and joins with tables bucketed on the join columns, do
+    /// not need to repartition the rows. See [`BucketSpec`] for details.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use datafusion::datasource::{listing::{BucketSpec, ListingOptions}, file_format::parquet::ParquetFormat};
+    ///
+    /// let bucket_spec = BucketSpec::try_new(vec!["a".to_string()], 8).unwrap();
+    /// let listing_options = ListingOptions::new(Arc::new(
+    ///     ParquetFormat::default()
+    ///   ))
+    ///   .with_bucket_spec(Some(bucket_spec.clone()));
+    ///
+    /// assert_eq!(listing_options.bucket_spec, Some(bucket_spec));
+    /// ```
+    pub fn with_bucket_spec(mut self, bucket_spec: Option<BucketSpec>) -> Self {
+        self.bucket_spec = bucket_spec;
         self
     }
 
@@ -503,6 +629,30 @@ impl ListingOptions {
         Ok(schema)
     }
 
+    /// Infer the sort order of the rows of each of the files at the given path
+    /// on the provided object store, from the sort order recorded in their
+    /// metadata (see [`FileFormat::infer_sort_order`]).
+    ///
+    /// Returns the longest sort order shared by all the files, which is empty
+    /// if there is none.
+    pub async fn infer_file_sort_order(
+        &self,
+        state: &dyn Session,
+        table_path: &ListingTableUrl,
+    ) -> Result<Vec<SortExpr>> {
+        let store = state.runtime_env().object_store(table_path)?;
+
+        let files: Vec<_> = table_path
+            .list_all_files(state, store.as_ref(), &self.file_extension)
+            .await?
+            .try_filter(|object_meta| future::ready(object_meta.size > 0))
+            .try_collect()
+            .await?;
+
+        let sort_order = self.format.infer_sort_order(state, &store, &files).await?;
+        Ok(sort_order.unwrap_or_default())
+    }
+
     /// Infers the partition columns stored in `LOCATION` and compares
     /// them with the columns provided in `PARTITIONED BY` to help prevent
     /// accidental corrupts of partitioned tables.
@@ -567,15 +717,33 @@ impl ListingOptions {
         state: &dyn Session,
         table_path: &ListingTableUrl,
     ) -> Result<Vec<String>> {
+        Ok(self
+            .infer_partition_values(state, table_path, Some(10))
+            .await?
+            .into_iter()
+            .map(|(col_name, _)| col_name)
+            .collect())
+    }
+
+    /// Infer the partition columns at the given path on the provided object
+    /// store, with the distinct values of each column in the directory names
+    /// of the files used for inference. See [`Self::infer_partitions`].
+    ///
+    /// Only the first `max_files` files are used for inference, if set.
+    async fn infer_partition_values(
+        &self,
+        state: &dyn Session,
+        table_path: &ListingTableUrl,
+        max_files: Option<usize>,
+    ) -> Result<Vec<(String, Vec<String>)>> {
         let store = state.runtime_env().object_store(table_path)?;
 
-        // only use 10 files for inference
-        // This can fail to detect inconsistent partition keys
-        // A DFS traversal approach of the store can help here
+        // Using only some of the files can fail to detect inconsistent
+        // partition keys. A DFS traversal approach of the store can help here
         let files: Vec<_> = table_path
             .list_all_files(state, store.as_ref(), &self.file_extension)
             .await?
-            .take(10)
+            .take(max_files.unwrap_or(usize::MAX))
             .try_collect()
             .await?;
 
@@ -586,20 +754,37 @@ impl ListingOptions {
                 .collect_vec()
         });
 
-        let partition_keys = stripped_path_parts
+        let partitions = stripped_path_parts
             .map(|path_parts| {
                 path_parts
                     .into_iter()
                     .rev()
                     .skip(1) // get parents only; skip the file itself
                     .rev()
-                    .map(|s| s.split('=').take(1).collect())
+                    .map(|s| match s.split_once('=') {
+                        Some((key, value)) => (key.to_string(), value.to_string()),
+                        None => (s.to_string(), String::new()),
+                    })
                     .collect_vec()
             })
             .collect_vec();
 
-        match partition_keys.into_iter().all_equal_value() {
-            Ok(v) => Ok(v),
+        let mut partition_keys = partitions
+            .iter()
+            .map(|parts| parts.iter().map(|(key, _)| key.as_str()).collect_vec());
+        match partition_keys.all_equal_value() {
+            Ok(keys) => Ok(keys
+                .into_iter()
+                .enumerate()
+                .map(|(idx, key)| {
+                    let values = partitions
+                        .iter()
+                        .map(|parts| parts[idx].1.clone())
+                        .unique()
+                        .collect();
+                    (key.to_string(), values)
+                })
+                .collect()),
             Err(None) => Ok(vec![]),
             Err(Some(diff)) => {
                 let mut sorted_diff = [diff.0, diff.1];
@@ -704,7 +889,7 @@ impl ListingOptions {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ListingTable {
     table_paths: Vec<ListingTableUrl>,
     /// File fields only
@@ -716,6 +901,10 @@ pub struct ListingTable {
     collected_statistics: FileStatisticsCache,
     constraints: Constraints,
     column_defaults: HashMap<String, Expr>,
+    schema_evolution: Option<SchemaEvolutionAdapterFactory>,
+    file_index: Option<Arc<dyn FileIndex>>,
+    /// The version of the table read from `file_index`, if not the current
+    version: Option<TableVersion>,
 }
 
 impl ListingTable {
@@ -759,6 +948,9 @@ impl ListingTable {
             collected_statistics: Arc::new(DefaultFileStatisticsCache::default()),
             constraints: Constraints::empty(),
             column_defaults: HashMap::new(),
+            schema_evolution: None,
+            file_index: None,
+            version: None,
         };
 
         Ok(table)
@@ -779,6 +971,46 @@ impl ListingTable {
         self
     }
 
+    /// Read files written with previous schemas of the table with
+    /// `schema_evolution`, which matches their columns to the columns of the
+    /// table by field id, name and previous names, and evolves the types of
+    /// the columns to the types of the table.
+    ///
+    /// Columns of the table that are not in a file are filled with the
+    /// defaults of [`Self::with_column_defaults`] that are constants, in
+    /// addition to the defaults of `schema_evolution`.
+    ///
+    /// Only supported for Parquet files. As the statistics and filters of the
+    /// files are resolved by column name, filters are not pushed down into the
+    /// scans of tables with schema evolution, and the statistics of their
+    /// columns are unknown.
+    pub fn with_schema_evolution(
+        mut self,
+        schema_evolution: SchemaEvolutionAdapterFactory,
+    ) -> Self {
+        self.schema_evolution = Some(schema_evolution);
+        self
+    }
+
+    /// Read the files of the table from `file_index` instead of listing the
+    /// files of the table paths, see [`FileIndex`].
+    ///
+    /// The statistics of the files returned by the index are used instead of
+    /// reading the statistics from the files. The schema of the table is not
+    /// inferred from the index, so it must be provided. Inserting into a
+    /// table with a file index is not supported, as the index would not
+    /// include the written files.
+    pub fn with_file_index(mut self, file_index: Arc<dyn FileIndex>) -> Self {
+        self.file_index = Some(file_index);
+        self
+    }
+
+    /// The [`FileIndex`] of the table, if the files of the table are not
+    /// listed
+    pub fn file_index(&self) -> Option<&Arc<dyn FileIndex>> {
+        self.file_index.as_ref()
+    }
+
     /// Set the [`FileStatisticsCache`] used to cache parquet file statistics.
     ///
     /// Setting a statistics cache on the `SessionContext` can avoid refetching statistics
@@ -807,10 +1039,116 @@ impl ListingTable {
         &self.options
     }
 
+    /// List all files of the table paths with their current version, ordered
+    /// by location, or `None` if the files of the table are read from its
+    /// [`FileIndex`]
+    pub(crate) async fn list_all_files(
+        &self,
+        state: &dyn Session,
+    ) -> Result<Option<Vec<ObjectMeta>>> {
+        if self.file_index.is_some() {
+            return Ok(None);
+        }
+        let extension = &self.options.file_extension;
+        let mut files = vec![];
+        for table_path in &self.table_paths {
+            let store = state.runtime_env().object_store(table_path)?;
+            let table_files: Vec<_> = table_path
+                .list_all_files(state, store.as_ref(), extension)
+                .await?
+                .try_collect()
+                .await?;
+            files.extend(table_files);
+        }
+        files.sort_by(|a, b| a.location.cmp(&b.location));
+        files.dedup_by(|a, b| a.location == b.location);
+        Ok(Some(files))
+    }
+
     /// If file_sort_order is specified, creates the appropriate physical expressions
     fn try_create_output_ordering(&self) -> Result<Vec<LexOrdering>> {
         create_ordering(&self.table_schema, &self.options.file_sort_order)
     }
+
+    /// The output ordering of the scan of `files`, which is empty if the sort
+    /// order of the table was inferred from the metadata of the files, and
+    /// one of `files` does not record it
+    async fn scan_output_ordering(
+        &self,
+        state: &dyn Session,
+        files: &[Vec<PartitionedFile>],
+    ) -> Result<Vec<LexOrdering>> {
+        let inferred = self
+            .options
+            .file_sort_order
+            .first()
+            .filter(|_| self.options.file_sort_order_inferred);
+        if let (Some(inferred), Some(url)) = (inferred, self.table_paths.first()) {
+            let store = state.runtime_env().object_store(url)?;
+            let objects = files
+                .iter()
+                .flatten()
+                .map(|file| file.object_meta.clone())
+                .filter(|object| object.size > 0)
+                .collect::<Vec<_>>();
+            let sort_order = self
+                .options
+                .format
+                .infer_sort_order(state, &store, &objects)
+                .await?
+                .unwrap_or_default();
+            if !sort_order.starts_with(inferred) {
+                return Ok(vec![]);
+            }
+        }
+        self.try_create_output_ordering()
+    }
+
+    /// The [`FileSource`] of the scans of the table, which adapts the files to
+    /// the table schema with the schema evolution of the table, if any
+    #[cfg_attr(not(feature = "parquet"), allow(unused_variables))]
+    fn create_file_source(&self, state: &dyn Session) -> Result<Arc<dyn FileSource>> {
+        match &self.schema_evolution {
+            None => Ok(self.options.format.file_source()),
+            #[cfg(feature = "parquet")]
+            Some(schema_evolution)
+                if self
+                    .options
+                    .format
+                    .as_any()
+                    .is::<crate::datasource::file_format::parquet::ParquetFormat>() =>
+            {
+                let schema_evolution = schema_evolution
+                    .clone()
+                    .with_column_defaults(self.constant_column_defaults(state)?);
+                let source = crate::datasource::physical_plan::ParquetSource::default()
+                    .with_schema_adapter_factory(Arc::new(schema_evolution));
+                Ok(Arc::new(source))
+            }
+            Some(_) => not_impl_err!(
+                "Schema evolution is only supported for Parquet files, not {}",
+                self.options.format.get_ext()
+            ),
+        }
+    }
+
+    /// The values of the column defaults that are constants
+    #[cfg_attr(not(feature = "parquet"), allow(dead_code))]
+    fn constant_column_defaults(
+        &self,
+        state: &dyn Session,
+    ) -> Result<Vec<(String, ScalarValue)>> {
+        let schema = DFSchema::empty();
+        let batch = RecordBatch::new_empty(Arc::new(Schema::empty()));
+        let mut defaults = vec![];
+        for (name, expr) in &self.column_defaults {
+            let expr = create_physical_expr(expr, &schema, state.execution_props())?;
+            if let ColumnarValue::Scalar(value) = expr.evaluate(&batch)? {
+                defaults.push((name.clone(), value));
+            }
+        }
+        Ok(defaults)
+    }
 }
 
 // Expressions can be used for parttion pruning if they can be evaluated using
@@ -883,11 +1221,28 @@ impl TableProvider for ListingTable {
             return Ok(Arc::new(EmptyExec::new(projected_schema)));
         }
 
-        let output_ordering = self.try_create_output_ordering()?;
-        match state
-            .config_options()
-            .execution
-            .split_file_groups_by_statistics
+        // read the files of each bucket in the same partition, if possible
+        let target_partitions = state.config().target_partitions();
+        let hash_partition_cols =
+            match self.regroup_buckets(&mut partitioned_file_lists, target_partitions) {
+                Some(spec) => spec
+                    .columns
+                    .iter()
+                    .map(|name| self.table_schema.index_of(name))
+                    .collect::<Result<Vec<_>, _>>()?,
+                None => vec![],
+            };
+
+        let output_ordering = self
+            .scan_output_ordering(state, &partitioned_file_lists)
+            .await?;
+        // the files of a bucket must stay in the partition of the bucket
+        let split_by_statistics = hash_partition_cols.is_empty()
+            && state
+                .config_options()
+                .execution
+                .split_file_groups_by_statistics;
+        match split_by_statistics
             .then(|| {
                 output_ordering.first().map(|output_ordering| {
                     FileScanConfig::split_groups_by_statistics(
@@ -910,7 +1265,11 @@ impl TableProvider for ListingTable {
             None => {} // no ordering required
         };
 
-        let filters = match conjunction(filters.to_vec()) {
+        // the filters are resolved by column name, which may not match the
+        // columns of the files of a table with schema evolution
+        let filters = match conjunction(filters.to_vec())
+            .filter(|_| self.schema_evolution.is_none())
+        {
             Some(expr) => {
                 let table_df_schema = self.table_schema.as_ref().clone().to_dfschema()?;
                 let filters = create_physical_expr(
@@ -937,7 +1296,7 @@ impl TableProvider for ListingTable {
                 FileScanConfig::new(
                     object_store_url,
                     Arc::clone(&self.file_schema),
-                    self.options.format.file_source(),
+                    self.create_file_source(state)?,
                 )
                 .with_file_groups(partitioned_file_lists)
                 .with_constraints(self.constraints.clone())
@@ -945,7 +1304,8 @@ impl TableProvider for ListingTable {
                 .with_projection(projection.cloned())
                 .with_limit(limit)
                 .with_output_ordering(output_ordering)
-                .with_table_partition_cols(table_partition_cols),
+                .with_table_partition_cols(table_partition_cols)
+                .with_hash_partition_cols(hash_partition_cols),
                 filters.as_ref(),
             )
             .await
@@ -970,6 +1330,11 @@ impl TableProvider for ListingTable {
                     return Ok(TableProviderFilterPushDown::Exact);
                 }
 
+                if self.schema_evolution.is_some() {
+                    // filters are not pushed down into the scan, see `Self::scan`
+                    return Ok(TableProviderFilterPushDown::Inexact);
+                }
+
                 // if we can't push it down completely with only the filename-based/path-based
                 // column names, then we should check if we can do parquet predicate pushdown
                 let supports_pushdown = self.options.format.supports_filters_pushdown(
@@ -991,6 +1356,22 @@ impl TableProvider for ListingTable {
         self.definition.as_deref()
     }
 
+    fn at_version(&self, version: &TableVersion) -> Result<Arc<dyn TableProvider>> {
+        let Some(file_index) = &self.file_index else {
+            return not_impl_err!(
+                "Reading the {version} of a ListingTable requires a FileIndex"
+            );
+        };
+        let mut table = self.clone();
+        table.file_index = Some(file_index.at_version(version)?);
+        table.version = Some(*version);
+        Ok(Arc::new(table))
+    }
+
+    fn table_version(&self) -> Option<&TableVersion> {
+        self.version.as_ref()
+    }
+
     async fn insert_into(
         &self,
         state: &dyn Session,
@@ -1001,6 +1382,20 @@ impl TableProvider for ListingTable {
         self.schema()
             .logically_equivalent_names_and_types(&input.schema())?;
 
+        if self.file_index.is_some() {
+            return not_impl_err!(
+                "Inserting into a ListingTable with a FileIndex is not supported"
+            );
+        }
+
+        if self.options.bucket_spec.is_some()
+            && !self.options.table_partition_cols.is_empty()
+        {
+            return not_impl_err!(
+                "Inserting into a ListingTable with both partition columns and buckets is not supported"
+            );
+        }
+
         let table_path = &self.table_paths()[0];
         if !table_path.is_collection() {
             return plan_err!(
@@ -1028,6 +1423,21 @@ impl TableProvider for ListingTable {
         let keep_partition_by_columns =
             state.config_options().execution.keep_partition_by_columns;
 
+        // An unbounded input never ends, so its files are committed as they
+        // are rolled rather than at the end of the input
+        let rolling_policy = if input.properties().boundedness.is_unbounded() {
+            let policy = RollingPolicy::from_options(&state.config_options().execution);
+            if policy.is_none() {
+                return plan_err!(
+                    "Inserting an unbounded input into a ListingTable requires rolling its files, \
+                    set one of the datafusion.execution.streaming_write_* options"
+                );
+            }
+            policy
+        } else {
+            None
+        };
+
         // Sink related option, apart from format
         let config = FileSinkConfig {
             object_store_url: self.table_paths()[0].object_store(),
@@ -1038,6 +1448,8 @@ impl TableProvider for ListingTable {
             insert_op,
             keep_partition_by_columns,
             file_extension: self.options().format.get_ext(),
+            bucket_spec: self.options.bucket_spec.clone(),
+            rolling_policy,
         };
 
         let order_requirements = if !self.options().file_sort_order.is_empty() {
@@ -1086,26 +1498,58 @@ impl ListingTable {
         } else {
             return Ok((vec![], Statistics::new_unknown(&self.file_schema)));
         };
-        // list files (with partitions)
-        let file_list = future::try_join_all(self.table_paths.iter().map(|table_path| {
-            pruned_partition_list(
-                ctx,
-                store.as_ref(),
-                table_path,
-                filters,
-                &self.options.file_extension,
-                &self.options.table_partition_cols,
-            )
-        }))
-        .await?;
-        let file_list = stream::iter(file_list).flatten();
+        let partition_cols = &self.options.table_partition_cols;
+        let file_list = match &self.file_index {
+            // read the files (with partitions) from the index
+            Some(file_index) => {
+                let files = file_index
+                    .list_files(ctx, filters, &self.file_schema, partition_cols)
+                    .await?;
+                let files =
+                    prune_files_by_partition_values(files, filters, partition_cols)?;
+                stream::iter(files.into_iter().map(Ok)).boxed()
+            }
+            // list files (with partitions)
+            None => {
+                let file_list =
+                    future::try_join_all(self.table_paths.iter().map(|table_path| {
+                        pruned_partition_list(
+                            ctx,
+                            store.as_ref(),
+                            table_path,
+                            filters,
+                            &self.options.file_extension,
+                            partition_cols,
+                        )
+                    }))
+                    .await?;
+                stream::iter(file_list).flatten().boxed()
+            }
+        };
         // collect the statistics if required by the config
         let files = file_list
             .map(|part_file| async {
                 let part_file = part_file?;
                 if self.options.collect_stat {
-                    let statistics =
-                        self.do_collect_statistics(ctx, &store, &part_file).await?;
+                    let statistics = match &part_file.statistics {
+                        Some(statistics) => Arc::new(statistics.clone()),
+                        None => {
+                            self.do_collect_statistics(ctx, &store, &part_file).await?
+                        }
+                    };
+                    // the column statistics are of the columns of the file with
+                    // the names of the columns of the table, which may not be the
+                    // columns that the schema evolution maps to the table
+                    let statistics = match &self.schema_evolution {
+                        Some(_) => Arc::new(Statistics {
+                            num_rows: statistics.num_rows,
+                            total_byte_size: statistics.total_byte_size,
+                            column_statistics: Statistics::unknown_column(
+                                &self.file_schema,
+                            ),
+                        }),
+                        None => statistics,
+                    };
                     Ok((part_file, statistics))
                 } else {
                     Ok((
@@ -1131,6 +1575,22 @@ impl ListingTable {
         ))
     }
 
+    /// Group the files of a bucketed table by bucket, see
+    /// [`BucketSpec::group_files`] into the `target_partitions` of the
+    /// session. Returns the [`BucketSpec`] if the files were regrouped, in
+    /// which case the groups are hash partitioned by the bucket columns.
+    fn regroup_buckets(
+        &self,
+        file_groups: &mut Vec<Vec<PartitionedFile>>,
+        target_partitions: usize,
+    ) -> Option<&BucketSpec> {
+        let spec = self.options.bucket_spec.as_ref()?;
+        let files = file_groups.iter().flatten().cloned();
+        let groups = spec.group_files(files, target_partitions)?;
+        *file_groups = groups;
+        Some(spec)
+    }
+
     /// Collects statistics for a given partitioned file.
     ///
     /// This method first checks if the statistics for the given file are already cached.
@@ -1185,12 +1645,15 @@ mod tests {
         assert_batches_eq,
         test::{columns, object_store::register_test_store},
     };
-    use datafusion_physical_plan::collect;
+    use datafusion_datasource::write::orchestration::CommitMarker;
+    use datafusion_physical_plan::{collect, displayable};
 
+    use arrow::array::AsArray;
     use arrow::compute::SortOptions;
+    use arrow::datatypes::Int64Type;
     use arrow::record_batch::RecordBatch;
     use datafusion_common::stats::Precision;
-    use datafusion_common::{assert_contains, ScalarValue};
+    use datafusion_common::{assert_contains, ColumnStatistics, ScalarValue};
     use datafusion_expr::{BinaryExpr, LogicalPlanBuilder, Operator};
     use datafusion_physical_expr::PhysicalSortExpr;
     use datafusion_physical_plan::ExecutionPlanProperties;
@@ -2207,6 +2670,621 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_insert_overwrite_replaces_files() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+        session_ctx
+            .sql(&format!(
+                "create external table foo(a int) stored as parquet location '{str_path}/'"
+            ))
+            .await?
+            .collect()
+            .await?;
+
+        for sql in [
+            "insert into foo values (1), (2)",
+            "insert into foo values (3)",
+            "insert overwrite foo values (4), (5)",
+        ] {
+            session_ctx.sql(sql).await?.collect().await?;
+        }
+
+        let batches = session_ctx
+            .sql("select * from foo order by a")
+            .await?
+            .collect()
+            .await?;
+        let expected = ["+---+", "| a |", "+---+", "| 4 |", "| 5 |", "+---+"];
+        assert_batches_eq!(expected, &batches);
+
+        // the files of the previous inserts were deleted
+        let num_files = tmp_dir.path().read_dir()?.count();
+        assert_eq!(num_files, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_insert_leaves_no_files() -> Result<()> {
+        use datafusion_common_runtime::SpawnedTask;
+        use std::time::Duration;
+
+        let session_ctx = SessionContext::new();
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+        session_ctx
+            .sql(&format!(
+                "create external table foo(a bigint) stored as csv location '{str_path}/'"
+            ))
+            .await?
+            .collect()
+            .await?;
+
+        let insert = session_ctx
+            .sql("insert into foo select value from generate_series(1, 1000000000)")
+            .await?;
+        let insert = SpawnedTask::spawn(insert.collect());
+        while tmp_dir.path().read_dir()?.next().is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        // cancelling the insert deletes its staged files in the background
+        drop(insert);
+        for _ in 0..1000 {
+            if tmp_dir.path().read_dir()?.next().is_none() {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let files: Vec<_> = tmp_dir.path().read_dir()?.collect();
+        internal_err!("The staged files were not deleted: {files:?}")
+    }
+
+    #[tokio::test]
+    async fn test_failed_insert_leaves_no_files() -> Result<()> {
+        let config = SessionConfig::new().with_batch_size(10);
+        let session_ctx = SessionContext::new_with_config(config);
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+        session_ctx
+            .sql(&format!(
+                "create external table foo(a bigint) stored as csv location '{str_path}/'"
+            ))
+            .await?
+            .collect()
+            .await?;
+        session_ctx
+            .sql("insert into foo values (1)")
+            .await?
+            .collect()
+            .await?;
+
+        // fails with a division by zero after the first batches are written
+        let result = session_ctx
+            .sql("insert into foo select 100 / (value - 50) from generate_series(1, 100)")
+            .await?
+            .collect()
+            .await;
+        assert!(result.is_err());
+
+        let batches = session_ctx
+            .sql("select count(*) as count from foo")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 1     |",
+            "+-------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        // the files of the failed insert were removed
+        let num_files = tmp_dir.path().read_dir()?.count();
+        assert_eq!(num_files, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_commit_markers_hide_files() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+        session_ctx
+            .sql(&format!(
+                "create external table foo(a bigint) stored as csv location '{str_path}/'"
+            ))
+            .await?
+            .collect()
+            .await?;
+        session_ctx
+            .sql("insert overwrite foo values (1), (2)")
+            .await?
+            .collect()
+            .await?;
+        // the marker of the insert was deleted once it committed
+        let names: Vec<_> = tmp_dir
+            .path()
+            .read_dir()?
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        assert_eq!(names.len(), 1);
+        assert!(!names[0].starts_with(".staging-"));
+        let old = object_store::path::Path::from_filesystem_path(
+            tmp_dir.path().join(&names[0]),
+        )?;
+
+        // the file of a write left over while pending is not visible
+        std::fs::write(tmp_dir.path().join("new.csv"), "a\n3\n")?;
+        let new = object_store::path::Path::from_filesystem_path(
+            tmp_dir.path().join("new.csv"),
+        )?;
+        let marker = tmp_dir.path().join(".staging-test.commit");
+        let mut commit = CommitMarker {
+            committed: false,
+            added: vec![new],
+            replaced: vec![old],
+        };
+        std::fs::write(&marker, commit.to_bytes())?;
+        let count = "select count(*) as count from foo";
+        let batches = session_ctx.sql(count).await?.collect().await?;
+        assert_eq!(batches[0].column(0).as_primitive::<Int64Type>().value(0), 2);
+
+        // once committed, the replaced file is not visible
+        commit.committed = true;
+        std::fs::write(&marker, commit.to_bytes())?;
+        let batches = session_ctx.sql(count).await?.collect().await?;
+        assert_eq!(batches[0].column(0).as_primitive::<Int64Type>().value(0), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bucketed_table() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+
+        for (i, target_partitions) in [4, 2, 3].into_iter().enumerate() {
+            let config = SessionConfig::new().with_target_partitions(target_partitions);
+            let session_ctx = SessionContext::new_with_config(config);
+            session_ctx
+                .sql(&format!(
+                    "create external table foo(a int, b int) stored as parquet \
+                     clustered by (a) into 4 buckets location '{str_path}/'"
+                ))
+                .await?
+                .collect()
+                .await?;
+            session_ctx
+                .sql("insert into foo select value % 10, value from generate_series(1, 100)")
+                .await?
+                .collect()
+                .await?;
+
+            let sql = "select a, count(*) as n from foo group by a order by a limit 3";
+            let dataframe = session_ctx.sql(sql).await?;
+            let plan = dataframe.clone().create_physical_plan().await?;
+            let plan = displayable(plan.as_ref()).indent(true).to_string();
+            // the files of each bucket are read by the same partition, so the
+            // rows do not need to be repartitioned by `a`, unless the number of
+            // partitions does not divide the number of buckets
+            assert_eq!(
+                plan.contains("RepartitionExec"),
+                target_partitions == 3,
+                "{plan}"
+            );
+
+            // each insert adds 10 rows for each value of `a`
+            let n = 10 * (i + 1);
+            let expected = [
+                "+---+----+".to_string(),
+                "| a | n  |".to_string(),
+                "+---+----+".to_string(),
+                format!("| 0 | {n} |"),
+                format!("| 1 | {n} |"),
+                format!("| 2 | {n} |"),
+                "+---+----+".to_string(),
+            ];
+            let expected: Vec<_> = expected.iter().map(String::as_str).collect();
+            assert_batches_eq!(expected, &dataframe.collect().await?);
+        }
+
+        // each insert writes at most one file per bucket
+        for entry in tmp_dir.path().read_dir()? {
+            let name = entry?.file_name().into_string().unwrap();
+            assert!(name.contains("_bucket-"), "{name}");
+        }
+        assert!(tmp_dir.path().read_dir()?.count() <= 12);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bucketed_table_target_partitions_of_session() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+
+        let config = SessionConfig::new().with_target_partitions(4);
+        let session_ctx = SessionContext::new_with_config(config);
+        for (name, num_buckets) in [("foo", 4), ("bar", 8)] {
+            session_ctx
+                .sql(&format!(
+                    "create external table {name}(a int, b int) stored as parquet \
+                     clustered by (a) into {num_buckets} buckets location '{str_path}/{name}/'"
+                ))
+                .await?
+                .collect()
+                .await?;
+            session_ctx
+                .sql(&format!(
+                    "insert into {name} select value % 10, value from generate_series(1, 100)"
+                ))
+                .await?
+                .collect()
+                .await?;
+        }
+
+        // the tables are read with the target partitions of the session at
+        // scan time, rather than when the tables were created
+        session_ctx
+            .sql("set datafusion.execution.target_partitions = 2")
+            .await?
+            .collect()
+            .await?;
+        let sql = "select foo.a, count(*) as n from foo join bar on foo.a = bar.a \
+                   group by foo.a order by foo.a limit 2";
+        let dataframe = session_ctx.sql(sql).await?;
+        let plan = dataframe.clone().create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!plan.contains("RepartitionExec"), "{plan}");
+        assert!(plan.contains("file_groups={2 groups"), "{plan}");
+
+        let expected = [
+            "+---+-----+",
+            "| a | n   |",
+            "+---+-----+",
+            "| 0 | 100 |",
+            "| 1 | 100 |",
+            "+---+-----+",
+        ];
+        assert_batches_eq!(expected, &dataframe.collect().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bucketed_table_joined_with_unbucketed_input() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+
+        let config = SessionConfig::new().with_target_partitions(4);
+        let session_ctx = SessionContext::new_with_config(config);
+        session_ctx
+            .sql(&format!(
+                "create external table foo(a int, b int) stored as parquet \
+                 clustered by (a) into 4 buckets location '{str_path}/'"
+            ))
+            .await?
+            .collect()
+            .await?;
+        session_ctx
+            .sql("insert into foo select value % 10, value from generate_series(1, 100)")
+            .await?
+            .collect()
+            .await?;
+
+        // the rows of the bucket files are hashed with another hash function
+        // than `RepartitionExec`, so the bucketed side is repartitioned too
+        let sql = "select foo.a, count(*) as n from foo \
+                   join (select cast(value as int) as a from generate_series(0, 9)) bar \
+                   on foo.a = bar.a group by foo.a order by foo.a";
+        let dataframe = session_ctx.sql(sql).await?;
+        let plan = dataframe.clone().create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert_contains!(&plan, "RepartitionExec: partitioning=Hash([a@0], 4)");
+        let batches = dataframe.collect().await?;
+        let counts = batches
+            .iter()
+            .flat_map(|batch| batch.column(1).as_primitive::<Int64Type>().values())
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![10; 10]);
+
+        // files that do not record the current bucket hash function and
+        // version are not read as buckets
+        for entry in tmp_dir.path().read_dir()? {
+            let path = entry?.path();
+            let name = path.file_name().unwrap().to_str().unwrap();
+            let old_name = name.replace("_bucket-murmur3v1-", "_bucket-");
+            std::fs::rename(&path, tmp_dir.path().join(old_name))?;
+        }
+        let sql = "select a, count(*) as n from foo group by a";
+        let plan = session_ctx.sql(sql).await?.create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert_contains!(&plan, "RepartitionExec: partitioning=Hash([a@0], 4)");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn test_schema_evolution() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+
+        // "old_v" was renamed to "v" and widened to Int64, and "w" was added
+        let session_ctx = SessionContext::new();
+        for sql in [
+            format!(
+                "copy (select cast(value as int) as old_v from generate_series(1, 3)) \
+                 to '{str_path}/1.parquet'"
+            ),
+            format!(
+                "copy (select value as v, value * 10 as w from generate_series(4, 5)) \
+                 to '{str_path}/2.parquet'"
+            ),
+            format!(
+                "copy (select cast(value as int) as v from generate_series(6, 7)) \
+                 to '{str_path}/3.parquet'"
+            ),
+        ] {
+            session_ctx.sql(&sql).await?.collect().await?;
+        }
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("v", DataType::Int64, true),
+            Field::new("w", DataType::Int64, true),
+        ]));
+        let options = ListingOptions::new(Arc::new(ParquetFormat::default()));
+        let config = ListingTableConfig::new(ListingTableUrl::parse(str_path)?)
+            .with_listing_options(options)
+            .with_schema(schema);
+        let table = ListingTable::try_new(config)?
+            .with_column_defaults(HashMap::from([("w".to_string(), lit(-1i64))]))
+            .with_schema_evolution(
+                SchemaEvolutionAdapterFactory::new().with_rename("v", "old_v"),
+            );
+        let table = Arc::new(table);
+        session_ctx.register_table("t", Arc::clone(&table) as _)?;
+
+        let result = session_ctx
+            .sql("select v, w from t where v > 1 order by v")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+---+----+",
+            "| v | w  |",
+            "+---+----+",
+            "| 2 | -1 |",
+            "| 3 | -1 |",
+            "| 4 | 40 |",
+            "| 5 | 50 |",
+            "| 6 | -1 |",
+            "| 7 | -1 |",
+            "+---+----+",
+        ];
+        assert_batches_eq!(expected, &result);
+
+        // the statistics of the files are of the columns with the names of the
+        // columns of the table, such as the missing `v` of the first file, so
+        // the statistics of the columns are unknown
+        let exec = table.scan(&session_ctx.state(), None, &[], None).await?;
+        let statistics = exec.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Exact(7));
+        assert_eq!(
+            statistics.column_statistics[0],
+            ColumnStatistics::new_unknown()
+        );
+
+        let result = session_ctx
+            .sql("select min(v), max(v), count(*) from t")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+----------+----------+----------+",
+            "| min(t.v) | max(t.v) | count(*) |",
+            "+----------+----------+----------+",
+            "| 1        | 7        | 7        |",
+            "+----------+----------+----------+",
+        ];
+        assert_batches_eq!(expected, &result);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn test_file_index() -> Result<()> {
+        use crate::datasource::listing::JsonManifestIndex;
+
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+
+        let session_ctx = SessionContext::new();
+        for (part, start) in [(1, 1), (2, 10), (2, 100)] {
+            let end = start + 1;
+            let sql = format!(
+                "copy (select value as v from generate_series({start}, {end})) \
+                 to '{str_path}/p={part}/{start}.parquet'"
+            );
+            session_ctx.sql(&sql).await?.collect().await?;
+        }
+        let size =
+            |path: &str| std::fs::metadata(tmp_dir.path().join(path)).map(|m| m.len());
+
+        // the file of 100 is not in the manifest, and the file of 10 is only
+        // in the current snapshot
+        let manifest = format!(
+            r#"{{
+                "snapshots": [
+                    {{
+                        "snapshot_id": 1,
+                        "timestamp_ms": 1000,
+                        "files": [
+                            {{ "path": "p=1/1.parquet", "size": {}, "partition_values": {{ "p": "1" }} }}
+                        ]
+                    }},
+                    {{
+                        "snapshot_id": 2,
+                        "timestamp_ms": 2000,
+                        "files": [
+                            {{ "path": "p=1/1.parquet", "size": {}, "partition_values": {{ "p": "1" }} }},
+                            {{ "path": "p=2/10.parquet", "size": {}, "partition_values": {{ "p": "2" }} }}
+                        ]
+                    }}
+                ]
+            }}"#,
+            size("p=1/1.parquet")?,
+            size("p=1/1.parquet")?,
+            size("p=2/10.parquet")?,
+        );
+        std::fs::write(tmp_dir.path().join("_manifest.json"), manifest)?;
+
+        let table_path = ListingTableUrl::parse(str_path)?;
+        let options = ListingOptions::new(Arc::new(ParquetFormat::default()))
+            .with_table_partition_cols(vec![("p".to_string(), DataType::Int64)]);
+        let schema = Arc::new(Schema::new(vec![Field::new("v", DataType::Int64, true)]));
+        for (name, index) in [
+            ("t", JsonManifestIndex::new(table_path.clone())),
+            (
+                "t1",
+                JsonManifestIndex::new(table_path.clone()).with_snapshot_id(1),
+            ),
+        ] {
+            let config = ListingTableConfig::new(table_path.clone())
+                .with_listing_options(options.clone())
+                .with_schema(Arc::clone(&schema));
+            let table = ListingTable::try_new(config)?.with_file_index(Arc::new(index));
+            session_ctx.register_table(name, Arc::new(table))?;
+        }
+
+        let sql = "select p, sum(v) as s from t group by p order by p";
+        let result = session_ctx.sql(sql).await?.collect().await?;
+        let expected = [
+            "+---+----+",
+            "| p | s  |",
+            "+---+----+",
+            "| 1 | 3  |",
+            "| 2 | 21 |",
+            "+---+----+",
+        ];
+        assert_batches_eq!(expected, &result);
+
+        let sql = "select sum(v) as s from t where p = 2";
+        let result = session_ctx.sql(sql).await?.collect().await?;
+        let expected = ["+----+", "| s  |", "+----+", "| 21 |", "+----+"];
+        assert_batches_eq!(expected, &result);
+
+        let sql = "select sum(v) as s from t1";
+        let result = session_ctx.sql(sql).await?.collect().await?;
+        let expected = ["+---+", "| s |", "+---+", "| 3 |", "+---+"];
+        assert_batches_eq!(expected, &result);
+
+        // previous snapshots with `FOR SYSTEM_TIME AS OF`, which is parsed by
+        // the BigQuery dialect
+        let sql = "set datafusion.sql_parser.dialect = 'BigQuery'";
+        session_ctx.sql(sql).await?.collect().await?;
+        for version in ["1", "timestamp '1970-01-01 00:00:01.5'"] {
+            let sql =
+                format!("select sum(v) as s from t for system_time as of {version}");
+            let result = session_ctx.sql(&sql).await?.collect().await?;
+            assert_batches_eq!(expected, &result);
+        }
+        let df = session_ctx
+            .table_at_version("t", TableVersion::Version(2))
+            .await?;
+        assert_eq!(df.count().await?, 4);
+        let err = session_ctx
+            .sql("select * from t for system_time as of 3")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "has no snapshot 3");
+
+        let err = session_ctx
+            .sql("insert into t values (1, 1)")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "with a FileIndex is not supported");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_infer_partition_types_from_all_files() -> Result<()> {
+        let config = SessionConfig::new().set_bool(
+            "datafusion.execution.listing_table_infer_partition_types",
+            true,
+        );
+        let ctx = SessionContext::new_with_config(config);
+        // the value of the last file is not an integer
+        let files = (0..10)
+            .map(|i| format!("table/p1=1{i}/p2=2024-01-01/file.avro"))
+            .chain(["table/p1=a/p2=2024-01-02/file.avro".to_string()])
+            .collect::<Vec<_>>();
+        register_test_store(
+            &ctx,
+            &files.iter().map(|f| (f.as_str(), 10)).collect::<Vec<_>>(),
+        );
+
+        let opt = ListingOptions::new(Arc::new(AvroFormat {}))
+            .with_file_extension(AvroFormat.get_ext());
+        let table_path = ListingTableUrl::parse("test:///table/").unwrap();
+        let config = ListingTableConfig::new(table_path)
+            .with_listing_options(opt)
+            .infer_partitions_from_path(&ctx.state())
+            .await?;
+
+        let dictionary =
+            DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8));
+        assert_eq!(
+            config.options.unwrap().table_partition_cols,
+            vec![
+                ("p1".to_string(), dictionary),
+                ("p2".to_string(), DataType::Date32)
+            ]
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_infer_options_compressed_csv() -> Result<()> {
         let testdata = crate::test_util::arrow_test_data();
@@ -2225,4 +3303,168 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_insert_unbounded_input() -> Result<()> {
+        use arrow::array::{AsArray, Int64Array, StringArray};
+        use arrow::datatypes::Int64Type;
+        use datafusion_catalog::streaming::StreamingTable;
+        use datafusion_common_runtime::SpawnedTask;
+        use datafusion_datasource::write::orchestration::STAGING_FILE_PREFIX;
+        use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+        use datafusion_physical_plan::stream::RecordBatchStreamAdapter;
+        use datafusion_physical_plan::streaming::PartitionStream;
+        use tokio::sync::mpsc;
+
+        /// An unbounded stream of the batches sent to a channel
+        #[derive(Debug)]
+        struct ChannelStream {
+            schema: SchemaRef,
+            rx: std::sync::Mutex<Option<mpsc::Receiver<RecordBatch>>>,
+        }
+
+        impl PartitionStream for ChannelStream {
+            fn schema(&self) -> &SchemaRef {
+                &self.schema
+            }
+
+            fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+                let rx = self.rx.lock().unwrap().take().expect("executed once");
+                let stream = stream::unfold(rx, |mut rx| async move {
+                    rx.recv().await.map(|batch| (Ok(batch), rx))
+                });
+                Box::pin(RecordBatchStreamAdapter::new(
+                    Arc::clone(&self.schema),
+                    stream,
+                ))
+            }
+        }
+
+        async fn wait_for_rows(ctx: &SessionContext, rows: i64) -> Result<()> {
+            for _ in 0..1000 {
+                let batches = ctx.sql("SELECT count(*) FROM t").await?.collect().await?;
+                if batches[0].column(0).as_primitive::<Int64Type>().value(0) == rows {
+                    return Ok(());
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+            internal_err!("Table t did not reach {rows} rows")
+        }
+
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir
+            .path()
+            .to_str()
+            .expect("Temp path should convert to &str");
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("p", DataType::Utf8, true),
+        ]));
+        let batch = |a: Vec<i64>, p: Vec<&str>| {
+            RecordBatch::try_new(
+                Arc::clone(&schema),
+                vec![
+                    Arc::new(Int64Array::from(a)),
+                    Arc::new(StringArray::from(p)),
+                ],
+            )
+        };
+        let (tx, rx) = mpsc::channel(10);
+        let source = StreamingTable::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(ChannelStream {
+                schema: Arc::clone(&schema),
+                rx: std::sync::Mutex::new(Some(rx)),
+            })],
+        )?
+        .with_infinite_table(true);
+
+        let mut config = SessionConfig::new();
+        let options = &mut config.options_mut().execution;
+        options.streaming_write_max_rows_per_file = Some(2);
+        options.streaming_write_rollover_interval_ms = Some(500);
+        let session_ctx = SessionContext::new_with_config(config);
+        session_ctx.register_table("source", Arc::new(source))?;
+        let sql = format!(
+            "CREATE EXTERNAL TABLE t (a BIGINT, p VARCHAR) STORED AS PARQUET \
+             PARTITIONED BY (p) LOCATION '{str_path}/'"
+        );
+        session_ctx.sql(&sql).await?.collect().await?;
+
+        // an unbounded input requires rolling the files
+        let mut state = session_ctx.state();
+        let options = &mut state.config_mut().options_mut().execution;
+        options.streaming_write_max_rows_per_file = None;
+        options.streaming_write_rollover_interval_ms = None;
+        let err = SessionContext::new_with_state(state)
+            .sql("INSERT INTO t SELECT a, p FROM source")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "streaming_write_* options");
+
+        let insert = session_ctx
+            .sql("INSERT INTO t SELECT a, p FROM source")
+            .await?;
+        let insert = SpawnedTask::spawn(insert.collect());
+
+        // each file is committed once it contains 2 rows, on its own
+        tx.send(batch(vec![1, 2, 3], vec!["x", "x", "y"])?)
+            .await
+            .unwrap();
+        wait_for_rows(&session_ctx, 2).await?;
+        tx.send(batch(vec![4], vec!["y"])?).await.unwrap();
+        wait_for_rows(&session_ctx, 4).await?;
+
+        // and once the rollover interval has passed since its first row
+        tx.send(batch(vec![5], vec!["x"])?).await.unwrap();
+        wait_for_rows(&session_ctx, 5).await?;
+
+        drop(tx);
+        let batches = insert.join().await.unwrap()?;
+        let expected = [
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 5     |",
+            "+-------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        let files = |part: &str| {
+            std::fs::read_dir(tmp_dir.path().join(part))
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect::<Vec<_>>()
+        };
+        for (part, num_files) in [("p=x", 2), ("p=y", 1)] {
+            let files = files(part);
+            assert_eq!(files.len(), num_files, "{files:?}");
+            assert!(files
+                .iter()
+                .all(|file| !file.starts_with(STAGING_FILE_PREFIX)));
+        }
+
+        let batches = session_ctx
+            .sql("SELECT a, p FROM t ORDER BY a")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+---+---+",
+            "| a | p |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | x |",
+            "| 3 | y |",
+            "| 4 | y |",
+            "| 5 | x |",
+            "+---+---+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        Ok(())
+    }
 }
@@ -23,12 +23,14 @@ use std::sync::Arc;
 
 use crate::catalog::{TableProvider, TableProviderFactory};
 use crate::datasource::listing::{
-    ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+    BucketSpec, ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
 };
 use crate::execution::context::SessionState;
 
 use arrow::datatypes::{DataType, SchemaRef};
-use datafusion_common::{arrow_datafusion_err, plan_err, DataFusionError, ToDFSchema};
+use datafusion_common::{
+    arrow_datafusion_err, not_impl_err, plan_err, DataFusionError, ToDFSchema,
+};
 use datafusion_common::{config_datafusion_err, Result};
 use datafusion_expr::CreateExternalTable;
 
@@ -108,18 +110,34 @@ impl TableProviderFactory for ListingTableFactory {
             (Some(schema), table_partition_cols)
         };
 
+        let bucket_spec = match cmd.num_buckets {
+            Some(_) if !cmd.table_partition_cols.is_empty() => {
+                return not_impl_err!(
+                    "Tables with both PARTITIONED BY and CLUSTERED BY are not supported"
+                );
+            }
+            Some(num_buckets) => {
+                Some(BucketSpec::try_new(cmd.clustered_by.clone(), num_buckets)?)
+            }
+            None => None,
+        };
+
         let table_path = ListingTableUrl::parse(&cmd.location)?;
 
         let options = ListingOptions::new(file_format)
             .with_collect_stat(state.config().collect_statistics())
             .with_file_extension(file_extension)
             .with_target_partitions(state.config().target_partitions())
-            .with_table_partition_cols(table_partition_cols);
+            .with_table_partition_cols(table_partition_cols)
+            .with_bucket_spec(bucket_spec);
 
         options
             .validate_partitions(session_state, &table_path)
             .await?;
 
+        // the files are only read to infer their sort order if they are read
+        // to infer the schema anyway
+        let infer_file_sort_order = provided_schema.is_none();
         let resolved_schema = match provided_schema {
             // We will need to check the table columns against the schema
             // this is done so that we can do an ORDER BY for external table creation
@@ -145,9 +163,17 @@ impl TableProviderFactory for ListingTableFactory {
             }
             Some(s) => s,
         };
-        let config = ListingTableConfig::new(table_path)
+        for column in &cmd.clustered_by {
+            if resolved_schema.index_of(column).is_err() {
+                return plan_err!("Bucket column {column} is not in schema");
+            }
+        }
+        let mut config = ListingTableConfig::new(table_path)
             .with_listing_options(options.with_file_sort_order(cmd.order_exprs.clone()))
             .with_schema(resolved_schema);
+        if infer_file_sort_order {
+            config = config.infer_file_sort_order(session_state).await?;
+        }
         let provider = ListingTable::try_new(config)?
             .with_cache(state.runtime_env().cache_manager.get_file_statistic_cache());
         let table = provider
@@ -196,6 +222,8 @@ mod tests {
             file_type: "csv".to_string(),
             schema: Arc::new(DFSchema::empty()),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             if_not_exists: false,
             temporary: false,
             definition: None,
@@ -236,6 +264,8 @@ mod tests {
             file_type: "csv".to_string(),
             schema: Arc::new(DFSchema::empty()),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             if_not_exists: false,
             temporary: false,
             definition: None,
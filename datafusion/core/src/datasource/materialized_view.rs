@@ -0,0 +1,267 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Materialized view data source, which stores the results of a LogicalPlan
+
+use std::{any::Any, sync::Arc};
+
+use crate::datasource::listing::{ListingTable, ListingTableUrl};
+use crate::datasource::{source_as_provider, MemTable, TableProvider, TableType};
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::logical_expr::{Expr, LogicalPlan};
+use crate::physical_plan::{collect, collect_partitioned, ExecutionPlan};
+
+use arrow::datatypes::SchemaRef;
+use async_trait::async_trait;
+use datafusion_catalog::Session;
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::tree_node::TreeNodeRecursion;
+use datafusion_common::{plan_err, SchemaExt, Statistics};
+use datafusion_expr::dml::InsertOp;
+use datafusion_expr::TableProviderFilterPushDown;
+use datafusion_optimizer::analyzer::expand_wildcard_rule::ExpandWildcardRule;
+use datafusion_optimizer::analyzer::type_coercion::TypeCoercion;
+use datafusion_optimizer::Analyzer;
+use object_store::ObjectMeta;
+use parking_lot::RwLock;
+
+/// An implementation of `TableProvider` that stores the results of a
+/// logical plan, which are recomputed by [`Self::refresh`].
+///
+/// The results are stored in memory, or in the files of a table at a
+/// location, such as a `ListingTable` (see [`Self::with_location`]).
+#[derive(Debug)]
+pub struct MaterializedViewTable {
+    /// LogicalPlan of the view definition
+    logical_plan: LogicalPlan,
+    /// Schema of the results
+    table_schema: SchemaRef,
+    /// SQL used to create the view, if available
+    definition: Option<String>,
+    /// The table storing the results
+    storage: RwLock<Arc<dyn TableProvider>>,
+    /// The location of the files of `storage`, if not stored in memory
+    location: Option<ListingTableUrl>,
+    /// The files of the tables the view reads when it was last refreshed,
+    /// see [`Self::sources_unchanged`]
+    source_files: RwLock<Option<Vec<ObjectMeta>>>,
+}
+
+impl MaterializedViewTable {
+    /// Create a new materialized view, storing the results of `logical_plan`
+    /// in memory. The view is empty until it is refreshed.
+    pub fn try_new(
+        logical_plan: LogicalPlan,
+        definition: Option<String>,
+    ) -> Result<Self> {
+        let logical_plan = Self::apply_required_rule(logical_plan)?;
+        let table_schema: SchemaRef = Arc::new(logical_plan.schema().as_arrow().clone());
+        let storage = MemTable::try_new(Arc::clone(&table_schema), vec![vec![]])?;
+
+        Ok(Self {
+            logical_plan,
+            table_schema,
+            definition,
+            storage: RwLock::new(Arc::new(storage)),
+            location: None,
+            source_files: RwLock::new(None),
+        })
+    }
+
+    /// Store the results in `storage`, a table of the files at `location`
+    /// with the same schema as the view.
+    ///
+    /// All files at `location` belong to the view: refreshing it overwrites
+    /// the files of `storage` with the new results, so `storage` must
+    /// support [`InsertOp::Overwrite`].
+    pub fn with_location(
+        mut self,
+        storage: Arc<dyn TableProvider>,
+        location: ListingTableUrl,
+    ) -> Result<Self> {
+        if let Err(e) = storage
+            .schema()
+            .logically_equivalent_names_and_types(&self.table_schema)
+        {
+            return plan_err!(
+                "Storage at {location} does not match the schema of the materialized view: {e}"
+            );
+        }
+        self.storage = RwLock::new(storage);
+        self.location = Some(location);
+        Ok(self)
+    }
+
+    fn apply_required_rule(logical_plan: LogicalPlan) -> Result<LogicalPlan> {
+        let options = ConfigOptions::default();
+        Analyzer::with_rules(vec![
+            Arc::new(ExpandWildcardRule::new()),
+            Arc::new(TypeCoercion::new()),
+        ])
+        .execute_and_check(logical_plan, &options, |_, _| {})
+    }
+
+    /// Get definition ref
+    pub fn definition(&self) -> Option<&String> {
+        self.definition.as_ref()
+    }
+
+    /// Get logical_plan ref
+    pub fn logical_plan(&self) -> &LogicalPlan {
+        &self.logical_plan
+    }
+
+    /// The location of the stored results, or `None` if stored in memory
+    pub fn location(&self) -> Option<&ListingTableUrl> {
+        self.location.as_ref()
+    }
+
+    /// The table storing the results
+    pub fn storage(&self) -> Arc<dyn TableProvider> {
+        Arc::clone(&self.storage.read())
+    }
+
+    /// Recompute the results of the view with `state`, and replace the
+    /// stored results with them
+    ///
+    /// The query waits to be admitted like the queries of a [`DataFrame`],
+    /// see [`SessionState::admit`].
+    ///
+    /// [`DataFrame`]: crate::dataframe::DataFrame
+    pub async fn refresh(&self, state: &SessionState) -> Result<()> {
+        // the view must not be answered from its own results
+        let mut state = state.clone();
+        state
+            .config_mut()
+            .options_mut()
+            .optimizer
+            .enable_materialized_view_rewrite = false;
+        let _permit = state.admit(&self.logical_plan).await;
+        // listed before computing the results, so that files changed while
+        // the view is refreshed are detected as changes afterwards
+        let source_files = self.list_source_files(&state).await?;
+        let plan = state.create_physical_plan(&self.logical_plan).await?;
+
+        match &self.location {
+            None => {
+                let batches = collect_partitioned(plan, state.task_ctx()).await?;
+                let table = MemTable::try_new(Arc::clone(&self.table_schema), batches)?;
+                *self.storage.write() = Arc::new(table);
+            }
+            Some(_) => {
+                let insert = self
+                    .storage()
+                    .insert_into(&state, plan, InsertOp::Overwrite)
+                    .await?;
+                collect(insert, state.task_ctx()).await?;
+            }
+        }
+        *self.source_files.write() = source_files;
+        Ok(())
+    }
+
+    /// Returns `true` if the files of the tables the view reads are the same
+    /// as when the view was last refreshed, with the same size, last
+    /// modification time and ETag.
+    ///
+    /// This detects changes made outside of the session, such as files
+    /// written to the location of a `ListingTable` by other writers. Returns
+    /// `false` if the view reads a table whose changes can not be detected
+    /// this way, which is any table other than a `ListingTable` whose files
+    /// are listed or a [`MemTable`].
+    pub async fn sources_unchanged(&self, state: &SessionState) -> Result<bool> {
+        let Some(refreshed) = self.source_files.read().clone() else {
+            return Ok(false);
+        };
+        let current = self.list_source_files(state).await?;
+        Ok(current.as_ref() == Some(&refreshed))
+    }
+
+    /// List the files of the `ListingTable`s the view reads, or `None` if
+    /// it reads a table whose changes can not be detected from its files
+    async fn list_source_files(
+        &self,
+        state: &SessionState,
+    ) -> Result<Option<Vec<ObjectMeta>>> {
+        let mut sources = vec![];
+        self.logical_plan.apply_with_subqueries(|plan| {
+            if let LogicalPlan::TableScan(scan) = plan {
+                sources.push(Arc::clone(&scan.source));
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+
+        let mut files = vec![];
+        for source in sources {
+            let Ok(provider) = source_as_provider(&source) else {
+                return Ok(None);
+            };
+            if let Some(table) = provider.as_any().downcast_ref::<ListingTable>() {
+                let Some(table_files) = table.list_all_files(state).await? else {
+                    return Ok(None);
+                };
+                files.extend(table_files);
+            } else if provider.as_any().downcast_ref::<MemTable>().is_none() {
+                return Ok(None);
+            }
+        }
+        files.sort_by(|a, b| a.location.cmp(&b.location));
+        files.dedup_by(|a, b| a.location == b.location);
+        Ok(Some(files))
+    }
+}
+
+#[async_trait]
+impl TableProvider for MaterializedViewTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.table_schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::View
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.storage().supports_filters_pushdown(filters)
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.storage().scan(state, projection, filters, limit).await
+    }
+
+    fn statistics(&self) -> Option<Statistics> {
+        self.storage().statistics()
+    }
+}
@@ -27,6 +27,7 @@ pub mod empty;
 pub mod file_format;
 pub mod listing;
 pub mod listing_table_factory;
+pub mod materialized_view;
 pub mod memory;
 pub mod physical_plan;
 pub mod provider;
@@ -41,6 +42,7 @@ pub use datafusion_datasource::source;
 pub use self::default_table_source::{
     provider_as_source, source_as_provider, DefaultTableSource,
 };
+pub use self::materialized_view::MaterializedViewTable;
 pub use self::memory::MemTable;
 pub use self::view::ViewTable;
 pub use crate::catalog::TableProvider;
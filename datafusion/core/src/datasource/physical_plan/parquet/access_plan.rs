@@ -166,6 +166,51 @@ impl ParquetAccessPlan {
         }
     }
 
+    /// Updates the plan to not scan the rows at `positions` of the file, such
+    /// as deleted rows. `positions` must be in increasing order.
+    ///
+    /// Row groups whose rows are all skipped are skipped entirely, and
+    /// others only scan the selection of rows that are not skipped (see
+    /// [`Self::scan_selection`]).
+    ///
+    /// Returns the number of skipped rows in the row groups that were scanned
+    pub fn skip_rows(
+        &mut self,
+        positions: &[u64],
+        row_group_meta_data: &[RowGroupMetaData],
+    ) -> usize {
+        assert_eq!(row_group_meta_data.len(), self.row_groups.len());
+        let mut skipped = 0;
+        let mut start = 0;
+        for (idx, rg_meta) in row_group_meta_data.iter().enumerate() {
+            let end = start + rg_meta.num_rows() as u64;
+            let first = positions.partition_point(|&position| position < start);
+            let last = positions.partition_point(|&position| position < end);
+            let rg_positions = &positions[first..last];
+            if rg_positions.is_empty() || !self.should_scan(idx) {
+                start = end;
+                continue;
+            }
+
+            skipped += rg_positions.len();
+            if rg_positions.len() as u64 == end - start {
+                self.skip(idx);
+            } else {
+                let mut selectors = Vec::with_capacity(2 * rg_positions.len() + 1);
+                let mut next = start;
+                for &position in rg_positions {
+                    selectors.push(RowSelector::select((position - next) as usize));
+                    selectors.push(RowSelector::skip(1));
+                    next = position + 1;
+                }
+                selectors.push(RowSelector::select((end - next) as usize));
+                self.scan_selection(idx, selectors.into_iter().collect());
+            }
+            start = end;
+        }
+        skipped
+    }
+
     /// Return an overall `RowSelection`, if needed
     ///
     /// This is used to compute the row selection for the parquet reader. See
@@ -464,6 +509,52 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_skip_rows() {
+        let mut access_plan = ParquetAccessPlan::new(vec![
+            RowGroupAccess::Scan,
+            RowGroupAccess::Scan,
+            RowGroupAccess::Selection(
+                vec![RowSelector::skip(10), RowSelector::select(20)].into(),
+            ),
+            RowGroupAccess::Skip,
+        ]);
+
+        // all the rows of row group 1, and positions in a skipped row group
+        // and past the end of the file are ignored
+        let positions = [0, 3, 4]
+            .into_iter()
+            .chain(10..30)
+            .chain([35, 50, 99, 100])
+            .collect::<Vec<_>>();
+        let skipped = access_plan.skip_rows(&positions, &ROW_GROUP_METADATA);
+        assert_eq!(skipped, 25);
+
+        let row_group_indexes = access_plan.row_group_indexes();
+        let row_selection = access_plan
+            .into_overall_row_selection(&ROW_GROUP_METADATA)
+            .unwrap();
+        assert_eq!(row_group_indexes, vec![0, 2]);
+        assert_eq!(
+            row_selection,
+            Some(
+                vec![
+                    // row group 0
+                    RowSelector::skip(1),
+                    RowSelector::select(2),
+                    RowSelector::skip(2),
+                    RowSelector::select(5),
+                    // row group 2, intersected with the existing selection
+                    RowSelector::skip(10),
+                    RowSelector::select(10),
+                    RowSelector::skip(1),
+                    RowSelector::select(9),
+                ]
+                .into()
+            )
+        );
+    }
+
     #[test]
     fn test_invalid_too_few() {
         let access_plan = ParquetAccessPlan::new(vec![
@@ -0,0 +1,147 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Filtering of the rows of a parquet file deleted by equality deletes, see
+//! [`FileDeletes`](crate::datasource::listing::FileDeletes)
+
+use std::collections::HashSet;
+
+use arrow::array::{new_null_array, Array, BooleanArray, RecordBatch};
+use arrow::compute::cast_with_options;
+use arrow::datatypes::{Fields, Schema};
+use arrow::error::Result as ArrowResult;
+use arrow::row::{RowConverter, SortField};
+use parquet::arrow::arrow_reader::ArrowPredicate;
+use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::ParquetMetaData;
+
+use datafusion_common::format::DEFAULT_CAST_OPTIONS;
+use datafusion_common::Result;
+
+use crate::physical_plan::metrics;
+
+/// How the rows of a file are deleted by a batch of equality deletes
+#[derive(Debug)]
+pub(crate) enum EqualityDeleteFilter {
+    /// No row of the file is deleted
+    None,
+    /// Every row of the file is deleted
+    All,
+    /// The rows are deleted by evaluating the predicate
+    Predicate(EqualityDeletePredicate),
+}
+
+impl EqualityDeleteFilter {
+    /// Create the filter of the rows of the file with `file_schema` deleted
+    /// by the rows of `deletes`
+    pub fn try_new(
+        deletes: &RecordBatch,
+        file_schema: &Schema,
+        metadata: &ParquetMetaData,
+        rows_pruned: metrics::Count,
+    ) -> Result<Self> {
+        let fields = deletes.schema().fields().clone();
+        let projection = fields
+            .iter()
+            .filter_map(|field| file_schema.index_of(field.name()).ok())
+            .collect::<Vec<_>>();
+
+        // the delete columns of every row of the file are null
+        if projection.is_empty() {
+            let nulls = deletes
+                .columns()
+                .iter()
+                .map(|column| column.logical_nulls())
+                .collect::<Vec<_>>();
+            let deletes_nulls = (0..deletes.num_rows()).any(|row| {
+                nulls
+                    .iter()
+                    .all(|nulls| nulls.as_ref().is_some_and(|n| n.is_null(row)))
+            });
+            return Ok(if deletes_nulls { Self::All } else { Self::None });
+        }
+
+        let converter = RowConverter::new(
+            fields
+                .iter()
+                .map(|field| SortField::new(field.data_type().clone()))
+                .collect(),
+        )?;
+        let deleted = converter
+            .convert_columns(deletes.columns())?
+            .iter()
+            .map(|row| row.as_ref().into())
+            .collect();
+
+        Ok(Self::Predicate(EqualityDeletePredicate {
+            projection_mask: ProjectionMask::roots(
+                metadata.file_metadata().schema_descr(),
+                projection,
+            ),
+            fields,
+            converter,
+            deleted,
+            rows_pruned,
+        }))
+    }
+}
+
+/// An [`ArrowPredicate`] that filters out the rows of a file that are equal
+/// to a row of a batch of equality deletes, like an anti join
+#[derive(Debug)]
+pub(crate) struct EqualityDeletePredicate {
+    /// The columns of the file that are delete columns
+    projection_mask: ProjectionMask,
+    /// The delete columns
+    fields: Fields,
+    /// Converts the delete columns to rows, where nulls are equal
+    converter: RowConverter,
+    /// The rows of the deletes
+    deleted: HashSet<Box<[u8]>>,
+    /// how many rows were filtered out by this predicate
+    rows_pruned: metrics::Count,
+}
+
+impl ArrowPredicate for EqualityDeletePredicate {
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection_mask
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> ArrowResult<BooleanArray> {
+        // values that can not be cast are errors rather than nulls, which
+        // would match the deletes of nulls
+        let columns = self
+            .fields
+            .iter()
+            .map(|field| match batch.column_by_name(field.name()) {
+                Some(column) => {
+                    cast_with_options(column, field.data_type(), &DEFAULT_CAST_OPTIONS)
+                }
+                None => Ok(new_null_array(field.data_type(), batch.num_rows())),
+            })
+            .collect::<ArrowResult<Vec<_>>>()?;
+
+        let keep = self
+            .converter
+            .convert_columns(&columns)?
+            .iter()
+            .map(|row| Some(!self.deleted.contains(row.as_ref())))
+            .collect::<BooleanArray>();
+        self.rows_pruned.add(keep.false_count());
+        Ok(keep)
+    }
+}
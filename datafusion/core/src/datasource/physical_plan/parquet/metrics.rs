@@ -57,6 +57,10 @@ pub struct ParquetFileMetrics {
     pub page_index_eval_time: Time,
     /// Total time spent reading and parsing metadata from the footer
     pub metadata_load_time: Time,
+    /// Total rows not read because of positional deletes
+    pub positional_delete_rows_pruned: Count,
+    /// Total rows filtered out by equality deletes
+    pub equality_delete_rows_pruned: Count,
 }
 
 impl ParquetFileMetrics {
@@ -122,6 +126,13 @@ impl ParquetFileMetrics {
             .with_new_label("filename", filename.to_string())
             .subset_time("metadata_load_time", partition);
 
+        let positional_delete_rows_pruned = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("positional_delete_rows_pruned", partition);
+        let equality_delete_rows_pruned = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("equality_delete_rows_pruned", partition);
+
         Self {
             predicate_evaluation_errors,
             row_groups_matched_bloom_filter,
@@ -138,6 +149,8 @@ impl ParquetFileMetrics {
             bloom_filter_eval_time,
             page_index_eval_time,
             metadata_load_time,
+            positional_delete_rows_pruned,
+            equality_delete_rows_pruned,
         }
     }
 }
@@ -18,6 +18,7 @@
 //! [`ParquetExec`] FileSource for reading Parquet files
 
 mod access_plan;
+mod deletes;
 mod metrics;
 mod opener;
 mod page_filter;
@@ -558,7 +559,9 @@ mod tests {
     use crate::datasource::file_format::options::CsvReadOptions;
     use crate::datasource::file_format::parquet::test_util::store_parquet;
     use crate::datasource::file_format::test_util::scan_format;
-    use crate::datasource::listing::{FileRange, ListingOptions, PartitionedFile};
+    use crate::datasource::listing::{
+        FileDeletes, FileRange, ListingOptions, PartitionedFile,
+    };
     use crate::datasource::object_store::ObjectStoreUrl;
     use crate::execution::context::SessionState;
     use crate::physical_plan::displayable;
@@ -614,6 +617,7 @@ mod tests {
         predicate: Option<Expr>,
         pushdown_predicate: bool,
         page_index_predicate: bool,
+        deletes: Option<FileDeletes>,
     }
 
     impl RoundTrip {
@@ -646,6 +650,11 @@ mod tests {
             self
         }
 
+        fn with_deletes(mut self, deletes: FileDeletes) -> Self {
+            self.deletes = Some(deletes);
+            self
+        }
+
         /// run the test, returning only the resulting RecordBatches
         async fn round_trip_to_batches(
             self,
@@ -662,6 +671,7 @@ mod tests {
                 predicate,
                 pushdown_predicate,
                 page_index_predicate,
+                deletes,
             } = self;
 
             let file_schema = match schema {
@@ -677,7 +687,16 @@ mod tests {
             // files with multiple pages
             let multi_page = page_index_predicate;
             let (meta, _files) = store_parquet(batches, multi_page).await.unwrap();
-            let file_group = meta.into_iter().map(Into::into).collect();
+            let file_group = meta
+                .into_iter()
+                .map(|meta| {
+                    let file = PartitionedFile::from(meta);
+                    match &deletes {
+                        Some(deletes) => file.with_deletes(deletes.clone()),
+                        None => file,
+                    }
+                })
+                .collect();
 
             // set up predicate (this is normally done by a layer higher up)
             let predicate = predicate.map(|p| logical2physical(&p, &file_schema));
@@ -1337,6 +1356,7 @@ mod tests {
                 range: Some(FileRange { start, end }),
                 statistics: None,
                 extensions: None,
+                deletes: None,
                 metadata_size_hint: None,
             }
         }
@@ -1432,6 +1452,7 @@ mod tests {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         };
 
@@ -1520,6 +1541,7 @@ mod tests {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         };
 
@@ -1582,6 +1604,92 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parquet_exec_with_deletes() {
+        let c1: ArrayRef = Arc::new(Int32Array::from((1..=7).collect::<Vec<_>>()));
+        let c2: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+            Some("e"),
+            None,
+            Some("g"),
+        ]));
+        let batch = create_batch(vec![("c1", c1), ("c2", c2)]);
+
+        // `missing` is not a column of the file, so is null in every row,
+        // and null values of the deletes are equal to nulls of the file
+        let c2: ArrayRef = Arc::new(StringArray::from(vec![Some("e"), None, Some("b")]));
+        let missing: ArrayRef = Arc::new(Int32Array::from(vec![None, None, Some(1)]));
+        let equality_deletes = create_batch(vec![("c2", c2), ("missing", missing)]);
+
+        let deletes = FileDeletes::new()
+            .with_positions([3, 0])
+            .with_equality_deletes(equality_deletes);
+
+        let rt = RoundTrip::new()
+            .with_deletes(deletes)
+            .round_trip(vec![batch])
+            .await;
+
+        let metrics = rt.parquet_exec.metrics().unwrap();
+
+        let expected = [
+            "+----+----+",
+            "| c1 | c2 |",
+            "+----+----+",
+            "| 2  | b  |",
+            "| 7  | g  |",
+            "+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &rt.batches.unwrap());
+        assert_eq!(get_value(&metrics, "positional_delete_rows_pruned"), 2);
+        assert_eq!(get_value(&metrics, "equality_delete_rows_pruned"), 3);
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_with_equality_deletes_of_missing_columns() {
+        let batch = string_batch();
+
+        // deletes the rows where `missing` is 1, which are none of the rows
+        let missing: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let deletes = FileDeletes::new()
+            .with_equality_deletes(create_batch(vec![("missing", missing)]));
+        let batches = RoundTrip::new()
+            .with_deletes(deletes)
+            .round_trip_to_batches(vec![batch.clone()])
+            .await
+            .unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 7);
+
+        // deletes the rows where `missing` is null, which are all the rows
+        let missing: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let deletes = FileDeletes::new()
+            .with_equality_deletes(create_batch(vec![("missing", missing)]));
+        let batches = RoundTrip::new()
+            .with_deletes(deletes)
+            .round_trip_to_batches(vec![batch])
+            .await
+            .unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_with_equality_deletes_of_another_type() {
+        // the values of `c1` can not be cast to the type of the deletes, so
+        // the scan fails rather than deleting them like nulls
+        let c1: ArrayRef = Arc::new(Int32Array::from(vec![None]));
+        let deletes =
+            FileDeletes::new().with_equality_deletes(create_batch(vec![("c1", c1)]));
+        let err = RoundTrip::new()
+            .with_deletes(deletes)
+            .round_trip_to_batches(vec![string_batch()])
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Cannot cast string 'Foo'");
+    }
+
     /// Returns a string array with contents:
     /// "[Foo, null, bar, bar, bar, bar, zzz]"
     fn string_batch() -> RecordBatch {
@@ -2169,6 +2277,7 @@ mod tests {
                     range: None,
                     statistics: None,
                     extensions: None,
+                    deletes: None,
                     metadata_size_hint: None,
                 }
                 .with_metadata_size_hint(123),
@@ -2185,6 +2294,7 @@ mod tests {
                 range: None,
                 statistics: None,
                 extensions: None,
+                deletes: None,
                 metadata_size_hint: None,
             })
             .build();
@@ -22,6 +22,7 @@ use std::sync::Arc;
 use crate::datasource::file_format::{
     coerce_file_schema_to_string_type, coerce_file_schema_to_view_type,
 };
+use crate::datasource::physical_plan::parquet::deletes::EqualityDeleteFilter;
 use crate::datasource::physical_plan::parquet::page_filter::PagePruningAccessPlanFilter;
 use crate::datasource::physical_plan::parquet::row_group_filter::RowGroupAccessPlanFilter;
 use crate::datasource::physical_plan::parquet::{
@@ -41,7 +42,9 @@ use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
 
 use futures::{StreamExt, TryStreamExt};
 use log::debug;
-use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::arrow::arrow_reader::{
+    ArrowPredicate, ArrowReaderMetadata, ArrowReaderOptions, RowFilter,
+};
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::{ParquetRecordBatchStreamBuilder, ProjectionMask};
 
@@ -89,6 +92,7 @@ impl FileOpener for ParquetOpener {
     fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
         let file_range = file_meta.range.clone();
         let extensions = file_meta.extensions.clone();
+        let deletes = file_meta.deletes.clone();
         let file_name = file_meta.location().to_string();
         let file_metrics =
             ParquetFileMetrics::new(self.partition_index, &file_name, &self.metrics);
@@ -164,9 +168,11 @@ impl FileOpener for ParquetOpener {
                 adapted_projections.iter().cloned(),
             );
 
+            let mut predicates: Vec<Box<dyn ArrowPredicate>> = vec![];
+
             // Filter pushdown: evaluate predicates during scan
             if let Some(predicate) = pushdown_filters.then_some(predicate).flatten() {
-                let row_filter = row_filter::build_row_filter(
+                let pushdown_predicates = row_filter::build_row_filter(
                     &predicate,
                     &file_schema,
                     &table_schema,
@@ -176,11 +182,8 @@ impl FileOpener for ParquetOpener {
                     Arc::clone(&schema_mapping),
                 );
 
-                match row_filter {
-                    Ok(Some(filter)) => {
-                        builder = builder.with_row_filter(filter);
-                    }
-                    Ok(None) => {}
+                match pushdown_predicates {
+                    Ok(pushdown_predicates) => predicates.extend(pushdown_predicates),
                     Err(e) => {
                         debug!(
                             "Ignoring error building row filter for '{:?}': {}",
@@ -190,6 +193,28 @@ impl FileOpener for ParquetOpener {
                 };
             };
 
+            // Equality deletes: filter out the deleted rows during scan, after
+            // the pushed down predicates
+            let mut deletes_all_rows = false;
+            for equality_deletes in deletes.iter().flat_map(|d| d.equality_deletes()) {
+                match EqualityDeleteFilter::try_new(
+                    equality_deletes,
+                    &file_schema,
+                    builder.metadata(),
+                    file_metrics.equality_delete_rows_pruned.clone(),
+                )? {
+                    EqualityDeleteFilter::None => {}
+                    EqualityDeleteFilter::All => deletes_all_rows = true,
+                    EqualityDeleteFilter::Predicate(predicate) => {
+                        predicates.push(Box::new(predicate))
+                    }
+                }
+            }
+
+            if !predicates.is_empty() {
+                builder = builder.with_row_filter(RowFilter::new(predicates));
+            }
+
             // Determine which row groups to actually read. The idea is to skip
             // as many row groups as possible based on the metadata and query
             let file_metadata = Arc::clone(builder.metadata());
@@ -242,6 +267,15 @@ impl FileOpener for ParquetOpener {
                 }
             }
 
+            // positional deletes: skip the deleted rows
+            if let Some(deletes) = &deletes {
+                let skipped = access_plan.skip_rows(deletes.positions(), rg_metadata);
+                file_metrics.positional_delete_rows_pruned.add(skipped);
+            }
+            if deletes_all_rows {
+                access_plan = ParquetAccessPlan::new_none(rg_metadata.len());
+            }
+
             let row_group_indexes = access_plan.row_group_indexes();
             if let Some(row_selection) =
                 access_plan.into_overall_row_selection(rg_metadata)?
@@ -67,7 +67,7 @@ use arrow::array::BooleanArray;
 use arrow::datatypes::{DataType, Schema};
 use arrow::error::{ArrowError, Result as ArrowResult};
 use arrow::record_batch::RecordBatch;
-use parquet::arrow::arrow_reader::{ArrowPredicate, RowFilter};
+use parquet::arrow::arrow_reader::ArrowPredicate;
 use parquet::arrow::ProjectionMask;
 use parquet::file::metadata::ParquetMetaData;
 
@@ -506,20 +506,23 @@ fn columns_sorted(
     Ok(false)
 }
 
-/// Build a [`RowFilter`] from the given predicate `Expr` if possible
+/// Build the predicates of a [`RowFilter`] from the given predicate `Expr` if
+/// possible
 ///
 /// # returns
-/// * `Ok(Some(row_filter))` if the expression can be used as RowFilter
-/// * `Ok(None)` if the expression cannot be used as an RowFilter
+/// * `Ok(predicates)` with the predicates of the expression that can be used
+///   in a RowFilter, which is empty if none can
 /// * `Err(e)` if an error occurs while building the filter
 ///
-/// Note that the returned `RowFilter` may not contains all conjuncts in the
+/// Note that the returned predicates may not contain all conjuncts in the
 /// original expression. This is because some conjuncts may not be able to be
 /// evaluated as an `ArrowPredicate` and will be ignored.
 ///
 /// For example, if the expression is `a = 1 AND b = 2 AND c = 3` and `b = 2`
-/// can not be evaluated for some reason, the returned `RowFilter` will contain
+/// can not be evaluated for some reason, the returned predicates will contain
 /// `a = 1` and `c = 3`.
+///
+/// [`RowFilter`]: parquet::arrow::arrow_reader::RowFilter
 pub fn build_row_filter(
     expr: &Arc<dyn PhysicalExpr>,
     file_schema: &Schema,
@@ -528,7 +531,7 @@ pub fn build_row_filter(
     reorder_predicates: bool,
     file_metrics: &ParquetFileMetrics,
     schema_mapping: Arc<dyn SchemaMapper>,
-) -> Result<Option<RowFilter>> {
+) -> Result<Vec<Box<dyn ArrowPredicate>>> {
     let rows_pruned = &file_metrics.pushdown_rows_pruned;
     let rows_matched = &file_metrics.pushdown_rows_matched;
     let time = &file_metrics.row_pushdown_eval_time;
@@ -551,7 +554,7 @@ pub fn build_row_filter(
 
     // no candidates
     if candidates.is_empty() {
-        return Ok(None);
+        return Ok(vec![]);
     }
 
     if reorder_predicates {
@@ -577,8 +580,7 @@ pub fn build_row_filter(
             )
             .map(|pred| Box::new(pred) as _)
         })
-        .collect::<Result<Vec<_>, _>>()
-        .map(|filters| Some(RowFilter::new(filters)))
+        .collect()
 }
 
 #[cfg(test)]
@@ -126,6 +126,9 @@ use object_store::ObjectStore;
 /// * User provided  `ParquetAccessPlan`s to skip row groups and/or pages
 ///   based on external information. See "Implementing External Indexes" below
 ///
+/// * Deleted rows: skips the rows of files deleted by position, and filters out
+///   the rows deleted by equality during decode. See [`FileDeletes`].
+///
 /// # Predicate Pushdown
 ///
 /// `DataSourceExec` uses the provided [`PhysicalExpr`] predicate as a filter to
@@ -146,6 +149,7 @@ use object_store::ObjectStore;
 /// (no error is raised on predicate evaluation errors).
 ///
 /// [`ArrowPredicate`]: parquet::arrow::arrow_reader::ArrowPredicate
+/// [`FileDeletes`]: crate::datasource::listing::FileDeletes
 /// [`RowFilter`]: parquet::arrow::arrow_reader::RowFilter
 /// [Parquet PageIndex]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
 ///
@@ -580,4 +584,7 @@ impl FileSource for ParquetSource {
             }
         }
     }
+    fn supports_deletes(&self) -> bool {
+        true
+    }
 }
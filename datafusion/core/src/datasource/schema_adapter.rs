@@ -21,10 +21,13 @@
 //! physical format into how they should be used by DataFusion.  For instance, a schema
 //! can be stored external to a parquet file that maps parquet logical types to arrow types.
 
-use arrow::array::{new_null_array, RecordBatch, RecordBatchOptions};
+use arrow::array::{
+    new_null_array, Array, ArrayRef, AsArray, GenericListArray, OffsetSizeTrait,
+    RecordBatch, RecordBatchOptions, StructArray,
+};
 use arrow::compute::{can_cast_types, cast};
-use arrow::datatypes::{Schema, SchemaRef};
-use datafusion_common::plan_err;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, Schema, SchemaRef};
+use datafusion_common::{plan_err, HashMap, ScalarValue};
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -428,14 +431,417 @@ impl SchemaMapper for SchemaMapping {
     }
 }
 
+/// The metadata key of the field id of a field, as read from the field ids of
+/// the columns of Parquet files
+const FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
+/// [`SchemaAdapterFactory`] for tables whose schema evolves over time, so
+/// that files written with older schemas can be read with the current schema.
+///
+/// Unlike [`DefaultSchemaAdapterFactory`], which matches the columns of files
+/// to the columns of the table by name, this factory matches fields:
+///
+/// 1. By field id, if the table field and the fields of the file have field
+///    ids, such as the field ids of Parquet columns (see
+///    [`Self::with_field_ids`])
+/// 2. By name
+/// 3. By the previous names of the field (see [`Self::with_rename`])
+///
+/// The matched columns are then evolved to the type of the table field:
+///
+/// * The fields of structs, including the structs in lists, are matched in
+///   the same way: fields added to the table are filled with nulls, and
+///   fields removed from the table are ignored
+/// * Other types are cast, which widens types such as `Int32` to `Int64`,
+///   `Float32` to `Float64` or `Decimal128(5, 2)` to `Decimal128(10, 2)`
+///
+/// Columns of the table that are not in a file are filled with their default
+/// value (see [`Self::with_column_defaults`]), or nulls.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use datafusion::datasource::schema_adapter::{SchemaAdapterFactory, SchemaEvolutionAdapterFactory};
+/// # use datafusion_common::{record_batch, ScalarValue};
+/// // the column "name" was called "full_name", and "score" was added later
+/// let table_schema = Arc::new(Schema::new(vec![
+///     Field::new("id", DataType::Int64, true),
+///     Field::new("name", DataType::Utf8, true),
+///     Field::new("score", DataType::Float64, true),
+/// ]));
+/// let factory = SchemaEvolutionAdapterFactory::new()
+///     .with_rename("name", "full_name")
+///     .with_column_defaults([("score".to_string(), ScalarValue::Float64(Some(0.0)))]);
+/// let adapter = factory.create(Arc::clone(&table_schema), table_schema);
+///
+/// let file_batch = record_batch!(
+///     ("id", Int32, vec![1, 2]),
+///     ("full_name", Utf8, vec!["a", "b"])
+/// ).unwrap();
+/// let (mapper, _indices) = adapter.map_schema(&file_batch.schema()).unwrap();
+///
+/// let expected_batch = record_batch!(
+///     ("id", Int64, vec![1, 2]),
+///     ("name", Utf8, vec!["a", "b"]),
+///     ("score", Float64, vec![0.0, 0.0])
+/// ).unwrap();
+/// assert_eq!(mapper.map_batch(file_batch).unwrap(), expected_batch);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SchemaEvolutionAdapterFactory {
+    /// The previous names of fields, by the path of the field in the table
+    /// schema
+    renames: HashMap<String, Vec<String>>,
+    /// The values of the columns of the table that are not in a file
+    column_defaults: HashMap<String, ScalarValue>,
+    /// Whether to match fields by field id
+    field_ids: bool,
+}
+
+impl Default for SchemaEvolutionAdapterFactory {
+    fn default() -> Self {
+        Self {
+            renames: HashMap::new(),
+            column_defaults: HashMap::new(),
+            field_ids: true,
+        }
+    }
+}
+
+impl SchemaEvolutionAdapterFactory {
+    /// Create a new factory, matching fields by field id and name
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare that the field at `path` in the table schema was previously
+    /// named `previous_name` in the files.
+    ///
+    /// The path of a top level column is its name, and the path of the field
+    /// of a struct is the path of the struct and the name of the field
+    /// separated by a `.`, such as `address.zip`. The fields of the structs in
+    /// a list have the path of the list, such as `items.price`.
+    pub fn with_rename(
+        mut self,
+        path: impl Into<String>,
+        previous_name: impl Into<String>,
+    ) -> Self {
+        self.renames
+            .entry(path.into())
+            .or_default()
+            .push(previous_name.into());
+        self
+    }
+
+    /// Add the values of the columns of the table that are not in a file,
+    /// such as the columns added to the table after the file was written
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: impl IntoIterator<Item = (String, ScalarValue)>,
+    ) -> Self {
+        self.column_defaults.extend(column_defaults);
+        self
+    }
+
+    /// Set whether fields are matched by their field id, from the
+    /// `PARQUET:field_id` metadata of the fields. Defaults to true.
+    ///
+    /// Fields are only matched by field id if the table field and the fields
+    /// of the file have ids, otherwise they are matched by name.
+    pub fn with_field_ids(mut self, field_ids: bool) -> Self {
+        self.field_ids = field_ids;
+        self
+    }
+
+    /// The index of the field of `file_fields` that matches the field
+    /// `table_field` at `path` in the table schema
+    fn find_field(
+        &self,
+        path: &str,
+        table_field: &Field,
+        file_fields: &Fields,
+    ) -> Option<usize> {
+        let field_id = |field: &Field| field.metadata().get(FIELD_ID_META_KEY).cloned();
+        if let Some(id) = field_id(table_field).filter(|_| self.field_ids) {
+            if file_fields.iter().any(|field| field_id(field).is_some()) {
+                return file_fields
+                    .iter()
+                    .position(|field| field_id(field) == Some(id.clone()));
+            }
+        }
+        if let Some((idx, _)) = file_fields.find(table_field.name()) {
+            return Some(idx);
+        }
+        self.renames
+            .get(path)?
+            .iter()
+            .find_map(|name| Some(file_fields.find(name)?.0))
+    }
+
+    /// Whether values of `file_type` can be evolved to `table_type`, see
+    /// [`Self::evolve_array`]
+    fn can_evolve(
+        &self,
+        path: &str,
+        file_type: &DataType,
+        table_type: &DataType,
+    ) -> bool {
+        match (file_type, table_type) {
+            (DataType::Struct(file_fields), DataType::Struct(table_fields)) => {
+                table_fields.iter().all(|table_field| {
+                    let path = format!("{path}.{}", table_field.name());
+                    match self.find_field(&path, table_field, file_fields) {
+                        Some(idx) => self.can_evolve(
+                            &path,
+                            file_fields[idx].data_type(),
+                            table_field.data_type(),
+                        ),
+                        None => table_field.is_nullable(),
+                    }
+                })
+            }
+            (DataType::List(file_item), DataType::List(table_item))
+            | (DataType::LargeList(file_item), DataType::LargeList(table_item)) => {
+                self.can_evolve(path, file_item.data_type(), table_item.data_type())
+            }
+            _ => can_cast_types(file_type, table_type),
+        }
+    }
+
+    /// Evolve `array` of a file to the type of `table_field` at `path` in the
+    /// table schema
+    fn evolve_array(
+        &self,
+        path: &str,
+        array: &ArrayRef,
+        table_field: &Field,
+    ) -> datafusion_common::Result<ArrayRef> {
+        match (array.data_type(), table_field.data_type()) {
+            (DataType::Struct(file_fields), DataType::Struct(table_fields)) => {
+                let array = array.as_struct();
+                let columns = table_fields
+                    .iter()
+                    .map(|table_field| {
+                        let path = format!("{path}.{}", table_field.name());
+                        match self.find_field(&path, table_field, file_fields) {
+                            Some(idx) => {
+                                self.evolve_array(&path, array.column(idx), table_field)
+                            }
+                            None => {
+                                Ok(new_null_array(table_field.data_type(), array.len()))
+                            }
+                        }
+                    })
+                    .collect::<datafusion_common::Result<Vec<_>>>()?;
+                Ok(Arc::new(StructArray::try_new(
+                    table_fields.clone(),
+                    columns,
+                    array.nulls().cloned(),
+                )?))
+            }
+            (DataType::List(_), DataType::List(table_item)) => {
+                self.evolve_list::<i32>(path, array, table_item)
+            }
+            (DataType::LargeList(_), DataType::LargeList(table_item)) => {
+                self.evolve_list::<i64>(path, array, table_item)
+            }
+            _ => Ok(cast(array, table_field.data_type())?),
+        }
+    }
+
+    /// Evolve the items of the list `array` to the type of `table_item`
+    fn evolve_list<O: OffsetSizeTrait>(
+        &self,
+        path: &str,
+        array: &ArrayRef,
+        table_item: &FieldRef,
+    ) -> datafusion_common::Result<ArrayRef> {
+        let array = array.as_list::<O>();
+        let values = self.evolve_array(path, array.values(), table_item)?;
+        Ok(Arc::new(GenericListArray::<O>::try_new(
+            Arc::clone(table_item),
+            array.offsets().clone(),
+            values,
+            array.nulls().cloned(),
+        )?))
+    }
+}
+
+impl SchemaAdapterFactory for SchemaEvolutionAdapterFactory {
+    fn create(
+        &self,
+        projected_table_schema: SchemaRef,
+        table_schema: SchemaRef,
+    ) -> Box<dyn SchemaAdapter> {
+        Box::new(SchemaEvolutionAdapter {
+            projected_table_schema,
+            table_schema,
+            factory: Arc::new(self.clone()),
+        })
+    }
+}
+
+/// [`SchemaAdapter`] created by [`SchemaEvolutionAdapterFactory`]
+#[derive(Clone, Debug)]
+struct SchemaEvolutionAdapter {
+    /// The schema for the table, projected to include only the fields being
+    /// output (projected) by the associated ParquetSource
+    projected_table_schema: SchemaRef,
+    /// The entire table schema for the table we're using this to adapt
+    table_schema: SchemaRef,
+    /// The factory of this adapter
+    factory: Arc<SchemaEvolutionAdapterFactory>,
+}
+
+impl SchemaAdapter for SchemaEvolutionAdapter {
+    fn map_column_index(&self, index: usize, file_schema: &Schema) -> Option<usize> {
+        let field = self.projected_table_schema.field(index);
+        self.factory
+            .find_field(field.name(), field, file_schema.fields())
+    }
+
+    fn map_schema(
+        &self,
+        file_schema: &Schema,
+    ) -> datafusion_common::Result<(Arc<dyn SchemaMapper>, Vec<usize>)> {
+        let mut file_indices = vec![None; self.projected_table_schema.fields().len()];
+        for (table_idx, table_field) in
+            self.projected_table_schema.fields().iter().enumerate()
+        {
+            let path = table_field.name();
+            let Some(file_idx) =
+                self.factory
+                    .find_field(path, table_field, file_schema.fields())
+            else {
+                continue;
+            };
+            let file_field = file_schema.field(file_idx);
+            if !self.factory.can_evolve(
+                path,
+                file_field.data_type(),
+                table_field.data_type(),
+            ) {
+                return plan_err!(
+                    "Cannot evolve file schema field {} of type {:?} to table schema field {} of type {:?}",
+                    file_field.name(),
+                    file_field.data_type(),
+                    table_field.name(),
+                    table_field.data_type()
+                );
+            }
+            file_indices[table_idx] = Some(file_idx);
+        }
+
+        // the columns to read from the file, in the order of the file schema
+        let mut projection = file_indices.iter().flatten().copied().collect::<Vec<_>>();
+        projection.sort_unstable();
+        projection.dedup();
+        let field_mappings = file_indices
+            .iter()
+            .map(|file_idx| {
+                file_idx.and_then(|file_idx| projection.binary_search(&file_idx).ok())
+            })
+            .collect();
+
+        Ok((
+            Arc::new(SchemaEvolutionMapping {
+                projected_table_schema: Arc::clone(&self.projected_table_schema),
+                field_mappings,
+                table_schema: Arc::clone(&self.table_schema),
+                factory: Arc::clone(&self.factory),
+            }),
+            projection,
+        ))
+    }
+}
+
+/// [`SchemaMapper`] created by [`SchemaEvolutionAdapter`], see
+/// [`SchemaMapping`] for the schemas of `map_batch` and `map_partial_batch`
+#[derive(Debug)]
+struct SchemaEvolutionMapping {
+    /// The schema of the table, projected to the output fields
+    projected_table_schema: SchemaRef,
+    /// Mapping from field index in `projected_table_schema` to index in
+    /// projected file_schema, or `None` if the field is not in the file
+    field_mappings: Vec<Option<usize>>,
+    /// The entire table schema
+    table_schema: SchemaRef,
+    /// The factory of the adapter of this mapping
+    factory: Arc<SchemaEvolutionAdapterFactory>,
+}
+
+impl SchemaMapper for SchemaEvolutionMapping {
+    fn map_batch(&self, batch: RecordBatch) -> datafusion_common::Result<RecordBatch> {
+        let batch_rows = batch.num_rows();
+        let cols = self
+            .projected_table_schema
+            .fields()
+            .iter()
+            .zip(&self.field_mappings)
+            .map(|(field, batch_idx)| match batch_idx {
+                Some(batch_idx) => self.factory.evolve_array(
+                    field.name(),
+                    batch.column(*batch_idx),
+                    field,
+                ),
+                None => match self.factory.column_defaults.get(field.name()) {
+                    Some(value) => {
+                        let array = value.to_array_of_size(batch_rows)?;
+                        Ok(cast(&array, field.data_type())?)
+                    }
+                    None => Ok(new_null_array(field.data_type(), batch_rows)),
+                },
+            })
+            .collect::<datafusion_common::Result<Vec<_>>>()?;
+
+        // Necessary to handle empty batches
+        let options = RecordBatchOptions::new().with_row_count(Some(batch_rows));
+
+        let schema = Arc::clone(&self.projected_table_schema);
+        Ok(RecordBatch::try_new_with_options(schema, cols, &options)?)
+    }
+
+    fn map_partial_batch(
+        &self,
+        batch: RecordBatch,
+    ) -> datafusion_common::Result<RecordBatch> {
+        let schema = batch.schema();
+        let mut cols = vec![];
+        let mut fields = vec![];
+        for table_field in self.table_schema.fields() {
+            let path = table_field.name();
+            if let Some(batch_idx) =
+                self.factory.find_field(path, table_field, schema.fields())
+            {
+                let array = batch.column(batch_idx);
+                cols.push(self.factory.evolve_array(path, array, table_field)?);
+                fields.push(Arc::clone(table_field));
+            }
+        }
+
+        // Necessary to handle empty batches
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+
+        let schema =
+            Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+        Ok(RecordBatch::try_new_with_options(schema, cols, &options)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
     use std::sync::Arc;
 
     use crate::assert_batches_sorted_eq;
-    use arrow::array::{Int32Array, StringArray};
-    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+    use arrow::array::{
+        ArrayRef, Float32Array, Float64Array, Int32Array, Int64Array, ListArray,
+        StringArray, StructArray,
+    };
+    use arrow::buffer::OffsetBuffer;
+    use arrow::datatypes::{DataType, Field, Fields, Schema, SchemaRef};
     use arrow::record_batch::RecordBatch;
     use datafusion_datasource::file_scan_config::FileScanConfig;
     use object_store::path::Path;
@@ -445,12 +851,13 @@ mod tests {
     use crate::datasource::object_store::ObjectStoreUrl;
     use crate::datasource::physical_plan::ParquetSource;
     use crate::datasource::schema_adapter::{
-        DefaultSchemaAdapterFactory, SchemaAdapter, SchemaAdapterFactory, SchemaMapper,
+        DefaultSchemaAdapterFactory, SchemaAdapter, SchemaAdapterFactory,
+        SchemaEvolutionAdapterFactory, SchemaMapper, FIELD_ID_META_KEY,
     };
     use crate::physical_plan::collect;
     use crate::prelude::SessionContext;
 
-    use datafusion_common::record_batch;
+    use datafusion_common::{record_batch, ScalarValue};
     #[cfg(feature = "parquet")]
     use parquet::arrow::ArrowWriter;
     use tempfile::TempDir;
@@ -493,6 +900,7 @@ mod tests {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         };
 
@@ -577,6 +985,174 @@ mod tests {
         assert!(err.contains("Invalid argument error: Column 'a' is declared as non-nullable but contains null values"), "{err}");
     }
 
+    #[test]
+    fn schema_evolution_adapter() {
+        let file_struct = Fields::from(vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("removed", DataType::Utf8, true),
+        ]);
+        let file_item = Fields::from(vec![Field::new("p", DataType::Float32, true)]);
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("old_id", DataType::Int32, true),
+            Field::new("s", DataType::Struct(file_struct.clone()), true),
+            Field::new_list(
+                "l",
+                Field::new_list_field(DataType::Struct(file_item.clone()), true),
+                true,
+            ),
+        ]));
+
+        // "old_id" was renamed to "id" and widened, "s.y", "l.q" and "d" were
+        // added, and "s.removed" was removed
+        let table_struct = Fields::from(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let table_item = Fields::from(vec![
+            Field::new("p", DataType::Float64, true),
+            Field::new("q", DataType::Int32, true),
+        ]);
+        let table_schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("s", DataType::Struct(table_struct.clone()), true),
+            Field::new_list(
+                "l",
+                Field::new_list_field(DataType::Struct(table_item.clone()), true),
+                true,
+            ),
+            Field::new("d", DataType::Utf8, true),
+        ]));
+
+        let factory = SchemaEvolutionAdapterFactory::new()
+            .with_rename("id", "old_id")
+            .with_column_defaults([(
+                "d".to_string(),
+                ScalarValue::Utf8(Some("default".to_string())),
+            )]);
+        let adapter =
+            factory.create(Arc::clone(&table_schema), Arc::clone(&table_schema));
+        let (mapper, indices) = adapter.map_schema(&file_schema).unwrap();
+        assert_eq!(indices, vec![0, 1, 2]);
+
+        let offsets = OffsetBuffer::new(vec![0, 1, 3].into());
+        let file_batch = RecordBatch::try_new(
+            file_schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StructArray::new(
+                    file_struct,
+                    vec![
+                        Arc::new(Int32Array::from(vec![10, 20])),
+                        Arc::new(StringArray::from(vec!["a", "b"])),
+                    ],
+                    None,
+                )),
+                Arc::new(ListArray::new(
+                    Arc::new(Field::new_list_field(DataType::Struct(file_item), true)),
+                    offsets.clone(),
+                    Arc::new(StructArray::new(
+                        Fields::from(vec![Field::new("p", DataType::Float32, true)]),
+                        vec![Arc::new(Float32Array::from(vec![0.5, 1.5, 2.5]))],
+                        None,
+                    )),
+                    None,
+                )),
+            ],
+        )
+        .unwrap();
+        let mapped_batch = mapper.map_batch(file_batch).unwrap();
+
+        let expected_columns: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(StructArray::new(
+                table_struct,
+                vec![
+                    Arc::new(Int64Array::from(vec![10, 20])),
+                    Arc::new(StringArray::from(vec![None::<&str>, None])),
+                ],
+                None,
+            )),
+            Arc::new(ListArray::new(
+                Arc::new(Field::new_list_field(
+                    DataType::Struct(table_item.clone()),
+                    true,
+                )),
+                offsets,
+                Arc::new(StructArray::new(
+                    table_item,
+                    vec![
+                        Arc::new(Float64Array::from(vec![0.5, 1.5, 2.5])),
+                        Arc::new(Int32Array::from(vec![None, None, None])),
+                    ],
+                    None,
+                )),
+                None,
+            )),
+            Arc::new(StringArray::from(vec!["default", "default"])),
+        ];
+        let expected_batch =
+            RecordBatch::try_new(table_schema, expected_columns).unwrap();
+        assert_eq!(mapped_batch, expected_batch);
+
+        // columns that cannot be evolved are rejected
+        let file_schema = Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(Fields::from(vec![Field::new("x", DataType::Binary, true)])),
+            true,
+        )]);
+        let err = adapter.map_schema(&file_schema).unwrap_err().to_string();
+        assert!(err.contains("Cannot evolve file schema field s"), "{err}");
+    }
+
+    #[test]
+    fn schema_evolution_adapter_field_ids() {
+        let with_id = |name: &str, id: &str| {
+            Field::new(name, DataType::Int32, true)
+                .with_metadata([(FIELD_ID_META_KEY.to_string(), id.to_string())].into())
+        };
+        // the names of "a" and "b" were swapped, which only the field ids track
+        let table_schema = Arc::new(Schema::new(vec![
+            with_id("a", "2"),
+            with_id("b", "1"),
+            with_id("c", "3"),
+        ]));
+        let file_schema = Schema::new(vec![with_id("a", "1"), with_id("b", "2")]);
+        let file_batch = RecordBatch::try_new(
+            Arc::new(file_schema.clone()),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(Int32Array::from(vec![3, 4])),
+            ],
+        )
+        .unwrap();
+
+        let factory = SchemaEvolutionAdapterFactory::new();
+        let adapter =
+            factory.create(Arc::clone(&table_schema), Arc::clone(&table_schema));
+        let (mapper, indices) = adapter.map_schema(&file_schema).unwrap();
+        assert_eq!(indices, vec![0, 1]);
+        let mapped_batch = mapper.map_batch(file_batch.clone()).unwrap();
+        assert_eq!(mapped_batch.schema(), table_schema);
+        assert_eq!(
+            mapped_batch.columns(),
+            &[
+                Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(Int32Array::from(vec![None, None])),
+            ]
+        );
+
+        // without field ids, the fields are matched by name
+        let factory = SchemaEvolutionAdapterFactory::new().with_field_ids(false);
+        let adapter = factory.create(Arc::clone(&table_schema), table_schema);
+        let (mapper, _) = adapter.map_schema(&file_schema).unwrap();
+        let mapped_batch = mapper.map_batch(file_batch).unwrap();
+        assert_eq!(
+            mapped_batch.column(0),
+            &(Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef)
+        );
+    }
+
     #[derive(Debug)]
     struct TestSchemaAdapterFactory;
 
@@ -32,7 +32,7 @@ impl SessionContext {
         table_paths: P,
         options: AvroReadOptions<'_>,
     ) -> Result<DataFrame> {
-        self._read_type(table_paths, options).await
+        self._read_type(table_paths, options.schema, options).await
     }
 
     /// Registers an Avro file as a table that can be referenced from
@@ -48,7 +48,7 @@ impl SessionContext {
         table_paths: P,
         options: CsvReadOptions<'_>,
     ) -> Result<DataFrame> {
-        self._read_type(table_paths, options).await
+        self._read_type(table_paths, options.schema, options).await
     }
 
     /// Registers a CSV file as a table which can referenced from SQL
@@ -34,7 +34,7 @@ impl SessionContext {
         table_paths: P,
         options: NdJsonReadOptions<'_>,
     ) -> Result<DataFrame> {
-        self._read_type(table_paths, options).await
+        self._read_type(table_paths, options.schema, options).await
     }
 
     /// Registers a JSON file as a table that it can be referenced
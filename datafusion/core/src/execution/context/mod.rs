@@ -33,16 +33,17 @@ use crate::{
     datasource::listing::{
         ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
     },
-    datasource::{provider_as_source, MemTable, ViewTable},
+    datasource::{provider_as_source, MaterializedViewTable, MemTable, ViewTable},
     error::{DataFusionError, Result},
     execution::{options::ArrowReadOptions, runtime_env::RuntimeEnv, FunctionRegistry},
     logical_expr::AggregateUDF,
     logical_expr::ScalarUDF,
     logical_expr::{
         CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateFunction,
-        CreateMemoryTable, CreateView, DropCatalogSchema, DropFunction, DropTable,
-        DropView, Execute, LogicalPlan, LogicalPlanBuilder, Prepare, SetVariable,
-        TableType, UNNAMED_TABLE,
+        CreateMaterializedView, CreateMemoryTable, CreateView, DropCatalogSchema,
+        DropFunction, DropTable, DropView, Execute, LogicalPlan, LogicalPlanBuilder,
+        Prepare, RefreshMaterializedView, SetVariable, TableType, TableVersion,
+        UNNAMED_TABLE,
     },
     physical_expr::PhysicalExpr,
     physical_plan::ExecutionPlan,
@@ -55,8 +56,9 @@ use datafusion_common::{
     config::{ConfigExtension, TableOptions},
     exec_datafusion_err, exec_err, not_impl_err, plan_datafusion_err, plan_err,
     tree_node::{TreeNodeRecursion, TreeNodeVisitor},
-    DFSchema, ParamValues, ScalarValue, SchemaReference, TableReference,
+    Constraints, DFSchema, ParamValues, ScalarValue, SchemaReference, TableReference,
 };
+use datafusion_execution::query_registry::QueryRegistry;
 use datafusion_execution::registry::SerializerRegistry;
 use datafusion_expr::{
     expr_rewriter::FunctionRewrite,
@@ -78,9 +80,13 @@ use datafusion_catalog::{
 pub use datafusion_execution::config::SessionConfig;
 pub use datafusion_execution::TaskContext;
 pub use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_optimizer::materialized_view_rewrite::{
+    MaterializedView, MaterializedViewRegistry,
+};
 use datafusion_optimizer::{AnalyzerRule, OptimizerRule};
 use object_store::ObjectStore;
 use parking_lot::RwLock;
+use tracing::Instrument;
 use url::Url;
 
 mod avro;
@@ -514,6 +520,16 @@ impl SessionContext {
         Arc::clone(self.state.read().runtime_env())
     }
 
+    /// Return the [`QueryRegistry`] tracking the running and completed
+    /// queries of this `SessionContext`, which backs the `running_queries`
+    /// and `query_history` tables of the `information_schema`.
+    ///
+    /// A registry is added to the [`SessionConfig`] of every session unless
+    /// it already has one, see [`SessionConfig::with_extension`].
+    pub fn query_registry(&self) -> Option<Arc<QueryRegistry>> {
+        self.state.read().config().get_extension::<QueryRegistry>()
+    }
+
     /// Returns an id that uniquely identifies this `SessionContext`.
     pub fn session_id(&self) -> String {
         self.session_id.clone()
@@ -610,10 +626,14 @@ impl SessionContext {
         sql: &str,
         options: SQLOptions,
     ) -> Result<DataFrame> {
-        let plan = self.state().create_logical_plan(sql).await?;
-        options.verify_plan(&plan)?;
+        async {
+            let plan = self.state().create_logical_plan(sql).await?;
+            options.verify_plan(&plan)?;
 
-        self.execute_logical_plan(plan).await
+            Ok(self.execute_logical_plan(plan).await?.with_sql(sql))
+        }
+        .instrument(tracing::info_span!("sql", sql))
+        .await
     }
 
     /// Creates logical expressions from SQL query text.
@@ -650,6 +670,7 @@ impl SessionContext {
     /// SQL, see [`Self::sql_with_options`] and
     /// [`SQLOptions::verify_plan`].
     pub async fn execute_logical_plan(&self, plan: LogicalPlan) -> Result<DataFrame> {
+        self.state.read().invalidate_cached_results(&plan);
         match plan {
             LogicalPlan::Ddl(ddl) => {
                 // Box::pin avoids allocating the stack space within this function's frame
@@ -667,6 +688,12 @@ impl SessionContext {
                     DdlStatement::CreateView(cmd) => {
                         Box::pin(self.create_view(cmd)).await
                     }
+                    DdlStatement::CreateMaterializedView(cmd) => {
+                        Box::pin(self.create_materialized_view(cmd)).await
+                    }
+                    DdlStatement::RefreshMaterializedView(cmd) => {
+                        Box::pin(self.refresh_materialized_view(cmd)).await
+                    }
                     DdlStatement::CreateCatalogSchema(cmd) => {
                         Box::pin(self.create_catalog_schema(cmd)).await
                     }
@@ -886,6 +913,145 @@ impl SessionContext {
         }
     }
 
+    async fn create_materialized_view(
+        &self,
+        cmd: CreateMaterializedView,
+    ) -> Result<DataFrame> {
+        let CreateMaterializedView {
+            name,
+            input,
+            if_not_exists,
+            or_replace,
+            definition,
+            file_type,
+            location,
+        } = cmd;
+
+        let exists = self.table_exist(name.clone())?;
+        match (if_not_exists, or_replace, exists) {
+            (true, true, _) => {
+                return exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'")
+            }
+            (true, false, true) => return self.return_empty_dataframe(),
+            (false, false, true) => return exec_err!("Table '{name}' already exists"),
+            _ => {}
+        }
+
+        let mut view =
+            MaterializedViewTable::try_new(Arc::unwrap_or_clone(input), definition)?;
+        if let (Some(file_type), Some(location)) = (file_type, location) {
+            // the results are written as new files into the location
+            let location = if location.ends_with('/') {
+                location
+            } else {
+                format!("{location}/")
+            };
+            let cmd = CreateExternalTable {
+                schema: Arc::clone(view.logical_plan().schema()),
+                name: name.clone(),
+                location: location.clone(),
+                file_type,
+                table_partition_cols: vec![],
+                clustered_by: vec![],
+                num_buckets: None,
+                if_not_exists: false,
+                temporary: false,
+                definition: None,
+                order_exprs: vec![],
+                unbounded: false,
+                options: Default::default(),
+                constraints: Constraints::empty(),
+                column_defaults: Default::default(),
+            };
+            let url = ListingTableUrl::parse(&location)?;
+
+            // All files at the location belong to the view, so it may only
+            // replace the view that already stores its results there
+            let replaced = match exists {
+                true => self
+                    .table_provider(name.clone())
+                    .await?
+                    .as_any()
+                    .downcast_ref::<MaterializedViewTable>()
+                    .and_then(|view| view.location().cloned()),
+                false => None,
+            };
+            if replaced.as_ref() != Some(&url) {
+                let store = self.runtime_env().object_store(&url)?;
+                let listing = store.list_with_delimiter(Some(url.prefix())).await?;
+                if !listing.objects.is_empty() || !listing.common_prefixes.is_empty() {
+                    return exec_err!(
+                        "Location {url} of materialized view {name} is not empty"
+                    );
+                }
+            }
+
+            let storage = self.create_custom_table(&cmd).await?;
+            view = view.with_location(storage, url)?;
+        }
+        let view = Arc::new(view);
+        view.refresh(&self.state()).await?;
+
+        if exists {
+            self.deregister_table(name.clone())?;
+        }
+        self.register_table(name.clone(), Arc::clone(&view) as _)?;
+        self.register_materialized_view(name, view)?;
+        self.return_empty_dataframe()
+    }
+
+    /// Make `view` available to the `MaterializedViewRewrite` optimizer rule
+    fn register_materialized_view(
+        &self,
+        name: TableReference,
+        view: Arc<MaterializedViewTable>,
+    ) -> Result<()> {
+        let mut state = self.state();
+        let Some(views) = state.config().get_extension::<MaterializedViewRegistry>()
+        else {
+            return Ok(());
+        };
+        state
+            .config_mut()
+            .options_mut()
+            .optimizer
+            .enable_materialized_view_rewrite = false;
+        let plan = state.optimize(view.logical_plan())?;
+
+        let mut tables: Vec<TableReference> = vec![];
+        plan.apply_with_subqueries(|plan| {
+            if let LogicalPlan::TableScan(scan) = plan {
+                tables.push(state.resolve_table_ref(scan.table_name.clone()).into());
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        let name = TableReference::from(state.resolve_table_ref(name));
+        let view = MaterializedView::new(name, provider_as_source(view), plan)
+            .with_tables(tables);
+        views.register(view);
+        Ok(())
+    }
+
+    async fn refresh_materialized_view(
+        &self,
+        cmd: RefreshMaterializedView,
+    ) -> Result<DataFrame> {
+        let RefreshMaterializedView { name, .. } = cmd;
+        let table = self.table_provider(name.clone()).await?;
+        let Some(view) = table.as_any().downcast_ref::<MaterializedViewTable>() else {
+            return exec_err!("'{name}' is not a materialized view");
+        };
+        let state = self.state();
+        view.refresh(&state).await?;
+
+        if let Some(views) = state.config().get_extension::<MaterializedViewRegistry>() {
+            if let Some(view) = views.get(&state.resolve_table_ref(name).into()) {
+                view.set_fresh(true);
+            }
+        }
+        self.return_empty_dataframe()
+    }
+
     async fn create_catalog_schema(&self, cmd: CreateCatalogSchema) -> Result<DataFrame> {
         let CreateCatalogSchema {
             schema_name,
@@ -1055,7 +1221,7 @@ impl SessionContext {
         let table = table_ref.table().to_owned();
         let maybe_schema = {
             let state = self.state.read();
-            let resolved = state.resolve_table_ref(table_ref);
+            let resolved = state.resolve_table_ref(table_ref.clone());
             state
                 .catalog_list()
                 .catalog(&resolved.catalog)
@@ -1065,6 +1231,7 @@ impl SessionContext {
         if let Some(schema) = maybe_schema {
             if let Some(table_provider) = schema.table(&table).await? {
                 if table_provider.table_type() == table_type {
+                    self.state.read().deregister_materialized_view(table_ref);
                     schema.deregister_table(&table)?;
                     return Ok(true);
                 }
@@ -1247,6 +1414,7 @@ impl SessionContext {
     async fn _read_type<'a, P: DataFilePaths>(
         &self,
         table_paths: P,
+        schema: Option<&Schema>,
         options: impl ReadOptions<'a>,
     ) -> Result<DataFrame> {
         let table_paths = table_paths.to_urls()?;
@@ -1272,12 +1440,21 @@ impl SessionContext {
             }
         }
 
-        let resolved_schema = options
-            .get_resolved_schema(&session_config, self.state(), table_paths[0].clone())
-            .await?;
         let config = ListingTableConfig::new_with_multi_paths(table_paths)
-            .with_listing_options(listing_options)
-            .with_schema(resolved_schema);
+            .with_listing_options(listing_options);
+        let config = match schema {
+            Some(schema) => config.with_schema(Arc::new(schema.clone())),
+            None => {
+                // the files read to infer the schema are not read again to
+                // infer their sort order
+                let state = self.state();
+                config
+                    .infer_schema(&state)
+                    .await?
+                    .infer_file_sort_order(&state)
+                    .await?
+            }
+        };
         let provider = ListingTable::try_new(config)?;
         self.read_table(Arc::new(provider))
     }
@@ -1293,7 +1470,7 @@ impl SessionContext {
         table_paths: P,
         options: ArrowReadOptions<'_>,
     ) -> Result<DataFrame> {
-        self._read_type(table_paths, options).await
+        self._read_type(table_paths, options.schema, options).await
     }
 
     /// Creates an empty DataFrame.
@@ -1366,13 +1543,19 @@ impl SessionContext {
         sql_definition: Option<String>,
     ) -> Result<()> {
         let table_path = ListingTableUrl::parse(table_path)?;
-        let resolved_schema = match provided_schema {
-            Some(s) => s,
-            None => options.infer_schema(&self.state(), &table_path).await?,
+        let config = ListingTableConfig::new(table_path.clone());
+        let config = match provided_schema {
+            Some(s) => config.with_listing_options(options).with_schema(s),
+            None => {
+                let state = self.state();
+                let schema = options.infer_schema(&state, &table_path).await?;
+                config
+                    .with_listing_options(options)
+                    .with_schema(schema)
+                    .infer_file_sort_order(&state)
+                    .await?
+            }
         };
-        let config = ListingTableConfig::new(table_path)
-            .with_listing_options(options)
-            .with_schema(resolved_schema);
         let table = ListingTable::try_new(config)?.with_definition(sql_definition);
         self.register_table(table_ref, Arc::new(table))?;
         Ok(())
@@ -1463,8 +1646,10 @@ impl SessionContext {
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let table_ref: TableReference = table_ref.into();
         let table = table_ref.table().to_owned();
-        self.state
-            .read()
+        let state = self.state.read();
+        state.invalidate_table(table_ref.clone());
+        state.deregister_materialized_view(table_ref.clone());
+        state
             .schema_for_ref(table_ref)?
             .register_table(table, provider)
     }
@@ -1478,10 +1663,10 @@ impl SessionContext {
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        self.state
-            .read()
-            .schema_for_ref(table_ref)?
-            .deregister_table(&table)
+        let state = self.state.read();
+        state.invalidate_table(table_ref.clone());
+        state.deregister_materialized_view(table_ref.clone());
+        state.schema_for_ref(table_ref)?.deregister_table(&table)
     }
 
     /// Return `true` if the specified table exists in the schema provider.
@@ -1515,6 +1700,39 @@ impl SessionContext {
         Ok(DataFrame::new(self.state(), plan))
     }
 
+    /// Creates a [`DataFrame`] reading the previous `version` of a table, as
+    /// `FOR SYSTEM_TIME AS OF` in SQL, see [`TableProvider::at_version`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::logical_expr::TableVersion;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// // the same as `SELECT * FROM t FOR SYSTEM_TIME AS OF 3`
+    /// let df = ctx.table_at_version("t", TableVersion::Version(3)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn table_at_version(
+        &self,
+        table_ref: impl Into<TableReference>,
+        version: TableVersion,
+    ) -> Result<DataFrame> {
+        let table_ref: TableReference = table_ref.into();
+        let provider = self.table_provider(table_ref.clone()).await?;
+        let plan = LogicalPlanBuilder::scan(
+            table_ref,
+            provider_as_source(provider.at_version(&version)?),
+            None,
+        )?
+        .build()?;
+        Ok(DataFrame::new(self.state(), plan))
+    }
+
     /// Retrieves a [`TableFunction`] reference by name.
     ///
     /// Returns an error if no table function has been registered with the provided name.
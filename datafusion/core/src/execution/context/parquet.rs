@@ -36,7 +36,7 @@ impl SessionContext {
         table_paths: P,
         options: ParquetReadOptions<'_>,
     ) -> Result<DataFrame> {
-        self._read_type(table_paths, options).await
+        self._read_type(table_paths, options.schema, options).await
     }
 
     /// Registers a Parquet file as a table that can be referenced from SQL
@@ -0,0 +1,502 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ResultCache`]: reuses the results of queries with the same plan
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::datasource::listing::ListingTable;
+use crate::datasource::{source_as_provider, MemTable};
+use crate::execution::session_state::SessionState;
+
+use arrow::array::RecordBatch;
+use datafusion_common::instant::Instant;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::{ResolvedTableReference, Result};
+use datafusion_expr::{Expr, LogicalPlan, Volatility};
+use datafusion_optimizer::LogicalPlanSignature;
+use object_store::ObjectMeta;
+use parking_lot::Mutex;
+
+/// The total size of the results a [`ResultCache`] holds by default: 64 MiB
+pub const DEFAULT_RESULT_CACHE_SIZE: usize = 64 * 1024 * 1024;
+
+/// The limits of a [`ResultCache`]
+#[derive(Debug, Clone)]
+pub struct ResultCacheConfig {
+    /// Maximum total size in bytes of the cached results. The least
+    /// recently used results are evicted to stay below this size
+    pub max_size: usize,
+    /// Results larger than this many bytes are not cached. Defaults to
+    /// `max_size` if `None`
+    pub max_entry_size: Option<usize>,
+    /// How long cached results may be reused. Results are reused until
+    /// they are evicted or invalidated if `None`
+    pub ttl: Option<Duration>,
+}
+
+impl Default for ResultCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_RESULT_CACHE_SIZE,
+            max_entry_size: None,
+            ttl: None,
+        }
+    }
+}
+
+impl ResultCacheConfig {
+    /// Create a new config with the default limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum total size in bytes of the cached results
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Do not cache results larger than `max_entry_size` bytes
+    pub fn with_max_entry_size(mut self, max_entry_size: usize) -> Self {
+        self.max_entry_size = Some(max_entry_size);
+        self
+    }
+
+    /// Reuse cached results for at most `ttl`
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// Identifies the results of a query in a [`ResultCache`], see
+/// [`ResultCache::key`]
+#[derive(Debug, Clone)]
+pub struct ResultCacheKey {
+    entry: EntryKey,
+    /// The optimized plan of the query
+    plan: LogicalPlan,
+    /// The files read by the query, ordered by location
+    files: Vec<ObjectMeta>,
+    /// The generation of the cache when the key was created
+    generation: u64,
+}
+
+impl ResultCacheKey {
+    /// The optimized plan of the query
+    pub fn plan(&self) -> &LogicalPlan {
+        &self.plan
+    }
+
+    /// The fully qualified names of the tables read by the query
+    pub fn tables(&self) -> &BTreeSet<String> {
+        &self.entry.tables
+    }
+
+    /// The files read by the query, with their version when the key was
+    /// created
+    pub fn files(&self) -> &[ObjectMeta] {
+        &self.files
+    }
+}
+
+/// Cached results are looked up by the signature of their plan and the
+/// resolved names of the tables they read, so that queries using the same
+/// table name in sessions with a different default schema do not share
+/// their results
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct EntryKey {
+    signature: LogicalPlanSignature,
+    tables: BTreeSet<String>,
+}
+
+/// Caches the results of queries, so that running a query with the same
+/// optimized plan again returns the cached results instead of executing it.
+///
+/// The cache is opt-in: it is only used by sessions it was set on with
+/// [`SessionStateBuilder::with_result_cache`]. It can be shared by multiple
+/// sessions. Only the results of [`DataFrame::collect`] are cached.
+///
+/// # Keys
+///
+/// Results are keyed by the [`LogicalPlanSignature`] of the optimized plan
+/// of the query, together with the resolved names of the tables it reads.
+/// A cached result is only returned if its plan is equal to the plan of the
+/// query, so signature collisions never return wrong results.
+///
+/// Only queries whose results are fully determined by their plan and the
+/// data they read are cached. A query is not cached if it
+///
+/// * is not a `SELECT`-like query, such as DML, DDL or `EXPLAIN`
+/// * calls a volatile or stable function such as `random()` or `now()`
+/// * contains placeholders, variables or user defined plan nodes
+/// * reads no table, or a table other than a [`ListingTable`] or [`MemTable`]
+///
+/// # Invalidation
+///
+/// The cached results are invalidated when
+///
+/// * the files of a [`ListingTable`] read by the query change: the location,
+///   size, last modification time and ETag of the files are part of the key.
+///   Note that changes are not detected if a list files cache is configured
+///   in the [`CacheManager`](crate::execution::cache::cache_manager::CacheManager)
+/// * a table read by the query is registered, deregistered, or modified by an
+///   `INSERT`, `UPDATE` or `DELETE` statement of a session using the cache
+/// * they are older than the [`ResultCacheConfig::ttl`]
+///
+/// A DML statement invalidates the cached results of its table both when it
+/// starts and once it completed, however it is executed.
+///
+/// # Cost
+///
+/// To detect changed files, every [`DataFrame::collect`] of a cacheable query
+/// lists all files of the [`ListingTable`]s it reads before the cache is
+/// looked up, which can be slow for tables with many files or on remote
+/// object stores. A list files cache configured in the
+/// [`CacheManager`](crate::execution::cache::cache_manager::CacheManager)
+/// avoids listing the files again, at the cost of not detecting changes.
+///
+/// [`SessionStateBuilder::with_result_cache`]: crate::execution::session_state::SessionStateBuilder::with_result_cache
+/// [`DataFrame::collect`]: crate::dataframe::DataFrame::collect
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion::prelude::*;
+/// # use datafusion::error::Result;
+/// # use datafusion::execution::SessionStateBuilder;
+/// # use datafusion::execution::result_cache::{ResultCache, ResultCacheConfig};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let cache = Arc::new(ResultCache::new(ResultCacheConfig::new()));
+/// let state = SessionStateBuilder::new()
+///     .with_default_features()
+///     .with_result_cache(Some(Arc::clone(&cache)))
+///     .build();
+/// let ctx = SessionContext::new_with_state(state);
+/// ctx.register_csv("example", "tests/data/example.csv", CsvReadOptions::new()).await?;
+///
+/// let sql = "SELECT a, b FROM example WHERE a > 0";
+/// ctx.sql(sql).await?.collect().await?;
+/// // the second query returns the results of the first one
+/// ctx.sql(sql).await?.collect().await?;
+/// assert_eq!(cache.hits(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ResultCache {
+    config: ResultCacheConfig,
+    state: Mutex<ResultCacheState>,
+}
+
+#[derive(Debug, Default)]
+struct ResultCacheState {
+    entries: HashMap<EntryKey, CachedResult>,
+    /// The total size of the cached results
+    size: usize,
+    /// Incremented whenever tables are invalidated, so that the results of
+    /// queries that started before are not cached
+    generation: u64,
+    /// Incremented whenever a result is cached or returned
+    clock: u64,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Debug)]
+struct CachedResult {
+    plan: LogicalPlan,
+    files: Vec<ObjectMeta>,
+    batches: Vec<RecordBatch>,
+    size: usize,
+    created: Instant,
+    /// The value of the clock when the result was last used
+    last_used: u64,
+}
+
+impl ResultCache {
+    /// Create a new, empty cache with the limits of `config`
+    pub fn new(config: ResultCacheConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(ResultCacheState::default()),
+        }
+    }
+
+    /// The limits of this cache
+    pub fn config(&self) -> &ResultCacheConfig {
+        &self.config
+    }
+
+    /// Return the key of the results of `plan` in `state`, or `None` if
+    /// the results of `plan` can not be cached.
+    ///
+    /// `plan` is the plan before optimization. This lists the files of the
+    /// [`ListingTable`]s read by the query to record their versions, using
+    /// the list files cache of the runtime if any, see the
+    /// [cost](ResultCache#cost) of the cache.
+    pub async fn key(
+        &self,
+        state: &SessionState,
+        plan: &LogicalPlan,
+    ) -> Result<Option<ResultCacheKey>> {
+        // Stable functions such as `now()` are replaced with constants by the
+        // optimizer, so they are only visible before optimization
+        if !is_deterministic(plan) {
+            return Ok(None);
+        }
+        let generation = self.state.lock().generation;
+        state.check_materialized_views().await?;
+        let plan = state.optimize(plan)?;
+
+        let mut scans = vec![];
+        plan.apply_with_subqueries(|node| {
+            if let LogicalPlan::TableScan(scan) = node {
+                scans.push((scan.table_name.clone(), Arc::clone(&scan.source)));
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        if scans.is_empty() {
+            return Ok(None);
+        }
+
+        let mut tables = BTreeSet::new();
+        let mut files = vec![];
+        for (table_name, source) in scans {
+            let Ok(provider) = source_as_provider(&source) else {
+                return Ok(None);
+            };
+            if let Some(table) = provider.as_any().downcast_ref::<ListingTable>() {
+                // the files of the index are not listed
+                let Some(table_files) = table.list_all_files(state).await? else {
+                    return Ok(None);
+                };
+                files.extend(table_files);
+            } else if provider.as_any().downcast_ref::<MemTable>().is_none() {
+                return Ok(None);
+            }
+            tables.insert(state.resolve_table_ref(table_name).to_string());
+        }
+        files.sort_by(|a, b| a.location.cmp(&b.location));
+        files.dedup_by(|a, b| a.location == b.location);
+
+        Ok(Some(ResultCacheKey {
+            entry: EntryKey {
+                signature: LogicalPlanSignature::new(&plan),
+                tables,
+            },
+            plan,
+            files,
+            generation,
+        }))
+    }
+
+    /// Return the cached results of the query identified by `key`, if any.
+    ///
+    /// Results cached for a different version of the files read by the
+    /// query, or older than the [`ResultCacheConfig::ttl`], are removed.
+    pub fn get(&self, key: &ResultCacheKey) -> Option<Vec<RecordBatch>> {
+        let mut guard = self.state.lock();
+        let state = &mut *guard;
+        state.clock += 1;
+
+        let status = state.entries.get(&key.entry).map(|cached| {
+            let expired = self
+                .config
+                .ttl
+                .is_some_and(|ttl| cached.created.elapsed() >= ttl);
+            let stale = expired || !same_files(&cached.files, &key.files);
+            (stale, cached.plan == key.plan)
+        });
+        match status {
+            Some((false, true)) => {
+                let cached = state.entries.get_mut(&key.entry)?;
+                cached.last_used = state.clock;
+                state.hits += 1;
+                Some(cached.batches.clone())
+            }
+            Some((true, _)) => {
+                state.remove(&key.entry);
+                state.misses += 1;
+                None
+            }
+            _ => {
+                state.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Cache `batches` as the results of the query identified by `key`,
+    /// evicting the least recently used results if needed. Returns `true`
+    /// if the results were cached.
+    ///
+    /// Results are not cached if they are larger than the
+    /// [`ResultCacheConfig::max_entry_size`], or if tables were invalidated
+    /// since `key` was created, as the query may have read their old data.
+    pub fn put(&self, key: ResultCacheKey, batches: &[RecordBatch]) -> bool {
+        let size = batches.iter().map(|b| b.get_array_memory_size()).sum();
+        let max_entry_size = self.config.max_entry_size.unwrap_or(self.config.max_size);
+        if size > max_entry_size.min(self.config.max_size) {
+            return false;
+        }
+
+        let mut state = self.state.lock();
+        if state.generation != key.generation {
+            return false;
+        }
+        state.remove(&key.entry);
+        while state.size + size > self.config.max_size {
+            let Some(lru) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(entry, _)| entry.clone())
+            else {
+                break;
+            };
+            state.remove(&lru);
+        }
+
+        state.clock += 1;
+        let cached = CachedResult {
+            plan: key.plan,
+            files: key.files,
+            batches: batches.to_vec(),
+            size,
+            created: Instant::now(),
+            last_used: state.clock,
+        };
+        state.size += size;
+        state.entries.insert(key.entry, cached);
+        true
+    }
+
+    /// Remove the cached results of the queries reading `table`.
+    ///
+    /// Results of queries reading `table` that are running are not cached
+    /// once they complete.
+    pub fn invalidate_table(&self, table: &ResolvedTableReference) {
+        let table = table.to_string();
+        let mut state = self.state.lock();
+        state.generation += 1;
+        let invalidated: Vec<_> = state
+            .entries
+            .keys()
+            .filter(|entry| entry.tables.contains(&table))
+            .cloned()
+            .collect();
+        for entry in invalidated {
+            state.remove(&entry);
+        }
+    }
+
+    /// Remove all cached results
+    pub fn clear(&self) {
+        let mut state = self.state.lock();
+        state.generation += 1;
+        state.entries.clear();
+        state.size = 0;
+    }
+
+    /// The number of cached results
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    /// Returns `true` if no results are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The total size in bytes of the cached results
+    pub fn size(&self) -> usize {
+        self.state.lock().size
+    }
+
+    /// The number of lookups that returned cached results
+    pub fn hits(&self) -> usize {
+        self.state.lock().hits
+    }
+
+    /// The number of lookups that did not return cached results
+    pub fn misses(&self) -> usize {
+        self.state.lock().misses
+    }
+}
+
+impl ResultCacheState {
+    fn remove(&mut self, entry: &EntryKey) {
+        if let Some(cached) = self.entries.remove(entry) {
+            self.size -= cached.size;
+        }
+    }
+}
+
+/// Returns `true` if both lists contain the same versions of the same files
+fn same_files(cached: &[ObjectMeta], current: &[ObjectMeta]) -> bool {
+    cached.len() == current.len()
+        && cached.iter().zip(current).all(|(cached, current)| {
+            cached.location == current.location
+                && cached.size == current.size
+                && cached.last_modified == current.last_modified
+                && cached.e_tag == current.e_tag
+                && cached.version == current.version
+        })
+}
+
+/// Returns `true` if the results of `plan` only depend on the data it reads
+fn is_deterministic(plan: &LogicalPlan) -> bool {
+    let mut deterministic = true;
+    plan.apply_with_subqueries(|node| {
+        match node {
+            LogicalPlan::Dml(_)
+            | LogicalPlan::Ddl(_)
+            | LogicalPlan::Copy(_)
+            | LogicalPlan::Statement(_)
+            | LogicalPlan::Explain(_)
+            | LogicalPlan::Analyze(_)
+            | LogicalPlan::DescribeTable(_)
+            | LogicalPlan::Extension(_) => deterministic = false,
+            _ => {
+                node.apply_expressions(|expr| {
+                    expr.apply(|expr| {
+                        deterministic &= match expr {
+                            Expr::ScalarFunction(func) => {
+                                func.func.signature().volatility == Volatility::Immutable
+                            }
+                            Expr::Placeholder(_) | Expr::ScalarVariable(_, _) => false,
+                            _ => true,
+                        };
+                        Ok(TreeNodeRecursion::Continue)
+                    })
+                })?;
+            }
+        }
+        Ok(if deterministic {
+            TreeNodeRecursion::Continue
+        } else {
+            TreeNodeRecursion::Stop
+        })
+    })
+    .expect("no errors are returned");
+    deterministic
+}
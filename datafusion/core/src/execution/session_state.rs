@@ -21,13 +21,15 @@ use std::any::Any;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use crate::catalog::{CatalogProviderList, SchemaProvider, TableProviderFactory};
 use crate::datasource::cte_worktable::CteWorkTable;
 use crate::datasource::file_format::{format_as_file_type, FileFormatFactory};
-use crate::datasource::provider_as_source;
+use crate::datasource::{provider_as_source, source_as_provider, MaterializedViewTable};
 use crate::execution::context::{EmptySerializerRegistry, FunctionFactory, QueryPlanner};
+use crate::execution::result_cache::ResultCache;
 use crate::execution::SessionStateDefaults;
 use crate::physical_planner::{DefaultPhysicalPlanner, PhysicalPlanner};
 use datafusion_catalog::information_schema::{
@@ -41,12 +43,15 @@ use datafusion_common::alias::AliasGenerator;
 use datafusion_common::config::{ConfigExtension, ConfigOptions, TableOptions};
 use datafusion_common::display::{PlanType, StringifiedPlan, ToStringifiedPlan};
 use datafusion_common::file_options::file_type::FileType;
-use datafusion_common::tree_node::TreeNode;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{
     config_err, exec_err, not_impl_err, plan_datafusion_err, DFSchema, DataFusionError,
     ResolvedTableReference, TableReference,
 };
+use datafusion_execution::admission::{AdmissionPermit, AdmissionRequest};
 use datafusion_execution::config::SessionConfig;
+use datafusion_execution::memory_pool::ChildMemoryPool;
+use datafusion_execution::query_registry::QueryRegistry;
 use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_execution::TaskContext;
 use datafusion_expr::execution_props::ExecutionProps;
@@ -56,9 +61,10 @@ use datafusion_expr::registry::{FunctionRegistry, SerializerRegistry};
 use datafusion_expr::simplify::SimplifyInfo;
 use datafusion_expr::var_provider::{is_system_variables, VarType};
 use datafusion_expr::{
-    AggregateUDF, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF, TableSource,
-    WindowUDF,
+    AggregateUDF, DdlStatement, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF,
+    TableSource, TableVersion, WindowUDF,
 };
+use datafusion_optimizer::materialized_view_rewrite::MaterializedViewRegistry;
 use datafusion_optimizer::simplify_expressions::ExprSimplifier;
 use datafusion_optimizer::{
     Analyzer, AnalyzerRule, Optimizer, OptimizerConfig, OptimizerRule,
@@ -78,6 +84,7 @@ use log::{debug, info};
 use object_store::ObjectStore;
 use sqlparser::ast::{Expr as SQLExpr, ExprWithAlias as SQLExprWithAlias};
 use sqlparser::dialect::dialect_from_str;
+use tracing::Instrument;
 use url::Url;
 use uuid::Uuid;
 
@@ -175,6 +182,8 @@ pub struct SessionState {
     /// It will be invoked on `CREATE FUNCTION` statements.
     /// thus, changing dialect o PostgreSql is required
     function_factory: Option<Arc<dyn FunctionFactory>>,
+    /// Cache of query results, reused by queries with the same plan
+    result_cache: Option<Arc<ResultCache>>,
     /// Cache logical plans of prepared statements for later execution.
     /// Key is the prepared statement name.
     prepared_plans: HashMap<String, Arc<PreparedPlan>>,
@@ -195,6 +204,7 @@ impl Debug for SessionState {
             .field("table_options", &self.table_options)
             .field("table_factories", &self.table_factories)
             .field("function_factory", &self.function_factory)
+            .field("result_cache", &self.result_cache)
             .field("expr_planners", &self.expr_planners)
             .field("type_planner", &self.type_planner)
             .field("query_planners", &self.query_planner)
@@ -455,6 +465,91 @@ impl SessionState {
         self.function_factory.as_ref()
     }
 
+    /// Set the [`ResultCache`] reused by queries with the same plan
+    pub fn set_result_cache(&mut self, result_cache: Option<Arc<ResultCache>>) {
+        self.result_cache = result_cache;
+    }
+
+    /// Get the [`ResultCache`], if results of this session are cached
+    pub fn result_cache(&self) -> Option<&Arc<ResultCache>> {
+        self.result_cache.as_ref()
+    }
+
+    /// Remove the cached results of the queries reading the tables that
+    /// are modified by `plan`, such as the target of an `INSERT` or a
+    /// dropped table, from the [`ResultCache`], and mark the materialized
+    /// views reading them as stale
+    pub(crate) fn invalidate_cached_results(&self, plan: &LogicalPlan) {
+        let table = match plan {
+            LogicalPlan::Dml(dml) => &dml.table_name,
+            LogicalPlan::Ddl(DdlStatement::CreateExternalTable(cmd)) => &cmd.name,
+            LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(cmd)) => &cmd.name,
+            LogicalPlan::Ddl(DdlStatement::CreateView(cmd)) => &cmd.name,
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(cmd)) => &cmd.name,
+            LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(cmd)) => &cmd.name,
+            LogicalPlan::Ddl(DdlStatement::DropTable(cmd)) => &cmd.name,
+            LogicalPlan::Ddl(DdlStatement::DropView(cmd)) => &cmd.name,
+            _ => return,
+        };
+        self.invalidate_table(table.clone());
+    }
+
+    /// Remove the cached results of the queries reading `table` from the
+    /// [`ResultCache`], and mark the materialized views reading it as stale
+    pub(crate) fn invalidate_table(&self, table: TableReference) {
+        let table = self.resolve_table_ref(table);
+        if let Some(result_cache) = &self.result_cache {
+            result_cache.invalidate_table(&table);
+        }
+        if let Some(views) = self.config.get_extension::<MaterializedViewRegistry>() {
+            views.mark_stale(&TableReference::from(table));
+        }
+    }
+
+    /// Mark the materialized views whose sources changed since they were
+    /// last refreshed as stale, see [`MaterializedViewTable::sources_unchanged`]
+    ///
+    /// This detects changes made outside of the session before the views are
+    /// used to answer a query. It lists the files of the tables read by the
+    /// fresh views, and does nothing unless
+    /// `datafusion.optimizer.enable_materialized_view_rewrite` is enabled.
+    pub(crate) async fn check_materialized_views(&self) -> datafusion_common::Result<()> {
+        if !self
+            .config
+            .options()
+            .optimizer
+            .enable_materialized_view_rewrite
+        {
+            return Ok(());
+        }
+        let Some(views) = self.config.get_extension::<MaterializedViewRegistry>() else {
+            return Ok(());
+        };
+        for view in views.views() {
+            if !view.is_fresh() {
+                continue;
+            }
+            let provider = source_as_provider(view.source())?;
+            let unchanged =
+                match provider.as_any().downcast_ref::<MaterializedViewTable>() {
+                    Some(table) => table.sources_unchanged(self).await?,
+                    None => false,
+                };
+            if !unchanged {
+                view.set_fresh(false);
+            }
+        }
+        Ok(())
+    }
+
+    /// Stop using the materialized view named `table`, if any, to answer
+    /// queries, such as when it is dropped or replaced
+    pub(crate) fn deregister_materialized_view(&self, table: TableReference) {
+        if let Some(views) = self.config.get_extension::<MaterializedViewRegistry>() {
+            views.deregister(&self.resolve_table_ref(table).into());
+        }
+    }
+
     /// Get the table factories
     pub fn table_factories(&self) -> &HashMap<String, Arc<dyn TableProviderFactory>> {
         &self.table_factories
@@ -602,10 +697,16 @@ impl SessionState {
         &self,
         sql: &str,
     ) -> datafusion_common::Result<LogicalPlan> {
-        let dialect = self.config.options().sql_parser.dialect.as_str();
-        let statement = self.sql_to_statement(sql, dialect)?;
-        let plan = self.statement_to_plan(statement).await?;
-        Ok(plan)
+        async {
+            let dialect = self.config.options().sql_parser.dialect.as_str();
+            let statement = tracing::debug_span!("parse_sql")
+                .in_scope(|| self.sql_to_statement(sql, dialect))?;
+            self.statement_to_plan(statement)
+                .instrument(tracing::debug_span!("sql_to_logical_plan"))
+                .await
+        }
+        .instrument(tracing::info_span!("create_logical_plan"))
+        .await
     }
 
     /// Creates a datafusion style AST [`Expr`] from a SQL string.
@@ -672,6 +773,7 @@ impl SessionState {
                         stringified_plans,
                         schema: Arc::clone(&e.schema),
                         logical_optimization_succeeded: false,
+                        explain_format: e.explain_format,
                     }));
                 }
                 Err(e) => return Err(e),
@@ -708,6 +810,7 @@ impl SessionState {
                 stringified_plans,
                 schema: Arc::clone(&e.schema),
                 logical_optimization_succeeded,
+                explain_format: e.explain_format,
             }))
         } else {
             let analyzed_plan = self.analyzer.execute_and_check(
@@ -733,10 +836,15 @@ impl SessionState {
         &self,
         logical_plan: &LogicalPlan,
     ) -> datafusion_common::Result<Arc<dyn ExecutionPlan>> {
-        let logical_plan = self.optimize(logical_plan)?;
-        self.query_planner
-            .create_physical_plan(&logical_plan, self)
-            .await
+        async {
+            self.check_materialized_views().await?;
+            let logical_plan = self.optimize(logical_plan)?;
+            self.query_planner
+                .create_physical_plan(&logical_plan, self)
+                .await
+        }
+        .instrument(tracing::info_span!("create_physical_plan"))
+        .await
     }
 
     /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
@@ -871,6 +979,33 @@ impl SessionState {
         Arc::new(TaskContext::from(self))
     }
 
+    /// Waits until the query running `plan` may run, if the runtime has an
+    /// [`AdmissionController`]. The query is considered running until the
+    /// returned permit is dropped.
+    ///
+    /// This is called by the methods of [`DataFrame`] that execute a query,
+    /// before the query is planned. Plans executed directly, such as with
+    /// [`collect`], are not admitted.
+    ///
+    /// Queries that only read `information_schema` tables are not queued, so
+    /// that the admission queue can be inspected while it is full.
+    ///
+    /// [`AdmissionController`]: datafusion_execution::admission::AdmissionController
+    /// [`DataFrame`]: crate::dataframe::DataFrame
+    /// [`collect`]: crate::physical_plan::collect
+    pub async fn admit(&self, plan: &LogicalPlan) -> Option<AdmissionPermit> {
+        let controller = self.runtime_env.admission_controller.clone()?;
+        if reads_only_information_schema(plan) {
+            return None;
+        }
+        let options = &self.config.options().execution;
+        let request = AdmissionRequest::new(self.session_id.clone())
+            .with_queue(options.admission_queue.clone())
+            .with_priority(options.admission_priority)
+            .with_cpu_slots(options.target_partitions);
+        Some(controller.admit(request).await)
+    }
+
     /// Return catalog list
     pub fn catalog_list(&self) -> &Arc<dyn CatalogProviderList> {
         &self.catalog_list
@@ -991,6 +1126,7 @@ pub struct SessionStateBuilder {
     table_factories: Option<HashMap<String, Arc<dyn TableProviderFactory>>>,
     runtime_env: Option<Arc<RuntimeEnv>>,
     function_factory: Option<Arc<dyn FunctionFactory>>,
+    result_cache: Option<Arc<ResultCache>>,
     // fields to support convenience functions
     analyzer_rules: Option<Vec<Arc<dyn AnalyzerRule + Send + Sync>>>,
     optimizer_rules: Option<Vec<Arc<dyn OptimizerRule + Send + Sync>>>,
@@ -1021,6 +1157,7 @@ impl SessionStateBuilder {
             table_factories: None,
             runtime_env: None,
             function_factory: None,
+            result_cache: None,
             // fields to support convenience functions
             analyzer_rules: None,
             optimizer_rules: None,
@@ -1071,6 +1208,7 @@ impl SessionStateBuilder {
             table_factories: Some(existing.table_factories),
             runtime_env: Some(existing.runtime_env),
             function_factory: existing.function_factory,
+            result_cache: existing.result_cache,
 
             // fields to support convenience functions
             analyzer_rules: None,
@@ -1315,6 +1453,13 @@ impl SessionStateBuilder {
         self
     }
 
+    /// Set a [`ResultCache`] to reuse the results of queries with the same
+    /// plan, see [`ResultCache`] for details
+    pub fn with_result_cache(mut self, result_cache: Option<Arc<ResultCache>>) -> Self {
+        self.result_cache = result_cache;
+        self
+    }
+
     /// Register an `ObjectStore` to the [`RuntimeEnv`]. See [`RuntimeEnv::register_object_store`]
     /// for more details.
     ///
@@ -1377,12 +1522,19 @@ impl SessionStateBuilder {
             table_factories,
             runtime_env,
             function_factory,
+            result_cache,
             analyzer_rules,
             optimizer_rules,
             physical_optimizer_rules,
         } = self;
 
-        let config = config.unwrap_or_default();
+        let mut config = config.unwrap_or_default();
+        if config.get_extension::<QueryRegistry>().is_none() {
+            config.set_extension(Arc::new(QueryRegistry::default()));
+        }
+        if config.get_extension::<MaterializedViewRegistry>().is_none() {
+            config.set_extension(Arc::new(MaterializedViewRegistry::default()));
+        }
         let runtime_env = runtime_env.unwrap_or(Arc::new(RuntimeEnv::default()));
 
         let mut state = SessionState {
@@ -1410,6 +1562,7 @@ impl SessionStateBuilder {
             table_factories: table_factories.unwrap_or_default(),
             runtime_env,
             function_factory,
+            result_cache,
             prepared_plans: HashMap::new(),
         };
 
@@ -1589,6 +1742,11 @@ impl SessionStateBuilder {
         &mut self.function_factory
     }
 
+    /// Returns the current result_cache value
+    pub fn result_cache(&mut self) -> &mut Option<Arc<ResultCache>> {
+        &mut self.result_cache
+    }
+
     /// Returns the current analyzer_rules value
     pub fn analyzer_rules(
         &mut self,
@@ -1626,6 +1784,7 @@ impl Debug for SessionStateBuilder {
             .field("table_options", &self.table_options)
             .field("table_factories", &self.table_factories)
             .field("function_factory", &self.function_factory)
+            .field("result_cache", &self.result_cache)
             .field("expr_planners", &self.expr_planners)
             .field("type_planner", &self.type_planner)
             .field("query_planners", &self.query_planner)
@@ -1688,6 +1847,15 @@ impl ContextProvider for SessionContextProvider<'_> {
             .ok_or_else(|| plan_datafusion_err!("table '{name}' not found"))
     }
 
+    fn get_table_source_at_version(
+        &self,
+        name: TableReference,
+        version: &TableVersion,
+    ) -> datafusion_common::Result<Arc<dyn TableSource>> {
+        let provider = source_as_provider(&self.get_table_source(name)?)?;
+        Ok(provider_as_source(provider.at_version(version)?))
+    }
+
     fn get_table_function_source(
         &self,
         name: &str,
@@ -1911,12 +2079,52 @@ impl OptimizerConfig for SessionState {
     fn function_registry(&self) -> Option<&dyn FunctionRegistry> {
         Some(self)
     }
+
+    fn materialized_views(&self) -> Option<Arc<MaterializedViewRegistry>> {
+        self.config.get_extension()
+    }
 }
 
+fn reads_only_information_schema(plan: &LogicalPlan) -> bool {
+    let mut has_scan = false;
+    let mut only_information_schema = true;
+    plan.apply_with_subqueries(|node| {
+        if let LogicalPlan::TableScan(scan) = node {
+            has_scan = true;
+            only_information_schema &=
+                scan.table_name.schema() == Some(INFORMATION_SCHEMA);
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .expect("no errors are returned");
+    has_scan && only_information_schema
+}
+
+/// Used to name the memory pools of queries with a
+/// [`SessionConfig::query_memory_limit`]
+static NEXT_QUERY_ID: AtomicUsize = AtomicUsize::new(0);
+
 /// Create a new task context instance from SessionState
+///
+/// If the session has a [`SessionConfig::query_memory_limit`], the task
+/// reserves memory from its own [`ChildMemoryPool`] of the runtime's pool.
 impl From<&SessionState> for TaskContext {
     fn from(state: &SessionState) -> Self {
         let task_id = None;
+        let runtime = match state.config.query_memory_limit() {
+            Some(limit) => {
+                let query_id = NEXT_QUERY_ID.fetch_add(1, Ordering::Relaxed);
+                let pool = ChildMemoryPool::new(
+                    format!("query {query_id} of session {}", state.session_id),
+                    Arc::clone(&state.runtime_env.memory_pool),
+                    limit,
+                );
+                let mut runtime = RuntimeEnv::clone(&state.runtime_env);
+                runtime.memory_pool = Arc::new(pool);
+                Arc::new(runtime)
+            }
+            None => Arc::clone(&state.runtime_env),
+        };
         TaskContext::new(
             task_id,
             state.session_id.clone(),
@@ -1924,7 +2132,7 @@ impl From<&SessionState> for TaskContext {
             state.scalar_functions.clone(),
             state.aggregate_functions.clone(),
             state.window_functions.clone(),
-            Arc::clone(&state.runtime_env),
+            runtime,
         )
     }
 }
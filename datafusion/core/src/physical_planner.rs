@@ -62,7 +62,6 @@ use datafusion_physical_plan::recursive_query::RecursiveQueryExec;
 use arrow::array::{builder::StringBuilder, RecordBatch};
 use arrow::compute::SortOptions;
 use arrow::datatypes::{Schema, SchemaRef};
-use datafusion_common::display::ToStringifiedPlan;
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion, TreeNodeVisitor};
 use datafusion_common::{
     exec_err, internal_datafusion_err, internal_err, not_impl_err, plan_err, DFSchema,
@@ -95,6 +94,7 @@ use itertools::{multiunzip, Itertools};
 use log::{debug, trace};
 use sqlparser::ast::NullTreatment;
 use tokio::sync::Mutex;
+use tracing::Instrument;
 
 /// Physical query planner that converts a `LogicalPlan` to an
 /// `ExecutionPlan` suitable for execution.
@@ -180,6 +180,7 @@ impl PhysicalPlanner for DefaultPhysicalPlanner {
             None => {
                 let plan = self
                     .create_initial_plan(logical_plan, session_state)
+                    .instrument(tracing::debug_span!("create_initial_physical_plan"))
                     .await?;
 
                 self.optimize_physical_plan(plan, session_state, |_, _| {})
@@ -535,6 +536,8 @@ impl DefaultPhysicalPlanner {
                     insert_op: InsertOp::Append,
                     keep_partition_by_columns,
                     file_extension: sink_format.get_ext(),
+                    bucket_spec: None,
+                    rolling_policy: None,
                 };
 
                 sink_format
@@ -1727,7 +1730,12 @@ impl DefaultPhysicalPlanner {
             if !config.physical_plan_only {
                 stringified_plans.clone_from(&e.stringified_plans);
                 if e.logical_optimization_succeeded {
-                    stringified_plans.push(e.plan.to_stringified(FinalLogicalPlan));
+                    stringified_plans.push(
+                        e.plan.to_stringified_with_format(
+                            FinalLogicalPlan,
+                            e.explain_format,
+                        ),
+                    );
                 }
             }
 
@@ -1791,7 +1799,11 @@ impl DefaultPhysicalPlanner {
                                     displayable(input.as_ref())
                                         .set_show_statistics(config.show_statistics)
                                         .set_show_schema(config.show_schema)
-                                        .to_stringified(e.verbose, FinalPhysicalPlan),
+                                        .to_stringified_with_format(
+                                            e.verbose,
+                                            FinalPhysicalPlan,
+                                            e.explain_format,
+                                        ),
                                 );
 
                                 // Show statistics + schema in verbose output even if not
@@ -1845,12 +1857,10 @@ impl DefaultPhysicalPlanner {
             let input = self.create_physical_plan(&a.input, session_state).await?;
             let schema = SchemaRef::new((*a.schema).clone().into());
             let show_statistics = session_state.config_options().explain.show_statistics;
-            Ok(Some(Arc::new(AnalyzeExec::new(
-                a.verbose,
-                show_statistics,
-                input,
-                schema,
-            ))))
+            Ok(Some(Arc::new(
+                AnalyzeExec::new(a.verbose, show_statistics, input, schema)
+                    .with_explain_format(a.explain_format),
+            )))
         } else {
             Ok(None)
         }
@@ -1867,6 +1877,7 @@ impl DefaultPhysicalPlanner {
     where
         F: FnMut(&dyn ExecutionPlan, &dyn PhysicalOptimizerRule),
     {
+        let _span = tracing::info_span!("optimize_physical_plan").entered();
         let optimizers = session_state.physical_optimizers();
         debug!(
             "Input physical plan:\n{}\n",
@@ -1883,6 +1894,12 @@ impl DefaultPhysicalPlanner {
 
         let mut new_plan = Arc::clone(&plan);
         for optimizer in optimizers {
+            let _span = tracing::debug_span!(
+                "physical_optimizer_rule",
+                otel.name = optimizer.name(),
+                rule = optimizer.name()
+            )
+            .entered();
             let before_schema = new_plan.schema();
             new_plan = optimizer
                 .optimize(new_plan, session_state.config_options())
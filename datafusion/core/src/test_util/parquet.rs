@@ -168,6 +168,7 @@ impl TestParquetFile {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         });
 
@@ -62,6 +62,7 @@ use datafusion::test_util::{
 };
 use datafusion::{assert_batches_eq, assert_batches_sorted_eq};
 use datafusion_catalog::TableProvider;
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::{
     assert_contains, Constraint, Constraints, DataFusionError, ParamValues, ScalarValue,
     TableReference, UnnestOptions,
@@ -1264,6 +1265,33 @@ async fn explain() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn explain_with_format() -> Result<()> {
+    let ctx = SessionContext::new();
+    let df = ctx
+        .sql("SELECT a FROM (VALUES (1), (2)) AS t(a) WHERE a > 1")
+        .await?;
+
+    let batches = df
+        .clone()
+        .explain_with_format(false, false, ExplainFormat::Tree)?
+        .collect()
+        .await?;
+    let formatted = pretty_format_batches(&batches)?.to_string();
+    assert_contains!(&formatted, "│           Filter          │");
+    assert_contains!(&formatted, "│         FilterExec        │");
+
+    let batches = df
+        .explain_with_format(false, false, ExplainFormat::Json)?
+        .collect()
+        .await?;
+    let formatted = pretty_format_batches(&batches)?.to_string();
+    assert_contains!(&formatted, "\"Node Type\": \"Filter\"");
+    assert_contains!(&formatted, "\"Node Type\": \"FilterExec\"");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn registry() -> Result<()> {
     let ctx = SessionContext::new();
@@ -0,0 +1,160 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for the admission control of queries
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use datafusion::assert_batches_eq;
+use datafusion::execution::admission::AdmissionConfig;
+use datafusion::execution::memory_pool::MemoryConsumer;
+use datafusion::execution::runtime_env::RuntimeEnvBuilder;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion_common_runtime::SpawnedTask;
+
+fn admission_context(config: AdmissionConfig) -> SessionContext {
+    let runtime = RuntimeEnvBuilder::new()
+        .with_admission_control(config)
+        .build_arc()
+        .unwrap();
+    let config = SessionConfig::new().with_information_schema(true);
+    SessionContext::new_with_config_rt(config, runtime)
+}
+
+async fn admission_queue(ctx: &SessionContext) -> Vec<arrow::array::RecordBatch> {
+    ctx.sql(
+        "SELECT queue, priority, cpu_slots, state \
+         FROM information_schema.admission_queue ORDER BY query_id",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn queue_queries_over_max_concurrent_queries() {
+    let ctx = admission_context(AdmissionConfig::new().with_max_concurrent_queries(1));
+    ctx.sql("SET datafusion.execution.target_partitions = 2")
+        .await
+        .unwrap();
+
+    // the stream holds its admission until it is dropped
+    let running = ctx
+        .sql("SELECT * FROM generate_series(1, 10)")
+        .await
+        .unwrap()
+        .execute_stream()
+        .await
+        .unwrap();
+
+    ctx.sql("SET datafusion.execution.admission_queue = 'etl'")
+        .await
+        .unwrap();
+    ctx.sql("SET datafusion.execution.admission_priority = 5")
+        .await
+        .unwrap();
+    let df = ctx
+        .sql("SELECT max(value) FROM generate_series(1, 10)")
+        .await
+        .unwrap();
+    let queued = SpawnedTask::spawn(df.collect());
+
+    let runtime = ctx.runtime_env();
+    let controller = Arc::clone(runtime.admission_controller.as_ref().unwrap());
+    while controller.num_queued() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // reading the queue itself is never queued
+    let expected = [
+        "+---------+----------+-----------+---------+",
+        "| queue   | priority | cpu_slots | state   |",
+        "+---------+----------+-----------+---------+",
+        "| default | 0        | 2         | running |",
+        "| etl     | 5        | 2         | queued  |",
+        "+---------+----------+-----------+---------+",
+    ];
+    assert_batches_eq!(expected, &admission_queue(&ctx).await);
+    assert_eq!(controller.num_queued(), 1);
+
+    drop(running);
+    let batches = queued.join().await.unwrap().unwrap();
+    assert_eq!(batches[0].num_rows(), 1);
+
+    assert_eq!(controller.num_running(), 0);
+    assert_eq!(controller.num_queued(), 0);
+    assert!(admission_queue(&ctx)
+        .await
+        .iter()
+        .all(|b| b.num_rows() == 0));
+}
+
+#[tokio::test]
+async fn no_admission_controller() {
+    let ctx = SessionContext::new_with_config(
+        SessionConfig::new().with_information_schema(true),
+    );
+    assert!(ctx.runtime_env().admission_controller.is_none());
+
+    // queries run without a controller and the queue is empty
+    let _running = ctx
+        .sql("SELECT * FROM generate_series(1, 10)")
+        .await
+        .unwrap()
+        .execute_stream()
+        .await
+        .unwrap();
+    assert!(admission_queue(&ctx)
+        .await
+        .iter()
+        .all(|b| b.num_rows() == 0));
+}
+
+#[tokio::test]
+async fn admit_queued_queries_when_memory_is_freed() {
+    let ctx = admission_context(AdmissionConfig::new().with_max_reserved_memory(100));
+    let runtime = ctx.runtime_env();
+    let controller = Arc::clone(runtime.admission_controller.as_ref().unwrap());
+
+    let running = ctx
+        .sql("SELECT * FROM generate_series(1, 10)")
+        .await
+        .unwrap()
+        .execute_stream()
+        .await
+        .unwrap();
+    let mut reservation = MemoryConsumer::new("test").register(&runtime.memory_pool);
+    reservation.grow(200);
+
+    let df = ctx
+        .sql("SELECT max(value) FROM generate_series(1, 10)")
+        .await
+        .unwrap();
+    let queued = SpawnedTask::spawn(df.collect());
+    while controller.num_queued() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // the query is admitted while the other query is still running
+    reservation.free();
+    let batches = queued.join().await.unwrap().unwrap();
+    assert_eq!(batches[0].num_rows(), 1);
+    drop(running);
+}
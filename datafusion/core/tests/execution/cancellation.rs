@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for query timeouts and cancellation
+
+use std::time::Duration;
+
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion_common::{CancellationReason, DataFusionError};
+use datafusion_common_runtime::SpawnedTask;
+
+/// Aggregates more rows than could be processed before the test times out
+const LONG_RUNNING_AGGREGATE: &str =
+    "SELECT max(value) FROM generate_series(1, 100000000000)";
+
+/// Sorts more rows than could be processed before the test times out
+const LONG_RUNNING_TOPK: &str =
+    "SELECT value FROM generate_series(1, 100000000000) ORDER BY value DESC LIMIT 1";
+
+#[tokio::test]
+async fn statement_timeout() {
+    let ctx = SessionContext::new();
+    ctx.sql("SET datafusion.execution.statement_timeout = 100")
+        .await
+        .unwrap();
+
+    for sql in [LONG_RUNNING_AGGREGATE, LONG_RUNNING_TOPK] {
+        let err = ctx.sql(sql).await.unwrap().collect().await.unwrap_err();
+        assert!(
+            matches!(
+                err,
+                DataFusionError::Cancelled(CancellationReason::Timeout(timeout))
+                    if timeout == Duration::from_millis(100)
+            ),
+            "unexpected error for {sql}: {err}"
+        );
+    }
+
+    // queries that finish in time are unaffected
+    let batches = ctx
+        .sql("SELECT max(value) FROM generate_series(1, 1000)")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_eq!(batches[0].num_rows(), 1);
+}
+
+#[tokio::test]
+async fn statement_timeout_from_config() {
+    let config = SessionConfig::new().with_statement_timeout(Duration::from_millis(100));
+    let ctx = SessionContext::new_with_config(config);
+
+    let err = ctx
+        .sql(LONG_RUNNING_AGGREGATE)
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Query cancelled: query exceeded the statement timeout of 100ms"
+    );
+}
+
+// The query is cancelled from another thread while it runs
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_running_query() {
+    let ctx = SessionContext::new();
+
+    for sql in [LONG_RUNNING_AGGREGATE, LONG_RUNNING_TOPK] {
+        let df = ctx.sql(sql).await.unwrap();
+        let cancellation = df.cancellation_token();
+        let query = SpawnedTask::spawn(df.collect());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancellation.cancel();
+
+        let err = query.join().await.unwrap().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                DataFusionError::Cancelled(CancellationReason::Cancelled)
+            ),
+            "unexpected error for {sql}: {err}"
+        );
+    }
+}
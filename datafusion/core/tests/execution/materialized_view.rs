@@ -0,0 +1,336 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for `CREATE MATERIALIZED VIEW`, `REFRESH MATERIALIZED VIEW` and
+//! answering queries from materialized views
+
+use arrow::array::RecordBatch;
+use datafusion::assert_batches_eq;
+use datafusion::prelude::{CsvReadOptions, SessionConfig, SessionContext};
+
+async fn query(ctx: &SessionContext, sql: &str) -> Vec<RecordBatch> {
+    ctx.sql(sql).await.unwrap().collect().await.unwrap()
+}
+
+/// The optimized logical plan of `sql`
+async fn optimized_plan(ctx: &SessionContext, sql: &str) -> String {
+    let plan = ctx.sql(sql).await.unwrap().into_optimized_plan().unwrap();
+    format!("{plan}")
+}
+
+async fn context_with_table(rewrite: bool) -> SessionContext {
+    let config = SessionConfig::new().set_bool(
+        "datafusion.optimizer.enable_materialized_view_rewrite",
+        rewrite,
+    );
+    let ctx = SessionContext::new_with_config(config);
+    query(
+        &ctx,
+        "CREATE TABLE t AS SELECT * FROM \
+         (VALUES (1, 1, 10), (1, 2, 20), (2, 1, 30)) AS v(a, b, c)",
+    )
+    .await;
+    ctx
+}
+
+#[tokio::test]
+async fn create_refresh_and_drop() {
+    let ctx = context_with_table(false).await;
+    query(
+        &ctx,
+        "CREATE MATERIALIZED VIEW mv AS SELECT a, sum(c) AS s FROM t GROUP BY a",
+    )
+    .await;
+
+    let sql = "SELECT * FROM mv ORDER BY a";
+    let expected = [
+        "+---+----+",
+        "| a | s  |",
+        "+---+----+",
+        "| 1 | 30 |",
+        "| 2 | 30 |",
+        "+---+----+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    // the stored results do not change until the view is refreshed
+    query(&ctx, "INSERT INTO t VALUES (3, 1, 5)").await;
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    query(&ctx, "REFRESH MATERIALIZED VIEW mv").await;
+    let expected = [
+        "+---+----+",
+        "| a | s  |",
+        "+---+----+",
+        "| 1 | 30 |",
+        "| 2 | 30 |",
+        "| 3 | 5  |",
+        "+---+----+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    let err = ctx
+        .sql("CREATE MATERIALIZED VIEW mv AS SELECT 1")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{err}");
+    query(
+        &ctx,
+        "CREATE MATERIALIZED VIEW IF NOT EXISTS mv AS SELECT 1",
+    )
+    .await;
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    query(
+        &ctx,
+        "CREATE OR REPLACE MATERIALIZED VIEW mv AS SELECT 1 AS a",
+    )
+    .await;
+    let expected = ["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    let err = ctx.sql("REFRESH MATERIALIZED VIEW t").await.unwrap_err();
+    assert!(
+        err.to_string().contains("is not a materialized view"),
+        "{err}"
+    );
+
+    query(&ctx, "DROP VIEW mv").await;
+    assert!(ctx.sql(sql).await.is_err());
+}
+
+#[tokio::test]
+async fn stored_at_location() {
+    let dir = tempfile::tempdir().unwrap();
+    let location = dir.path().join("mv");
+    let ctx = context_with_table(false).await;
+    query(
+        &ctx,
+        &format!(
+            "CREATE MATERIALIZED VIEW mv STORED AS PARQUET LOCATION '{}' \
+             AS SELECT a, c FROM t WHERE c > 10",
+            location.display()
+        ),
+    )
+    .await;
+    assert!(std::fs::read_dir(&location).unwrap().count() > 0);
+
+    let sql = "SELECT count(*) AS n, sum(c) AS s FROM mv";
+    let expected = [
+        "+---+----+",
+        "| n | s  |",
+        "+---+----+",
+        "| 2 | 50 |",
+        "+---+----+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    // refreshing replaces the files of the previous results
+    query(&ctx, "INSERT INTO t VALUES (3, 1, 40)").await;
+    query(&ctx, "REFRESH MATERIALIZED VIEW mv").await;
+    let expected = [
+        "+---+----+",
+        "| n | s  |",
+        "+---+----+",
+        "| 3 | 90 |",
+        "+---+----+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    // files at the location of another view are not replaced
+    let err = ctx
+        .sql(&format!(
+            "CREATE MATERIALIZED VIEW other STORED AS PARQUET LOCATION '{}' AS SELECT 1",
+            location.display()
+        ))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("is not empty"), "{err}");
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+}
+
+#[tokio::test]
+async fn failed_refresh_keeps_results() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = context_with_table(false).await;
+    query(
+        &ctx,
+        &format!(
+            "CREATE MATERIALIZED VIEW mv STORED AS PARQUET LOCATION '{}' \
+             AS SELECT a, 60 / c AS d FROM t",
+            dir.path().display()
+        ),
+    )
+    .await;
+    let files = std::fs::read_dir(dir.path()).unwrap().count();
+
+    let sql = "SELECT sum(d) AS s FROM mv";
+    let expected = ["+----+", "| s  |", "+----+", "| 11 |", "+----+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    // the division by zero fails the refresh
+    query(&ctx, "INSERT INTO t VALUES (3, 1, 0)").await;
+    let err = ctx.sql("REFRESH MATERIALIZED VIEW mv").await.unwrap_err();
+    assert!(err.to_string().contains("Divide by zero"), "{err}");
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), files);
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+}
+
+#[tokio::test]
+async fn answer_queries_from_views() {
+    let ctx = context_with_table(true).await;
+    query(
+        &ctx,
+        "CREATE MATERIALIZED VIEW agg AS \
+         SELECT a, b, sum(c) AS s, count(*) AS n FROM t GROUP BY a, b",
+    )
+    .await;
+    query(
+        &ctx,
+        "CREATE MATERIALIZED VIEW filtered AS SELECT a, c FROM t WHERE c > 10",
+    )
+    .await;
+
+    // the same query as the view definition
+    let sql = "SELECT a, b, sum(c) AS s, count(*) AS n FROM t GROUP BY a, b";
+    assert!(optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.agg"));
+
+    // a rollup of the view aggregates
+    let sql = "SELECT a, sum(c) AS s, count(*) AS n FROM t GROUP BY a ORDER BY a";
+    assert!(optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.agg"));
+    let expected = [
+        "+---+----+---+",
+        "| a | s  | n |",
+        "+---+----+---+",
+        "| 1 | 30 | 2 |",
+        "| 2 | 30 | 1 |",
+        "+---+----+---+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    // a subset of the rows of the view
+    let filter_sql = "SELECT a FROM t WHERE c > 10 AND a = 1";
+    assert!(optimized_plan(&ctx, filter_sql)
+        .await
+        .contains("TableScan: datafusion.public.filtered"));
+    let expected = ["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, filter_sql).await);
+
+    // rows that are not in the view
+    let sql = "SELECT a FROM t WHERE c > 5";
+    assert!(!optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.filtered"));
+
+    // stale views are not used until they are refreshed
+    query(&ctx, "INSERT INTO t VALUES (1, 3, 40)").await;
+    let sql = "SELECT a, sum(c) AS s, count(*) AS n FROM t GROUP BY a ORDER BY a";
+    assert!(!optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.agg"));
+    let expected = [
+        "+---+----+---+",
+        "| a | s  | n |",
+        "+---+----+---+",
+        "| 1 | 70 | 3 |",
+        "| 2 | 30 | 1 |",
+        "+---+----+---+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    query(&ctx, "REFRESH MATERIALIZED VIEW agg").await;
+    assert!(optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.agg"));
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+    assert!(!optimized_plan(&ctx, filter_sql)
+        .await
+        .contains("TableScan: datafusion.public.filtered"));
+
+    // dropped views are not used
+    query(&ctx, "DROP VIEW agg").await;
+    assert!(!optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.agg"));
+}
+
+#[tokio::test]
+async fn views_of_files_changed_outside_the_session_are_not_used() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("1.csv"), "a,c\n1,10\n2,20\n").unwrap();
+
+    let config = SessionConfig::new().set_bool(
+        "datafusion.optimizer.enable_materialized_view_rewrite",
+        true,
+    );
+    let ctx = SessionContext::new_with_config(config);
+    ctx.register_csv("t", dir.path().to_str().unwrap(), CsvReadOptions::new())
+        .await
+        .unwrap();
+    query(
+        &ctx,
+        "CREATE MATERIALIZED VIEW mv AS SELECT a, sum(c) AS s FROM t GROUP BY a",
+    )
+    .await;
+
+    let sql = "EXPLAIN SELECT a, sum(c) AS s FROM t GROUP BY a";
+    let explain = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+    let explain = arrow::util::pretty::pretty_format_batches(&explain)
+        .unwrap()
+        .to_string();
+    assert!(
+        explain.contains("TableScan: datafusion.public.mv"),
+        "{explain}"
+    );
+
+    // a file written by another writer makes the view stale
+    std::fs::write(dir.path().join("2.csv"), "a,c\n1,5\n").unwrap();
+    let sql = "SELECT a, sum(c) AS s FROM t GROUP BY a ORDER BY a";
+    let expected = [
+        "+---+----+",
+        "| a | s  |",
+        "+---+----+",
+        "| 1 | 15 |",
+        "| 2 | 20 |",
+        "+---+----+",
+    ];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+    assert!(!optimized_plan(&ctx, sql)
+        .await
+        .contains("TableScan: datafusion.public.mv"));
+
+    query(&ctx, "REFRESH MATERIALIZED VIEW mv").await;
+    let explain = ctx
+        .sql(&format!("EXPLAIN {sql}"))
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let explain = arrow::util::pretty::pretty_format_batches(&explain)
+        .unwrap()
+        .to_string();
+    assert!(
+        explain.contains("TableScan: datafusion.public.mv"),
+        "{explain}"
+    );
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+}
@@ -15,4 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod admission;
+mod cancellation;
 mod logical_plan;
+mod materialized_view;
+mod result_cache;
+mod system_tables;
+mod tracing_spans;
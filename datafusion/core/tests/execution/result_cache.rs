@@ -0,0 +1,213 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for reusing query results with a [`ResultCache`]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow::array::{Int32Array, RecordBatch};
+use datafusion::assert_batches_eq;
+use datafusion::execution::result_cache::{ResultCache, ResultCacheConfig};
+use datafusion::execution::SessionStateBuilder;
+use datafusion::prelude::{CsvReadOptions, SessionContext};
+use futures::TryStreamExt;
+
+fn context(cache: &Arc<ResultCache>) -> SessionContext {
+    let state = SessionStateBuilder::new()
+        .with_default_features()
+        .with_result_cache(Some(Arc::clone(cache)))
+        .build();
+    SessionContext::new_with_state(state)
+}
+
+async fn query(ctx: &SessionContext, sql: &str) -> Vec<RecordBatch> {
+    ctx.sql(sql).await.unwrap().collect().await.unwrap()
+}
+
+fn register_numbers(ctx: &SessionContext) {
+    let batch = RecordBatch::try_from_iter([(
+        "n",
+        Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+    )])
+    .unwrap();
+    ctx.register_batch("t", batch).unwrap();
+}
+
+#[tokio::test]
+async fn reuse_results_of_same_plan() {
+    let cache = Arc::new(ResultCache::new(ResultCacheConfig::new()));
+    let ctx = context(&cache);
+    register_numbers(&ctx);
+
+    let expected = ["+---+", "| s |", "+---+", "| 6 |", "+---+"];
+    let batches = query(&ctx, "SELECT sum(n) AS s FROM t").await;
+    assert_batches_eq!(expected, &batches);
+    assert_eq!((cache.len(), cache.hits(), cache.misses()), (1, 0, 1));
+
+    // the same query with different formatting has the same optimized plan
+    let batches = query(&ctx, "select   SUM(n) as s\nFROM t WHERE 1 = 1").await;
+    assert_batches_eq!(expected, &batches);
+    assert_eq!((cache.len(), cache.hits(), cache.misses()), (1, 1, 1));
+
+    // a different query is not answered from the cache
+    query(&ctx, "SELECT max(n) AS s FROM t").await;
+    assert_eq!((cache.len(), cache.hits(), cache.misses()), (2, 1, 2));
+}
+
+#[tokio::test]
+async fn non_deterministic_queries_are_not_cached() {
+    let cache = Arc::new(ResultCache::new(ResultCacheConfig::new()));
+    let ctx = context(&cache);
+    register_numbers(&ctx);
+
+    query(&ctx, "SELECT n, random() FROM t").await;
+    query(&ctx, "SELECT n, now() FROM t").await;
+    query(&ctx, "SELECT * FROM generate_series(1, 3)").await;
+    query(&ctx, "EXPLAIN SELECT n FROM t").await;
+    assert!(cache.is_empty());
+}
+
+#[tokio::test]
+async fn invalidate_on_dml_and_ddl() {
+    let cache = Arc::new(ResultCache::new(ResultCacheConfig::new()));
+    let ctx = context(&cache);
+    query(&ctx, "CREATE TABLE t AS VALUES (1), (2)").await;
+
+    let sql = "SELECT count(*) AS c FROM t";
+    query(&ctx, sql).await;
+    assert_eq!(cache.len(), 1);
+
+    query(&ctx, "INSERT INTO t VALUES (3)").await;
+    assert!(cache.is_empty());
+    let expected = ["+---+", "| c |", "+---+", "| 3 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+
+    query(&ctx, "CREATE OR REPLACE TABLE t AS SELECT 1 AS n").await;
+    assert!(cache.is_empty());
+    let expected = ["+---+", "| c |", "+---+", "| 1 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+    assert_eq!(cache.hits(), 0);
+}
+
+#[tokio::test]
+async fn invalidate_when_streamed_dml_completes() {
+    let cache = Arc::new(ResultCache::new(ResultCacheConfig::new()));
+    let ctx = context(&cache);
+    query(&ctx, "CREATE TABLE t AS VALUES (1), (2)").await;
+
+    let insert = ctx
+        .sql("INSERT INTO t VALUES (3)")
+        .await
+        .unwrap()
+        .execute_stream()
+        .await
+        .unwrap();
+
+    // a query running while the statement writes caches the old data
+    let sql = "SELECT count(*) AS c FROM t";
+    query(&ctx, sql).await;
+    assert_eq!(cache.len(), 1);
+
+    insert.try_collect::<Vec<_>>().await.unwrap();
+    assert!(cache.is_empty());
+    let expected = ["+---+", "| c |", "+---+", "| 3 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+}
+
+#[tokio::test]
+async fn invalidate_on_file_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("1.csv"), "a\n1\n2\n").unwrap();
+
+    let cache = Arc::new(ResultCache::new(ResultCacheConfig::new()));
+    let ctx = context(&cache);
+    ctx.register_csv("t", dir.path().to_str().unwrap(), CsvReadOptions::new())
+        .await
+        .unwrap();
+
+    let sql = "SELECT sum(a) AS s FROM t";
+    let expected = ["+---+", "| s |", "+---+", "| 3 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+    assert_eq!(cache.hits(), 1);
+
+    std::fs::write(dir.path().join("2.csv"), "a\n4\n").unwrap();
+    let expected = ["+---+", "| s |", "+---+", "| 7 |", "+---+"];
+    assert_batches_eq!(expected, &query(&ctx, sql).await);
+    assert_eq!(cache.hits(), 1);
+}
+
+/// The size of the results of `sql`, as accounted by the [`ResultCache`]
+async fn result_size(sql: &str) -> usize {
+    let ctx = SessionContext::new();
+    register_numbers(&ctx);
+    let batches = query(&ctx, sql).await;
+    batches.iter().map(|b| b.get_array_memory_size()).sum()
+}
+
+#[tokio::test]
+async fn size_limit_and_ttl() {
+    let sql = [
+        "SELECT n FROM t",
+        "SELECT n + 1 AS n FROM t",
+        "SELECT n * 2 AS n FROM t",
+    ];
+    let sizes = [
+        result_size(sql[0]).await,
+        result_size(sql[1]).await,
+        result_size(sql[2]).await,
+    ];
+
+    // results larger than the cache are not cached
+    let cache = Arc::new(ResultCache::new(
+        ResultCacheConfig::new().with_max_size(sizes[0] - 1),
+    ));
+    let ctx = context(&cache);
+    register_numbers(&ctx);
+    query(&ctx, sql[0]).await;
+    assert!(cache.is_empty());
+
+    // the least recently used results are evicted
+    let max_size = sizes[0] + sizes[1].max(sizes[2]);
+    let cache = Arc::new(ResultCache::new(
+        ResultCacheConfig::new().with_max_size(max_size),
+    ));
+    let ctx = context(&cache);
+    register_numbers(&ctx);
+    query(&ctx, sql[0]).await;
+    query(&ctx, sql[1]).await;
+    query(&ctx, sql[0]).await;
+    query(&ctx, sql[2]).await;
+    assert_eq!(cache.len(), 2);
+    assert!(cache.size() <= max_size);
+    query(&ctx, sql[0]).await;
+    assert_eq!(cache.hits(), 2);
+    query(&ctx, sql[1]).await;
+    assert_eq!(cache.hits(), 2);
+
+    // expired results are not reused
+    let cache = Arc::new(ResultCache::new(
+        ResultCacheConfig::new().with_ttl(Duration::from_millis(10)),
+    ));
+    let ctx = context(&cache);
+    register_numbers(&ctx);
+    query(&ctx, sql[0]).await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    query(&ctx, sql[0]).await;
+    assert_eq!((cache.len(), cache.hits()), (1, 0));
+}
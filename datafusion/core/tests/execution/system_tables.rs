@@ -0,0 +1,216 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for the runtime tables of the `information_schema`
+
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use arrow::array::{RecordBatch, StringArray};
+use datafusion::assert_batches_eq;
+use datafusion::execution::cache::cache_manager::CacheManagerConfig;
+use datafusion::execution::cache::cache_unit::DefaultListFilesCache;
+use datafusion::execution::cache::CacheAccessor;
+use datafusion::execution::memory_pool::{
+    GreedyMemoryPool, MemoryConsumer, TrackConsumersPool,
+};
+use datafusion::execution::query_registry::QueryStatus;
+use datafusion::execution::runtime_env::{RuntimeEnv, RuntimeEnvBuilder};
+use datafusion::prelude::{SessionConfig, SessionContext};
+use futures::StreamExt;
+use object_store::path::Path;
+
+fn context(runtime: Arc<RuntimeEnv>) -> SessionContext {
+    let config = SessionConfig::new().with_information_schema(true);
+    SessionContext::new_with_config_rt(config, runtime)
+}
+
+async fn query(ctx: &SessionContext, sql: &str) -> Vec<RecordBatch> {
+    ctx.sql(sql).await.unwrap().collect().await.unwrap()
+}
+
+#[tokio::test]
+async fn running_queries_and_query_history() {
+    let ctx = context(Arc::new(RuntimeEnv::default()));
+    let batch = RecordBatch::try_from_iter([(
+        "s",
+        Arc::new(StringArray::from(vec!["1", "a"])) as _,
+    )])
+    .unwrap();
+    ctx.register_batch("t", batch).unwrap();
+
+    let mut running = ctx
+        .sql("SELECT * FROM generate_series(1, 10)")
+        .await
+        .unwrap()
+        .execute_stream()
+        .await
+        .unwrap();
+    running.next().await.unwrap().unwrap();
+
+    let expected = [
+        "+--------------------------------------+-------------+",
+        "| sql                                  | output_rows |",
+        "+--------------------------------------+-------------+",
+        "| SELECT * FROM generate_series(1, 10) | 10          |",
+        "+--------------------------------------+-------------+",
+    ];
+    let batches = query(
+        &ctx,
+        "SELECT sql, output_rows FROM information_schema.running_queries \
+         WHERE sql NOT LIKE '%information_schema%'",
+    )
+    .await;
+    assert_batches_eq!(expected, &batches);
+
+    // the query completes once its stream is exhausted
+    assert!(running.next().await.is_none());
+    drop(running);
+
+    let err = ctx
+        .sql("SELECT CAST(s AS INT) FROM t")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+
+    let mut cancelled = ctx
+        .sql("SELECT * FROM generate_series(1, 10000000)")
+        .await
+        .unwrap()
+        .execute_stream()
+        .await
+        .unwrap();
+    cancelled.next().await.unwrap().unwrap();
+    drop(cancelled);
+
+    let expected = [
+        "+--------------------------------------------+-----------+-------------+",
+        "| sql                                        | status    | output_rows |",
+        "+--------------------------------------------+-----------+-------------+",
+        "| SELECT * FROM generate_series(1, 10)       | completed | 10          |",
+        "| SELECT CAST(s AS INT) FROM t               | failed    | 0           |",
+        "| SELECT * FROM generate_series(1, 10000000) | cancelled | 8192        |",
+        "+--------------------------------------------+-----------+-------------+",
+    ];
+    let batches = query(
+        &ctx,
+        "SELECT sql, status, output_rows FROM information_schema.query_history \
+         WHERE sql NOT LIKE '%information_schema%' ORDER BY query_id",
+    )
+    .await;
+    assert_batches_eq!(expected, &batches);
+
+    let registry = ctx.query_registry().unwrap();
+    assert!(registry.running().is_empty());
+    let failed = registry
+        .history()
+        .into_iter()
+        .find(|query| query.status == QueryStatus::Failed)
+        .unwrap();
+    assert_eq!(failed.error, Some(err.to_string()));
+}
+
+#[tokio::test]
+async fn dataframe_without_sql() {
+    let ctx = SessionContext::new();
+    ctx.read_empty().unwrap().collect().await.unwrap();
+
+    let history = ctx.query_registry().unwrap().history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].sql, "EmptyRelation");
+    assert_eq!(history[0].status, QueryStatus::Completed);
+}
+
+#[tokio::test]
+async fn memory_consumers() {
+    let pool = Arc::new(TrackConsumersPool::new(
+        GreedyMemoryPool::new(1024 * 1024),
+        NonZeroUsize::new(5).unwrap(),
+    ));
+    let runtime = RuntimeEnvBuilder::new()
+        .with_memory_pool(pool)
+        .build_arc()
+        .unwrap();
+    let ctx = context(Arc::clone(&runtime));
+
+    let mut reservation = MemoryConsumer::new("test consumer")
+        .with_can_spill(true)
+        .register(&runtime.memory_pool);
+    reservation.grow(100);
+
+    let expected = [
+        "+---------------+-----------+----------+",
+        "| name          | can_spill | reserved |",
+        "+---------------+-----------+----------+",
+        "| test consumer | true      | 100      |",
+        "+---------------+-----------+----------+",
+    ];
+    let batches = query(
+        &ctx,
+        "SELECT * FROM information_schema.memory_consumers \
+         WHERE name = 'test consumer'",
+    )
+    .await;
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn memory_consumers_of_untracked_pool() {
+    // the default pool does not track its consumers
+    let ctx = context(RuntimeEnvBuilder::new().build_arc().unwrap());
+
+    let err = ctx
+        .sql("SELECT * FROM information_schema.memory_consumers")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("The memory pool of the runtime does not track its consumers"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn caches() {
+    let list_files_cache = Arc::new(DefaultListFilesCache::default());
+    let cache_config = CacheManagerConfig::default()
+        .with_list_files_cache(Some(Arc::clone(&list_files_cache) as _));
+    let runtime = RuntimeEnvBuilder::new()
+        .with_cache_manager(cache_config)
+        .build_arc()
+        .unwrap();
+    let ctx = context(runtime);
+
+    list_files_cache.put(&Path::from("data/b"), Arc::new(vec![]));
+    list_files_cache.put(&Path::from("data/a"), Arc::new(vec![]));
+
+    let expected = [
+        "+------------+-----------------------+--------+",
+        "| cache_type | cache_name            | path   |",
+        "+------------+-----------------------+--------+",
+        "| list_files | DefaultListFilesCache | data/a |",
+        "| list_files | DefaultListFilesCache | data/b |",
+        "+------------+-----------------------+--------+",
+    ];
+    let batches = query(&ctx, "SELECT * FROM information_schema.caches").await;
+    assert_batches_eq!(expected, &batches);
+}
@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for the `tracing` spans emitted while planning and executing queries
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion_common::Result;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::{LookupSpan, Registry};
+
+/// The label of a span in its path: the operator for `execute` spans, the
+/// span name otherwise
+struct Label(String);
+
+impl Visit for Label {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "operator" {
+            self.0 = value.to_string();
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn Debug) {}
+}
+
+/// Records the path of every new span, e.g. `sql/create_logical_plan`
+#[derive(Debug, Clone, Default)]
+struct SpanPaths(Arc<Mutex<Vec<String>>>);
+
+impl SpanPaths {
+    fn contains(&self, path: &str) -> bool {
+        self.0.lock().unwrap().iter().any(|p| p.contains(path))
+    }
+}
+
+impl<S> Layer<S> for SpanPaths
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut label = Label(span.name().to_string());
+        attrs.record(&mut label);
+        span.extensions_mut().insert(label);
+
+        let path = span
+            .scope()
+            .from_root()
+            .map(|span| span.extensions().get::<Label>().unwrap().0.clone())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.0.lock().unwrap().push(path);
+    }
+}
+
+#[tokio::test]
+async fn planning_and_execution_spans() -> Result<()> {
+    let paths = SpanPaths::default();
+    let _guard =
+        tracing::subscriber::set_default(Registry::default().with(paths.clone()));
+
+    let config = SessionConfig::new().with_target_partitions(2);
+    let ctx = SessionContext::new_with_config(config);
+    let df = ctx
+        .sql("SELECT a, count(*) FROM (VALUES (1), (2), (1)) AS t(a) GROUP BY a")
+        .await?;
+    let plan = df.create_physical_plan().await?;
+    datafusion::physical_plan::collect(Arc::clone(&plan), ctx.task_ctx()).await?;
+    // the spans do not replace the operators of the plan
+    assert_eq!(plan.metrics().unwrap().output_rows(), Some(2));
+
+    for path in [
+        "sql/create_logical_plan/parse_sql",
+        "sql/create_logical_plan/sql_to_logical_plan",
+        "create_physical_plan/analyze_logical_plan/analyzer_rule",
+        "create_physical_plan/optimize_logical_plan/optimizer_rule",
+        "create_physical_plan/create_initial_physical_plan",
+        "create_physical_plan/optimize_physical_plan/physical_optimizer_rule",
+        // the inputs of the repartitions are executed in spawned tasks,
+        // which still run in the span of their RepartitionExec
+        "AggregateExec/CoalesceBatchesExec/RepartitionExec/RepartitionExec/AggregateExec",
+    ] {
+        assert!(
+            paths.contains(path),
+            "missing span {path} in {:#?}",
+            paths.0.lock().unwrap()
+        );
+    }
+    Ok(())
+}
@@ -33,12 +33,14 @@ use datafusion::datasource::{MemTable, TableProvider};
 use datafusion::execution::disk_manager::DiskManagerConfig;
 use datafusion::execution::runtime_env::RuntimeEnvBuilder;
 use datafusion::execution::session_state::SessionStateBuilder;
+use datafusion::physical_plan::metrics::MetricsSet;
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
 use datafusion::physical_plan::streaming::PartitionStream;
-use datafusion::physical_plan::{ExecutionPlan, SendableRecordBatchStream};
+use datafusion::physical_plan::{collect, ExecutionPlan, SendableRecordBatchStream};
 use datafusion::prelude::{SessionConfig, SessionContext};
 use datafusion_catalog::streaming::StreamingTable;
 use datafusion_catalog::Session;
+use datafusion_common::config::SpillCompression;
 use datafusion_common::{assert_contains, Result};
 use datafusion_execution::memory_pool::{
     GreedyMemoryPool, MemoryPool, TrackConsumersPool,
@@ -403,6 +405,144 @@ async fn oom_with_tracked_consumer_pool() {
         .await
 }
 
+#[tokio::test]
+async fn oom_query_memory_limit() {
+    TestCase::new()
+        .with_query("select count(*) from t GROUP BY response_bytes")
+        .with_expected_errors(vec![
+            "Failed to allocate additional",
+            "remain available for query",
+            "(limit 2000 bytes)",
+        ])
+        .with_config(SessionConfig::new().with_query_memory_limit(2_000))
+        .with_memory_limit(100_000_000)
+        .run()
+        .await
+}
+
+#[tokio::test]
+async fn set_query_memory_limit() {
+    let runtime = RuntimeEnvBuilder::new()
+        .with_memory_limit(100_000_000, 1.0)
+        .build_arc()
+        .unwrap();
+    let config = SessionConfig::new().with_information_schema(true);
+    let ctx = SessionContext::new_with_config_rt(config, runtime);
+    ctx.register_table("t", Scenario::AccessLog.table())
+        .unwrap();
+    let query = "select count(*) from t GROUP BY response_bytes";
+
+    ctx.sql("SET datafusion.runtime.memory_limit = '2K'")
+        .await
+        .unwrap();
+    let show = ctx
+        .sql("SHOW datafusion.runtime.memory_limit")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_batches_eq!(
+        [
+            "+---------------------------------+-------+",
+            "| name                            | value |",
+            "+---------------------------------+-------+",
+            "| datafusion.runtime.memory_limit | 2K    |",
+            "+---------------------------------+-------+",
+        ],
+        &show
+    );
+    let err = ctx.sql(query).await.unwrap().collect().await.unwrap_err();
+    assert_contains!(err.to_string(), "remain available for query");
+    assert_contains!(err.to_string(), "(limit 2048 bytes)");
+
+    // the limit only applies to queries of this session
+    let other = SessionContext::new_with_state(
+        SessionStateBuilder::new()
+            .with_runtime_env(ctx.runtime_env())
+            .with_default_features()
+            .build(),
+    );
+    other
+        .register_table("t", Scenario::AccessLog.table())
+        .unwrap();
+    other.sql(query).await.unwrap().collect().await.unwrap();
+
+    ctx.sql("SET datafusion.runtime.memory_limit = '0'")
+        .await
+        .unwrap();
+    ctx.sql(query).await.unwrap().collect().await.unwrap();
+
+    let err = ctx
+        .sql("SET datafusion.runtime.memory_limit = 'lots'")
+        .await
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Invalid value 'lots' for datafusion.runtime.memory_limit"
+    );
+}
+
+#[tokio::test]
+async fn sort_spill_compression() {
+    let uncompressed = run_spilling_sort(SpillCompression::Uncompressed, u64::MAX)
+        .await
+        .unwrap();
+    let spilled_bytes = uncompressed.spilled_bytes().unwrap();
+    let uncompressed_disk_bytes = uncompressed.spilled_disk_bytes().unwrap();
+    assert!(spilled_bytes > 0);
+    assert!(uncompressed_disk_bytes > 0);
+
+    for compression in [SpillCompression::Lz4Frame, SpillCompression::Zstd] {
+        let compressed = run_spilling_sort(compression, u64::MAX).await.unwrap();
+        assert_eq!(compressed.spilled_bytes().unwrap(), spilled_bytes);
+        let disk_bytes = compressed.spilled_disk_bytes().unwrap();
+        assert!(
+            disk_bytes < uncompressed_disk_bytes,
+            "{compression}: {disk_bytes} >= {uncompressed_disk_bytes}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn sort_spill_exceeds_max_temp_directory_size() {
+    let err = run_spilling_sort(SpillCompression::Uncompressed, 1024)
+        .await
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "The temporary files of the DiskManager use"
+    );
+    assert_contains!(err.to_string(), "which exceeds the limit of 1024.0 B");
+}
+
+/// Runs a sort that spills with the given spill file compression and
+/// limit on the size of the spill files, returning the metrics of the sort
+async fn run_spilling_sort(
+    compression: SpillCompression,
+    max_temp_directory_size: u64,
+) -> Result<MetricsSet> {
+    let mut config = SessionConfig::new()
+        .with_target_partitions(1)
+        .with_sort_spill_reservation_bytes(1536 * 1024);
+    config.options_mut().execution.spill_compression = compression;
+    let runtime = RuntimeEnvBuilder::new()
+        .with_memory_limit(2048 * 1024, 1.0)
+        .with_disk_manager(DiskManagerConfig::NewOs)
+        .with_max_temp_directory_size(max_temp_directory_size)
+        .build_arc()?;
+    let ctx = SessionContext::new_with_config_rt(config, runtime);
+
+    let plan = ctx
+        .sql("SELECT value FROM generate_series(1, 100000) ORDER BY value DESC")
+        .await?
+        .create_physical_plan()
+        .await?;
+    assert_eq!(plan.name(), "SortExec");
+    collect(Arc::clone(&plan), ctx.task_ctx()).await?;
+    Ok(plan.metrics().unwrap())
+}
+
 /// Run the query with the specified memory limit,
 /// and verifies the expected errors are returned
 #[derive(Clone, Debug)]
@@ -67,6 +67,7 @@ async fn get_parquet_exec(state: &SessionState, filter: Expr) -> DataSourceExec
         range: None,
         statistics: None,
         extensions: None,
+        deletes: None,
         metadata_size_hint: None,
     };
 
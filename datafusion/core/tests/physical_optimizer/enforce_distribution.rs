@@ -186,6 +186,32 @@ fn parquet_exec_multiple_sorted(
     .build()
 }
 
+/// Created a parquet exec whose files are hash partitioned by `a` into
+/// `num_groups` groups, as for the scan of a bucketed table
+fn parquet_exec_hash_partitioned(num_groups: usize) -> Arc<DataSourceExec> {
+    parquet_exec_hash_partitioned_on(vec![0], num_groups)
+}
+
+/// Created a parquet exec whose files are hash partitioned by the columns at
+/// `columns` into `num_groups` groups
+fn parquet_exec_hash_partitioned_on(
+    columns: Vec<usize>,
+    num_groups: usize,
+) -> Arc<DataSourceExec> {
+    FileScanConfig::new(
+        ObjectStoreUrl::parse("test:///").unwrap(),
+        schema(),
+        Arc::new(ParquetSource::default()),
+    )
+    .with_file_groups(
+        (0..num_groups)
+            .map(|i| vec![PartitionedFile::new(format!("x{i}"), 100)])
+            .collect(),
+    )
+    .with_hash_partition_cols(columns)
+    .build()
+}
+
 fn csv_exec() -> Arc<DataSourceExec> {
     csv_exec_with_sort(vec![])
 }
@@ -3154,3 +3180,91 @@ fn optimize_away_unnecessary_repartition2() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn hash_partitioned_inputs_with_different_partition_counts() -> Result<()> {
+    let left = parquet_exec_hash_partitioned(4);
+    let right = parquet_exec_hash_partitioned(2);
+    let join_on = vec![(
+        Arc::new(Column::new_with_schema("a", &left.schema()).unwrap()) as _,
+        Arc::new(Column::new_with_schema("a", &right.schema()).unwrap()) as _,
+    )];
+
+    // The inputs are already hash partitioned by the join key, but partition
+    // `i` of each input must contain the same hash values. The input that does
+    // not have the target number of partitions is repartitioned with the hash
+    // function of `RepartitionExec`, so the other input is repartitioned too
+    let join = hash_join_exec(left.clone(), right, &join_on, &JoinType::Inner);
+    let expected = &[
+        "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+        "RepartitionExec: partitioning=Hash([a@0], 2), input_partitions=4",
+        "DataSourceExec: file_groups={4 groups: [[x0], [x1], [x2], [x3]]}, projection=[a, b, c, d, e], file_type=parquet",
+        "RepartitionExec: partitioning=Hash([a@0], 2), input_partitions=2",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+    ];
+    assert_optimized!(expected, join.clone(), true, false, 2, false, 1024);
+    assert_optimized!(expected, join, false, false, 2, false, 1024);
+
+    // Inputs with the same number of partitions are not repartitioned
+    let join = hash_join_exec(left.clone(), left, &join_on, &JoinType::Inner);
+    let expected = &[
+        "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+        "DataSourceExec: file_groups={4 groups: [[x0], [x1], [x2], [x3]]}, projection=[a, b, c, d, e], file_type=parquet",
+        "DataSourceExec: file_groups={4 groups: [[x0], [x1], [x2], [x3]]}, projection=[a, b, c, d, e], file_type=parquet",
+    ];
+    assert_optimized!(expected, join.clone(), true, false, 2, false, 1024);
+    assert_optimized!(expected, join, false, false, 2, false, 1024);
+
+    Ok(())
+}
+
+#[test]
+fn hash_partitioned_inputs_on_other_columns() -> Result<()> {
+    let schema = schema();
+    let a = || Arc::new(Column::new_with_schema("a", &schema).unwrap()) as _;
+    let b = || Arc::new(Column::new_with_schema("b", &schema).unwrap()) as _;
+
+    // An input hash partitioned by a data source on a column that is not a
+    // join key is repartitioned, and so is the other input, even though it is
+    // hash partitioned by a data source on the join key
+    let left = parquet_exec_hash_partitioned_on(vec![1], 2);
+    let right = parquet_exec_hash_partitioned(2);
+    let join = hash_join_exec(left, right, &vec![(a(), a())], &JoinType::Inner);
+    let expected = &[
+        "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+        "RepartitionExec: partitioning=Hash([a@0], 2), input_partitions=2",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+        "RepartitionExec: partitioning=Hash([a@0], 2), input_partitions=2",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+    ];
+    assert_optimized!(expected, join.clone(), true, false, 2, false, 1024);
+    assert_optimized!(expected, join, false, false, 2, false, 1024);
+
+    // Inputs hash partitioned by a data source on the join keys in another
+    // order hash the keys in another order, so both inputs are repartitioned
+    let left = parquet_exec_hash_partitioned_on(vec![0, 1], 2);
+    let right = parquet_exec_hash_partitioned_on(vec![1, 0], 2);
+    let join_on = vec![(a(), a()), (b(), b())];
+    let join = hash_join_exec(left.clone(), right, &join_on, &JoinType::Inner);
+    let expected = &[
+        "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0), (b@1, b@1)]",
+        "RepartitionExec: partitioning=Hash([a@0, b@1], 2), input_partitions=2",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+        "RepartitionExec: partitioning=Hash([a@0, b@1], 2), input_partitions=2",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+    ];
+    assert_optimized!(expected, join.clone(), true, false, 2, false, 1024);
+    assert_optimized!(expected, join, false, false, 2, false, 1024);
+
+    // but not if both are partitioned on the join keys in the same order
+    let join = hash_join_exec(left.clone(), left, &join_on, &JoinType::Inner);
+    let expected = &[
+        "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0), (b@1, b@1)]",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+        "DataSourceExec: file_groups={2 groups: [[x0], [x1]]}, projection=[a, b, c, d, e], file_type=parquet",
+    ];
+    assert_optimized!(expected, join.clone(), true, false, 2, false, 1024);
+    assert_optimized!(expected, join, false, false, 2, false, 1024);
+
+    Ok(())
+}
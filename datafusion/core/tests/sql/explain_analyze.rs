@@ -19,6 +19,7 @@ use super::*;
 use rstest::rstest;
 
 use datafusion::config::ConfigOptions;
+use datafusion::execution::runtime_env::RuntimeEnvBuilder;
 use datafusion::physical_plan::display::DisplayableExecutionPlan;
 use datafusion::physical_plan::metrics::Timestamp;
 
@@ -806,6 +807,56 @@ async fn explain_physical_plan_only() {
     assert_eq!(expected, actual);
 }
 
+#[tokio::test]
+async fn explain_analyze_format_tree() {
+    let ctx = SessionContext::new();
+    let sql = "EXPLAIN ANALYZE FORMAT TREE \
+               SELECT count(*) FROM (VALUES (1), (2), (3)) AS t(a) WHERE a > 1";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+
+    assert_contains!(&formatted, "Plan with Metrics");
+    assert_contains!(&formatted, "│         FilterExec        │");
+    assert_contains!(&formatted, "│       output_rows=2       │");
+    assert_contains!(&formatted, "└─────────────┬─────────────┘");
+}
+
+#[tokio::test]
+async fn explain_analyze_format_json() {
+    let ctx = SessionContext::new();
+    let sql = "EXPLAIN ANALYZE FORMAT JSON \
+               SELECT count(*) FROM (VALUES (1), (2), (3)) AS t(a) WHERE a > 1";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let plan = actual[0].column(1).as_string::<i32>().value(0).to_string();
+    let plan: serde_json::Value = serde_json::from_str(&plan).unwrap();
+
+    /// Returns the first node named `name` in the plan
+    fn find<'a>(
+        node: &'a serde_json::Value,
+        name: &str,
+    ) -> Option<&'a serde_json::Value> {
+        if node["Node Type"] == name {
+            return Some(node);
+        }
+        node["Plans"]
+            .as_array()?
+            .iter()
+            .find_map(|child| find(child, name))
+    }
+
+    let filter = find(&plan[0]["Plan"], "FilterExec").expect("FilterExec in plan");
+    assert_eq!(filter["Details"], "column1@0 > 1");
+    let output_rows = filter["Metrics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|metric| metric["Name"] == "output_rows")
+        .unwrap();
+    assert_eq!(output_rows["Value"], 2);
+}
+
 #[tokio::test]
 async fn csv_explain_analyze_with_statistics() {
     let mut config = ConfigOptions::new();
@@ -826,3 +877,57 @@ async fn csv_explain_analyze_with_statistics() {
         ", statistics=[Rows=Absent, Bytes=Absent, [(Col[0]:)]]"
     );
 }
+
+#[tokio::test]
+async fn explain_analyze_join_and_window_peak_memory() {
+    let ctx =
+        SessionContext::new_with_config(SessionConfig::new().with_target_partitions(1));
+    let sql = "EXPLAIN ANALYZE SELECT t1.a, row_number() OVER (ORDER BY t1.a) \
+               FROM (VALUES (1), (2)) AS t1(a) JOIN (VALUES (1), (2)) AS t2(b) ON t1.a = t2.b";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+
+    for operator in ["HashJoinExec", "BoundedWindowAggExec"] {
+        let line = formatted
+            .lines()
+            .find(|line| line.contains(operator))
+            .unwrap_or_else(|| panic!("{operator} in {formatted}"));
+        assert_contains!(line, "peak_mem_used=");
+    }
+}
+
+#[tokio::test]
+async fn explain_analyze_verbose_memory_summary() {
+    let config = SessionConfig::new()
+        .with_target_partitions(1)
+        .with_sort_spill_reservation_bytes(1536 * 1024);
+    let runtime = RuntimeEnvBuilder::new()
+        .with_memory_limit(2048 * 1024, 1.0)
+        .build_arc()
+        .unwrap();
+    let ctx = SessionContext::new_with_config_rt(config, runtime);
+
+    let sql = "EXPLAIN ANALYZE VERBOSE SELECT value FROM generate_series(1, 100000) ORDER BY value DESC";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+
+    // the sort reports its peak reservation and spills
+    assert_contains!(&formatted, "peak_mem_used=");
+    assert_contains!(&formatted, "Memory Summary");
+    assert_contains!(&formatted, "top_consumers=[ExternalSorterMerge[0]=");
+    assert_contains!(&formatted, "ExternalSorter[0]=");
+    assert_not_contains!(&formatted, "spill_count=0");
+
+    // the summary is only shown in verbose mode
+    let sql =
+        "EXPLAIN ANALYZE SELECT value FROM generate_series(1, 10) ORDER BY value DESC";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+    assert_not_contains!(&formatted, "Memory Summary");
+}
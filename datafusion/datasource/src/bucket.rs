@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bucketed (hash clustered) layout of the files of a table
+
+use std::sync::Arc;
+
+use crate::PartitionedFile;
+use arrow::array::{AsArray, Int64Array, RecordBatch};
+use arrow::datatypes::Int32Type;
+use datafusion_common::{plan_err, Result};
+use datafusion_functions::hash::bucket::bucket;
+use object_store::path::Path;
+
+/// Marks the bucket of a file in its name, see [`BucketSpec::file_name`]
+const BUCKET_MARKER: &str = "_bucket-";
+
+/// The hash function that assigns rows to buckets, recorded in the name of
+/// every bucket file
+pub const BUCKET_HASH_FUNCTION: &str = "murmur3";
+
+/// The version of the bucket assignment of [`BUCKET_HASH_FUNCTION`],
+/// recorded in the name of every bucket file. It must be increased if the
+/// bucket of any value changes.
+pub const BUCKET_HASH_VERSION: u32 = 1;
+
+/// Layout of a table whose rows are clustered into a fixed number of buckets
+/// by the hash of the bucket columns, as declared by
+/// `CLUSTERED BY (<columns>) INTO <num_buckets> BUCKETS`.
+///
+/// The bucket of a row is computed like the Spark compatible `bucket()`
+/// function, that is the Spark Murmur3 hash of its bucket columns modulo
+/// `num_buckets`, which is stable across versions of DataFusion (see
+/// [`Self::buckets`]). Each file of the table only contains rows of a single
+/// bucket, identified by the file name along with the hash function and its
+/// version (see [`Self::file_name`]).
+///
+/// This allows a scan to read the files of each bucket in the same
+/// partition, and report `Partitioning::Hash` over the bucket columns, so
+/// that aggregations on the bucket columns, and joins between tables
+/// bucketed on the join columns, do not need to repartition the rows of the
+/// table (see [`Self::group_files`]). As the bucket hash differs from the
+/// hash of a `RepartitionExec`, `EnforceDistribution` repartitions the scan
+/// when it is joined with an input that is hash partitioned otherwise.
+///
+/// Files whose name does not record the current [`BUCKET_HASH_FUNCTION`] and
+/// [`BUCKET_HASH_VERSION`], such as the files written by an older version,
+/// are still read, but the scan then does not report a hash partitioning.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BucketSpec {
+    /// The columns whose values determine the bucket of a row
+    pub columns: Vec<String>,
+    /// The number of buckets
+    pub num_buckets: usize,
+}
+
+impl BucketSpec {
+    /// Create a new [`BucketSpec`], checking that there is at least one
+    /// column, and that the number of buckets is positive and fits in an
+    /// `Int32` as for `bucket()`
+    pub fn try_new(columns: Vec<String>, num_buckets: usize) -> Result<Self> {
+        if columns.is_empty() {
+            return plan_err!("CLUSTERED BY requires at least one column");
+        }
+        if num_buckets == 0 {
+            return plan_err!("The number of buckets must be greater than zero");
+        }
+        if num_buckets > i32::MAX as usize {
+            return plan_err!("The number of buckets must be at most {}", i32::MAX);
+        }
+        Ok(Self {
+            columns,
+            num_buckets,
+        })
+    }
+
+    /// The bucket of each row of `batch`, which must contain the bucket
+    /// columns
+    pub fn buckets(&self, batch: &RecordBatch) -> Result<Vec<usize>> {
+        let num_buckets =
+            Int64Array::from_value(self.num_buckets as i64, batch.num_rows());
+        let mut args = vec![Arc::new(num_buckets) as _];
+        for name in &self.columns {
+            let index = batch.schema().index_of(name)?;
+            args.push(Arc::clone(batch.column(index)));
+        }
+        let buckets = bucket(&args)?;
+        Ok(buckets
+            .as_primitive::<Int32Type>()
+            .values()
+            .iter()
+            .map(|bucket| *bucket as usize)
+            .collect())
+    }
+
+    /// The name of the file of bucket `bucket` written by the write
+    /// `write_id`, such as `<write_id>_bucket-murmur3v1-00003.parquet`,
+    /// which records the [`BUCKET_HASH_FUNCTION`] and [`BUCKET_HASH_VERSION`]
+    pub fn file_name(write_id: &str, bucket: usize, file_extension: &str) -> String {
+        format!(
+            "{write_id}{BUCKET_MARKER}{BUCKET_HASH_FUNCTION}v{BUCKET_HASH_VERSION}-{bucket:05}.{file_extension}"
+        )
+    }
+
+    /// The bucket of the file at `path`, or `None` if the file name does not
+    /// identify a bucket of this [`BucketSpec`] computed with the current
+    /// [`BUCKET_HASH_FUNCTION`] and [`BUCKET_HASH_VERSION`]
+    pub fn bucket_of(&self, path: &Path) -> Option<usize> {
+        let (_, suffix) = path.filename()?.rsplit_once(BUCKET_MARKER)?;
+        let (hash, bucket) = suffix.split('.').next()?.split_once('-')?;
+        if hash != format!("{BUCKET_HASH_FUNCTION}v{BUCKET_HASH_VERSION}") {
+            return None;
+        }
+        let bucket = bucket.parse::<usize>().ok()?;
+        (bucket < self.num_buckets).then_some(bucket)
+    }
+
+    /// Group `files` into `num_partitions` groups, such that group `i`
+    /// contains the files of the buckets `b` with `b % num_partitions == i`.
+    ///
+    /// As the bucket of a row is its hash modulo `num_buckets`, group `i`
+    /// then contains the rows whose hash modulo `num_partitions` is `i`, so
+    /// that the groups of tables with the same bucket columns contain the
+    /// same values.
+    ///
+    /// Returns `None` if `num_partitions` does not divide `num_buckets`, or
+    /// if any of the files is not a bucket file of the current hash function
+    /// and version.
+    pub fn group_files(
+        &self,
+        files: impl IntoIterator<Item = PartitionedFile>,
+        num_partitions: usize,
+    ) -> Option<Vec<Vec<PartitionedFile>>> {
+        if num_partitions == 0 || self.num_buckets % num_partitions != 0 {
+            return None;
+        }
+        let mut groups = vec![vec![]; num_partitions];
+        for file in files {
+            let bucket = self.bucket_of(&file.object_meta.location)?;
+            groups[bucket % num_partitions].push(file);
+        }
+        Some(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, StringArray};
+
+    #[test]
+    fn bucket_of_file() {
+        let spec = BucketSpec::try_new(vec!["a".to_string()], 8).unwrap();
+        let name = BucketSpec::file_name("abc", 3, "parquet");
+        assert_eq!(name, "abc_bucket-murmur3v1-00003.parquet");
+        assert_eq!(spec.bucket_of(&Path::from(format!("t/{name}"))), Some(3));
+        assert_eq!(
+            spec.bucket_of(&Path::from("t/abc_bucket-murmur3v1-00003.csv.gz")),
+            Some(3)
+        );
+        assert_eq!(
+            spec.bucket_of(&Path::from("t/abc_bucket-murmur3v1-00008.parquet")),
+            None
+        );
+        assert_eq!(spec.bucket_of(&Path::from("t/abc_0.parquet")), None);
+        // files of another hash function or version are not bucket files
+        assert_eq!(
+            spec.bucket_of(&Path::from("t/abc_bucket-00003.parquet")),
+            None
+        );
+        assert_eq!(
+            spec.bucket_of(&Path::from("t/abc_bucket-murmur3v2-00003.parquet")),
+            None
+        );
+
+        assert!(BucketSpec::try_new(vec![], 8).is_err());
+        assert!(BucketSpec::try_new(vec!["a".to_string()], 0).is_err());
+        assert!(BucketSpec::try_new(vec!["a".to_string()], 1 << 31).is_err());
+    }
+
+    #[test]
+    fn buckets_of_known_values() {
+        // the buckets are persisted in the files of bucketed tables, so they
+        // must never change, and match the buckets of Apache Spark
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), None])) as _,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![Some("a"), Some("bb"), None])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let spec = BucketSpec::try_new(vec!["a".to_string()], 4).unwrap();
+        assert_eq!(spec.buckets(&batch).unwrap(), vec![3, 2, 2]);
+        let spec = BucketSpec::try_new(vec!["b".to_string()], 16).unwrap();
+        assert_eq!(spec.buckets(&batch).unwrap(), vec![2, 13, 10]);
+        let spec =
+            BucketSpec::try_new(vec!["a".to_string(), "b".to_string()], 8).unwrap();
+        assert_eq!(spec.buckets(&batch).unwrap(), vec![5, 1, 2]);
+
+        let spec = BucketSpec::try_new(vec!["c".to_string()], 16).unwrap();
+        assert!(spec.buckets(&batch).is_err());
+    }
+
+    #[test]
+    fn group_files() {
+        let spec = BucketSpec::try_new(vec!["a".to_string()], 4).unwrap();
+        let files = (0..4)
+            .map(|b| PartitionedFile::new(BucketSpec::file_name("x", b, "csv"), 10))
+            .collect::<Vec<_>>();
+
+        let groups = spec.group_files(files.clone(), 2).unwrap();
+        let names = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|file| spec.bucket_of(&file.object_meta.location).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![vec![0, 2], vec![1, 3]]);
+
+        assert!(spec.group_files(files.clone(), 3).is_none());
+        let other = PartitionedFile::new("x_0.csv", 10);
+        assert!(spec
+            .group_files(files.into_iter().chain([other]), 4)
+            .is_none());
+    }
+}
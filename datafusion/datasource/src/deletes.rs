@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Rows deleted from a file without rewriting it, see [`FileDeletes`]
+
+use arrow::array::RecordBatch;
+
+/// Rows of a [`PartitionedFile`] that are deleted, and are not returned when
+/// the file is scanned. This allows tables to delete rows without rewriting
+/// their files, such as with the delete files of lakehouse table formats.
+///
+/// Rows are deleted either
+///
+/// * by position: the rows at the given positions of the file, starting from
+///   0, are deleted. These are skipped without decoding them where possible.
+/// * by equality: the rows whose values of the columns of a [`RecordBatch`]
+///   of equality deletes are equal to the values of any row of the batch
+///   are deleted, like an anti join of the file with the batch. Null values
+///   are equal to each other, and the columns are matched to the columns of
+///   the file by name, where columns missing from the file are null.
+///
+/// Not all file formats support deletes: scanning files with deletes of a
+/// format that doesn't returns an error (see [`FileSource::supports_deletes`]).
+///
+/// [`PartitionedFile`]: crate::PartitionedFile
+/// [`FileSource::supports_deletes`]: crate::file::FileSource::supports_deletes
+#[derive(Debug, Clone, Default)]
+pub struct FileDeletes {
+    /// The positions of the deleted rows, sorted and without duplicates
+    positions: Vec<u64>,
+    /// Each batch deletes the rows equal to one of its rows
+    equality_deletes: Vec<RecordBatch>,
+}
+
+impl FileDeletes {
+    /// Create a new [`FileDeletes`] that deletes no rows
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deletes the rows at `positions` of the file, in any order
+    pub fn with_positions(mut self, positions: impl IntoIterator<Item = u64>) -> Self {
+        self.positions.extend(positions);
+        self.positions.sort_unstable();
+        self.positions.dedup();
+        self
+    }
+
+    /// Deletes the rows whose values of the columns of `rows` are equal to
+    /// the values of any row of `rows`
+    pub fn with_equality_deletes(mut self, rows: RecordBatch) -> Self {
+        if rows.num_rows() > 0 {
+            self.equality_deletes.push(rows);
+        }
+        self
+    }
+
+    /// The positions of the deleted rows, in increasing order
+    pub fn positions(&self) -> &[u64] {
+        &self.positions
+    }
+
+    /// The batches of equality deletes
+    pub fn equality_deletes(&self) -> &[RecordBatch] {
+        &self.equality_deletes
+    }
+
+    /// Returns true if no rows are deleted
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty() && self.equality_deletes.is_empty()
+    }
+}
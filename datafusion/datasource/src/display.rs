@@ -289,6 +289,7 @@ mod tests {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         }
     }
@@ -65,6 +65,15 @@ pub trait FileSource: Send + Sync {
         Ok(())
     }
 
+    /// Returns true if the [`FileOpener`]s of this [`FileSource`] skip the
+    /// deleted rows of files with [`FileDeletes`]. Scanning such files with
+    /// a source that does not returns an error.
+    ///
+    /// [`FileDeletes`]: crate::deletes::FileDeletes
+    fn supports_deletes(&self) -> bool {
+        false
+    }
+
     /// If supported by the [`FileSource`], redistribute files across partitions according to their size.
     /// Allows custom file formats to implement their own repartitioning logic.
     ///
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use object_store::{path::Path, ObjectMeta};
 
+use crate::deletes::FileDeletes;
 use crate::FileRange;
 
 /// A single file or part of a file that should be read, along with its schema, statistics
@@ -29,6 +30,8 @@ pub struct FileMeta {
     pub range: Option<FileRange>,
     /// An optional field for user defined per object metadata
     pub extensions: Option<Arc<dyn std::any::Any + Send + Sync>>,
+    /// Optional rows of the file that are deleted, and must not be read
+    pub deletes: Option<Arc<FileDeletes>>,
     /// Size hint for the metadata of this file
     pub metadata_size_hint: Option<usize>,
 }
@@ -46,6 +49,7 @@ impl From<ObjectMeta> for FileMeta {
             object_meta,
             range: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         }
     }
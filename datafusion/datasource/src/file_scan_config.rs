@@ -32,14 +32,15 @@ use arrow::{
     datatypes::{ArrowNativeType, DataType, Field, Schema, SchemaRef, UInt16Type},
 };
 use datafusion_common::{
-    exec_err, stats::Precision, ColumnStatistics, Constraints, Result, Statistics,
+    exec_err, not_impl_err, stats::Precision, ColumnStatistics, Constraints, Result,
+    Statistics,
 };
 use datafusion_common::{DataFusionError, ScalarValue};
 use datafusion_execution::{
     object_store::ObjectStoreUrl, SendableRecordBatchStream, TaskContext,
 };
 use datafusion_physical_expr::{
-    expressions::Column, EquivalenceProperties, LexOrdering, Partitioning,
+    expressions::Column, EquivalenceProperties, LexOrdering, Partitioning, PhysicalExpr,
     PhysicalSortExpr,
 };
 use datafusion_physical_plan::{
@@ -167,6 +168,13 @@ pub struct FileScanConfig {
     pub new_lines_in_values: bool,
     /// File source such as `ParquetSource`, `CsvSource`, `JsonSource`, etc.
     pub source: Arc<dyn FileSource>,
+    /// Columns by which the `file_groups` are hash partitioned, as indexes
+    /// into the table schema like `projection`: group `i` contains the rows
+    /// whose hash modulo the number of groups is `i`, as for
+    /// [`Partitioning::Hash`]. Empty if the groups are not hash partitioned.
+    ///
+    /// See [`BucketSpec::group_files`](crate::bucket::BucketSpec::group_files)
+    pub hash_partition_cols: Vec<usize>,
 }
 
 impl DataSource for FileScanConfig {
@@ -175,6 +183,13 @@ impl DataSource for FileScanConfig {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
+        if !self.source.supports_deletes() && self.has_deletes() {
+            return not_impl_err!(
+                "Scanning {} files with deleted rows is not supported",
+                self.source.file_type()
+            );
+        }
+
         let object_store = context.runtime_env().object_store(&self.object_store_url)?;
 
         let source = self
@@ -217,12 +232,17 @@ impl DataSource for FileScanConfig {
     }
 
     /// If supported by the underlying [`FileSource`], redistribute files across partitions according to their size.
+    ///
+    /// Files that are hash partitioned are never redistributed.
     fn repartitioned(
         &self,
         target_partitions: usize,
         repartition_file_min_size: usize,
         output_ordering: Option<LexOrdering>,
     ) -> Result<Option<Arc<dyn DataSource>>> {
+        if !self.hash_partition_cols.is_empty() {
+            return Ok(None);
+        }
         let source = self.source.repartitioned(
             target_partitions,
             repartition_file_min_size,
@@ -234,7 +254,10 @@ impl DataSource for FileScanConfig {
     }
 
     fn output_partitioning(&self) -> Partitioning {
-        Partitioning::UnknownPartitioning(self.file_groups.len())
+        let partition_count = self.file_groups.len();
+        self.projected_hash_partition_exprs()
+            .map(|exprs| Partitioning::Hash(exprs, partition_count))
+            .unwrap_or(Partitioning::UnknownPartitioning(partition_count))
     }
 
     fn eq_properties(&self) -> EquivalenceProperties {
@@ -316,6 +339,7 @@ impl FileScanConfig {
             file_compression_type: FileCompressionType::UNCOMPRESSED,
             new_lines_in_values: false,
             source: Arc::clone(&file_source),
+            hash_partition_cols: vec![],
         };
 
         config = config.with_source(Arc::clone(&file_source));
@@ -405,6 +429,13 @@ impl FileScanConfig {
         self
     }
 
+    /// Set the columns by which the file groups are hash partitioned, see
+    /// [`Self::hash_partition_cols`]
+    pub fn with_hash_partition_cols(mut self, hash_partition_cols: Vec<usize>) -> Self {
+        self.hash_partition_cols = hash_partition_cols;
+        self
+    }
+
     /// Set the new_lines_in_values property
     pub fn with_newlines_in_values(mut self, new_lines_in_values: bool) -> Self {
         self.new_lines_in_values = new_lines_in_values;
@@ -422,13 +453,49 @@ impl FileScanConfig {
         self.new_lines_in_values
     }
 
+    /// The expressions of [`Self::hash_partition_cols`] in the projected
+    /// schema, or `None` if the files are not hash partitioned, or any of the
+    /// columns is not projected
+    fn projected_hash_partition_exprs(&self) -> Option<Vec<Arc<dyn PhysicalExpr>>> {
+        if self.hash_partition_cols.is_empty() {
+            return None;
+        }
+        let (schema, ..) = self.project();
+        self.hash_partition_cols
+            .iter()
+            .map(|col| {
+                let idx = match &self.projection {
+                    Some(projection) => projection.iter().position(|p| p == col)?,
+                    None => *col,
+                };
+                let name = schema.fields().get(idx)?.name();
+                Some(Arc::new(Column::new(name, idx)) as _)
+            })
+            .collect()
+    }
+
+    /// Returns true if any of the files has deleted rows
+    fn has_deletes(&self) -> bool {
+        self.file_groups
+            .iter()
+            .flatten()
+            .any(|file| file.deletes.is_some())
+    }
+
     /// Project the schema, constraints, and the statistics on the given column indices
     pub fn project(&self) -> (SchemaRef, Constraints, Statistics, Vec<LexOrdering>) {
+        // the statistics of files are computed without their deleted rows
+        let statistics = if self.has_deletes() {
+            self.statistics.clone().to_inexact()
+        } else {
+            self.statistics.clone()
+        };
+
         if self.projection.is_none() && self.table_partition_cols.is_empty() {
             return (
                 Arc::clone(&self.file_schema),
                 self.constraints.clone(),
-                self.statistics.clone(),
+                statistics,
                 self.output_ordering.clone(),
             );
         }
@@ -446,7 +513,7 @@ impl FileScanConfig {
             if *idx < self.file_schema.fields().len() {
                 let field = self.file_schema.field(*idx);
                 table_fields.push(field.clone());
-                table_cols_stats.push(self.statistics.column_statistics[*idx].clone())
+                table_cols_stats.push(statistics.column_statistics[*idx].clone())
             } else {
                 let partition_idx = idx - self.file_schema.fields().len();
                 table_fields.push(self.table_partition_cols[partition_idx].to_owned());
@@ -456,7 +523,7 @@ impl FileScanConfig {
         }
 
         let table_stats = Statistics {
-            num_rows: self.statistics.num_rows,
+            num_rows: statistics.num_rows,
             // TODO correct byte size?
             total_byte_size: Precision::Absent,
             column_statistics: table_cols_stats,
@@ -1661,6 +1728,7 @@ mod tests {
                             .collect::<Vec<_>>(),
                     }),
                     extensions: None,
+                    deletes: None,
                     metadata_size_hint: None,
                 }
             }
@@ -15,11 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::bucket::BucketSpec;
 use crate::write::demux::{start_demuxer_task, DemuxedStreamReceiver};
+use crate::write::orchestration::StagedFiles;
+use crate::write::rolling::RollingPolicy;
 use crate::{ListingTableUrl, PartitionedFile};
 use arrow::datatypes::{DataType, SchemaRef};
 use async_trait::async_trait;
-use datafusion_common::Result;
+use datafusion_common::{DataFusionError, Result};
 use datafusion_common_runtime::SpawnedTask;
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
@@ -63,23 +66,82 @@ pub trait FileSink: DataSink {
     ) -> Result<u64>;
 
     /// File sink implementation of the [`DataSink::write_all`] method.
+    ///
+    /// Files are written at staging paths, and only committed to their
+    /// final paths once all of them have been written successfully. For
+    /// [`InsertOp::Overwrite`], the files of [`FileSinkConfig::file_groups`]
+    /// are then deleted. See [`StagedFiles`] for details.
+    ///
+    /// If [`FileSinkConfig::rolling_policy`] is set, each output file is
+    /// instead committed on its own as soon as the policy rolls it, without
+    /// waiting for the end of the input.
     async fn write_all(
         &self,
         data: SendableRecordBatchStream,
         context: &Arc<TaskContext>,
     ) -> Result<u64> {
-        let config = self.config();
-        let object_store = context
-            .runtime_env()
-            .object_store(&config.object_store_url)?;
-        let (demux_task, file_stream_rx) = start_demuxer_task(config, data, context);
-        self.spawn_writer_tasks_and_join(
-            context,
-            demux_task,
-            file_stream_rx,
-            object_store,
-        )
+        let Some(policy) = self.config().rolling_policy else {
+            return write_and_commit(self, data, context).await;
+        };
+
+        let buffer_size = context
+            .session_config()
+            .options()
+            .execution
+            .max_buffered_batches_per_output_file;
+        policy
+            .write(data, self.config(), buffer_size, |segment| {
+                write_and_commit(self, segment, context)
+            })
+            .await
+    }
+}
+
+/// Writes `data` to staged files, which are committed once all of them have
+/// been written successfully, see [`FileSink::write_all`]
+async fn write_and_commit<S: FileSink + ?Sized>(
+    sink: &S,
+    data: SendableRecordBatchStream,
+    context: &Arc<TaskContext>,
+) -> Result<u64> {
+    let config = sink.config();
+    let object_store = context
+        .runtime_env()
+        .object_store(&config.object_store_url)?;
+    let (demux_task, file_stream_rx) = start_demuxer_task(config, data, context);
+    let directory = config
+        .table_paths
+        .first()
+        .filter(|table_path| table_path.is_collection())
+        .map(|table_path| table_path.prefix());
+    let staged_files = StagedFiles::new(Arc::clone(&object_store), directory);
+    let (stage_task, file_stream_rx) = staged_files.stage(file_stream_rx);
+    let written = sink
+        .spawn_writer_tasks_and_join(context, demux_task, file_stream_rx, object_store)
+        .await;
+    let staged = stage_task
+        .join_unwind()
         .await
+        .map_err(DataFusionError::ExecutionJoin)
+        .and_then(|res| res);
+
+    match (written, staged) {
+        (Ok(row_count), Ok(())) => {
+            let replaced = match config.insert_op {
+                InsertOp::Overwrite => config
+                    .file_groups
+                    .iter()
+                    .map(|file| file.object_meta.location.clone())
+                    .collect(),
+                InsertOp::Append | InsertOp::Replace => vec![],
+            };
+            staged_files.commit(&replaced).await?;
+            Ok(row_count)
+        }
+        (Err(e), _) | (_, Err(e)) => {
+            staged_files.abort().await?;
+            Err(e)
+        }
     }
 }
 
@@ -88,7 +150,9 @@ pub trait FileSink: DataSink {
 pub struct FileSinkConfig {
     /// Object store URL, used to get an ObjectStore instance
     pub object_store_url: ObjectStoreUrl,
-    /// A vector of [`PartitionedFile`] structs, each representing a file partition
+    /// A vector of [`PartitionedFile`] structs, each representing a file partition.
+    /// For [`InsertOp::Overwrite`], these files are deleted once the new files
+    /// have been written.
     pub file_groups: Vec<PartitionedFile>,
     /// Vector of partition paths
     pub table_paths: Vec<ListingTableUrl>,
@@ -104,6 +168,12 @@ pub struct FileSinkConfig {
     pub keep_partition_by_columns: bool,
     /// File extension without a dot(.)
     pub file_extension: String,
+    /// If set, the rows are written to one file per bucket, see [`BucketSpec`]
+    pub bucket_spec: Option<BucketSpec>,
+    /// If set, the files are committed as they are rolled by the policy,
+    /// rather than once the whole input has been written, such as when
+    /// writing an unbounded input. See [`RollingPolicy`]
+    pub rolling_policy: Option<RollingPolicy>,
 }
 
 impl FileSinkConfig {
@@ -123,6 +123,7 @@ impl FileStream {
             object_meta: part_file.object_meta,
             range: part_file.range,
             extensions: part_file.extensions,
+            deletes: part_file.deletes,
             metadata_size_hint: part_file.metadata_size_hint,
         };
 
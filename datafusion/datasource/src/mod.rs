@@ -24,6 +24,8 @@
 //! A table that uses the `ObjectStore` listing capability
 //! to get the list of files to process.
 
+pub mod bucket;
+pub mod deletes;
 pub mod display;
 pub mod file;
 pub mod file_compression_type;
@@ -42,6 +44,7 @@ pub mod write;
 use chrono::TimeZone;
 use datafusion_common::Result;
 use datafusion_common::{ScalarValue, Statistics};
+use deletes::FileDeletes;
 use futures::Stream;
 use object_store::{path::Path, ObjectMeta};
 use std::pin::Pin;
@@ -97,6 +100,8 @@ pub struct PartitionedFile {
     pub statistics: Option<Statistics>,
     /// An optional field for user defined per object metadata
     pub extensions: Option<Arc<dyn std::any::Any + Send + Sync>>,
+    /// Optional rows of the file that are deleted, and must not be read
+    pub deletes: Option<Arc<FileDeletes>>,
     /// The estimated size of the parquet metadata, in bytes
     pub metadata_size_hint: Option<usize>,
 }
@@ -116,6 +121,7 @@ impl PartitionedFile {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         }
     }
@@ -134,6 +140,7 @@ impl PartitionedFile {
             range: Some(FileRange { start, end }),
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         }
         .with_range(start, end)
@@ -174,6 +181,12 @@ impl PartitionedFile {
         self.extensions = Some(extensions);
         self
     }
+
+    /// Update the rows of this file that are deleted, see [`FileDeletes`]
+    pub fn with_deletes(mut self, deletes: FileDeletes) -> Self {
+        self.deletes = (!deletes.is_empty()).then(|| Arc::new(deletes));
+        self
+    }
 }
 
 impl From<ObjectMeta> for PartitionedFile {
@@ -184,6 +197,7 @@ impl From<ObjectMeta> for PartitionedFile {
             range: None,
             statistics: None,
             extensions: None,
+            deletes: None,
             metadata_size_hint: None,
         }
     }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::write::orchestration::{CommitMarkers, STAGING_FILE_PREFIX};
 use datafusion_catalog::Session;
 use datafusion_common::{DataFusionError, Result};
 use datafusion_execution::object_store::ObjectStoreUrl;
@@ -155,7 +156,16 @@ impl ListingTableUrl {
     }
 
     /// Returns `true` if `path` matches this [`ListingTableUrl`]
+    ///
+    /// Files that have been written but not yet committed, whose names start
+    /// with [`STAGING_FILE_PREFIX`], never match.
     pub fn contains(&self, path: &Path, ignore_subdirectory: bool) -> bool {
+        if path
+            .filename()
+            .is_some_and(|name| name.starts_with(STAGING_FILE_PREFIX))
+        {
+            return false;
+        }
         let Some(all_segments) = self.strip_prefix(path) else {
             return false;
         };
@@ -227,6 +237,9 @@ impl ListingTableUrl {
     }
 
     /// List all files identified by this [`ListingTableUrl`] for the provided `file_extension`
+    ///
+    /// Files hidden by the commit markers of writes to the table are not
+    /// listed, see [`StagedFiles`](crate::write::orchestration::StagedFiles).
     pub async fn list_all_files<'a>(
         &'a self,
         ctx: &'a dyn Session,
@@ -238,18 +251,24 @@ impl ListingTableUrl {
         // If the prefix is a file, use a head request, otherwise list
         let list = match self.is_collection() {
             true => match ctx.runtime_env().cache_manager.get_list_files_cache() {
-                None => store.list(Some(&self.prefix)),
+                None => self.list_committed_files(store).await?,
                 Some(cache) => {
-                    if let Some(res) = cache.get(&self.prefix) {
+                    let objects = if let Some(res) = cache.get(&self.prefix) {
                         debug!("Hit list all files cache");
-                        futures::stream::iter(res.as_ref().clone().into_iter().map(Ok))
-                            .boxed()
+                        res.as_ref().clone()
                     } else {
                         let list_res = store.list(Some(&self.prefix));
                         let vec = list_res.try_collect::<Vec<ObjectMeta>>().await?;
                         cache.put(&self.prefix, Arc::new(vec.clone()));
-                        futures::stream::iter(vec.into_iter().map(Ok)).boxed()
-                    }
+                        vec
+                    };
+                    // hide the files of pending writes and the files replaced
+                    // by committed writes
+                    let markers = CommitMarkers::load(store, &objects).await?;
+                    let objects = objects
+                        .into_iter()
+                        .filter(move |meta| markers.is_visible(&meta.location));
+                    futures::stream::iter(objects.map(Ok)).boxed()
                 }
             },
             false => futures::stream::once(store.head(&self.prefix)).boxed(),
@@ -265,6 +284,30 @@ impl ListingTableUrl {
             .boxed())
     }
 
+    /// Lazily list the objects under the prefix of this collection, without
+    /// the files hidden by the commit markers of writes to the table
+    ///
+    /// The commit markers are at the root of the table, so the root is listed
+    /// first and the markers among its objects, if any, are loaded before the
+    /// subdirectories are listed.
+    async fn list_committed_files<'a>(
+        &'a self,
+        store: &'a dyn ObjectStore,
+    ) -> Result<BoxStream<'a, object_store::Result<ObjectMeta>>> {
+        let prefix = Some(&self.prefix).filter(|p| !p.as_ref().is_empty());
+        let root = store.list_with_delimiter(prefix).await?;
+        let markers = CommitMarkers::load(store, &root.objects).await?;
+        let subdirectories = futures::stream::iter(root.common_prefixes)
+            .map(move |path| store.list(Some(&path)))
+            .flatten();
+        Ok(futures::stream::iter(root.objects.into_iter().map(Ok))
+            .chain(subdirectories)
+            .try_filter(move |meta| {
+                futures::future::ready(markers.is_visible(&meta.location))
+            })
+            .boxed())
+    }
+
     /// Returns this [`ListingTableUrl`] as a string
     pub fn as_str(&self) -> &str {
         self.as_ref()
@@ -454,6 +497,78 @@ mod tests {
         assert!(url.strip_prefix(&path).is_none());
     }
 
+    #[test]
+    fn test_contains_staged_files() {
+        use crate::write::orchestration::{committed_path, staging_path};
+
+        let url = ListingTableUrl::parse("s3://bucket/foo/").unwrap();
+        let path = Path::from("foo/a=1/bar.parquet");
+        let staged = staging_path(&path, "abc").unwrap();
+        assert_eq!(staged.as_ref(), "foo/a=1/.staging-abc-bar.parquet");
+        assert!(url.contains(&path, true));
+        assert!(!url.contains(&staged, true));
+        assert!(!url.contains(&staged, false));
+
+        assert_eq!(committed_path(&staged).unwrap(), path);
+        assert_eq!(committed_path(&path).unwrap(), path);
+    }
+
+    #[tokio::test]
+    async fn test_commit_markers() {
+        use crate::write::orchestration::{
+            is_commit_marker, CommitMarker, CommitMarkers,
+        };
+        use object_store::memory::InMemory;
+        use object_store::ObjectStore;
+
+        let url = ListingTableUrl::parse("s3://bucket/foo/").unwrap();
+        let pending = Path::from("foo/.staging-abc.commit");
+        let committed = Path::from("foo/.staging-def.commit");
+        assert!(is_commit_marker(&pending));
+        assert!(!is_commit_marker(&Path::from("foo/bar.parquet")));
+        assert!(!url.contains(&pending, false));
+
+        let marker = |committed: bool, added: &str, replaced: &str| CommitMarker {
+            committed,
+            added: vec![Path::from(added)],
+            replaced: vec![Path::from(replaced)],
+        };
+        let store = InMemory::new();
+        let pending_marker = marker(false, "foo/new1.parquet", "foo/old1.parquet");
+        let bytes = pending_marker.to_bytes();
+        assert_eq!(
+            CommitMarker::try_from_bytes(&bytes).unwrap(),
+            pending_marker
+        );
+        store.put(&pending, bytes.into()).await.unwrap();
+        let committed_marker = marker(true, "foo/new2.parquet", "foo/old2.parquet");
+        store
+            .put(&committed, committed_marker.to_bytes().into())
+            .await
+            .unwrap();
+
+        // a marker deleted since it was listed is ignored
+        let deleted = Path::from("foo/.staging-ghi.commit");
+        let objects: Vec<_> = [&pending, &committed, &deleted]
+            .into_iter()
+            .map(|location| ObjectMeta {
+                location: location.clone(),
+                last_modified: Default::default(),
+                size: 0,
+                e_tag: None,
+                version: None,
+            })
+            .collect();
+        let markers = CommitMarkers::load(&store, &objects).await.unwrap();
+
+        // the files of a pending write are hidden until it is committed, and
+        // the replaced files of a committed write until they are deleted
+        assert!(!markers.is_visible(&Path::from("foo/new1.parquet")));
+        assert!(markers.is_visible(&Path::from("foo/old1.parquet")));
+        assert!(markers.is_visible(&Path::from("foo/new2.parquet")));
+        assert!(!markers.is_visible(&Path::from("foo/old2.parquet")));
+    }
+
     #[test]
     fn test_split_glob() {
         fn test(input: &str, expected: Option<(&str, &str)>) {
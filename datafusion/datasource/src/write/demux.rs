@@ -19,9 +19,11 @@
 //! dividing input stream into multiple output files at execution time
 
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
+use crate::bucket::BucketSpec;
 use crate::url::ListingTableUrl;
 use crate::write::FileSinkConfig;
 use datafusion_common::error::Result;
@@ -29,8 +31,9 @@ use datafusion_physical_plan::SendableRecordBatchStream;
 
 use arrow::array::{
     builder::UInt64Builder, cast::AsArray, downcast_dictionary_array, RecordBatch,
-    StringArray, StructArray,
+    StringArray, StructArray, UInt64Array,
 };
+use arrow::compute::take_record_batch;
 use arrow::datatypes::{DataType, Schema};
 use datafusion_common::cast::{
     as_boolean_array, as_date32_array, as_date64_array, as_int32_array, as_int64_array,
@@ -76,6 +79,9 @@ pub type DemuxedStreamReceiver = UnboundedReceiver<(Path, RecordBatchReceiver)>;
 /// unique values of a specific column, see
 /// <https://github.com/apache/datafusion/issues/7744>
 ///
+/// If the table is bucketed, the input is instead split into one file per
+/// bucket, see [`BucketSpec`].
+///
 /// ```text
 ///                                                                              ┌───────────┐               ┌────────────┐    ┌─────────────┐
 ///                                                                     ┌──────▶ │  batch 1  ├────▶...──────▶│   Batch a  │    │ Output File1│
@@ -98,9 +104,23 @@ pub(crate) fn start_demuxer_task(
     let context = Arc::clone(context);
     let file_extension = config.file_extension.clone();
     let base_output_path = config.table_paths[0].clone();
-    let task = if config.table_partition_cols.is_empty() {
+    let task = if let Some(bucket_spec) = config.bucket_spec.clone() {
+        SpawnedTask::spawn(async move {
+            bucket_demuxer(
+                tx,
+                data,
+                context,
+                bucket_spec,
+                base_output_path,
+                file_extension,
+            )
+            .await
+        })
+    } else if config.table_partition_cols.is_empty() {
         let single_file_output = !base_output_path.is_collection()
             && base_output_path.file_extension().is_some();
+        // the files of a rolled write are already split by the policy
+        let rolled = config.rolling_policy.is_some();
         SpawnedTask::spawn(async move {
             row_count_demuxer(
                 tx,
@@ -109,6 +129,7 @@ pub(crate) fn start_demuxer_task(
                 base_output_path,
                 file_extension,
                 single_file_output,
+                rolled,
             )
             .await
         })
@@ -135,6 +156,10 @@ pub(crate) fn start_demuxer_task(
 }
 
 /// Dynamically partitions input stream to achieve desired maximum rows per file
+///
+/// If `rolled` is set, the input is a file rolled by a
+/// [`RollingPolicy`](super::rolling::RollingPolicy), and is not split into
+/// `minimum_parallel_output_files` files.
 async fn row_count_demuxer(
     mut tx: UnboundedSender<(Path, Receiver<RecordBatch>)>,
     mut input: SendableRecordBatchStream,
@@ -142,6 +167,7 @@ async fn row_count_demuxer(
     base_output_path: ListingTableUrl,
     file_extension: String,
     single_file_output: bool,
+    rolled: bool,
 ) -> Result<()> {
     let exec_options = &context.session_config().options().execution;
 
@@ -157,8 +183,8 @@ async fn row_count_demuxer(
     let mut next_send_steam = 0;
     let mut row_counts = Vec::with_capacity(minimum_parallel_files);
 
-    // Overrides if single_file_output is set
-    let minimum_parallel_files = if single_file_output {
+    // Overrides if single_file_output or rolled is set
+    let minimum_parallel_files = if single_file_output || rolled {
         1
     } else {
         minimum_parallel_files
@@ -253,6 +279,64 @@ fn create_new_file_stream(
     Ok(tx_file)
 }
 
+/// Splits an input stream into one file per bucket of `bucket_spec`, see
+/// [`BucketSpec`]
+async fn bucket_demuxer(
+    tx: UnboundedSender<(Path, Receiver<RecordBatch>)>,
+    mut input: SendableRecordBatchStream,
+    context: Arc<TaskContext>,
+    bucket_spec: BucketSpec,
+    base_output_path: ListingTableUrl,
+    file_extension: String,
+) -> Result<()> {
+    let write_id =
+        rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+
+    let exec_options = &context.session_config().options().execution;
+    let max_buffered_recordbatches = exec_options.max_buffered_batches_per_output_file;
+
+    let mut bucket_txs: HashMap<usize, Sender<RecordBatch>> = HashMap::new();
+    while let Some(rb) = input.next().await.transpose()? {
+        let mut bucket_rows: BTreeMap<usize, Vec<u64>> = BTreeMap::new();
+        for (row, bucket) in bucket_spec.buckets(&rb)?.into_iter().enumerate() {
+            bucket_rows.entry(bucket).or_default().push(row as u64);
+        }
+        let bucket_batches = bucket_rows
+            .into_iter()
+            .map(|(bucket, rows)| {
+                let batch = take_record_batch(&rb, &UInt64Array::from(rows))?;
+                Ok((bucket, batch))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for (bucket, batch) in bucket_batches {
+            let bucket_tx = match bucket_txs.entry(bucket) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    // Create the file of a bucket when its first row is seen
+                    let (bucket_tx, bucket_rx) =
+                        mpsc::channel::<RecordBatch>(max_buffered_recordbatches);
+                    let file_path = base_output_path
+                        .prefix()
+                        .child(BucketSpec::file_name(&write_id, bucket, &file_extension));
+                    tx.send((file_path, bucket_rx)).map_err(|_| {
+                        DataFusionError::Execution(
+                            "Error sending new file stream!".into(),
+                        )
+                    })?;
+                    entry.insert(bucket_tx)
+                }
+            };
+
+            bucket_tx.send(batch).await.map_err(|_| {
+                DataFusionError::Internal("Unexpected error sending bucket batch!".into())
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Splits an input stream based on the distinct values of a set of columns
 /// Assumes standard hive style partition paths such as
 /// /col1=val1/col2=val2/outputfile.parquet
@@ -337,6 +421,37 @@ async fn hive_style_partitions_demuxer(
     Ok(())
 }
 
+/// Splits `rb` by the file its rows are written to by a write of `config`:
+/// by the values of the partition columns, by bucket, or not at all if the
+/// table is neither partitioned nor bucketed. Returns the rows of each file
+/// along with a key identifying the file, see
+/// [`RollingPolicy`](super::rolling::RollingPolicy).
+pub(crate) fn split_by_output_file(
+    config: &FileSinkConfig,
+    rb: RecordBatch,
+) -> Result<Vec<(Vec<String>, RecordBatch)>> {
+    let take_map = if let Some(bucket_spec) = &config.bucket_spec {
+        let mut take_map: HashMap<Vec<String>, UInt64Builder> = HashMap::new();
+        for (row, bucket) in bucket_spec.buckets(&rb)?.into_iter().enumerate() {
+            take_map
+                .entry(vec![bucket.to_string()])
+                .or_default()
+                .append_value(row as u64);
+        }
+        take_map
+    } else if !config.table_partition_cols.is_empty() {
+        let all_partition_values =
+            compute_partition_keys_by_row(&rb, &config.table_partition_cols)?;
+        compute_take_arrays(&rb, all_partition_values)
+    } else {
+        return Ok(vec![(vec![], rb)]);
+    };
+    take_map
+        .into_iter()
+        .map(|(key, mut builder)| Ok((key, take_record_batch(&rb, &builder.finish())?)))
+        .collect()
+}
+
 fn compute_partition_keys_by_row<'a>(
     rb: &'a RecordBatch,
     partition_by: &'a [(String, DataType)],
@@ -17,8 +17,9 @@
 
 //! Module containing helper methods/traits related to
 //! orchestrating file serialization, streaming to object store,
-//! parallelization, and abort handling
+//! parallelization, abort handling and committing written files
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use super::demux::DemuxedStreamReceiver;
@@ -33,7 +34,10 @@ use datafusion_execution::TaskContext;
 
 use bytes::Bytes;
 use futures::join;
-use object_store::ObjectStore;
+use object_store::path::{Path, DELIMITER};
+use object_store::{ObjectMeta, ObjectStore};
+use parking_lot::Mutex;
+use rand::distributions::DistString;
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tokio::sync::mpsc::{self, Receiver};
 use tokio::task::JoinSet;
@@ -286,3 +290,397 @@ pub async fn spawn_writer_tasks_and_join(
         internal_datafusion_err!("Did not receive row count from write coordinator")
     })
 }
+
+/// Prefix of the file names of files that have been written but not yet
+/// committed, see [`StagedFiles`].
+///
+/// Files whose names start with this prefix are ignored when listing the
+/// files of a table, see [`ListingTableUrl::contains`].
+///
+/// [`ListingTableUrl::contains`]: crate::ListingTableUrl::contains
+pub const STAGING_FILE_PREFIX: &str = ".staging-";
+
+/// Returns the path at which the file for `path` is written by the write
+/// `commit_id`, before being committed: a file in the same directory whose
+/// name starts with [`STAGING_FILE_PREFIX`].
+pub fn staging_path(path: &Path, commit_id: &str) -> Result<Path> {
+    let (dir, name) = match path.as_ref().rsplit_once(DELIMITER) {
+        Some((dir, name)) => (format!("{dir}{DELIMITER}"), name),
+        None => (String::new(), path.as_ref()),
+    };
+    let staged = format!("{dir}{STAGING_FILE_PREFIX}{commit_id}-{name}");
+    Ok(Path::parse(staged).map_err(object_store::Error::from)?)
+}
+
+/// Returns the path a file written at `path` is committed to, the inverse of
+/// [`staging_path`]. Paths that are not staging paths are returned unchanged.
+pub fn committed_path(path: &Path) -> Result<Path> {
+    let (dir, name) = match path.as_ref().rsplit_once(DELIMITER) {
+        Some((dir, name)) => (format!("{dir}{DELIMITER}"), name),
+        None => (String::new(), path.as_ref()),
+    };
+    let Some((_, name)) = name
+        .strip_prefix(STAGING_FILE_PREFIX)
+        .and_then(|name| name.split_once('-'))
+    else {
+        return Ok(path.clone());
+    };
+    Ok(Path::parse(format!("{dir}{name}")).map_err(object_store::Error::from)?)
+}
+
+/// Suffix of the name of the commit marker of a write, see [`StagedFiles`].
+///
+/// The name of a commit marker starts with [`STAGING_FILE_PREFIX`], so that
+/// it is not listed as a file of the table.
+pub const COMMIT_MARKER_SUFFIX: &str = ".commit";
+
+/// Returns `true` if `path` is the commit marker of a write
+pub fn is_commit_marker(path: &Path) -> bool {
+    path.filename().is_some_and(|name| {
+        name.starts_with(STAGING_FILE_PREFIX) && name.ends_with(COMMIT_MARKER_SUFFIX)
+    })
+}
+
+/// The contents of the commit marker of a write: whether the write has been
+/// committed, the files it adds and the files it replaces.
+///
+/// While a write is pending, the files it adds are not visible to queries.
+/// Once it is committed, the files it replaces are not visible anymore.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitMarker {
+    /// Whether the write has been committed
+    pub committed: bool,
+    /// The final paths of the files added by the write
+    pub added: Vec<Path>,
+    /// The paths of the files replaced by the write
+    pub replaced: Vec<Path>,
+}
+
+impl CommitMarker {
+    /// Serialize the marker, one line for the state and one per file
+    pub fn to_bytes(&self) -> Bytes {
+        let state = if self.committed {
+            "committed"
+        } else {
+            "pending"
+        };
+        let added = self.added.iter().map(|path| format!("added {path}"));
+        let replaced = self.replaced.iter().map(|path| format!("replaced {path}"));
+        let lines: Vec<_> = std::iter::once(state.to_string())
+            .chain(added)
+            .chain(replaced)
+            .collect();
+        Bytes::from(lines.join("\n"))
+    }
+
+    /// Parse a marker serialized with [`Self::to_bytes`]
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|e| internal_datafusion_err!("Invalid commit marker: {e}"))?;
+        let mut lines = text.lines();
+        let committed = match lines.next() {
+            Some("committed") => true,
+            Some("pending") => false,
+            state => return internal_err!("Invalid commit marker state {state:?}"),
+        };
+        let mut marker = Self {
+            committed,
+            added: vec![],
+            replaced: vec![],
+        };
+        for line in lines {
+            let path = |path: &str| Path::parse(path).map_err(object_store::Error::from);
+            match line.split_once(' ') {
+                Some(("added", path_str)) => marker.added.push(path(path_str)?),
+                Some(("replaced", path_str)) => marker.replaced.push(path(path_str)?),
+                _ => return internal_err!("Invalid commit marker line {line:?}"),
+            }
+        }
+        Ok(marker)
+    }
+}
+
+/// The files hidden from queries by the commit markers of a table, see
+/// [`StagedFiles`]
+#[derive(Debug, Default)]
+pub struct CommitMarkers {
+    hidden: HashSet<Path>,
+}
+
+impl CommitMarkers {
+    /// Read the commit markers among `objects`, the objects listed at the
+    /// root of a table
+    ///
+    /// The markers are found by their names, so no request is made if
+    /// `objects` contains no marker. Markers that were deleted since they were listed belong to writes
+    /// that completed, and are ignored.
+    pub async fn load(store: &dyn ObjectStore, objects: &[ObjectMeta]) -> Result<Self> {
+        let markers: Vec<_> = objects
+            .iter()
+            .map(|meta| &meta.location)
+            .filter(|path| is_commit_marker(path))
+            .collect();
+        let mut hidden = HashSet::new();
+        for path in markers {
+            let bytes = match store.get(path).await {
+                Ok(result) => result.bytes().await?,
+                Err(object_store::Error::NotFound { .. }) => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let marker = CommitMarker::try_from_bytes(&bytes)?;
+            match marker.committed {
+                true => hidden.extend(marker.replaced),
+                false => hidden.extend(marker.added),
+            }
+        }
+        Ok(Self { hidden })
+    }
+
+    /// Returns `true` if the file at `path` is visible to queries
+    pub fn is_visible(&self, path: &Path) -> bool {
+        !self.hidden.contains(path)
+    }
+}
+
+/// Commit protocol for the files written by a [`FileSink`]: files are
+/// written at staging paths (see [`staging_path`]), and only made visible
+/// by [`Self::commit`] once all of them have been successfully written, so
+/// that queries never read partially written files.
+///
+/// If writing fails, [`Self::abort`] removes the staged files. If the write
+/// is cancelled, the staged files are removed in the background when the
+/// [`StagedFiles`] is dropped. Staged files that are left over, such as when
+/// the process exits during a write, are ignored when listing the files of
+/// a table.
+///
+/// # Atomicity
+///
+/// When writing to a directory, such as the files of a `ListingTable`, the
+/// commit is atomic for queries listing the files of the table. A
+/// [`CommitMarker`], a single object whose name starts with
+/// [`STAGING_FILE_PREFIX`], is written at the root of the directory:
+///
+/// 1. A pending marker is written. Queries do not see the files it adds.
+/// 2. The staged files are moved to their final paths. A file is never
+///    moved over an existing file: the write fails if one of the final
+///    paths already exists.
+/// 3. The marker is overwritten with a committed marker. This is the commit
+///    point: queries see all added files at once and none of the files it
+///    replaces, such as the previous files of an `INSERT OVERWRITE`.
+/// 4. The replaced files, and then the marker, are deleted.
+///
+/// If a step before the commit point fails, the moved and staged files
+/// and the marker are deleted, so that the table is left as it was. A
+/// pending marker that is left over, such as when the process exits during
+/// a commit, keeps hiding the files it adds, and a committed marker the
+/// files it replaces. See [`CommitMarkers`].
+///
+/// A single file output, such as `COPY TO 'file.parquet'`, is moved to its
+/// path without a marker, replacing any existing file.
+///
+/// [`FileSink`]: crate::file_sink_config::FileSink
+pub struct StagedFiles {
+    object_store: Arc<dyn ObjectStore>,
+    /// Identifies the staged files of this write
+    commit_id: String,
+    /// The path of the commit marker, if writing to a directory
+    marker: Option<Path>,
+    /// The staging and final paths of the written files
+    files: Arc<Mutex<Vec<(Path, Path)>>>,
+}
+
+impl StagedFiles {
+    /// Create a new [`StagedFiles`] for files written to `object_store`
+    ///
+    /// If `directory` is set, the files are written to that directory, such
+    /// as the root of a table, and are committed with a [`CommitMarker`]
+    /// written to it.
+    pub fn new(object_store: Arc<dyn ObjectStore>, directory: Option<&Path>) -> Self {
+        let commit_id =
+            rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+        let marker = directory.map(|directory| {
+            directory.child(format!(
+                "{STAGING_FILE_PREFIX}{commit_id}{COMMIT_MARKER_SUFFIX}"
+            ))
+        });
+        Self {
+            object_store,
+            commit_id,
+            marker,
+            files: Default::default(),
+        }
+    }
+
+    /// Returns a receiver that yields the streams of `file_stream_rx` with
+    /// their staging paths, along with the task forwarding them.
+    pub fn stage(
+        &self,
+        mut file_stream_rx: DemuxedStreamReceiver,
+    ) -> (SpawnedTask<Result<()>>, DemuxedStreamReceiver) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let commit_id = self.commit_id.clone();
+        let files = Arc::clone(&self.files);
+        let task = SpawnedTask::spawn(async move {
+            while let Some((path, rb_stream)) = file_stream_rx.recv().await {
+                let staged = staging_path(&path, &commit_id)?;
+                files.lock().push((staged.clone(), path));
+                if tx.send((staged, rb_stream)).is_err() {
+                    // the writers stopped, so no more files are written
+                    break;
+                }
+            }
+            Ok(())
+        });
+        (task, rx)
+    }
+
+    /// Make the staged files visible at their final paths, and the files of
+    /// `replaced` that were not overwritten invisible, such as the previous
+    /// files of a table for an `INSERT OVERWRITE`.
+    ///
+    /// If the commit fails, the table is left as it was, see
+    /// [Atomicity](Self#atomicity).
+    pub async fn commit(self, replaced: &[Path]) -> Result<()> {
+        let files = std::mem::take(&mut *self.files.lock());
+        let Some(marker_path) = &self.marker else {
+            for (i, (staged, path)) in files.iter().enumerate() {
+                if let Err(e) = self.object_store.rename(staged, path).await {
+                    self.delete(files[i..].iter().map(|(staged, _)| staged))
+                        .await?;
+                    return Err(e.into());
+                }
+            }
+            return Ok(());
+        };
+
+        let added: HashSet<_> = files.iter().map(|(_, path)| path).collect();
+        let mut marker = CommitMarker {
+            committed: false,
+            added: files.iter().map(|(_, path)| path.clone()).collect(),
+            replaced: replaced
+                .iter()
+                .filter(|path| !added.contains(path))
+                .cloned()
+                .collect(),
+        };
+        if let Err(e) = self.put_marker(marker_path, &marker).await {
+            self.delete(files.iter().map(|(staged, _)| staged)).await?;
+            return Err(e);
+        }
+
+        let mut moved = 0;
+        let mut result = Ok(());
+        for (staged, path) in &files {
+            result = rename_if_not_exists(self.object_store.as_ref(), staged, path).await;
+            if result.is_err() {
+                break;
+            }
+            moved += 1;
+        }
+        if result.is_ok() {
+            marker.committed = true;
+            result = self.put_marker(marker_path, &marker).await;
+        }
+        if let Err(e) = result {
+            let moved_files = files[..moved].iter().map(|(_, path)| path);
+            let staged = files[moved..].iter().map(|(staged, _)| staged);
+            self.delete(moved_files.chain(staged)).await?;
+            self.delete(std::iter::once(marker_path)).await?;
+            return Err(e);
+        }
+
+        // the write is committed: the replaced files are hidden by the
+        // marker, which is kept until they are deleted
+        if let Err(e) = self.delete(marker.replaced.iter()).await {
+            log::warn!("Failed to delete the files replaced by a committed write: {e}");
+            return Ok(());
+        }
+        if let Err(e) = self.delete(std::iter::once(marker_path)).await {
+            log::warn!("Failed to delete the marker of a committed write: {e}");
+        }
+        Ok(())
+    }
+
+    /// Delete the staged files
+    pub async fn abort(self) -> Result<()> {
+        let files = std::mem::take(&mut *self.files.lock());
+        self.delete(files.iter().map(|(staged, _)| staged)).await
+    }
+
+    async fn put_marker(&self, path: &Path, marker: &CommitMarker) -> Result<()> {
+        self.object_store
+            .put(path, marker.to_bytes().into())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, paths: impl Iterator<Item = &Path>) -> Result<()> {
+        delete_files(self.object_store.as_ref(), paths).await
+    }
+}
+
+/// Move the file at `from` to `to`, failing if a file exists at `to`
+///
+/// Object stores that do not support renaming without overwriting check
+/// that `to` does not exist before renaming the file.
+async fn rename_if_not_exists(
+    object_store: &dyn ObjectStore,
+    from: &Path,
+    to: &Path,
+) -> Result<()> {
+    match object_store.rename_if_not_exists(from, to).await {
+        Ok(()) => Ok(()),
+        Err(object_store::Error::NotImplemented) => {
+            match object_store.head(to).await {
+                Ok(_) => {
+                    return Err(object_store::Error::AlreadyExists {
+                        path: to.to_string(),
+                        source: "the file of a write already exists".into(),
+                    }
+                    .into())
+                }
+                Err(object_store::Error::NotFound { .. }) => {}
+                Err(e) => return Err(e.into()),
+            }
+            Ok(object_store.rename(from, to).await?)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+impl Drop for StagedFiles {
+    /// Deletes the staged files of a write that was neither committed nor
+    /// aborted, such as a cancelled write
+    fn drop(&mut self) {
+        let files = std::mem::take(&mut *self.files.lock());
+        if files.is_empty() {
+            return;
+        }
+        // the write may be dropped outside of a runtime, leaving the files
+        // to be ignored when listing the table
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let object_store = Arc::clone(&self.object_store);
+        handle.spawn(async move {
+            let staged = files.iter().map(|(staged, _)| staged);
+            if let Err(e) = delete_files(object_store.as_ref(), staged).await {
+                log::warn!("Failed to delete the staged files of a cancelled write: {e}");
+            }
+        });
+    }
+}
+
+/// Delete the files at `paths`, ignoring files that do not exist
+async fn delete_files(
+    object_store: &dyn ObjectStore,
+    paths: impl Iterator<Item = &Path>,
+) -> Result<()> {
+    for path in paths {
+        match object_store.delete(path).await {
+            Ok(_) | Err(object_store::Error::NotFound { .. }) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module containing helper methods for writing an unbounded input to
+//! files that are committed as they are rolled, see [`RollingPolicy`]
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::file_sink_config::FileSinkConfig;
+use crate::write::demux::split_by_output_file;
+
+use arrow::array::RecordBatch;
+use datafusion_common::config::ExecutionOptions;
+use datafusion_common::Result;
+use datafusion_execution::SendableRecordBatchStream;
+use datafusion_physical_plan::stream::RecordBatchStreamAdapter;
+
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use tokio::sync::mpsc::{self, Sender};
+use tokio::time::Instant;
+
+/// When to roll the files written from an unbounded input: once any of the
+/// limits is reached for an output file, such as the file of a hive style
+/// partition, that file is committed, and the following rows of the file are
+/// written to a new file.
+///
+/// Each file is written and committed like the file of a bounded input, on
+/// its own, so the files of other partitions keep being written. A file
+/// starts with its first row, so no file is written while its rows are idle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RollingPolicy {
+    /// Roll a file once it contains at least this many rows
+    pub max_rows: Option<usize>,
+    /// Roll a file once the in-memory size of its rows reaches this many
+    /// bytes, an estimate of the size of the file
+    pub max_bytes: Option<usize>,
+    /// Roll a file once this long has passed since its first row
+    pub interval: Option<Duration>,
+}
+
+/// A file being written by [`RollingPolicy::write`]
+struct RollingFile {
+    tx: Sender<Result<RecordBatch>>,
+    rows: usize,
+    bytes: usize,
+    deadline: Option<Instant>,
+}
+
+impl RollingPolicy {
+    /// The [`RollingPolicy`] configured by the `streaming_write_*` options,
+    /// or `None` if none of them is set
+    pub fn from_options(options: &ExecutionOptions) -> Option<Self> {
+        let policy = Self {
+            max_rows: options.streaming_write_max_rows_per_file,
+            max_bytes: options.streaming_write_max_bytes_per_file,
+            interval: options
+                .streaming_write_rollover_interval_ms
+                .map(Duration::from_millis),
+        };
+        (policy != Self::default()).then_some(policy)
+    }
+
+    fn is_reached(&self, rows: usize, bytes: usize) -> bool {
+        self.max_rows.is_some_and(|max_rows| rows >= max_rows)
+            || self.max_bytes.is_some_and(|max_bytes| bytes >= max_bytes)
+    }
+
+    /// Splits `input` into the output files of `config`, and writes the rows
+    /// of each file with `write` until the policy rolls it.
+    ///
+    /// Returns the number of rows written by all the calls to `write`.
+    pub(crate) async fn write<F, Fut>(
+        &self,
+        mut input: SendableRecordBatchStream,
+        config: &FileSinkConfig,
+        buffer_size: usize,
+        write: F,
+    ) -> Result<u64>
+    where
+        F: Fn(SendableRecordBatchStream) -> Fut,
+        Fut: Future<Output = Result<u64>>,
+    {
+        let schema = input.schema();
+        let mut files: HashMap<Vec<String>, RollingFile> = HashMap::new();
+        let mut writes = FuturesUnordered::new();
+        let mut row_count = 0;
+        loop {
+            let deadline = files.values().filter_map(|file| file.deadline).min();
+            let rolled = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => futures::future::pending().await,
+                }
+            };
+            let batch = tokio::select! {
+                Some(written) = writes.next() => {
+                    row_count += written?;
+                    continue;
+                }
+                _ = rolled => {
+                    let now = Instant::now();
+                    files.retain(|_, file| file.deadline.is_none_or(|d| d > now));
+                    continue;
+                }
+                next = input.next() => match next {
+                    Some(batch) => batch?,
+                    None => break,
+                },
+            };
+
+            for (key, batch) in split_by_output_file(config, batch)? {
+                let file = files.entry(key).or_insert_with(|| {
+                    let (tx, rx) = mpsc::channel(buffer_size.max(1));
+                    let rows = futures::stream::unfold(rx, |mut rx| async move {
+                        rx.recv().await.map(|batch| (batch, rx))
+                    });
+                    let rows = RecordBatchStreamAdapter::new(Arc::clone(&schema), rows);
+                    writes.push(write(Box::pin(rows)));
+                    RollingFile {
+                        tx,
+                        rows: 0,
+                        bytes: 0,
+                        deadline: self.interval.map(|interval| Instant::now() + interval),
+                    }
+                });
+                file.rows += batch.num_rows();
+                file.bytes += batch.get_array_memory_size();
+
+                // keep writing the files while waiting for room in this one
+                let reserve = file.tx.reserve();
+                tokio::pin!(reserve);
+                let permit = loop {
+                    tokio::select! {
+                        permit = &mut reserve => break permit,
+                        Some(written) = writes.next() => row_count += written?,
+                    }
+                };
+                // a closed file failed, and its write reports the error
+                if let Ok(permit) = permit {
+                    permit.send(Ok(batch));
+                }
+            }
+            files.retain(|_, file| !self.is_reached(file.rows, file.bytes));
+        }
+
+        // the remaining files end with the input
+        drop(files);
+        while let Some(written) = writes.next().await {
+            row_count += written?;
+        }
+        Ok(row_count)
+    }
+}
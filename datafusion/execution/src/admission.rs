@@ -0,0 +1,737 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`AdmissionController`]: limits the queries that run at the same time
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use datafusion_common::instant::Instant;
+use datafusion_common::Result;
+use futures::channel::oneshot;
+use futures::{Stream, StreamExt};
+use parking_lot::Mutex;
+
+use crate::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use crate::{RecordBatchStream, SendableRecordBatchStream};
+
+/// The limits enforced by an [`AdmissionController`]
+///
+/// All limits are disabled by default.
+#[derive(Debug, Clone, Default)]
+pub struct AdmissionConfig {
+    /// Maximum number of queries running at the same time
+    pub max_concurrent_queries: Option<usize>,
+    /// Queries are queued while the memory pool has at least this many
+    /// bytes reserved
+    pub max_reserved_memory: Option<usize>,
+    /// Number of CPU slots shared by the running queries
+    pub cpu_slots: Option<usize>,
+    /// Maximum number of queries of a named queue running at the same time
+    pub queue_limits: HashMap<String, usize>,
+}
+
+impl AdmissionConfig {
+    /// Create a new config without any limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of queries running at the same time
+    pub fn with_max_concurrent_queries(mut self, max_concurrent_queries: usize) -> Self {
+        self.max_concurrent_queries = Some(max_concurrent_queries);
+        self
+    }
+
+    /// Queue queries while the memory pool has at least
+    /// `max_reserved_memory` bytes reserved
+    pub fn with_max_reserved_memory(mut self, max_reserved_memory: usize) -> Self {
+        self.max_reserved_memory = Some(max_reserved_memory);
+        self
+    }
+
+    /// Share `cpu_slots` CPU slots between the running queries
+    pub fn with_cpu_slots(mut self, cpu_slots: usize) -> Self {
+        self.cpu_slots = Some(cpu_slots);
+        self
+    }
+
+    /// Limit the number of queries of `queue` running at the same time
+    pub fn with_queue_limit(mut self, queue: impl Into<String>, limit: usize) -> Self {
+        self.queue_limits.insert(queue.into(), limit);
+        self
+    }
+}
+
+/// A query asking an [`AdmissionController`] to be run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdmissionRequest {
+    /// The session running the query
+    pub session_id: String,
+    /// The queue of the query
+    pub queue: String,
+    /// Queued queries with a higher priority are admitted first
+    pub priority: usize,
+    /// The number of CPU slots used by the query while it runs
+    pub cpu_slots: usize,
+}
+
+impl AdmissionRequest {
+    /// Create a request for a query of `session_id` in the `default` queue
+    /// with priority `0` using a single CPU slot
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            queue: "default".to_string(),
+            priority: 0,
+            cpu_slots: 1,
+        }
+    }
+
+    /// Set the queue of the query
+    pub fn with_queue(mut self, queue: impl Into<String>) -> Self {
+        self.queue = queue.into();
+        self
+    }
+
+    /// Set the priority of the query
+    pub fn with_priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set the number of CPU slots used by the query
+    pub fn with_cpu_slots(mut self, cpu_slots: usize) -> Self {
+        self.cpu_slots = cpu_slots;
+        self
+    }
+}
+
+/// Whether a query of an [`AdmissionController`] is waiting or running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdmissionState {
+    /// The query is waiting to be admitted
+    Queued,
+    /// The query has been admitted
+    Running,
+}
+
+impl std::fmt::Display for AdmissionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Queued => write!(f, "queued"),
+            Self::Running => write!(f, "running"),
+        }
+    }
+}
+
+/// A snapshot of a query known to an [`AdmissionController`]
+#[derive(Debug, Clone)]
+pub struct AdmissionEntry {
+    /// Identifies the query within its [`AdmissionController`]
+    pub query_id: u64,
+    /// The request of the query
+    pub request: AdmissionRequest,
+    /// Whether the query is waiting or running
+    pub state: AdmissionState,
+    /// How long the query has waited, or waited before it was admitted
+    pub wait_time: Duration,
+}
+
+/// Limits the queries that run at the same time.
+///
+/// Before a query starts executing, it asks the controller to be admitted
+/// with [`AdmissionController::admit`]. If running it would exceed one of
+/// the limits of the [`AdmissionConfig`], the query waits in a queue until
+/// enough running queries have finished. Queued queries are admitted in
+/// order of their priority, and in the order they arrived for the same
+/// priority. A query that only exceeds the limit of its own queue lets
+/// queries of other queues go first.
+///
+/// A query is always admitted if no other query is running, so that a
+/// single query exceeding the limits on its own, such as the memory
+/// reserved by other users of the memory pool, can still make progress.
+///
+/// Queries waiting for memory are admitted as soon as enough memory is
+/// freed if the reservations are made through an [`AdmissionMemoryPool`],
+/// and otherwise when the next query finishes or is queued. As an admitted
+/// query only reserves its memory once it runs, at most one query is
+/// admitted each time while the memory is limited, so that the queued
+/// queries do not all start at once and overshoot the limit.
+///
+/// A controller is usually shared by all sessions of a
+/// [`RuntimeEnv`](crate::runtime_env::RuntimeEnv), see
+/// [`RuntimeEnvBuilder::with_admission_control`](crate::runtime_env::RuntimeEnvBuilder::with_admission_control).
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion_execution::admission::{AdmissionConfig, AdmissionController, AdmissionRequest};
+/// # use datafusion_execution::memory_pool::UnboundedMemoryPool;
+/// # futures::executor::block_on(async {
+/// let config = AdmissionConfig::new().with_max_concurrent_queries(4);
+/// let controller = Arc::new(AdmissionController::new(
+///     config,
+///     Arc::new(UnboundedMemoryPool::default()),
+/// ));
+///
+/// // waits until the query may run
+/// let permit = controller.admit(AdmissionRequest::new("session")).await;
+/// assert_eq!(controller.num_running(), 1);
+///
+/// // the next query may run once the permit is dropped
+/// drop(permit);
+/// assert_eq!(controller.num_running(), 0);
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AdmissionController {
+    config: AdmissionConfig,
+    memory_pool: Arc<dyn MemoryPool>,
+    state: Mutex<ControllerState>,
+    /// The number of queued queries, so that freeing memory only takes the
+    /// lock of `state` if a query is waiting
+    num_queued: AtomicUsize,
+}
+
+#[derive(Debug, Default)]
+struct ControllerState {
+    next_query_id: u64,
+    running: Vec<RunningQuery>,
+    /// Ordered by priority and then `query_id`
+    queued: Vec<QueuedQuery>,
+}
+
+#[derive(Debug)]
+struct RunningQuery {
+    query_id: u64,
+    request: AdmissionRequest,
+    wait_time: Duration,
+}
+
+#[derive(Debug)]
+struct QueuedQuery {
+    query_id: u64,
+    request: AdmissionRequest,
+    queued_at: Instant,
+    /// Notifies the waiting query once it has been admitted
+    admitted: oneshot::Sender<()>,
+}
+
+/// Whether a queued query can be admitted
+enum Admission {
+    Admit,
+    /// The queue of the query has reached its limit
+    QueueFull,
+    /// The controller has reached one of its limits
+    Wait,
+}
+
+impl AdmissionController {
+    /// Create a new controller enforcing `config`, whose memory limit
+    /// refers to the memory reserved in `memory_pool`
+    pub fn new(config: AdmissionConfig, memory_pool: Arc<dyn MemoryPool>) -> Self {
+        Self {
+            config,
+            memory_pool,
+            state: Default::default(),
+            num_queued: AtomicUsize::new(0),
+        }
+    }
+
+    /// Return the limits enforced by this controller
+    pub fn config(&self) -> &AdmissionConfig {
+        &self.config
+    }
+
+    /// Wait until the query described by `request` may run.
+    ///
+    /// The query is considered running until the returned permit is
+    /// dropped. Dropping the returned future while the query is queued
+    /// removes it from the queue.
+    pub async fn admit(self: &Arc<Self>, request: AdmissionRequest) -> AdmissionPermit {
+        let (permit, admitted) = {
+            let mut state = self.state.lock();
+            let query_id = state.next_query_id;
+            state.next_query_id += 1;
+
+            let (sender, admitted) = oneshot::channel();
+            state.queued.push(QueuedQuery {
+                query_id,
+                request,
+                queued_at: Instant::now(),
+                admitted: sender,
+            });
+            self.schedule(&mut state);
+
+            let permit = AdmissionPermit {
+                controller: Arc::clone(self),
+                query_id,
+            };
+            (permit, admitted)
+        };
+
+        // The sender is only dropped without sending if the permit is
+        // released, which can not happen while it is held here
+        let _ = admitted.await;
+        permit
+    }
+
+    /// Return the number of running queries
+    pub fn num_running(&self) -> usize {
+        self.state.lock().running.len()
+    }
+
+    /// Return the number of queued queries
+    pub fn num_queued(&self) -> usize {
+        self.state.lock().queued.len()
+    }
+
+    /// Return a snapshot of the running queries followed by the queued
+    /// queries in the order they will be admitted
+    pub fn entries(&self) -> Vec<AdmissionEntry> {
+        let state = self.state.lock();
+        let running = state.running.iter().map(|query| AdmissionEntry {
+            query_id: query.query_id,
+            request: query.request.clone(),
+            state: AdmissionState::Running,
+            wait_time: query.wait_time,
+        });
+        let queued = state.queued.iter().map(|query| AdmissionEntry {
+            query_id: query.query_id,
+            request: query.request.clone(),
+            state: AdmissionState::Queued,
+            wait_time: query.queued_at.elapsed(),
+        });
+        running.chain(queued).collect()
+    }
+
+    /// Admit the queued queries that may run now that memory of the
+    /// memory pool was freed
+    fn memory_freed(&self) {
+        if self.config.max_reserved_memory.is_none()
+            || self.num_queued.load(Ordering::Relaxed) == 0
+        {
+            return;
+        }
+        let mut state = self.state.lock();
+        if !state.queued.is_empty() {
+            self.schedule(&mut state);
+        }
+    }
+
+    /// Remove the query from the running or queued queries, admitting
+    /// queued queries that may run now
+    fn release(&self, query_id: u64) {
+        let mut state = self.state.lock();
+        state.running.retain(|query| query.query_id != query_id);
+        state.queued.retain(|query| query.query_id != query_id);
+        self.schedule(&mut state);
+    }
+
+    /// Admit the queued queries that may run
+    ///
+    /// While the memory is limited, at most one query is admitted so that
+    /// the memory it reserves is accounted for before admitting the next.
+    fn schedule(&self, state: &mut ControllerState) {
+        state
+            .queued
+            .sort_by_key(|query| (Reverse(query.request.priority), query.query_id));
+
+        let mut idx = 0;
+        while idx < state.queued.len() {
+            match self.check(state, &state.queued[idx].request) {
+                Admission::Admit => {
+                    let query = state.queued.remove(idx);
+                    // the query is no longer waiting if the receiver is gone
+                    if query.admitted.send(()).is_ok() {
+                        state.running.push(RunningQuery {
+                            query_id: query.query_id,
+                            request: query.request,
+                            wait_time: query.queued_at.elapsed(),
+                        });
+                        if self.config.max_reserved_memory.is_some() {
+                            break;
+                        }
+                    }
+                }
+                Admission::QueueFull => idx += 1,
+                Admission::Wait => break,
+            }
+        }
+        self.num_queued.store(state.queued.len(), Ordering::Relaxed);
+    }
+
+    fn check(&self, state: &ControllerState, request: &AdmissionRequest) -> Admission {
+        if state.running.is_empty() {
+            return Admission::Admit;
+        }
+
+        let config = &self.config;
+        if let Some(max_concurrent_queries) = config.max_concurrent_queries {
+            if state.running.len() >= max_concurrent_queries {
+                return Admission::Wait;
+            }
+        }
+        if let Some(max_reserved_memory) = config.max_reserved_memory {
+            if self.memory_pool.reserved() >= max_reserved_memory {
+                return Admission::Wait;
+            }
+        }
+        if let Some(cpu_slots) = config.cpu_slots {
+            let used: usize = state
+                .running
+                .iter()
+                .map(|query| query.request.cpu_slots.min(cpu_slots))
+                .sum();
+            if used + request.cpu_slots.min(cpu_slots) > cpu_slots {
+                return Admission::Wait;
+            }
+        }
+        if let Some(limit) = config.queue_limits.get(&request.queue) {
+            let running = state
+                .running
+                .iter()
+                .filter(|query| query.request.queue == request.queue)
+                .count();
+            if running >= *limit {
+                return Admission::QueueFull;
+            }
+        }
+        Admission::Admit
+    }
+}
+
+/// Allows a query to run, returned by [`AdmissionController::admit`].
+///
+/// The query is considered running until the permit is dropped.
+#[derive(Debug)]
+pub struct AdmissionPermit {
+    controller: Arc<AdmissionController>,
+    query_id: u64,
+}
+
+impl AdmissionPermit {
+    /// Return the identifier of the admitted query
+    pub fn query_id(&self) -> u64 {
+        self.query_id
+    }
+
+    /// Return a stream that holds on to this permit until `stream` is
+    /// exhausted or dropped
+    pub fn wrap_stream(
+        self: &Arc<Self>,
+        stream: SendableRecordBatchStream,
+    ) -> SendableRecordBatchStream {
+        Box::pin(AdmittedStream {
+            inner: stream,
+            permit: Some(Arc::clone(self)),
+        })
+    }
+}
+
+impl Drop for AdmissionPermit {
+    fn drop(&mut self) {
+        self.controller.release(self.query_id);
+    }
+}
+
+/// A [`MemoryPool`] that lets an [`AdmissionController`] admit the queries
+/// waiting for memory as soon as memory of the pool is freed.
+///
+/// [`RuntimeEnvBuilder::with_admission_control`] wraps the memory pool of
+/// the runtime in an `AdmissionMemoryPool` if the [`AdmissionConfig`]
+/// limits the reserved memory.
+///
+/// [`RuntimeEnvBuilder::with_admission_control`]: crate::runtime_env::RuntimeEnvBuilder::with_admission_control
+#[derive(Debug)]
+pub struct AdmissionMemoryPool {
+    inner: Arc<dyn MemoryPool>,
+    controller: Weak<AdmissionController>,
+}
+
+impl AdmissionMemoryPool {
+    /// Create a pool reserving memory from `inner` that notifies
+    /// `controller` when memory is freed
+    pub fn new(
+        inner: Arc<dyn MemoryPool>,
+        controller: &Arc<AdmissionController>,
+    ) -> Self {
+        Self {
+            inner,
+            controller: Arc::downgrade(controller),
+        }
+    }
+
+    /// Return the pool memory is reserved from
+    pub fn inner(&self) -> &Arc<dyn MemoryPool> {
+        &self.inner
+    }
+}
+
+impl MemoryPool for AdmissionMemoryPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        self.inner.register(consumer)
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        self.inner.unregister(consumer)
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.inner.grow(reservation, additional)
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.inner.shrink(reservation, shrink);
+        if let Some(controller) = self.controller.upgrade() {
+            controller.memory_freed();
+        }
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        self.inner.try_grow(reservation, additional)
+    }
+
+    fn reserved(&self) -> usize {
+        self.inner.reserved()
+    }
+
+    fn consumers(&self) -> Option<Vec<(MemoryConsumer, usize)>> {
+        self.inner.consumers()
+    }
+}
+
+/// A stream of an admitted query, see [`AdmissionPermit::wrap_stream`]
+struct AdmittedStream {
+    inner: SendableRecordBatchStream,
+    permit: Option<Arc<AdmissionPermit>>,
+}
+
+impl Stream for AdmittedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.inner.poll_next_unpin(cx);
+        if let Poll::Ready(None) = poll {
+            self.permit = None;
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl RecordBatchStream for AdmittedStream {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_pool::GreedyMemoryPool;
+    use futures::FutureExt;
+
+    fn controller(config: AdmissionConfig) -> Arc<AdmissionController> {
+        let pool = Arc::new(GreedyMemoryPool::new(1000));
+        Arc::new(AdmissionController::new(config, pool))
+    }
+
+    fn request(queue: &str, priority: usize) -> AdmissionRequest {
+        AdmissionRequest::new("session")
+            .with_queue(queue)
+            .with_priority(priority)
+    }
+
+    fn states(controller: &AdmissionController) -> Vec<(u64, AdmissionState)> {
+        controller
+            .entries()
+            .into_iter()
+            .map(|entry| (entry.query_id, entry.state))
+            .collect()
+    }
+
+    #[test]
+    fn max_concurrent_queries() {
+        let controller =
+            controller(AdmissionConfig::new().with_max_concurrent_queries(2));
+
+        let p0 = controller.admit(request("a", 0)).now_or_never().unwrap();
+        let p1 = controller.admit(request("a", 0)).now_or_never().unwrap();
+        let mut q2 = controller.admit(request("a", 0)).boxed();
+        assert!((&mut q2).now_or_never().is_none());
+        let mut q3 = controller.admit(request("a", 1)).boxed();
+        assert!((&mut q3).now_or_never().is_none());
+
+        use AdmissionState::*;
+        assert_eq!(
+            states(&controller),
+            vec![(0, Running), (1, Running), (3, Queued), (2, Queued)]
+        );
+
+        // the query with the higher priority is admitted first
+        drop(p0);
+        assert!((&mut q2).now_or_never().is_none());
+        let p3 = (&mut q3).now_or_never().unwrap();
+        assert_eq!(p3.query_id(), 3);
+
+        drop(p1);
+        let p2 = (&mut q2).now_or_never().unwrap();
+        assert_eq!(states(&controller), vec![(3, Running), (2, Running)]);
+
+        drop(p2);
+        drop(p3);
+        assert_eq!(controller.num_running(), 0);
+    }
+
+    #[test]
+    fn queue_limits() {
+        let controller = controller(
+            AdmissionConfig::new()
+                .with_max_concurrent_queries(3)
+                .with_queue_limit("a", 1),
+        );
+
+        let p0 = controller.admit(request("a", 0)).now_or_never().unwrap();
+        let mut q1 = controller.admit(request("a", 0)).boxed();
+        assert!((&mut q1).now_or_never().is_none());
+
+        // other queues are not blocked by the limit of queue "a"
+        let _p2 = controller.admit(request("b", 0)).now_or_never().unwrap();
+        assert_eq!(controller.num_running(), 2);
+        assert_eq!(controller.num_queued(), 1);
+
+        drop(p0);
+        assert!((&mut q1).now_or_never().is_some());
+    }
+
+    #[test]
+    fn cpu_slots_and_memory() {
+        let controller = controller(
+            AdmissionConfig::new()
+                .with_cpu_slots(4)
+                .with_max_reserved_memory(500),
+        );
+
+        // a query is always admitted when nothing else is running
+        let p0 = controller
+            .admit(request("a", 0).with_cpu_slots(8))
+            .now_or_never()
+            .unwrap();
+        let mut q1 = controller.admit(request("a", 0).with_cpu_slots(1)).boxed();
+        assert!((&mut q1).now_or_never().is_none());
+        drop(p0);
+        let p1 = (&mut q1).now_or_never().unwrap();
+
+        let p2 = controller
+            .admit(request("a", 0).with_cpu_slots(3))
+            .now_or_never()
+            .unwrap();
+        drop(p2);
+
+        // queries wait while the memory pool is too full
+        let mut reservation =
+            MemoryConsumer::new("test").register(&controller.memory_pool);
+        reservation.grow(600);
+        let mut q3 = controller.admit(request("a", 0)).boxed();
+        assert!((&mut q3).now_or_never().is_none());
+        reservation.free();
+        drop(p1);
+        assert!((&mut q3).now_or_never().is_some());
+    }
+
+    #[test]
+    fn admit_when_memory_is_freed() {
+        let controller = controller(
+            AdmissionConfig::new()
+                .with_max_concurrent_queries(2)
+                .with_max_reserved_memory(500),
+        );
+        let pool: Arc<dyn MemoryPool> = Arc::new(AdmissionMemoryPool::new(
+            Arc::clone(&controller.memory_pool),
+            &controller,
+        ));
+
+        let _p0 = controller.admit(request("a", 0)).now_or_never().unwrap();
+        let mut reservation = MemoryConsumer::new("test").register(&pool);
+        reservation.grow(600);
+        let mut q1 = controller.admit(request("a", 0)).boxed();
+        assert!((&mut q1).now_or_never().is_none());
+
+        // the query is admitted while the first query is still running
+        reservation.shrink(200);
+        assert!((&mut q1).now_or_never().is_some());
+    }
+
+    #[test]
+    fn admit_one_query_at_a_time_when_memory_is_freed() {
+        let controller = controller(AdmissionConfig::new().with_max_reserved_memory(500));
+        let pool: Arc<dyn MemoryPool> = Arc::new(AdmissionMemoryPool::new(
+            Arc::clone(&controller.memory_pool),
+            &controller,
+        ));
+
+        let _p0 = controller.admit(request("a", 0)).now_or_never().unwrap();
+        let mut reservation = MemoryConsumer::new("test").register(&pool);
+        reservation.grow(600);
+        let mut q1 = controller.admit(request("a", 0)).boxed();
+        let mut q2 = controller.admit(request("a", 0)).boxed();
+        assert!((&mut q1).now_or_never().is_none());
+        assert!((&mut q2).now_or_never().is_none());
+
+        // the queued queries are not all admitted at once
+        reservation.shrink(200);
+        let _p1 = (&mut q1).now_or_never().unwrap();
+        assert!((&mut q2).now_or_never().is_none());
+        assert_eq!(controller.num_queued(), 1);
+
+        // the next query is admitted once the memory is checked again
+        reservation.shrink(100);
+        assert!((&mut q2).now_or_never().is_some());
+        assert_eq!(controller.num_queued(), 0);
+    }
+
+    #[test]
+    fn cancel_queued_query() {
+        let controller =
+            controller(AdmissionConfig::new().with_max_concurrent_queries(1));
+
+        let p0 = controller.admit(request("a", 0)).now_or_never().unwrap();
+        let mut q1 = controller.admit(request("a", 0)).boxed();
+        assert!((&mut q1).now_or_never().is_none());
+        assert_eq!(controller.num_queued(), 1);
+
+        drop(q1);
+        assert_eq!(controller.num_queued(), 0);
+        drop(p0);
+        assert_eq!(controller.num_running(), 0);
+    }
+}
@@ -89,6 +89,10 @@ impl CacheAccessor<Path, Arc<Statistics>> for DefaultFileStatisticsCache {
         self.statistics.len()
     }
 
+    fn keys(&self) -> Vec<Path> {
+        self.statistics.iter().map(|x| x.key().clone()).collect()
+    }
+
     fn clear(&self) {
         self.statistics.clear()
     }
@@ -148,6 +152,10 @@ impl CacheAccessor<Path, Arc<Vec<ObjectMeta>>> for DefaultListFilesCache {
         self.statistics.len()
     }
 
+    fn keys(&self) -> Vec<Path> {
+        self.statistics.iter().map(|x| x.key().clone()).collect()
+    }
+
     fn clear(&self) {
         self.statistics.clear()
     }
@@ -192,6 +200,7 @@ mod tests {
             &meta,
         );
         assert!(cache.get_with_extra(&meta.location, &meta).is_some());
+        assert_eq!(cache.keys(), vec![meta.location.clone()]);
 
         // file size changed
         let mut meta2 = meta.clone();
@@ -231,5 +240,6 @@ mod tests {
             cache.get(&meta.location).unwrap().first().unwrap().clone(),
             meta.clone()
         );
+        assert_eq!(cache.keys(), vec![meta.location.clone()]);
     }
 }
@@ -44,6 +44,12 @@ pub trait CacheAccessor<K, V>: Send + Sync {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /// Return the keys of all entries in the cache.
+    ///
+    /// Caches that can not list their entries return no keys.
+    fn keys(&self) -> Vec<K> {
+        vec![]
+    }
     /// Remove all entries from the cache.
     fn clear(&self);
     /// Return the cache name.
@@ -0,0 +1,250 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CancellationToken`] for cooperative cancellation of running queries
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use datafusion_common::instant::Instant;
+use datafusion_common::{CancellationReason, DataFusionError, Result};
+use futures::future::{self, BoxFuture, FutureExt};
+use tokio::sync::Notify;
+
+/// A handle to cancel a running query.
+///
+/// A query is stopped as soon as possible after [`Self::cancel`] is called, or
+/// once the token's timeout (if any) has elapsed. Operators that may run for a
+/// long time without producing output, such as sorts, hash table builds and
+/// aggregations, call [`Self::check`] at batch boundaries (between input
+/// batches) via [`TaskContext::cancellation`] and return the resulting
+/// [`DataFusionError::Cancelled`] error. Streams that wait for their input
+/// wait for [`Self::cancelled`] too, so that they stop even if their input
+/// never produces another batch.
+///
+/// Clones of a token refer to the same underlying state, so cancelling any
+/// clone cancels them all. A [child token](Self::child_token) is cancelled
+/// whenever its parent is, but can also be cancelled or time out on its own.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use datafusion_execution::cancellation::CancellationToken;
+/// let token = CancellationToken::new();
+/// let query = token.child_token_with_timeout(Duration::from_secs(60));
+/// assert!(query.check().is_ok());
+///
+/// token.cancel();
+/// assert!(query.is_cancelled());
+/// ```
+///
+/// [`TaskContext::cancellation`]: crate::TaskContext::cancellation
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationState>,
+}
+
+#[derive(Debug, Default)]
+struct CancellationState {
+    /// The token this token was created from, if any
+    parent: Option<CancellationToken>,
+    /// When this token times out, and the timeout it was created with
+    deadline: Option<(Instant, Duration)>,
+    /// Set once this token is cancelled or has timed out
+    reason: OnceLock<CancellationReason>,
+    /// Wakes the waiters of [`CancellationToken::cancelled`] on cancellation
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Creates a new token that is cancelled only by calling [`Self::cancel`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that is cancelled when `self` is cancelled
+    pub fn child_token(&self) -> Self {
+        Self {
+            inner: Arc::new(CancellationState {
+                parent: Some(self.clone()),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Creates a token that is cancelled when `self` is cancelled, or times
+    /// out once `timeout` has elapsed from now
+    pub fn child_token_with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            inner: Arc::new(CancellationState {
+                parent: Some(self.clone()),
+                deadline: Some((Instant::now() + timeout, timeout)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Cancels this token and all tokens created from it.
+    ///
+    /// Has no effect if the token is already cancelled or has timed out.
+    pub fn cancel(&self) {
+        let _ = self.inner.reason.set(CancellationReason::Cancelled);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Waits until this token is cancelled or has timed out.
+    ///
+    /// Waiting for the timeout of a token requires a Tokio runtime with the
+    /// time driver enabled.
+    pub fn cancelled(&self) -> BoxFuture<'static, ()> {
+        let token = self.clone();
+        async move {
+            let state = &token.inner;
+            let notified = state.notify.notified();
+            futures::pin_mut!(notified);
+            // register before checking, so that a concurrent `cancel` is seen
+            notified.as_mut().enable();
+            if token.is_cancelled() {
+                return;
+            }
+            let parent = match &state.parent {
+                Some(parent) => parent.cancelled(),
+                None => future::pending().boxed(),
+            };
+            let deadline = match state.deadline {
+                Some((deadline, _)) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    tokio::time::sleep(timeout).boxed()
+                }
+                None => future::pending().boxed(),
+            };
+            tokio::select! {
+                _ = notified => {}
+                _ = parent => {}
+                _ = deadline => {}
+            }
+        }
+        .boxed()
+    }
+
+    /// Returns why this token was cancelled, or `None` if it was not
+    pub fn reason(&self) -> Option<CancellationReason> {
+        let state = &self.inner;
+        if let Some(reason) = state.reason.get() {
+            return Some(*reason);
+        }
+        if let Some(reason) = state.parent.as_ref().and_then(|parent| parent.reason()) {
+            return Some(reason);
+        }
+        match state.deadline {
+            Some((deadline, timeout)) if Instant::now() >= deadline => Some(
+                *state
+                    .reason
+                    .get_or_init(|| CancellationReason::Timeout(timeout)),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this token was cancelled or has timed out
+    pub fn is_cancelled(&self) -> bool {
+        self.reason().is_some()
+    }
+
+    /// Returns a [`DataFusionError::Cancelled`] error if this token was
+    /// cancelled or has timed out
+    pub fn check(&self) -> Result<()> {
+        match self.reason() {
+            Some(reason) => Err(DataFusionError::Cancelled(reason)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        let child = token.child_token();
+        assert!(token.check().is_ok());
+        assert_eq!(child.reason(), None);
+
+        // cancelling a child does not cancel its parent
+        let other_child = token.child_token();
+        other_child.cancel();
+        assert!(other_child.is_cancelled());
+        assert!(!token.is_cancelled());
+
+        clone.cancel();
+        assert_eq!(token.reason(), Some(CancellationReason::Cancelled));
+        assert_eq!(child.reason(), Some(CancellationReason::Cancelled));
+        let err = child.check().unwrap_err();
+        assert!(matches!(
+            err,
+            DataFusionError::Cancelled(CancellationReason::Cancelled)
+        ));
+    }
+
+    #[tokio::test]
+    async fn cancelled() {
+        let token = CancellationToken::new();
+        let child = token.child_token();
+        let mut waiter = child.cancelled();
+        assert!(futures::poll!(&mut waiter).is_pending());
+        token.cancel();
+        waiter.await;
+        // and immediately once cancelled
+        child.cancelled().await;
+
+        // and once timed out
+        let child = CancellationToken::new()
+            .child_token_with_timeout(Duration::from_millis(10))
+            .child_token();
+        child.cancelled().await;
+        assert_eq!(
+            child.reason(),
+            Some(CancellationReason::Timeout(Duration::from_millis(10)))
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let token = CancellationToken::new();
+        let child = token.child_token_with_timeout(Duration::ZERO);
+        assert_eq!(
+            child.reason(),
+            Some(CancellationReason::Timeout(Duration::ZERO))
+        );
+        assert!(!token.is_cancelled());
+
+        // an explicit cancellation after the timeout keeps the timeout reason
+        child.cancel();
+        assert_eq!(
+            child.reason(),
+            Some(CancellationReason::Timeout(Duration::ZERO))
+        );
+
+        let child = token.child_token_with_timeout(Duration::from_secs(3600));
+        assert!(child.check().is_ok());
+        token.cancel();
+        assert_eq!(child.reason(), Some(CancellationReason::Cancelled));
+    }
+}
@@ -20,8 +20,10 @@ use std::{
     collections::HashMap,
     hash::{BuildHasherDefault, Hasher},
     sync::Arc,
+    time::Duration,
 };
 
+pub use datafusion_common::config::parse_memory_limit;
 use datafusion_common::{
     config::{ConfigExtension, ConfigOptions},
     Result, ScalarValue,
@@ -446,6 +448,52 @@ impl SessionConfig {
         self.options.execution.enforce_batch_size_in_joins
     }
 
+    /// Set the [`statement_timeout`] after which queries are cancelled.
+    /// A zero duration disables the timeout.
+    ///
+    /// [`statement_timeout`]: datafusion_common::config::ExecutionOptions::statement_timeout
+    pub fn with_statement_timeout(mut self, timeout: Duration) -> Self {
+        self.options.execution.statement_timeout =
+            usize::try_from(timeout.as_millis()).unwrap_or(usize::MAX);
+        self
+    }
+
+    /// Returns the [`statement_timeout`], or `None` if queries never time out
+    ///
+    /// [`statement_timeout`]: datafusion_common::config::ExecutionOptions::statement_timeout
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        match self.options.execution.statement_timeout {
+            0 => None,
+            millis => Some(Duration::from_millis(millis as u64)),
+        }
+    }
+
+    /// Limit the memory each query may reserve to `limit` bytes.
+    /// A zero limit disables the per-query limit.
+    ///
+    /// Each query run with this config reserves its memory from a
+    /// [`ChildMemoryPool`] of the [`RuntimeEnv`]'s memory pool, so the
+    /// limit of the runtime's pool still applies to all queries together.
+    /// This sets the `datafusion.runtime.memory_limit` option.
+    ///
+    /// [`ChildMemoryPool`]: crate::memory_pool::ChildMemoryPool
+    /// [`RuntimeEnv`]: crate::runtime_env::RuntimeEnv
+    pub fn with_query_memory_limit(mut self, limit: usize) -> Self {
+        self.options.runtime.memory_limit = (limit > 0).then(|| limit.to_string());
+        self
+    }
+
+    /// Returns the maximum number of bytes each query may reserve, or `None`
+    /// if queries are only limited by the runtime's memory pool
+    ///
+    /// `datafusion.runtime.memory_limit` is validated with
+    /// [`parse_memory_limit`] when it is set with [`ConfigOptions::set`], so
+    /// only a limit assigned directly to the field may be invalid, and ignored.
+    pub fn query_memory_limit(&self) -> Option<usize> {
+        let limit = self.options.runtime.memory_limit.as_deref()?;
+        parse_memory_limit(limit).ok().filter(|limit| *limit > 0)
+    }
+
     /// Convert configuration options to name-value pairs with values
     /// converted to strings.
     ///
@@ -17,11 +17,15 @@
 
 //! [`DiskManager`]: Manages files generated during query execution
 
+use crate::memory_pool::human_readable_size;
 use datafusion_common::{resources_datafusion_err, DataFusionError, Result};
 use log::debug;
 use parking_lot::Mutex;
 use rand::{thread_rng, Rng};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tempfile::{Builder, NamedTempFile, TempDir};
 
@@ -68,6 +72,12 @@ impl DiskManagerConfig {
 
 /// Manages files generated during query execution, e.g. spill files generated
 /// while processing dataset larger than available memory.
+///
+/// The total size of the temporary files can be limited with
+/// [`DiskManager::set_max_temp_directory_size`]. The bytes written with
+/// [`RefCountedTempFile::writer`] are counted as they are written, and a
+/// write that would exceed the limit returns an error. Files written
+/// otherwise report their size with [`RefCountedTempFile::update_disk_usage`].
 #[derive(Debug)]
 pub struct DiskManager {
     /// TempDirs to put temporary files in.
@@ -75,6 +85,52 @@ pub struct DiskManager {
     /// If `Some(vec![])` a new OS specified temporary directory will be created
     /// If `None` an error will be returned (configured not to spill)
     local_dirs: Mutex<Option<Vec<Arc<TempDir>>>>,
+    /// Disk space used by, and available to, the temporary files
+    disk_usage: Arc<DiskUsage>,
+}
+
+/// Disk space accounting shared by a [`DiskManager`] and its temporary files
+#[derive(Debug)]
+struct DiskUsage {
+    /// Total number of bytes of all temporary files
+    used: AtomicU64,
+    /// Maximum number of bytes all temporary files may use
+    limit: AtomicU64,
+}
+
+impl Default for DiskUsage {
+    fn default() -> Self {
+        Self {
+            used: AtomicU64::new(0),
+            limit: AtomicU64::new(u64::MAX),
+        }
+    }
+}
+
+impl DiskUsage {
+    /// Adds `bytes` to the used disk space, returning an error without
+    /// adding them if the total would exceed the limit
+    fn try_grow(&self, bytes: u64) -> Result<()> {
+        let used = self.used.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if let Err(e) = self.check(used) {
+            self.used.fetch_sub(bytes, Ordering::Relaxed);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `used` bytes exceed the limit
+    fn check(&self, used: u64) -> Result<()> {
+        let limit = self.limit.load(Ordering::Relaxed);
+        if used > limit {
+            return Err(resources_datafusion_err!(
+                "The temporary files of the DiskManager use {} which exceeds the limit of {}. Consider increasing the max_temp_directory_size of the DiskManager",
+                human_readable_size(used as usize),
+                human_readable_size(limit as usize)
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl DiskManager {
@@ -84,6 +140,7 @@ impl DiskManager {
             DiskManagerConfig::Existing(manager) => Ok(manager),
             DiskManagerConfig::NewOs => Ok(Arc::new(Self {
                 local_dirs: Mutex::new(Some(vec![])),
+                disk_usage: Default::default(),
             })),
             DiskManagerConfig::NewSpecified(conf_dirs) => {
                 let local_dirs = create_local_dirs(conf_dirs)?;
@@ -93,10 +150,12 @@ impl DiskManager {
                 );
                 Ok(Arc::new(Self {
                     local_dirs: Mutex::new(Some(local_dirs)),
+                    disk_usage: Default::default(),
                 }))
             }
             DiskManagerConfig::Disabled => Ok(Arc::new(Self {
                 local_dirs: Mutex::new(None),
+                disk_usage: Default::default(),
             })),
         }
     }
@@ -108,6 +167,25 @@ impl DiskManager {
         self.local_dirs.lock().is_some()
     }
 
+    /// Limit the total size of all temporary files to `max_size` bytes.
+    ///
+    /// Defaults to no limit.
+    pub fn set_max_temp_directory_size(&self, max_size: u64) {
+        self.disk_usage.limit.store(max_size, Ordering::Relaxed);
+    }
+
+    /// Return the maximum total size of all temporary files in bytes
+    pub fn max_temp_directory_size(&self) -> u64 {
+        self.disk_usage.limit.load(Ordering::Relaxed)
+    }
+
+    /// Return the total size of all temporary files in bytes, counting the
+    /// bytes written with [`RefCountedTempFile::writer`] and the sizes last
+    /// reported with [`RefCountedTempFile::update_disk_usage`]
+    pub fn used_disk_space(&self) -> u64 {
+        self.disk_usage.used.load(Ordering::Relaxed)
+    }
+
     /// Return a temporary file from a randomized choice in the configured locations
     ///
     /// If the file can not be created for some reason, returns an
@@ -142,6 +220,8 @@ impl DiskManager {
             tempfile: Builder::new()
                 .tempfile_in(local_dirs[dir_index].as_ref())
                 .map_err(DataFusionError::IoError)?,
+            current_file_disk_usage: Default::default(),
+            disk_usage: Arc::clone(&self.disk_usage),
         })
     }
 }
@@ -154,6 +234,11 @@ pub struct RefCountedTempFile {
     /// it is not cleaned up prior to the NamedTempFile
     _parent_temp_dir: Arc<TempDir>,
     tempfile: NamedTempFile,
+    /// The size of this file added to `disk_usage`, shared with the
+    /// [`TempFileWriter`]s of this file
+    current_file_disk_usage: Arc<AtomicU64>,
+    /// The disk usage of the [`DiskManager`] that created this file
+    disk_usage: Arc<DiskUsage>,
 }
 
 impl RefCountedTempFile {
@@ -164,6 +249,90 @@ impl RefCountedTempFile {
     pub fn inner(&self) -> &NamedTempFile {
         &self.tempfile
     }
+
+    /// Open this file for writing, appending to its current contents.
+    ///
+    /// The bytes written are added to the disk usage of the [`DiskManager`]
+    /// before they are written, and a write fails if the total size of all
+    /// temporary files would exceed the
+    /// [`DiskManager::max_temp_directory_size`].
+    pub fn writer(&self) -> Result<TempFileWriter> {
+        let file = OpenOptions::new().append(true).open(self.path())?;
+        Ok(TempFileWriter {
+            file,
+            current_file_disk_usage: Arc::clone(&self.current_file_disk_usage),
+            disk_usage: Arc::clone(&self.disk_usage),
+        })
+    }
+
+    /// Return the size of this file counted in the disk usage of the
+    /// [`DiskManager`]
+    pub fn current_disk_usage(&self) -> u64 {
+        self.current_file_disk_usage.load(Ordering::Relaxed)
+    }
+
+    /// Update the disk usage of the [`DiskManager`] with the current size
+    /// of this file, which should be called after writing to the file
+    /// without a [`Self::writer`].
+    ///
+    /// Returns the size of the file in bytes, or an error if the total
+    /// size of all temporary files exceeds the
+    /// [`DiskManager::max_temp_directory_size`].
+    pub fn update_disk_usage(&mut self) -> Result<u64> {
+        let size = std::fs::metadata(self.path())?.len();
+        let current = self.current_file_disk_usage.swap(size, Ordering::Relaxed);
+        let used = if size >= current {
+            let grown = size - current;
+            self.disk_usage.used.fetch_add(grown, Ordering::Relaxed) + grown
+        } else {
+            let shrunk = current - size;
+            self.disk_usage.used.fetch_sub(shrunk, Ordering::Relaxed) - shrunk
+        };
+        self.disk_usage.check(used)?;
+        Ok(size)
+    }
+}
+
+impl Drop for RefCountedTempFile {
+    fn drop(&mut self) {
+        self.disk_usage
+            .used
+            .fetch_sub(self.current_disk_usage(), Ordering::Relaxed);
+    }
+}
+
+/// A writer of a [`RefCountedTempFile`] that counts the bytes it writes in
+/// the disk usage of the [`DiskManager`], see [`RefCountedTempFile::writer`]
+#[derive(Debug)]
+pub struct TempFileWriter {
+    file: File,
+    current_file_disk_usage: Arc<AtomicU64>,
+    disk_usage: Arc<DiskUsage>,
+}
+
+impl Write for TempFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len() as u64;
+        self.disk_usage.try_grow(len).map_err(io::Error::other)?;
+        let written = match self.file.write(buf) {
+            Ok(written) => written,
+            Err(e) => {
+                self.disk_usage.used.fetch_sub(len, Ordering::Relaxed);
+                return Err(e);
+            }
+        };
+        // return the bytes that were not written
+        self.disk_usage
+            .used
+            .fetch_sub(len - written as u64, Ordering::Relaxed);
+        self.current_file_disk_usage
+            .fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
 }
 
 /// Setup local dirs by creating one new dir in each of the given dirs
@@ -237,6 +406,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_temp_file_writer_counts_disk_usage() -> Result<()> {
+        let dm = DiskManager::try_new(DiskManagerConfig::NewOs)?;
+        dm.set_max_temp_directory_size(100);
+
+        let file = dm.create_tmp_file("Testing")?;
+        let mut writer = file.writer()?;
+        writer.write_all(&[0; 60])?;
+        assert_eq!(file.current_disk_usage(), 60);
+        assert_eq!(dm.used_disk_space(), 60);
+
+        // the write exceeding the limit fails and is not counted
+        let err = writer.write_all(&[0; 60]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("The temporary files of the DiskManager use 120.0 B which exceeds the limit of 100.0 B"),
+            "{err}"
+        );
+        assert_eq!(std::fs::metadata(file.path())?.len(), 60);
+        assert_eq!(dm.used_disk_space(), 60);
+
+        drop(writer);
+        drop(file);
+        assert_eq!(dm.used_disk_space(), 0);
+        Ok(())
+    }
+
     #[test]
     fn test_disabled_disk_manager() {
         let config = DiskManagerConfig::Disabled;
@@ -311,4 +507,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_max_temp_directory_size() -> Result<()> {
+        let dm = DiskManager::try_new(DiskManagerConfig::new())?;
+        assert_eq!(dm.max_temp_directory_size(), u64::MAX);
+        dm.set_max_temp_directory_size(100);
+
+        let mut file1 = dm.create_tmp_file("Testing")?;
+        std::fs::write(file1.path(), [0u8; 60])?;
+        assert_eq!(file1.update_disk_usage()?, 60);
+        assert_eq!(dm.used_disk_space(), 60);
+
+        // growing a file only accounts for the additional bytes
+        std::fs::write(file1.path(), [0u8; 70])?;
+        assert_eq!(file1.update_disk_usage()?, 70);
+        assert_eq!(dm.used_disk_space(), 70);
+
+        let mut file2 = dm.create_tmp_file("Testing")?;
+        std::fs::write(file2.path(), [0u8; 40])?;
+        assert_eq!(
+            file2.update_disk_usage().unwrap_err().strip_backtrace(),
+            "Resources exhausted: The temporary files of the DiskManager use 110.0 B which exceeds the limit of 100.0 B. Consider increasing the max_temp_directory_size of the DiskManager",
+        );
+
+        // dropping files releases their disk usage
+        drop(file1);
+        assert_eq!(dm.used_disk_space(), 40);
+        drop(file2);
+        assert_eq!(dm.used_disk_space(), 0);
+
+        Ok(())
+    }
 }
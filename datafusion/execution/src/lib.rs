@@ -25,11 +25,14 @@
 
 //! DataFusion execution configuration and runtime structures
 
+pub mod admission;
 pub mod cache;
+pub mod cancellation;
 pub mod config;
 pub mod disk_manager;
 pub mod memory_pool;
 pub mod object_store;
+pub mod query_registry;
 pub mod runtime_env;
 mod stream;
 mod task;
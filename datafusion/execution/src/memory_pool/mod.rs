@@ -140,6 +140,15 @@ pub trait MemoryPool: Send + Sync + std::fmt::Debug {
 
     /// Return the total amount of memory reserved
     fn reserved(&self) -> usize;
+
+    /// Return the [`MemoryConsumer`]s with their reserved bytes, or `None`
+    /// if this pool does not track its consumers.
+    ///
+    /// Only [`TrackConsumersPool`] tracks its consumers; pools wrapping
+    /// another pool return the consumers of the wrapped pool.
+    fn consumers(&self) -> Option<Vec<(MemoryConsumer, usize)>> {
+        None
+    }
 }
 
 /// A memory consumer is a named allocation traced by a particular
@@ -23,6 +23,7 @@ use parking_lot::Mutex;
 use std::{
     num::NonZeroUsize,
     sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    sync::Arc,
 };
 
 /// A [`MemoryPool`] that enforces no limit
@@ -386,6 +387,223 @@ impl<I: MemoryPool> MemoryPool for TrackConsumersPool<I> {
     fn reserved(&self) -> usize {
         self.inner.reserved()
     }
+
+    fn consumers(&self) -> Option<Vec<(MemoryConsumer, usize)>> {
+        let consumers = self.tracked_consumers.lock();
+        let consumers = consumers.iter().map(|(consumer, reserved)| {
+            (consumer.clone(), reserved.load(Ordering::Acquire) as usize)
+        });
+        Some(consumers.collect())
+    }
+}
+
+/// A [`MemoryPool`] that records the peak memory usage of an inner pool
+/// and of each [`MemoryConsumer`] reserving memory through it.
+///
+/// Unlike [`TrackConsumersPool`], consumers are remembered after they are
+/// unregistered, so the peaks can be inspected once a query has completed.
+/// This is used by `EXPLAIN ANALYZE` to report the memory usage of a query.
+#[derive(Debug)]
+pub struct PeakTrackingPool {
+    inner: Arc<dyn MemoryPool>,
+    state: Mutex<PeakTrackingState>,
+}
+
+#[derive(Debug, Default)]
+struct PeakTrackingState {
+    /// Bytes currently reserved through this pool
+    reserved: usize,
+    /// The largest value `reserved` has had
+    peak: usize,
+    /// The current and peak reservation of each consumer
+    consumers: HashMap<MemoryConsumer, (usize, usize)>,
+}
+
+impl PeakTrackingState {
+    fn grow(&mut self, consumer: &MemoryConsumer, additional: usize) {
+        self.reserved += additional;
+        self.peak = self.peak.max(self.reserved);
+        match self.consumers.get_mut(consumer) {
+            Some((current, peak)) => {
+                *current += additional;
+                *peak = (*peak).max(*current);
+            }
+            None => {
+                self.consumers
+                    .insert(consumer.clone(), (additional, additional));
+            }
+        }
+    }
+
+    fn shrink(&mut self, consumer: &MemoryConsumer, shrink: usize) {
+        self.reserved = self.reserved.saturating_sub(shrink);
+        if let Some((current, _)) = self.consumers.get_mut(consumer) {
+            *current = current.saturating_sub(shrink);
+        }
+    }
+}
+
+impl PeakTrackingPool {
+    /// Creates a new [`PeakTrackingPool`] reserving memory from `inner`
+    pub fn new(inner: Arc<dyn MemoryPool>) -> Self {
+        Self {
+            inner,
+            state: Default::default(),
+        }
+    }
+
+    /// Returns the largest number of bytes reserved at the same time through
+    /// this pool
+    pub fn peak_reserved(&self) -> usize {
+        self.state.lock().peak
+    }
+
+    /// Returns up to `top` consumers with the largest peak reservation, along
+    /// with that peak in bytes, largest first
+    pub fn top_consumers(&self, top: usize) -> Vec<(MemoryConsumer, usize)> {
+        let mut consumers = self
+            .state
+            .lock()
+            .consumers
+            .iter()
+            .filter(|(_, (_, peak))| *peak > 0)
+            .map(|(consumer, (_, peak))| (consumer.clone(), *peak))
+            .collect::<Vec<_>>();
+        consumers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name().cmp(b.0.name())));
+        consumers.truncate(top);
+        consumers
+    }
+}
+
+impl MemoryPool for PeakTrackingPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        self.inner.register(consumer);
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        self.inner.unregister(consumer);
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.inner.grow(reservation, additional);
+        self.state.lock().grow(reservation.consumer(), additional);
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.inner.shrink(reservation, shrink);
+        self.state.lock().shrink(reservation.consumer(), shrink);
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        self.inner.try_grow(reservation, additional)?;
+        self.state.lock().grow(reservation.consumer(), additional);
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        self.inner.reserved()
+    }
+
+    fn consumers(&self) -> Option<Vec<(MemoryConsumer, usize)>> {
+        self.inner.consumers()
+    }
+}
+
+/// A [`MemoryPool`] that limits the memory reserved through it to a share
+/// of a parent pool.
+///
+/// Every reservation is also made against the parent, so the parent's limit
+/// still applies to all of its children together, while each child can
+/// reserve at most `limit` bytes. Giving each query its own child of a
+/// global pool prevents one query from starving all others.
+///
+/// Errors for exceeding the limit include the `name` of the child pool,
+/// such as the query it was created for.
+#[derive(Debug)]
+pub struct ChildMemoryPool {
+    name: String,
+    parent: Arc<dyn MemoryPool>,
+    limit: usize,
+    used: AtomicUsize,
+}
+
+impl ChildMemoryPool {
+    /// Creates a new [`ChildMemoryPool`] named `name` that can reserve up
+    /// to `limit` bytes from `parent`
+    pub fn new(
+        name: impl Into<String>,
+        parent: Arc<dyn MemoryPool>,
+        limit: usize,
+    ) -> Self {
+        let name = name.into();
+        debug!("Created new ChildMemoryPool(name={name}, limit={limit})");
+        Self {
+            name,
+            parent,
+            limit,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the name of this pool
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the maximum number of bytes that can be reserved through this pool
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the pool this pool reserves memory from
+    pub fn parent(&self) -> &Arc<dyn MemoryPool> {
+        &self.parent
+    }
+}
+
+impl MemoryPool for ChildMemoryPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        self.parent.register(consumer);
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        self.parent.unregister(consumer);
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.parent.grow(reservation, additional);
+        self.used.fetch_add(additional, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.parent.shrink(reservation, shrink);
+        self.used.fetch_sub(shrink, Ordering::Relaxed);
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                let new_used = used + additional;
+                (new_used <= self.limit).then_some(new_used)
+            })
+            .map_err(|used| {
+                resources_datafusion_err!("Failed to allocate additional {} bytes for {} with {} bytes already allocated for this reservation - {} bytes remain available for {} (limit {} bytes)", additional, reservation.registration.consumer.name, reservation.size, self.limit.saturating_sub(used), self.name, self.limit)
+            })?;
+
+        if let Err(e) = self.parent.try_grow(reservation, additional) {
+            self.used.fetch_sub(additional, Ordering::Relaxed);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    fn consumers(&self) -> Option<Vec<(MemoryConsumer, usize)>> {
+        self.parent.consumers()
+    }
 }
 
 fn provide_top_memory_consumers_to_error_msg(
@@ -511,6 +729,20 @@ mod tests {
             "should provide list of top memory consumers, instead found {:?}",
             res
         );
+
+        // Test: the pool lists all of its consumers
+        let mut consumers = pool
+            .consumers()
+            .unwrap()
+            .into_iter()
+            .map(|(consumer, reserved)| (consumer.name().to_owned(), reserved))
+            .collect::<Vec<_>>();
+        consumers.sort();
+        assert_eq!(
+            consumers,
+            [("r1", 50), ("r2", 15), ("r3", 20), ("r4", 10), ("r5", 0)]
+                .map(|(name, reserved)| (name.to_owned(), reserved))
+        );
     }
 
     #[test]
@@ -686,4 +918,71 @@ mod tests {
             res
         );
     }
+
+    #[test]
+    fn test_peak_tracking_pool() {
+        let inner = Arc::new(GreedyMemoryPool::new(100)) as _;
+        let tracking = Arc::new(PeakTrackingPool::new(Arc::clone(&inner)));
+        let pool = Arc::clone(&tracking) as _;
+
+        let mut r1 = MemoryConsumer::new("r1").register(&pool);
+        let mut r2 = MemoryConsumer::new("r2")
+            .with_can_spill(true)
+            .register(&pool);
+        r1.grow(20);
+        r2.try_grow(50).unwrap();
+        r2.shrink(40);
+        r1.try_grow(30).unwrap();
+        assert_eq!(inner.reserved(), 60);
+        assert_eq!(tracking.reserved(), 60);
+
+        // failed allocations are not tracked
+        r2.try_grow(100).unwrap_err();
+        assert_eq!(tracking.peak_reserved(), 70);
+
+        // consumers are still reported after they are dropped
+        drop(r1);
+        drop(r2);
+        assert_eq!(inner.reserved(), 0);
+        assert_eq!(tracking.peak_reserved(), 70);
+
+        let top = tracking.top_consumers(5);
+        let top = top
+            .iter()
+            .map(|(consumer, peak)| (consumer.name(), *peak))
+            .collect::<Vec<_>>();
+        assert_eq!(top, vec![("r1", 50), ("r2", 50)]);
+        assert_eq!(tracking.top_consumers(1).len(), 1);
+    }
+
+    #[test]
+    fn test_child_pool() {
+        let parent: Arc<dyn MemoryPool> = Arc::new(GreedyMemoryPool::new(100));
+        let q1 = Arc::new(ChildMemoryPool::new("q1", Arc::clone(&parent), 60)) as _;
+        let q2 = Arc::new(ChildMemoryPool::new("q2", Arc::clone(&parent), 60)) as _;
+
+        let mut r1 = MemoryConsumer::new("r1").register(&q1);
+        let mut r2 = MemoryConsumer::new("r2").register(&q2);
+
+        r1.try_grow(50).unwrap();
+        assert_eq!(q1.reserved(), 50);
+        assert_eq!(parent.reserved(), 50);
+
+        // exceeds the limit of q1
+        let err = r1.try_grow(20).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 20 bytes for r1 with 50 bytes already allocated for this reservation - 10 bytes remain available for q1 (limit 60 bytes)");
+        assert_eq!(q1.reserved(), 50);
+
+        // within the limit of q2, but exceeds the parent
+        let err = r2.try_grow(60).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 60 bytes for r2 with 0 bytes already allocated for this reservation - 50 bytes remain available for the total pool");
+        assert_eq!(q2.reserved(), 0);
+
+        r2.try_grow(40).unwrap();
+        assert_eq!(parent.reserved(), 90);
+
+        drop(r1);
+        assert_eq!(q1.reserved(), 0);
+        assert_eq!(parent.reserved(), 40);
+    }
 }
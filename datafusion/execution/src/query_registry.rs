@@ -0,0 +1,510 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`QueryRegistry`]: keeps track of the running and completed queries
+
+use std::collections::{BTreeMap, VecDeque};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::SystemTime;
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use datafusion_common::{DataFusionError, Result};
+use futures::{Stream, StreamExt};
+use parking_lot::Mutex;
+
+use crate::{RecordBatchStream, SendableRecordBatchStream};
+
+/// The number of completed queries a [`QueryRegistry`] remembers by default
+pub const DEFAULT_QUERY_HISTORY_SIZE: usize = 100;
+
+/// Keeps track of the queries of a `SessionContext`.
+///
+/// The registry is shared by the sessions created from the same
+/// `SessionContext` as an extension of their
+/// [`SessionConfig`](crate::config::SessionConfig), and backs the
+/// `information_schema.running_queries` and
+/// `information_schema.query_history` tables.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion_execution::query_registry::{QueryRegistry, QueryStatus};
+/// let registry = Arc::new(QueryRegistry::new(10));
+///
+/// let query = registry.start("session", "SELECT 1");
+/// assert_eq!(registry.running().len(), 1);
+///
+/// // the query is moved to the history once it is dropped
+/// drop(query);
+/// assert!(registry.running().is_empty());
+/// assert_eq!(registry.history()[0].status, QueryStatus::Completed);
+/// ```
+#[derive(Debug)]
+pub struct QueryRegistry {
+    history_size: usize,
+    state: Mutex<RegistryState>,
+}
+
+#[derive(Debug, Default)]
+struct RegistryState {
+    next_query_id: u64,
+    running: BTreeMap<u64, RunningQueryState>,
+    /// Oldest first
+    history: VecDeque<QueryRecord>,
+}
+
+#[derive(Debug)]
+struct RunningQueryState {
+    session_id: String,
+    sql: String,
+    start_time: SystemTime,
+    output_rows: Arc<AtomicUsize>,
+}
+
+/// A snapshot of a running query, see [`QueryRegistry::running`]
+#[derive(Debug, Clone)]
+pub struct RunningQueryInfo {
+    /// Identifies the query within its [`QueryRegistry`]
+    pub query_id: u64,
+    /// The session running the query
+    pub session_id: String,
+    /// The SQL text of the query, or a description of its plan
+    pub sql: String,
+    /// When the query started
+    pub start_time: SystemTime,
+    /// The number of rows the query has produced so far
+    pub output_rows: usize,
+}
+
+/// How a query ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryStatus {
+    /// All output of the query was produced
+    Completed,
+    /// The query returned an error
+    Failed,
+    /// The query was dropped before all of its output was produced
+    Cancelled,
+}
+
+impl std::fmt::Display for QueryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Completed => write!(f, "completed"),
+            Self::Failed => write!(f, "failed"),
+            Self::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// Metrics of a query summed over all of its operators
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryMetrics {
+    /// CPU time spent by the operators, in nanoseconds
+    pub elapsed_compute: usize,
+    /// The number of times the operators spilled
+    pub spill_count: usize,
+    /// The bytes the operators spilled
+    pub spilled_bytes: usize,
+}
+
+/// A completed query, see [`QueryRegistry::history`]
+#[derive(Debug, Clone)]
+pub struct QueryRecord {
+    /// Identifies the query within its [`QueryRegistry`]
+    pub query_id: u64,
+    /// The session that ran the query
+    pub session_id: String,
+    /// The SQL text of the query, or a description of its plan
+    pub sql: String,
+    /// When the query started
+    pub start_time: SystemTime,
+    /// When the query ended
+    pub end_time: SystemTime,
+    /// How the query ended
+    pub status: QueryStatus,
+    /// The error of a failed query
+    pub error: Option<String>,
+    /// The number of rows the query produced
+    pub output_rows: usize,
+    /// The final metrics of the query
+    pub metrics: QueryMetrics,
+}
+
+impl Default for QueryRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUERY_HISTORY_SIZE)
+    }
+}
+
+impl QueryRegistry {
+    /// Create a new registry remembering the last `history_size`
+    /// completed queries
+    pub fn new(history_size: usize) -> Self {
+        Self {
+            history_size,
+            state: Default::default(),
+        }
+    }
+
+    /// Return the number of completed queries this registry remembers
+    pub fn history_size(&self) -> usize {
+        self.history_size
+    }
+
+    /// Register a query of `session_id` that starts running now.
+    ///
+    /// The query is considered running until the returned
+    /// [`TrackedQuery`] is dropped.
+    pub fn start(
+        self: &Arc<Self>,
+        session_id: impl Into<String>,
+        sql: impl Into<String>,
+    ) -> TrackedQuery {
+        let output_rows = Arc::new(AtomicUsize::new(0));
+        let mut state = self.state.lock();
+        let query_id = state.next_query_id;
+        state.next_query_id += 1;
+        state.running.insert(
+            query_id,
+            RunningQueryState {
+                session_id: session_id.into(),
+                sql: sql.into(),
+                start_time: SystemTime::now(),
+                output_rows: Arc::clone(&output_rows),
+            },
+        );
+
+        TrackedQuery {
+            registry: Arc::clone(self),
+            query_id,
+            output_rows,
+            unfinished_streams: AtomicUsize::new(0),
+            error: Mutex::new(None),
+            metrics: None,
+        }
+    }
+
+    /// Return a snapshot of the running queries, in the order they started
+    pub fn running(&self) -> Vec<RunningQueryInfo> {
+        self.state
+            .lock()
+            .running
+            .iter()
+            .map(|(query_id, query)| RunningQueryInfo {
+                query_id: *query_id,
+                session_id: query.session_id.clone(),
+                sql: query.sql.clone(),
+                start_time: query.start_time,
+                output_rows: query.output_rows.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+
+    /// Return the last completed queries, oldest first
+    pub fn history(&self) -> Vec<QueryRecord> {
+        self.state.lock().history.iter().cloned().collect()
+    }
+
+    /// Forget all completed queries
+    pub fn clear_history(&self) {
+        self.state.lock().history.clear();
+    }
+
+    fn finish(
+        &self,
+        query_id: u64,
+        status: QueryStatus,
+        error: Option<String>,
+        metrics: QueryMetrics,
+    ) {
+        let mut state = self.state.lock();
+        let Some(query) = state.running.remove(&query_id) else {
+            return;
+        };
+        if self.history_size == 0 {
+            return;
+        }
+        if state.history.len() == self.history_size {
+            state.history.pop_front();
+        }
+        state.history.push_back(QueryRecord {
+            query_id,
+            session_id: query.session_id,
+            sql: query.sql,
+            start_time: query.start_time,
+            end_time: SystemTime::now(),
+            status,
+            error,
+            output_rows: query.output_rows.load(Ordering::Relaxed),
+            metrics,
+        });
+    }
+}
+
+/// A running query of a [`QueryRegistry`], returned by
+/// [`QueryRegistry::start`].
+///
+/// The output of the query is recorded by the streams returned by
+/// [`Self::wrap_stream`]. When the query is dropped it is moved to the
+/// history of the registry as
+/// * [`QueryStatus::Failed`] if [`Self::fail`] was called or one of its
+///   streams returned an error,
+/// * [`QueryStatus::Completed`] if all of its streams were exhausted,
+/// * [`QueryStatus::Cancelled`] otherwise.
+pub struct TrackedQuery {
+    registry: Arc<QueryRegistry>,
+    query_id: u64,
+    output_rows: Arc<AtomicUsize>,
+    unfinished_streams: AtomicUsize,
+    error: Mutex<Option<String>>,
+    metrics: Option<Box<dyn Fn() -> QueryMetrics + Send + Sync>>,
+}
+
+impl std::fmt::Debug for TrackedQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TrackedQuery")
+            .field("query_id", &self.query_id)
+            .field("output_rows", &self.output_rows)
+            .field("unfinished_streams", &self.unfinished_streams)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TrackedQuery {
+    /// Return the identifier of the query
+    pub fn query_id(&self) -> u64 {
+        self.query_id
+    }
+
+    /// Set the function computing the final metrics of the query once it
+    /// has ended
+    pub fn with_metrics(
+        mut self,
+        metrics: impl Fn() -> QueryMetrics + Send + Sync + 'static,
+    ) -> Self {
+        self.metrics = Some(Box::new(metrics));
+        self
+    }
+
+    /// Record that the query failed with `error`
+    pub fn fail(&self, error: &DataFusionError) {
+        self.error.lock().get_or_insert_with(|| error.to_string());
+    }
+
+    /// Record `rows` rows produced by the query without a stream returned
+    /// by [`Self::wrap_stream`]
+    pub fn record_output_rows(&self, rows: usize) {
+        self.output_rows.fetch_add(rows, Ordering::Relaxed);
+    }
+
+    /// Return a stream that records the rows produced by `stream` as output
+    /// of this query
+    pub fn wrap_stream(
+        self: &Arc<Self>,
+        stream: SendableRecordBatchStream,
+    ) -> SendableRecordBatchStream {
+        self.unfinished_streams.fetch_add(1, Ordering::Relaxed);
+        Box::pin(TrackedStream {
+            inner: stream,
+            query: Some(Arc::clone(self)),
+        })
+    }
+}
+
+impl Drop for TrackedQuery {
+    fn drop(&mut self) {
+        let error = self.error.lock().take();
+        let status = if error.is_some() {
+            QueryStatus::Failed
+        } else if self.unfinished_streams.load(Ordering::Relaxed) == 0 {
+            QueryStatus::Completed
+        } else {
+            QueryStatus::Cancelled
+        };
+        let metrics = self.metrics.as_ref().map(|f| f()).unwrap_or_default();
+        self.registry.finish(self.query_id, status, error, metrics);
+    }
+}
+
+/// A stream of a tracked query, see [`TrackedQuery::wrap_stream`]
+struct TrackedStream {
+    inner: SendableRecordBatchStream,
+    query: Option<Arc<TrackedQuery>>,
+}
+
+impl Stream for TrackedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.inner.poll_next_unpin(cx);
+        if let Some(query) = &self.query {
+            match &poll {
+                Poll::Ready(Some(Ok(batch))) => {
+                    query.record_output_rows(batch.num_rows());
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    query.fail(e);
+                    self.query = None;
+                }
+                Poll::Ready(None) => {
+                    query.unfinished_streams.fetch_sub(1, Ordering::Relaxed);
+                    self.query = None;
+                }
+                Poll::Pending => {}
+            }
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl RecordBatchStream for TrackedStream {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::exec_datafusion_err;
+    use futures::FutureExt;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]))
+    }
+
+    fn batch(rows: i32) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_new(
+            schema(),
+            vec![Arc::new(Int32Array::from_iter_values(0..rows))],
+        )?)
+    }
+
+    struct TestStream(futures::stream::Iter<std::vec::IntoIter<Result<RecordBatch>>>);
+
+    impl Stream for TestStream {
+        type Item = Result<RecordBatch>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            self.0.poll_next_unpin(cx)
+        }
+    }
+
+    impl RecordBatchStream for TestStream {
+        fn schema(&self) -> SchemaRef {
+            schema()
+        }
+    }
+
+    fn batches_stream(batches: Vec<Result<RecordBatch>>) -> SendableRecordBatchStream {
+        Box::pin(TestStream(futures::stream::iter(batches)))
+    }
+
+    fn drain(stream: SendableRecordBatchStream) -> Vec<Result<RecordBatch>> {
+        stream.collect::<Vec<_>>().now_or_never().unwrap()
+    }
+
+    #[test]
+    fn test_completed_query() {
+        let registry = Arc::new(QueryRegistry::default());
+        let query = Arc::new(registry.start("session", "SELECT a FROM t").with_metrics(
+            || QueryMetrics {
+                elapsed_compute: 10,
+                spill_count: 1,
+                spilled_bytes: 100,
+            },
+        ));
+
+        let mut stream = query.wrap_stream(batches_stream(vec![batch(3), batch(4)]));
+        stream.next().now_or_never().unwrap().unwrap().unwrap();
+
+        let running = registry.running();
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].session_id, "session");
+        assert_eq!(running[0].sql, "SELECT a FROM t");
+        assert_eq!(running[0].output_rows, 3);
+
+        assert_eq!(drain(stream).len(), 1);
+        drop(query);
+
+        assert!(registry.running().is_empty());
+        let history = registry.history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, QueryStatus::Completed);
+        assert_eq!(history[0].output_rows, 7);
+        assert_eq!(history[0].error, None);
+        assert_eq!(history[0].metrics.spilled_bytes, 100);
+        assert!(history[0].end_time >= history[0].start_time);
+    }
+
+    #[test]
+    fn test_failed_and_cancelled_queries() {
+        let registry = Arc::new(QueryRegistry::default());
+        let query = Arc::new(registry.start("session", "failing"));
+        let stream = batches_stream(vec![batch(1), Err(exec_datafusion_err!("boom"))]);
+        drain(query.wrap_stream(stream));
+        drop(query);
+
+        let query = Arc::new(registry.start("session", "cancelled"));
+        let stream = query.wrap_stream(batches_stream(vec![batch(1)]));
+        drop(query);
+        // the query runs until its last stream is dropped
+        assert_eq!(registry.running().len(), 1);
+        drop(stream);
+
+        let history = registry.history();
+        assert_eq!(history[0].sql, "failing");
+        assert_eq!(history[0].status, QueryStatus::Failed);
+        assert_eq!(history[0].error.as_deref(), Some("Execution error: boom"));
+        assert_eq!(history[0].output_rows, 1);
+        assert_eq!(history[1].sql, "cancelled");
+        assert_eq!(history[1].status, QueryStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_history_size() {
+        let registry = Arc::new(QueryRegistry::new(2));
+        for sql in ["q1", "q2", "q3"] {
+            drop(registry.start("session", sql));
+        }
+        let history = registry.history();
+        let sqls: Vec<_> = history.iter().map(|query| query.sql.as_str()).collect();
+        assert_eq!(sqls, ["q2", "q3"]);
+        assert_eq!(history[1].query_id, 2);
+
+        registry.clear_history();
+        assert!(registry.history().is_empty());
+    }
+}
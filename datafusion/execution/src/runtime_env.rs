@@ -19,6 +19,7 @@
 //! store, memory manager, disk manager.
 
 use crate::{
+    admission::{AdmissionConfig, AdmissionController, AdmissionMemoryPool},
     disk_manager::{DiskManager, DiskManagerConfig},
     memory_pool::{
         GreedyMemoryPool, MemoryPool, TrackConsumersPool, UnboundedMemoryPool,
@@ -76,6 +77,8 @@ pub struct RuntimeEnv {
     pub cache_manager: Arc<CacheManager>,
     /// Object Store Registry
     pub object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    /// Limits the queries that run at the same time, if any
+    pub admission_controller: Option<Arc<AdmissionController>>,
 }
 
 impl Debug for RuntimeEnv {
@@ -180,6 +183,14 @@ pub struct RuntimeEnvBuilder {
     pub cache_manager: CacheManagerConfig,
     /// ObjectStoreRegistry to get object store based on url
     pub object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    /// Maximum total size of temporary files in bytes
+    ///
+    /// Defaults to no limit if `None`
+    pub max_temp_directory_size: Option<u64>,
+    /// Limits of the queries that run at the same time
+    ///
+    /// Defaults to no admission control if `None`
+    pub admission_config: Option<AdmissionConfig>,
 }
 
 impl Default for RuntimeEnvBuilder {
@@ -196,6 +207,8 @@ impl RuntimeEnvBuilder {
             memory_pool: Default::default(),
             cache_manager: Default::default(),
             object_store_registry: Arc::new(DefaultObjectStoreRegistry::default()),
+            max_temp_directory_size: None,
+            admission_config: None,
         }
     }
 
@@ -245,6 +258,28 @@ impl RuntimeEnvBuilder {
         self.with_disk_manager(DiskManagerConfig::new_specified(vec![path.into()]))
     }
 
+    /// Limit the total size of temporary files, such as spill files, to
+    /// `max_size` bytes. Queries fail once their temporary files would
+    /// exceed the limit.
+    ///
+    /// If the disk manager is an existing [`DiskManager`], its limit is
+    /// changed for all its users.
+    pub fn with_max_temp_directory_size(mut self, max_size: u64) -> Self {
+        self.max_temp_directory_size = Some(max_size);
+        self
+    }
+
+    /// Limit the queries that run at the same time with an
+    /// [`AdmissionController`] shared by all users of the [`RuntimeEnv`]
+    ///
+    /// If `config` limits the reserved memory, the memory pool is wrapped in
+    /// an [`AdmissionMemoryPool`] so that queued queries are admitted as
+    /// soon as enough memory is freed.
+    pub fn with_admission_control(mut self, config: AdmissionConfig) -> Self {
+        self.admission_config = Some(config);
+        self
+    }
+
     /// Build a RuntimeEnv
     pub fn build(self) -> Result<RuntimeEnv> {
         let Self {
@@ -252,15 +287,35 @@ impl RuntimeEnvBuilder {
             memory_pool,
             cache_manager,
             object_store_registry,
+            max_temp_directory_size,
+            admission_config,
         } = self;
-        let memory_pool =
+        let mut memory_pool =
             memory_pool.unwrap_or_else(|| Arc::new(UnboundedMemoryPool::default()));
+        let disk_manager = DiskManager::try_new(disk_manager)?;
+        if let Some(max_size) = max_temp_directory_size {
+            disk_manager.set_max_temp_directory_size(max_size);
+        }
+        let admission_controller = admission_config.map(|config| {
+            let limits_memory = config.max_reserved_memory.is_some();
+            let controller =
+                Arc::new(AdmissionController::new(config, Arc::clone(&memory_pool)));
+            if limits_memory {
+                // admit the queries waiting for memory as soon as it is freed
+                memory_pool = Arc::new(AdmissionMemoryPool::new(
+                    Arc::clone(&memory_pool),
+                    &controller,
+                ));
+            }
+            controller
+        });
 
         Ok(RuntimeEnv {
             memory_pool,
-            disk_manager: DiskManager::try_new(disk_manager)?,
+            disk_manager,
             cache_manager: CacheManager::try_new(&cache_manager)?,
             object_store_registry,
+            admission_controller,
         })
     }
 
@@ -16,8 +16,8 @@
 // under the License.
 
 use crate::{
-    config::SessionConfig, memory_pool::MemoryPool, registry::FunctionRegistry,
-    runtime_env::RuntimeEnv,
+    cancellation::CancellationToken, config::SessionConfig, memory_pool::MemoryPool,
+    registry::FunctionRegistry, runtime_env::RuntimeEnv,
 };
 use datafusion_common::{plan_datafusion_err, DataFusionError, Result};
 use datafusion_expr::planner::ExprPlanner;
@@ -48,6 +48,8 @@ pub struct TaskContext {
     window_functions: HashMap<String, Arc<WindowUDF>>,
     /// Runtime environment associated with this task context
     runtime: Arc<RuntimeEnv>,
+    /// Token used to cancel the execution of this task
+    cancellation: CancellationToken,
 }
 
 impl Default for TaskContext {
@@ -63,6 +65,7 @@ impl Default for TaskContext {
             aggregate_functions: HashMap::new(),
             window_functions: HashMap::new(),
             runtime,
+            cancellation: CancellationToken::new(),
         }
     }
 }
@@ -70,6 +73,11 @@ impl Default for TaskContext {
 impl TaskContext {
     /// Create a new [`TaskContext`] instance.
     ///
+    /// If the `session_config` has a [`statement_timeout`], the task is
+    /// cancelled once the timeout has elapsed from now.
+    ///
+    /// [`statement_timeout`]: SessionConfig::statement_timeout
+    ///
     /// Most users will use [`SessionContext::task_ctx`] to create [`TaskContext`]s
     ///
     /// [`SessionContext::task_ctx`]: https://docs.rs/datafusion/latest/datafusion/execution/context/struct.SessionContext.html#method.task_ctx
@@ -82,6 +90,10 @@ impl TaskContext {
         window_functions: HashMap<String, Arc<WindowUDF>>,
         runtime: Arc<RuntimeEnv>,
     ) -> Self {
+        let cancellation = match session_config.statement_timeout() {
+            Some(timeout) => CancellationToken::new().child_token_with_timeout(timeout),
+            None => CancellationToken::new(),
+        };
         Self {
             task_id,
             session_id,
@@ -90,6 +102,7 @@ impl TaskContext {
             aggregate_functions,
             window_functions,
             runtime,
+            cancellation,
         }
     }
 
@@ -141,6 +154,18 @@ impl TaskContext {
         self.runtime = runtime;
         self
     }
+
+    /// Return the [`CancellationToken`] operators check to stop executing
+    /// once the task is cancelled or has timed out
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Update the [`CancellationToken`]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
 }
 
 impl FunctionRegistry for TaskContext {
@@ -208,8 +233,9 @@ mod tests {
     use super::*;
     use datafusion_common::{
         config::{ConfigExtension, ConfigOptions, Extensions},
-        extensions_options,
+        extensions_options, CancellationReason,
     };
+    use std::time::Duration;
 
     extensions_options! {
         struct TestExtension {
@@ -287,4 +313,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn task_context_statement_timeout() {
+        let task_context = |session_config| {
+            TaskContext::new(
+                None,
+                "session_id".to_string(),
+                session_config,
+                HashMap::default(),
+                HashMap::default(),
+                HashMap::default(),
+                Arc::new(RuntimeEnv::default()),
+            )
+        };
+
+        let task_context_without_timeout = task_context(SessionConfig::new());
+        assert!(task_context_without_timeout.cancellation().check().is_ok());
+
+        let session_config =
+            SessionConfig::new().with_statement_timeout(Duration::from_millis(1));
+        let task_context = task_context(session_config);
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(
+            task_context.cancellation().reason(),
+            Some(CancellationReason::Timeout(Duration::from_millis(1)))
+        );
+    }
 }
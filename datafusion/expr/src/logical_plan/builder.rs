@@ -51,6 +51,7 @@ use arrow::compute::can_cast_types;
 use arrow::datatypes::{DataType, Field, Fields, Schema, SchemaRef};
 use datafusion_common::display::ToStringifiedPlan;
 use datafusion_common::file_options::file_type::FileType;
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::{
     exec_err, get_target_functional_dependencies, internal_err, not_impl_err,
     plan_datafusion_err, plan_err, Column, Constraints, DFSchema, DFSchemaRef,
@@ -1186,6 +1187,18 @@ impl LogicalPlanBuilder {
     ///
     /// if `verbose` is true, prints out additional details.
     pub fn explain(self, verbose: bool, analyze: bool) -> Result<Self> {
+        self.explain_with_format(verbose, analyze, ExplainFormat::Indent)
+    }
+
+    /// Create an expression to represent the explanation of the plan, like
+    /// [`Self::explain`], printing the final plans in the given
+    /// [`ExplainFormat`]
+    pub fn explain_with_format(
+        self,
+        verbose: bool,
+        analyze: bool,
+        explain_format: ExplainFormat,
+    ) -> Result<Self> {
         let schema = LogicalPlan::explain_schema();
         let schema = schema.to_dfschema_ref()?;
 
@@ -1194,6 +1207,7 @@ impl LogicalPlanBuilder {
                 verbose,
                 input: self.plan,
                 schema,
+                explain_format,
             })))
         } else {
             let stringified_plans =
@@ -1205,6 +1219,7 @@ impl LogicalPlanBuilder {
                 stringified_plans,
                 schema,
                 logical_optimization_succeeded: false,
+                explain_format,
             })))
         }
     }
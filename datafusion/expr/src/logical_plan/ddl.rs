@@ -41,6 +41,10 @@ pub enum DdlStatement {
     CreateMemoryTable(CreateMemoryTable),
     /// Creates a new view.
     CreateView(CreateView),
+    /// Creates a new materialized view.
+    CreateMaterializedView(CreateMaterializedView),
+    /// Recomputes the data of a materialized view.
+    RefreshMaterializedView(RefreshMaterializedView),
     /// Creates a new catalog schema.
     CreateCatalogSchema(CreateCatalogSchema),
     /// Creates a new catalog (aka "Database").
@@ -67,7 +71,15 @@ impl DdlStatement {
                 schema
             }
             DdlStatement::CreateMemoryTable(CreateMemoryTable { input, .. })
-            | DdlStatement::CreateView(CreateView { input, .. }) => input.schema(),
+            | DdlStatement::CreateView(CreateView { input, .. })
+            | DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => input.schema(),
+            DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                schema,
+                ..
+            }) => schema,
             DdlStatement::CreateCatalogSchema(CreateCatalogSchema { schema, .. }) => {
                 schema
             }
@@ -88,6 +100,8 @@ impl DdlStatement {
             DdlStatement::CreateExternalTable(_) => "CreateExternalTable",
             DdlStatement::CreateMemoryTable(_) => "CreateMemoryTable",
             DdlStatement::CreateView(_) => "CreateView",
+            DdlStatement::CreateMaterializedView(_) => "CreateMaterializedView",
+            DdlStatement::RefreshMaterializedView(_) => "RefreshMaterializedView",
             DdlStatement::CreateCatalogSchema(_) => "CreateCatalogSchema",
             DdlStatement::CreateCatalog(_) => "CreateCatalog",
             DdlStatement::CreateIndex(_) => "CreateIndex",
@@ -109,6 +123,11 @@ impl DdlStatement {
                 vec![input]
             }
             DdlStatement::CreateView(CreateView { input, .. }) => vec![input],
+            DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => vec![input],
+            DdlStatement::RefreshMaterializedView(_) => vec![],
             DdlStatement::CreateIndex(_) => vec![],
             DdlStatement::DropTable(_) => vec![],
             DdlStatement::DropView(_) => vec![],
@@ -153,6 +172,18 @@ impl DdlStatement {
                     DdlStatement::CreateView(CreateView { name, .. }) => {
                         write!(f, "CreateView: {name:?}")
                     }
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        ..
+                    }) => {
+                        write!(f, "CreateMaterializedView: {name:?}")
+                    }
+                    DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                        name,
+                        ..
+                    }) => {
+                        write!(f, "RefreshMaterializedView: {name:?}")
+                    }
                     DdlStatement::CreateCatalogSchema(CreateCatalogSchema {
                         schema_name,
                         ..
@@ -211,6 +242,10 @@ pub struct CreateExternalTable {
     pub file_type: String,
     /// Partition Columns
     pub table_partition_cols: Vec<String>,
+    /// Bucket columns of `CLUSTERED BY`
+    pub clustered_by: Vec<String>,
+    /// Number of buckets of `CLUSTERED BY`
+    pub num_buckets: Option<usize>,
     /// Option to not error if table already exists
     pub if_not_exists: bool,
     /// Whether the table is a temporary table
@@ -237,6 +272,8 @@ impl Hash for CreateExternalTable {
         self.location.hash(state);
         self.file_type.hash(state);
         self.table_partition_cols.hash(state);
+        self.clustered_by.hash(state);
+        self.num_buckets.hash(state);
         self.if_not_exists.hash(state);
         self.definition.hash(state);
         self.order_exprs.hash(state);
@@ -259,6 +296,10 @@ impl PartialOrd for CreateExternalTable {
             pub file_type: &'a String,
             /// Partition Columns
             pub table_partition_cols: &'a Vec<String>,
+            /// Bucket columns of `CLUSTERED BY`
+            pub clustered_by: &'a Vec<String>,
+            /// Number of buckets of `CLUSTERED BY`
+            pub num_buckets: &'a Option<usize>,
             /// Option to not error if table already exists
             pub if_not_exists: &'a bool,
             /// SQL used to create the table, if available
@@ -275,6 +316,8 @@ impl PartialOrd for CreateExternalTable {
             location: &self.location,
             file_type: &self.file_type,
             table_partition_cols: &self.table_partition_cols,
+            clustered_by: &self.clustered_by,
+            num_buckets: &self.num_buckets,
             if_not_exists: &self.if_not_exists,
             definition: &self.definition,
             order_exprs: &self.order_exprs,
@@ -286,6 +329,8 @@ impl PartialOrd for CreateExternalTable {
             location: &other.location,
             file_type: &other.file_type,
             table_partition_cols: &other.table_partition_cols,
+            clustered_by: &other.clustered_by,
+            num_buckets: &other.num_buckets,
             if_not_exists: &other.if_not_exists,
             definition: &other.definition,
             order_exprs: &other.order_exprs,
@@ -330,6 +375,44 @@ pub struct CreateView {
     pub temporary: bool,
 }
 
+/// Creates a materialized view: a view whose results are computed when it is
+/// created or refreshed, and stored like a table.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash)]
+pub struct CreateMaterializedView {
+    /// The view name
+    pub name: TableReference,
+    /// The logical plan of the query defining the view
+    pub input: Arc<LogicalPlan>,
+    /// Option to not error if the view already exists
+    pub if_not_exists: bool,
+    /// Option to replace the view if it already exists
+    pub or_replace: bool,
+    /// SQL used to create the view, if available
+    pub definition: Option<String>,
+    /// File type of the files storing the results, if they are not stored
+    /// in memory
+    pub file_type: Option<String>,
+    /// Location of the files storing the results, if they are not stored
+    /// in memory
+    pub location: Option<String>,
+}
+
+/// Recomputes the data of a materialized view.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RefreshMaterializedView {
+    /// The view name
+    pub name: TableReference,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for RefreshMaterializedView {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.name.partial_cmp(&other.name)
+    }
+}
+
 /// Creates a catalog (aka "Database").
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateCatalog {
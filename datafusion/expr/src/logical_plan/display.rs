@@ -29,7 +29,7 @@ use crate::{
 
 use crate::dml::CopyTo;
 use arrow::datatypes::Schema;
-use datafusion_common::display::GraphvizBuilder;
+use datafusion_common::display::{GraphvizBuilder, TreeRenderNode};
 use datafusion_common::tree_node::{TreeNodeRecursion, TreeNodeVisitor};
 use datafusion_common::{Column, DataFusionError};
 use serde_json::json;
@@ -230,6 +230,56 @@ impl<'n> TreeNodeVisitor<'n> for GraphvizVisitor<'_, '_> {
     }
 }
 
+/// Builds a [`TreeRenderNode`] for each node of a plan, to draw the plan as a
+/// tree of boxes
+#[derive(Debug, Default)]
+pub struct TreeRenderVisitor {
+    /// The nodes whose children are being visited
+    parents: Vec<TreeRenderNode>,
+    /// The root of the plan, once it has been visited
+    root: Option<TreeRenderNode>,
+}
+
+impl TreeRenderVisitor {
+    /// Create a visitor without any visited nodes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the root of the visited plan, if any
+    pub fn into_root(self) -> Option<TreeRenderNode> {
+        self.root
+    }
+}
+
+impl<'n> TreeNodeVisitor<'n> for TreeRenderVisitor {
+    type Node = LogicalPlan;
+
+    fn f_down(
+        &mut self,
+        plan: &'n LogicalPlan,
+    ) -> datafusion_common::Result<TreeNodeRecursion> {
+        let description = plan.display().to_string();
+        self.parents
+            .push(TreeRenderNode::from_description(&description, None));
+        Ok(TreeNodeRecursion::Continue)
+    }
+
+    fn f_up(
+        &mut self,
+        _plan: &'n LogicalPlan,
+    ) -> datafusion_common::Result<TreeNodeRecursion> {
+        let node = self.parents.pop().ok_or_else(|| {
+            DataFusionError::Internal("Missing current node!".to_string())
+        })?;
+        match self.parents.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => self.root = Some(node),
+        }
+        Ok(TreeNodeRecursion::Continue)
+    }
+}
+
 /// Formats plans to display as postgresql plan json format.
 ///
 /// There are already many existing visualizer for this format, for example [dalibo](https://explain.dalibo.com/).
@@ -32,8 +32,9 @@ pub use builder::{
 };
 pub use ddl::{
     CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateFunction,
-    CreateFunctionBody, CreateIndex, CreateMemoryTable, CreateView, DdlStatement,
-    DropCatalogSchema, DropFunction, DropTable, DropView, OperateFunctionArg,
+    CreateFunctionBody, CreateIndex, CreateMaterializedView, CreateMemoryTable,
+    CreateView, DdlStatement, DropCatalogSchema, DropFunction, DropTable, DropView,
+    OperateFunctionArg, RefreshMaterializedView,
 };
 pub use dml::{DmlStatement, WriteOp};
 pub use plan::{
@@ -34,7 +34,7 @@ use crate::expr::{Placeholder, Sort as SortExpr, WindowFunction, WindowFunctionP
 use crate::expr_rewriter::{
     create_col_from_scalar_expr, normalize_cols, normalize_sorts, NamePreserver,
 };
-use crate::logical_plan::display::{GraphvizVisitor, IndentVisitor};
+use crate::logical_plan::display::{GraphvizVisitor, IndentVisitor, TreeRenderVisitor};
 use crate::logical_plan::extension::UserDefinedLogicalNode;
 use crate::logical_plan::{DmlStatement, Statement};
 use crate::utils::{
@@ -43,13 +43,15 @@ use crate::utils::{
     split_conjunction,
 };
 use crate::{
-    build_join_schema, expr_vec_fmt, BinaryExpr, CreateMemoryTable, CreateView, Execute,
-    Expr, ExprSchemable, LogicalPlanBuilder, Operator, Prepare,
-    TableProviderFilterPushDown, TableSource, WindowFunctionDefinition,
+    build_join_schema, expr_vec_fmt, BinaryExpr, CreateMaterializedView,
+    CreateMemoryTable, CreateView, Execute, Expr, ExprSchemable, LogicalPlanBuilder,
+    Operator, Prepare, TableProviderFilterPushDown, TableSource,
+    WindowFunctionDefinition,
 };
 
 use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
 use datafusion_common::cse::{NormalizeEq, Normalizeable};
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::tree_node::{
     Transformed, TreeNode, TreeNodeContainer, TreeNodeRecursion,
 };
@@ -1015,6 +1017,31 @@ impl LogicalPlan {
                     definition: definition.clone(),
                 })))
             }
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                CreateMaterializedView {
+                    name,
+                    if_not_exists,
+                    or_replace,
+                    definition,
+                    file_type,
+                    location,
+                    ..
+                },
+            )) => {
+                self.assert_no_expressions(expr)?;
+                let input = self.only_input(inputs)?;
+                Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                    CreateMaterializedView {
+                        input: Arc::new(input),
+                        name: name.clone(),
+                        if_not_exists: *if_not_exists,
+                        or_replace: *or_replace,
+                        definition: definition.clone(),
+                        file_type: file_type.clone(),
+                        location: location.clone(),
+                    },
+                )))
+            }
             LogicalPlan::Extension(e) => Ok(LogicalPlan::Extension(Extension {
                 node: e.node.with_exprs_and_inputs(expr, inputs)?,
             })),
@@ -1077,6 +1104,7 @@ impl LogicalPlan {
                     verbose: a.verbose,
                     schema: Arc::clone(&a.schema),
                     input: Arc::new(input),
+                    explain_format: a.explain_format,
                 }))
             }
             LogicalPlan::Explain(e) => {
@@ -1088,6 +1116,7 @@ impl LogicalPlan {
                     stringified_plans: e.stringified_plans.clone(),
                     schema: Arc::clone(&e.schema),
                     logical_optimization_succeeded: e.logical_optimization_succeeded,
+                    explain_format: e.explain_format,
                 }))
             }
             LogicalPlan::Statement(Statement::Prepare(Prepare {
@@ -1627,6 +1656,53 @@ impl LogicalPlan {
         Wrapper(self)
     }
 
+    /// Return a `format`able structure that draws the plan as a tree of boxes,
+    /// one per node, with the details of each node inside its box.
+    ///
+    /// ```
+    /// use arrow::datatypes::{Field, Schema, DataType};
+    /// use datafusion_expr::{lit, col, LogicalPlanBuilder, logical_plan::table_scan};
+    /// let schema = Schema::new(vec![
+    ///     Field::new("id", DataType::Int32, false),
+    /// ]);
+    /// let plan = table_scan(Some("t1"), &schema, None).unwrap()
+    ///     .filter(col("id").eq(lit(5))).unwrap()
+    ///     .build().unwrap();
+    ///
+    /// let display_string = format!("{}", plan.display_tree());
+    ///
+    /// assert_eq!("\
+    /// ┌───────────────────────────┐
+    /// │           Filter          │
+    /// │    ────────────────────   │
+    /// │      t1.id = Int32(5)     │
+    /// └─────────────┬─────────────┘
+    /// ┌─────────────┴─────────────┐
+    /// │         TableScan         │
+    /// │    ────────────────────   │
+    /// │             t1            │
+    /// └───────────────────────────┘
+    /// ", display_string);
+    /// ```
+    pub fn display_tree(&self) -> impl Display + '_ {
+        // Boilerplate structure to wrap LogicalPlan with something
+        // that that can be formatted
+        struct Wrapper<'a>(&'a LogicalPlan);
+        impl Display for Wrapper<'_> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let mut visitor = TreeRenderVisitor::new();
+                self.0
+                    .visit_with_subqueries(&mut visitor)
+                    .map_err(|_| fmt::Error)?;
+                match visitor.into_root() {
+                    Some(root) => write!(f, "{root}"),
+                    None => Ok(()),
+                }
+            }
+        }
+        Wrapper(self)
+    }
+
     /// Return a `format`able structure that produces lines meant for
     /// graphical display using the `DOT` language. This format can be
     /// visualized using software from
@@ -2034,6 +2110,23 @@ impl ToStringifiedPlan for LogicalPlan {
     }
 }
 
+impl LogicalPlan {
+    /// Create a stringified plan with the specified type in the given
+    /// [`ExplainFormat`]
+    pub fn to_stringified_with_format(
+        &self,
+        plan_type: PlanType,
+        format: ExplainFormat,
+    ) -> StringifiedPlan {
+        let plan = match format {
+            ExplainFormat::Indent => self.display_indent().to_string(),
+            ExplainFormat::Tree => self.display_tree().to_string(),
+            ExplainFormat::Json => self.display_pg_json().to_string(),
+        };
+        StringifiedPlan::new(plan_type, plan)
+    }
+}
+
 /// Produces no rows: An empty relation with an empty schema
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EmptyRelation {
@@ -2938,6 +3031,8 @@ pub struct Explain {
     pub schema: DFSchemaRef,
     /// Used by physical planner to check if should proceed with planning
     pub logical_optimization_succeeded: bool,
+    /// The format of the final logical and physical plans
+    pub explain_format: ExplainFormat,
 }
 
 // Manual implementation needed because of `schema` field. Comparison excludes this field.
@@ -2953,18 +3048,22 @@ impl PartialOrd for Explain {
             pub stringified_plans: &'a Vec<StringifiedPlan>,
             /// Used by physical planner to check if should proceed with planning
             pub logical_optimization_succeeded: &'a bool,
+            /// The format of the final logical and physical plans
+            pub explain_format: &'a ExplainFormat,
         }
         let comparable_self = ComparableExplain {
             verbose: &self.verbose,
             plan: &self.plan,
             stringified_plans: &self.stringified_plans,
             logical_optimization_succeeded: &self.logical_optimization_succeeded,
+            explain_format: &self.explain_format,
         };
         let comparable_other = ComparableExplain {
             verbose: &other.verbose,
             plan: &other.plan,
             stringified_plans: &other.stringified_plans,
             logical_optimization_succeeded: &other.logical_optimization_succeeded,
+            explain_format: &other.explain_format,
         };
         comparable_self.partial_cmp(&comparable_other)
     }
@@ -2980,6 +3079,8 @@ pub struct Analyze {
     pub input: Arc<LogicalPlan>,
     /// The output schema of the explain (2 columns of text)
     pub schema: DFSchemaRef,
+    /// The format of the plan with metrics
+    pub explain_format: ExplainFormat,
 }
 
 // Manual implementation needed because of `schema` field. Comparison excludes this field.
@@ -38,11 +38,11 @@
 //! * [`LogicalPlan::expressions`]: Return a copy of the plan's expressions
 
 use crate::{
-    dml::CopyTo, Aggregate, Analyze, CreateMemoryTable, CreateView, DdlStatement,
-    Distinct, DistinctOn, DmlStatement, Execute, Explain, Expr, Extension, Filter, Join,
-    Limit, LogicalPlan, Partitioning, Prepare, Projection, RecursiveQuery, Repartition,
-    Sort, Statement, Subquery, SubqueryAlias, TableScan, Union, Unnest,
-    UserDefinedLogicalNode, Values, Window,
+    dml::CopyTo, Aggregate, Analyze, CreateMaterializedView, CreateMemoryTable,
+    CreateView, DdlStatement, Distinct, DistinctOn, DmlStatement, Execute, Explain, Expr,
+    Extension, Filter, Join, Limit, LogicalPlan, Partitioning, Prepare, Projection,
+    RecursiveQuery, Repartition, Sort, Statement, Subquery, SubqueryAlias, TableScan,
+    Union, Unnest, UserDefinedLogicalNode, Values, Window,
 };
 use datafusion_common::tree_node::TreeNodeRefContainer;
 
@@ -206,6 +206,7 @@ impl TreeNode for LogicalPlan {
                 stringified_plans,
                 schema,
                 logical_optimization_succeeded,
+                explain_format,
             }) => plan.map_elements(f)?.update_data(|plan| {
                 LogicalPlan::Explain(Explain {
                     verbose,
@@ -213,17 +214,20 @@ impl TreeNode for LogicalPlan {
                     stringified_plans,
                     schema,
                     logical_optimization_succeeded,
+                    explain_format,
                 })
             }),
             LogicalPlan::Analyze(Analyze {
                 verbose,
                 input,
                 schema,
+                explain_format,
             }) => input.map_elements(f)?.update_data(|input| {
                 LogicalPlan::Analyze(Analyze {
                     verbose,
                     input,
                     schema,
+                    explain_format,
                 })
             }),
             LogicalPlan::Dml(DmlStatement {
@@ -292,6 +296,25 @@ impl TreeNode for LogicalPlan {
                             temporary,
                         })
                     }),
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        input,
+                        if_not_exists,
+                        or_replace,
+                        definition,
+                        file_type,
+                        location,
+                    }) => input.map_elements(f)?.update_data(|input| {
+                        DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                            name,
+                            input,
+                            if_not_exists,
+                            or_replace,
+                            definition,
+                            file_type,
+                            location,
+                        })
+                    }),
                     // no inputs in these statements
                     DdlStatement::CreateExternalTable(_)
                     | DdlStatement::CreateCatalogSchema(_)
@@ -299,6 +322,7 @@ impl TreeNode for LogicalPlan {
                     | DdlStatement::CreateIndex(_)
                     | DdlStatement::DropTable(_)
                     | DdlStatement::DropView(_)
+                    | DdlStatement::RefreshMaterializedView(_)
                     | DdlStatement::DropCatalogSchema(_)
                     | DdlStatement::CreateFunction(_)
                     | DdlStatement::DropFunction(_) => Transformed::no(ddl),
@@ -28,8 +28,8 @@ use datafusion_common::{
 use sqlparser::ast::{self, NullTreatment};
 
 use crate::{
-    AggregateUDF, Expr, GetFieldAccess, ScalarUDF, SortExpr, TableSource, WindowFrame,
-    WindowFunctionDefinition, WindowUDF,
+    AggregateUDF, Expr, GetFieldAccess, ScalarUDF, SortExpr, TableSource, TableVersion,
+    WindowFrame, WindowFunctionDefinition, WindowUDF,
 };
 
 /// Provides the `SQL` query planner meta-data about tables and
@@ -41,6 +41,16 @@ pub trait ContextProvider {
     /// Returns a table by reference, if it exists
     fn get_table_source(&self, name: TableReference) -> Result<Arc<dyn TableSource>>;
 
+    /// Returns the previous `version` of a table by reference, as read by
+    /// `FOR SYSTEM_TIME AS OF` in SQL
+    fn get_table_source_at_version(
+        &self,
+        _name: TableReference,
+        version: &TableVersion,
+    ) -> Result<Arc<dyn TableSource>> {
+        not_impl_err!("Reading the {version} of a table is not supported")
+    }
+
     /// Return the type of a file based on its extension (e.g. `.parquet`)
     ///
     /// This is used to plan `COPY` statements
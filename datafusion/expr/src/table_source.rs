@@ -71,6 +71,28 @@ impl std::fmt::Display for TableType {
     }
 }
 
+/// A previous version of a table, read with `FOR SYSTEM_TIME AS OF` in SQL
+/// or [`TableProvider::at_version`].
+///
+/// [`TableProvider::at_version`]: https://docs.rs/datafusion/latest/datafusion/datasource/provider/trait.TableProvider.html#method.at_version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TableVersion {
+    /// The version of the table at a timestamp, in nanoseconds since the
+    /// epoch in UTC
+    Timestamp(i64),
+    /// The version of the table with an id, such as the id of a snapshot
+    Version(u64),
+}
+
+impl std::fmt::Display for TableVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableVersion::Timestamp(nanos) => write!(f, "timestamp {nanos}"),
+            TableVersion::Version(version) => write!(f, "version {version}"),
+        }
+    }
+}
+
 /// Planning time information about a table.
 ///
 /// This trait is used during logical query planning and optimizations, and
@@ -129,4 +151,10 @@ pub trait TableSource: Sync + Send {
     fn get_column_default(&self, _column: &str) -> Option<&Expr> {
         None
     }
+
+    /// Get the version of the table read by this source, if it reads a
+    /// previous version of the table
+    fn table_version(&self) -> Option<&TableVersion> {
+        None
+    }
 }
@@ -969,12 +969,15 @@ pub mod scalar_doc_sections {
             DOC_SECTION_CONDITIONAL,
             DOC_SECTION_STRING,
             DOC_SECTION_BINARY_STRING,
+            DOC_SECTION_TEXT,
             DOC_SECTION_REGEX,
             DOC_SECTION_DATETIME,
             DOC_SECTION_ARRAY,
             DOC_SECTION_STRUCT,
             DOC_SECTION_MAP,
             DOC_SECTION_HASHING,
+            DOC_SECTION_NET,
+            DOC_SECTION_CLUSTERING,
             DOC_SECTION_UNION,
             DOC_SECTION_OTHER,
         ]
@@ -986,12 +989,15 @@ pub mod scalar_doc_sections {
             DOC_SECTION_CONDITIONAL,
             DOC_SECTION_STRING,
             DOC_SECTION_BINARY_STRING,
+            DOC_SECTION_TEXT,
             DOC_SECTION_REGEX,
             DOC_SECTION_DATETIME,
             DOC_SECTION_ARRAY,
             DOC_SECTION_STRUCT,
             DOC_SECTION_MAP,
             DOC_SECTION_HASHING,
+            DOC_SECTION_NET,
+            DOC_SECTION_CLUSTERING,
             DOC_SECTION_UNION,
             DOC_SECTION_OTHER,
         ]
@@ -1021,6 +1027,14 @@ pub mod scalar_doc_sections {
         description: None,
     };
 
+    pub const DOC_SECTION_TEXT: DocSection = DocSection {
+        include: true,
+        label: "Text Functions",
+        description: Some(
+            "Functions to compare and normalize text, such as string similarity measures and phonetic codes.",
+        ),
+    };
+
     pub const DOC_SECTION_REGEX: DocSection = DocSection {
         include: true,
         label: "Regular Expression Functions",
@@ -1062,6 +1076,22 @@ The following regular expression functions are supported:"#,
         description: None,
     };
 
+    pub const DOC_SECTION_NET: DocSection = DocSection {
+        include: true,
+        label: "Network Functions",
+        description: Some(
+            "Functions to parse and encode URLs and to work with IP addresses.",
+        ),
+    };
+
+    pub const DOC_SECTION_CLUSTERING: DocSection = DocSection {
+        include: true,
+        label: "Clustering Functions",
+        description: Some(
+            "Functions to compute keys that cluster rows by several columns when sorting by them, such as before writing files.",
+        ),
+    };
+
     pub const DOC_SECTION_OTHER: DocSection = DocSection {
         include: true,
         label: "Other Functions",
@@ -0,0 +1,166 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for element_at function.
+
+use crate::utils::{find_map_keys, get_map_entry_field, make_scalar_function};
+use arrow::array::{make_array, Array, ArrayRef, MutableArrayData};
+use arrow::datatypes::DataType;
+use datafusion_common::{cast::as_map_array, exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+make_udf_expr_and_func!(
+    ElementAt,
+    element_at,
+    "Return the value for a given key in a map, or a default value if the key is not contained in the map.",
+    element_at_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns the value for the given key in the map. If the key is not present in the map, returns the default value, or NULL if no default is given. Returns NULL if the map or the key is NULL.",
+    syntax_example = "element_at(map, key[, default])",
+    sql_example = r#"```sql
+SELECT element_at(MAP {'a': 1, 'b': NULL}, 'a');
+----
+1
+
+SELECT element_at(MAP {'a': 1, 'b': NULL}, 'c'), element_at(MAP {'a': 1, 'b': NULL}, 'c', 0);
+----
+NULL 0
+```"#,
+    argument(
+        name = "map",
+        description = "Map expression. Can be a constant, column, or function, and any combination of map operators."
+    ),
+    argument(
+        name = "key",
+        description = "Key to extract from the map. Can be a constant, column, or function, any combination of arithmetic or string operators, or a named expression of the previously listed."
+    ),
+    argument(
+        name = "default",
+        description = "Optional value to return if the key is not present in the map. Can be a constant, column, or function, and any combination of operators."
+    ),
+    related_udf(name = "map_extract")
+)]
+#[derive(Debug)]
+pub struct ElementAt {
+    signature: Signature,
+}
+
+impl Default for ElementAt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementAt {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ElementAt {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "element_at"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let map_fields = get_map_entry_field(&arg_types[0])?;
+        Ok(map_fields.last().unwrap().data_type().clone())
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(element_at_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !matches!(arg_types.len(), 2 | 3) {
+            return plan_err!(
+                "element_at was called with {} arguments. It requires 2 or 3.",
+                arg_types.len()
+            );
+        }
+        let map_type = &arg_types[0];
+        let field = get_map_entry_field(map_type)?;
+        let mut coerced = vec![map_type.clone(), field[0].data_type().clone()];
+        if arg_types.len() == 3 {
+            coerced.push(field[1].data_type().clone());
+        }
+        Ok(coerced)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn element_at_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let (map_arg, key_arg, default_arg) = match args {
+        [map_arg, key_arg] => (map_arg, key_arg, None),
+        [map_arg, key_arg, default_arg] => (map_arg, key_arg, Some(default_arg)),
+        _ => {
+            return exec_err!(
+                "element_at was called with {} arguments. It requires 2 or 3.",
+                args.len()
+            )
+        }
+    };
+
+    let map_array = match map_arg.data_type() {
+        DataType::Map(_, _) => as_map_array(&map_arg)?,
+        _ => return exec_err!("The first argument in element_at must be a map"),
+    };
+
+    let positions = find_map_keys(map_array, key_arg.as_ref())?;
+
+    let values = map_array.values().to_data();
+    let default = default_arg.map(|arg| arg.to_data());
+    let mut sources = vec![&values];
+    sources.extend(default.as_ref());
+    let mut mutable = MutableArrayData::new(sources, true, positions.len());
+    for (row, position) in positions.into_iter().enumerate() {
+        match position {
+            Some(position) => mutable.extend(0, position, position + 1),
+            None if default.is_some()
+                && map_array.is_valid(row)
+                && key_arg.is_valid(row) =>
+            {
+                mutable.extend(1, row, row + 1)
+            }
+            None => mutable.extend_nulls(1),
+        }
+    }
+    Ok(make_array(mutable.freeze()))
+}
@@ -41,6 +41,7 @@ pub mod cardinality;
 pub mod concat;
 pub mod dimension;
 pub mod distance;
+pub mod element_at;
 pub mod empty;
 pub mod except;
 pub mod expr_ext;
@@ -49,7 +50,12 @@ pub mod flatten;
 pub mod length;
 pub mod make_array;
 pub mod map;
+pub mod map_concat;
+pub mod map_contains_key;
+pub mod map_entries;
 pub mod map_extract;
+pub mod map_from_arrays;
+pub mod map_from_entries;
 pub mod map_keys;
 pub mod map_values;
 pub mod planner;
@@ -83,6 +89,7 @@ pub mod expr_fn {
     pub use super::dimension::array_dims;
     pub use super::dimension::array_ndims;
     pub use super::distance::array_distance;
+    pub use super::element_at::element_at;
     pub use super::empty::array_empty;
     pub use super::except::array_except;
     pub use super::extract::array_any_value;
@@ -93,7 +100,12 @@ pub mod expr_fn {
     pub use super::flatten::flatten;
     pub use super::length::array_length;
     pub use super::make_array::make_array;
+    pub use super::map_concat::map_concat;
+    pub use super::map_contains_key::map_contains_key;
+    pub use super::map_entries::map_entries;
     pub use super::map_extract::map_extract;
+    pub use super::map_from_arrays::map_from_arrays;
+    pub use super::map_from_entries::map_from_entries;
     pub use super::map_keys::map_keys;
     pub use super::map_values::map_values;
     pub use super::position::array_position;
@@ -160,7 +172,13 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         replace::array_replace_all_udf(),
         replace::array_replace_udf(),
         map::map_udf(),
+        map_concat::map_concat_udf(),
+        map_contains_key::map_contains_key_udf(),
+        map_entries::map_entries_udf(),
         map_extract::map_extract_udf(),
+        map_from_arrays::map_from_arrays_udf(),
+        map_from_entries::map_from_entries_udf(),
+        element_at::element_at_udf(),
         map_keys::map_keys_udf(),
         map_values::map_values_udf(),
     ]
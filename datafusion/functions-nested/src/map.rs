@@ -21,7 +21,7 @@ use std::sync::Arc;
 
 use arrow::array::{Array, ArrayData, ArrayRef, MapArray, OffsetSizeTrait, StructArray};
 use arrow::buffer::Buffer;
-use arrow::datatypes::{DataType, Field, SchemaBuilder, ToByteSlice};
+use arrow::datatypes::{DataType, Field, ToByteSlice};
 
 use datafusion_common::utils::{fixed_size_list_to_arrays, list_to_arrays};
 use datafusion_common::{
@@ -34,6 +34,7 @@ use datafusion_expr::{
 use datafusion_macros::user_doc;
 
 use crate::make_array::make_array;
+use crate::utils::map_type;
 
 /// Returns a map created from a key list and a value list
 pub fn map(keys: Vec<Expr>, values: Vec<Expr>) -> Expr {
@@ -95,7 +96,7 @@ fn make_map_batch(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     make_map_batch_internal(keys, values, can_evaluate_to_const, keys_arg.data_type())
 }
 
-fn check_unique_keys(array: &dyn Array) -> Result<()> {
+pub(crate) fn check_unique_keys(array: &dyn Array) -> Result<()> {
     let mut seen_keys = HashSet::with_capacity(array.len());
 
     for i in 0..array.len() {
@@ -255,21 +256,9 @@ impl ScalarUDFImpl for MapFunc {
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         let [keys_arg, values_arg] = take_function_args(self.name(), arg_types)?;
-        let mut builder = SchemaBuilder::new();
-        builder.push(Field::new(
-            "key",
+        Ok(map_type(
             get_element_type(keys_arg)?.clone(),
-            false,
-        ));
-        builder.push(Field::new(
-            "value",
             get_element_type(values_arg)?.clone(),
-            true,
-        ));
-        let fields = builder.finish().fields;
-        Ok(DataType::Map(
-            Arc::new(Field::new("entries", DataType::Struct(fields), false)),
-            false,
         ))
     }
 
@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_concat function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{Array, ArrayRef, AsArray, MapArray};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::interleave;
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, plan_err, HashMap, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapConcatFunc,
+    map_concat,
+    "Return the union of the given maps, taking the value of a duplicate key from the last map containing it.",
+    map_concat_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns the union of all the given maps. If a key is found in multiple maps, its value is taken from the last of those maps. Returns NULL if any of the maps is NULL.",
+    syntax_example = "map_concat(map1[, ..., map_n])",
+    sql_example = r#"```sql
+SELECT map_concat(MAP {'a': 1, 'b': 2}, MAP {'b': 3, 'c': 4});
+----
+{a: 1, b: 3, c: 4}
+```"#,
+    argument(
+        name = "map1, map_n",
+        description = "Maps of the same type. Can be a constant, column, or function, and any combination of map operators."
+    )
+)]
+#[derive(Debug)]
+pub struct MapConcatFunc {
+    signature: Signature,
+}
+
+impl Default for MapConcatFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapConcatFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapConcatFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_concat"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(map_concat_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        // NULL arguments are coerced to the type of the maps
+        let Some(map_type) = arg_types.iter().find(|arg_type| !arg_type.is_null()) else {
+            return plan_err!("map_concat requires at least one map argument");
+        };
+        if !matches!(map_type, DataType::Map(_, _)) {
+            return plan_err!("map_concat expects map arguments, got {map_type}");
+        }
+        for arg_type in arg_types {
+            if !arg_type.is_null() && !arg_type.equals_datatype(map_type) {
+                return plan_err!(
+                    "map_concat requires all maps to have the same type, got {map_type} and {arg_type}"
+                );
+            }
+        }
+        Ok(vec![map_type.clone(); arg_types.len()])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn map_concat_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let Some(first) = args.first() else {
+        return exec_err!("map_concat requires at least one map argument");
+    };
+    let DataType::Map(entries_field, ordered) = first.data_type() else {
+        return exec_err!(
+            "map_concat expects map arguments, got {}",
+            first.data_type()
+        );
+    };
+    let maps = args.iter().map(|arg| arg.as_map()).collect::<Vec<_>>();
+
+    // (argument, entry) of every entry of the result, with the position of
+    // the entries of the current row by key
+    let mut indices = vec![];
+    let mut positions = HashMap::new();
+    let mut lengths = Vec::with_capacity(first.len());
+    let mut validity = Vec::with_capacity(first.len());
+    for row in 0..first.len() {
+        if maps.iter().any(|map| map.is_null(row)) {
+            lengths.push(0);
+            validity.push(false);
+            continue;
+        }
+        let row_start = indices.len();
+        positions.clear();
+        for (arg, map) in maps.iter().enumerate() {
+            let offsets = &map.value_offsets()[row..row + 2];
+            for entry in offsets[0] as usize..offsets[1] as usize {
+                let key = ScalarValue::try_from_array(map.keys(), entry)?;
+                match positions.get(&key) {
+                    Some(&position) => indices[position] = (arg, entry),
+                    None => {
+                        positions.insert(key, indices.len());
+                        indices.push((arg, entry));
+                    }
+                }
+            }
+        }
+        lengths.push(indices.len() - row_start);
+        validity.push(true);
+    }
+
+    let entries = maps
+        .iter()
+        .map(|map| map.entries() as &dyn Array)
+        .collect::<Vec<_>>();
+    let entries = interleave(&entries, &indices)?;
+    Ok(Arc::new(MapArray::try_new(
+        Arc::clone(entries_field),
+        OffsetBuffer::from_lengths(lengths),
+        entries.as_struct().clone(),
+        Some(NullBuffer::from(validity)).filter(|nulls| nulls.null_count() > 0),
+        *ordered,
+    )?))
+}
@@ -0,0 +1,138 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_contains_key function.
+
+use crate::utils::{find_map_keys, get_map_entry_field, make_scalar_function};
+use arrow::array::{Array, ArrayRef, BooleanArray};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{cast::as_map_array, exec_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapContainsKey,
+    map_contains_key,
+    map key,
+    "Return true if the map contains the given key.",
+    map_contains_key_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns true if the map contains the given key, or NULL if the map or the key is NULL.",
+    syntax_example = "map_contains_key(map, key)",
+    sql_example = r#"```sql
+SELECT map_contains_key(MAP {'a': 1, 'b': NULL}, 'b');
+----
+true
+
+SELECT map_contains_key(MAP {1: 'one', 2: 'two'}, 3);
+----
+false
+```"#,
+    argument(
+        name = "map",
+        description = "Map expression. Can be a constant, column, or function, and any combination of map operators."
+    ),
+    argument(
+        name = "key",
+        description = "Key to look up in the map. Can be a constant, column, or function, any combination of arithmetic or string operators, or a named expression of the previously listed."
+    )
+)]
+#[derive(Debug)]
+pub struct MapContainsKey {
+    signature: Signature,
+}
+
+impl Default for MapContainsKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapContainsKey {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapContainsKey {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_contains_key"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(map_contains_key_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [map_type, _] = take_function_args(self.name(), arg_types)?;
+
+        let field = get_map_entry_field(map_type)?;
+        Ok(vec![
+            map_type.clone(),
+            field.first().unwrap().data_type().clone(),
+        ])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn map_contains_key_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [map_arg, key_arg] = take_function_args("map_contains_key", args)?;
+
+    let map_array = match map_arg.data_type() {
+        DataType::Map(_, _) => as_map_array(&map_arg)?,
+        _ => return exec_err!("The first argument in map_contains_key must be a map"),
+    };
+
+    let positions = find_map_keys(map_array, key_arg.as_ref())?;
+    let result = positions
+        .iter()
+        .enumerate()
+        .map(|(row, position)| {
+            (map_array.is_valid(row) && key_arg.is_valid(row))
+                .then_some(position.is_some())
+        })
+        .collect::<BooleanArray>();
+    Ok(Arc::new(result))
+}
@@ -66,12 +66,12 @@ SELECT map_extract(MAP {'x': 10, 'y': NULL, 'z': 30}, 'y');
     argument(
         name = "key",
         description = "Key to extract from the map. Can be a constant, column, or function, any combination of arithmetic or string operators, or a named expression of the previously listed."
-    )
+    ),
+    related_udf(name = "element_at")
 )]
 #[derive(Debug)]
 pub struct MapExtract {
     signature: Signature,
-    aliases: Vec<String>,
 }
 
 impl Default for MapExtract {
@@ -84,7 +84,6 @@ impl MapExtract {
     pub fn new() -> Self {
         Self {
             signature: Signature::user_defined(Volatility::Immutable),
-            aliases: vec![String::from("element_at")],
         }
     }
 }
@@ -117,10 +116,6 @@ impl ScalarUDFImpl for MapExtract {
         make_scalar_function(map_extract_inner)(&args.args)
     }
 
-    fn aliases(&self) -> &[String] {
-        &self.aliases
-    }
-
     fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
         let [map_type, _] = take_function_args(self.name(), arg_types)?;
 
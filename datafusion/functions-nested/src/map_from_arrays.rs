@@ -0,0 +1,182 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_from_arrays function.
+
+use crate::utils::{make_scalar_function, map_type, take_map_entries};
+use arrow::array::{Array, ArrayRef, UInt64Array};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::cast::as_list_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapFromArraysFunc,
+    map_from_arrays,
+    keys values,
+    "Return a map created from a list of keys and a list of values.",
+    map_from_arrays_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns a map created from a list of keys and a list of values of the same length. The keys must be unique and non-null. Returns NULL if either list is NULL.",
+    syntax_example = "map_from_arrays(keys, values)",
+    sql_example = r#"```sql
+SELECT map_from_arrays(['a', 'b', 'c'], [1, NULL, 3]);
+----
+{a: 1, b: NULL, c: 3}
+
+SELECT map_from_arrays([1, 2], [['x'], ['y', 'z']]);
+----
+{1: [x], 2: [y, z]}
+```"#,
+    argument(
+        name = "keys",
+        description = "List of the keys of the map. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "values",
+        description = "List of the values of the map. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug)]
+pub struct MapFromArraysFunc {
+    signature: Signature,
+}
+
+impl Default for MapFromArraysFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapFromArraysFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapFromArraysFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_from_arrays"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [keys_type, values_type] = take_function_args(self.name(), arg_types)?;
+        match (keys_type, values_type) {
+            (DataType::List(keys_field), DataType::List(values_field)) => Ok(map_type(
+                keys_field.data_type().clone(),
+                values_field.data_type().clone(),
+            )),
+            _ => plan_err!(
+                "map_from_arrays expects two lists, got {keys_type} and {values_type}"
+            ),
+        }
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(map_from_arrays_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [keys_type, values_type] = take_function_args(self.name(), arg_types)?;
+        Ok(vec![
+            coerce_to_list(self.name(), keys_type)?,
+            coerce_to_list(self.name(), values_type)?,
+        ])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Coerces the list types to `List`, so the map functions that take lists
+/// only need to handle one list type
+pub(crate) fn coerce_to_list(name: &str, data_type: &DataType) -> Result<DataType> {
+    match data_type {
+        DataType::List(_) => Ok(data_type.clone()),
+        DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            Ok(DataType::List(Arc::new(Field::new_list_field(
+                field.data_type().clone(),
+                field.is_nullable(),
+            ))))
+        }
+        _ => plan_err!("{name} expects list arguments, got {data_type}"),
+    }
+}
+
+fn map_from_arrays_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [keys_arg, values_arg] = take_function_args("map_from_arrays", args)?;
+    let keys = as_list_array(keys_arg)?;
+    let values = as_list_array(values_arg)?;
+
+    let mut key_indices = vec![];
+    let mut value_indices = vec![];
+    let mut lengths = Vec::with_capacity(keys.len());
+    let mut validity = Vec::with_capacity(keys.len());
+    for row in 0..keys.len() {
+        if keys.is_null(row) || values.is_null(row) {
+            lengths.push(0);
+            validity.push(false);
+            continue;
+        }
+        let key_offsets = &keys.value_offsets()[row..row + 2];
+        let value_offsets = &values.value_offsets()[row..row + 2];
+        let len = (key_offsets[1] - key_offsets[0]) as usize;
+        let values_len = (value_offsets[1] - value_offsets[0]) as usize;
+        if len != values_len {
+            return exec_err!(
+                "map_from_arrays requires the key and value lists to have the same length, got {len} and {values_len}"
+            );
+        }
+        key_indices.extend(key_offsets[0] as u64..key_offsets[1] as u64);
+        value_indices.extend(value_offsets[0] as u64..value_offsets[1] as u64);
+        lengths.push(len);
+        validity.push(true);
+    }
+
+    take_map_entries(
+        keys.values().as_ref(),
+        &UInt64Array::from(key_indices),
+        values.values().as_ref(),
+        &UInt64Array::from(value_indices),
+        OffsetBuffer::from_lengths(lengths),
+        Some(NullBuffer::from(validity)).filter(|nulls| nulls.null_count() > 0),
+    )
+}
@@ -0,0 +1,157 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_from_entries function.
+
+use crate::map_from_arrays::coerce_to_list;
+use crate::utils::{make_scalar_function, map_type, take_map_entries};
+use arrow::array::{Array, ArrayRef, UInt64Array};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::{as_list_array, as_struct_array};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+make_udf_expr_and_func!(
+    MapFromEntriesFunc,
+    map_from_entries,
+    entries,
+    "Return a map created from a list of key-value structs.",
+    map_from_entries_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns a map created from a list of structs with two fields, the key and the value. The keys must be unique and non-null. Returns NULL if the list or any of its entries is NULL.",
+    syntax_example = "map_from_entries(entries)",
+    sql_example = r#"```sql
+SELECT map_from_entries([struct('a', 1), struct('b', CAST(NULL AS BIGINT))]);
+----
+{a: 1, b: NULL}
+
+SELECT map_from_entries(map_entries(MAP {'x': 10}));
+----
+{x: 10}
+```"#,
+    argument(
+        name = "entries",
+        description = "List of structs with two fields. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    related_udf(name = "map_entries")
+)]
+#[derive(Debug)]
+pub struct MapFromEntriesFunc {
+    signature: Signature,
+}
+
+impl Default for MapFromEntriesFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapFromEntriesFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapFromEntriesFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_from_entries"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [entries_type] = take_function_args(self.name(), arg_types)?;
+        if let DataType::List(field) = entries_type {
+            if let DataType::Struct(fields) = field.data_type() {
+                if fields.len() == 2 {
+                    return Ok(map_type(
+                        fields[0].data_type().clone(),
+                        fields[1].data_type().clone(),
+                    ));
+                }
+            }
+        }
+        plan_err!(
+            "map_from_entries expects a list of structs with two fields, got {entries_type}"
+        )
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(map_from_entries_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [entries_type] = take_function_args(self.name(), arg_types)?;
+        Ok(vec![coerce_to_list(self.name(), entries_type)?])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn map_from_entries_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [entries_arg] = take_function_args("map_from_entries", args)?;
+    let list = as_list_array(entries_arg)?;
+    let entries = as_struct_array(list.values())?;
+
+    let mut indices = vec![];
+    let mut lengths = Vec::with_capacity(list.len());
+    let mut validity = Vec::with_capacity(list.len());
+    for (row, window) in list.value_offsets().windows(2).enumerate() {
+        let range = window[0] as usize..window[1] as usize;
+        // a map is null if the list or any of its entries is null
+        if list.is_null(row) || range.clone().any(|i| entries.is_null(i)) {
+            lengths.push(0);
+            validity.push(false);
+            continue;
+        }
+        lengths.push(range.len());
+        validity.push(true);
+        indices.extend(range.map(|i| i as u64));
+    }
+
+    let indices = UInt64Array::from(indices);
+    take_map_entries(
+        entries.column(0).as_ref(),
+        &indices,
+        entries.column(1).as_ref(),
+        &indices,
+        OffsetBuffer::from_lengths(lengths),
+        Some(NullBuffer::from(validity)).filter(|nulls| nulls.null_count() > 0),
+    )
+}
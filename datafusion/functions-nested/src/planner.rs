@@ -18,16 +18,15 @@
 //! SQL planning extensions like [`NestedFunctionPlanner`] and [`FieldAccessPlanner`]
 
 use arrow::datatypes::DataType;
-use datafusion_common::ExprSchema;
 use datafusion_common::{plan_err, utils::list_ndims, DFSchema, Result};
 use datafusion_expr::expr::ScalarFunction;
 use datafusion_expr::expr::{AggregateFunction, AggregateFunctionParams};
 use datafusion_expr::AggregateUDF;
 use datafusion_expr::{
+    lit,
     planner::{ExprPlanner, PlannerResult, RawBinaryExpr, RawFieldAccessExpr},
     sqlparser, Expr, ExprSchemable, GetFieldAccess,
 };
-use datafusion_functions::core::get_field as get_field_inner;
 use datafusion_functions::expr_fn::get_field;
 use datafusion_functions_aggregate::nth_value::nth_value_udaf;
 use std::sync::Arc;
@@ -38,6 +37,7 @@ use crate::{
     expr_fn::{array_append, array_concat, array_prepend},
     extract::{array_element, array_slice},
     make_array::make_array,
+    map_extract::map_extract,
 };
 
 #[derive(Debug)]
@@ -147,6 +147,22 @@ impl ExprPlanner for FieldAccessPlanner {
     ) -> Result<PlannerResult<RawFieldAccessExpr>> {
         let RawFieldAccessExpr { expr, field_access } = expr;
 
+        // map[key] => map_extract(map, key)[1]
+        if matches!(expr.get_type(schema)?, DataType::Map(_, _)) {
+            let key = match field_access {
+                GetFieldAccess::NamedStructField { name } => Some(lit(name)),
+                GetFieldAccess::ListIndex { key } => Some(*key),
+                GetFieldAccess::ListRange { .. } => None,
+            };
+            if let Some(key) = key {
+                return Ok(PlannerResult::Planned(array_element(
+                    map_extract(expr, key),
+                    lit(1i64),
+                )));
+            }
+            return plan_err!("Slices are not supported for maps");
+        }
+
         match field_access {
             // expr["field"] => get_field(expr, "field")
             GetFieldAccess::NamedStructField { name } => {
@@ -176,17 +192,6 @@ impl ExprPlanner for FieldAccessPlanner {
                             null_treatment,
                         )),
                     )),
-                    // special case for map access with
-                    Expr::Column(ref c)
-                        if matches!(schema.data_type(c)?, DataType::Map(_, _)) =>
-                    {
-                        Ok(PlannerResult::Planned(Expr::ScalarFunction(
-                            ScalarFunction::new_udf(
-                                get_field_inner(),
-                                vec![expr, *index],
-                            ),
-                        )))
-                    }
                     _ => Ok(PlannerResult::Planned(array_element(expr, *index))),
                 }
             }
@@ -22,10 +22,12 @@ use std::sync::Arc;
 use arrow::datatypes::{DataType, Field, Fields};
 
 use arrow::array::{
-    Array, ArrayRef, BooleanArray, GenericListArray, ListArray, OffsetSizeTrait, Scalar,
-    UInt32Array,
+    make_comparator, Array, ArrayRef, BooleanArray, GenericListArray, ListArray,
+    MapArray, OffsetSizeTrait, Scalar, StructArray, UInt32Array, UInt64Array,
 };
-use arrow::buffer::OffsetBuffer;
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::kernels::cmp::eq;
+use arrow::compute::SortOptions;
 use datafusion_common::cast::{as_large_list_array, as_list_array};
 use datafusion_common::{
     exec_err, internal_datafusion_err, internal_err, plan_err, Result, ScalarValue,
@@ -258,6 +260,107 @@ pub(crate) fn get_map_entry_field(data_type: &DataType) -> Result<&Fields> {
     }
 }
 
+/// Returns the type of the maps created by the map functions, which have a
+/// non-nullable `key` and a nullable `value` field
+pub(crate) fn map_type(key_type: DataType, value_type: DataType) -> DataType {
+    let fields = Fields::from(vec![
+        Field::new("key", key_type, false),
+        Field::new("value", value_type, true),
+    ]);
+    DataType::Map(
+        Arc::new(Field::new("entries", DataType::Struct(fields), false)),
+        false,
+    )
+}
+
+/// Returns, for every row, the index into the entries of `map_array` of the
+/// first entry whose key equals the row's value in `query_keys`.
+///
+/// The index is `None` if the key is not contained in the map, or if either
+/// the map or the query key is null.
+pub(crate) fn find_map_keys(
+    map_array: &MapArray,
+    query_keys: &dyn Array,
+) -> Result<Vec<Option<usize>>> {
+    let offsets = map_array.value_offsets();
+    let first = offsets[0] as usize;
+    let last = offsets[map_array.len()] as usize;
+    let keys = map_array.keys().slice(first, last - first);
+
+    // repeat the query key of every row once for each entry of the row's map
+    let indices = UInt32Array::from_iter_values(offsets.windows(2).enumerate().flat_map(
+        |(row, window)| std::iter::repeat_n(row as u32, (window[1] - window[0]) as usize),
+    ));
+    let query_keys = arrow::compute::take(query_keys, &indices, None)?;
+
+    let matches = if keys.data_type().is_nested() {
+        let comparator =
+            make_comparator(keys.as_ref(), query_keys.as_ref(), SortOptions::default())?;
+        (0..keys.len())
+            .map(|i| Some(query_keys.is_valid(i) && comparator(i, i).is_eq()))
+            .collect::<BooleanArray>()
+    } else {
+        eq(&keys, &query_keys)?
+    };
+
+    Ok(offsets
+        .windows(2)
+        .enumerate()
+        .map(|(row, window)| {
+            if map_array.is_null(row) {
+                return None;
+            }
+            let start = window[0] as usize - first;
+            let end = window[1] as usize - first;
+            (start..end)
+                .find(|&i| matches.is_valid(i) && matches.value(i))
+                .map(|i| i + first)
+        })
+        .collect())
+}
+
+/// Builds a map array of [`map_type`] from the entries of `keys` at
+/// `key_indices` and of `values` at `value_indices`. Map `i` contains the
+/// entries `offsets[i]..offsets[i + 1]` and is null if `nulls` says so.
+///
+/// Returns an error if a key is null or a map contains duplicate keys.
+pub(crate) fn take_map_entries(
+    keys: &dyn Array,
+    key_indices: &UInt64Array,
+    values: &dyn Array,
+    value_indices: &UInt64Array,
+    offsets: OffsetBuffer<i32>,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef> {
+    let keys = arrow::compute::take(keys, key_indices, None)?;
+    if keys.null_count() > 0 {
+        return exec_err!("map key cannot be null");
+    }
+    for window in offsets.windows(2) {
+        let start = window[0] as usize;
+        let end = window[1] as usize;
+        crate::map::check_unique_keys(keys.slice(start, end - start).as_ref())?;
+    }
+    let values = arrow::compute::take(values, value_indices, None)?;
+
+    let DataType::Map(entries_field, _) =
+        map_type(keys.data_type().clone(), values.data_type().clone())
+    else {
+        unreachable!("map_type always returns a map")
+    };
+    let DataType::Struct(fields) = entries_field.data_type() else {
+        unreachable!("map entries are always a struct")
+    };
+    let entries = StructArray::try_new(fields.clone(), vec![keys, values], None)?;
+    Ok(Arc::new(MapArray::try_new(
+        entries_field,
+        offsets,
+        entries,
+        nulls,
+        false,
+    )?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,4 +415,35 @@ mod tests {
             expected_dim
         );
     }
+
+    #[test]
+    fn test_find_map_keys_sliced() -> Result<()> {
+        let keys = Arc::new(arrow::array::StringArray::from(vec!["a", "b", "a", "c"]));
+        let values = Arc::new(arrow::array::Int64Array::from(vec![1, 2, 3, 4]));
+        let DataType::Map(entries_field, _) = map_type(DataType::Utf8, DataType::Int64)
+        else {
+            unreachable!()
+        };
+        let DataType::Struct(fields) = entries_field.data_type() else {
+            unreachable!()
+        };
+        let entries = StructArray::try_new(fields.clone(), vec![keys, values], None)?;
+        // {a: 1, b: 2}, {a: 3}, NULL, {c: 4}
+        let map = MapArray::try_new(
+            entries_field,
+            OffsetBuffer::new(vec![0, 2, 3, 3, 4].into()),
+            entries,
+            Some(NullBuffer::from(vec![true, true, false, true])),
+            false,
+        )?;
+
+        let query = arrow::array::StringArray::from(vec![Some("a"), None, Some("c")]);
+        let positions = find_map_keys(&map.slice(1, 3), &query)?;
+        assert_eq!(positions, vec![Some(2), None, Some(3)]);
+
+        let query = arrow::array::StringArray::from(vec!["b", "a", "a", "a"]);
+        let positions = find_map_keys(&map, &query)?;
+        assert_eq!(positions, vec![Some(1), Some(2), None, None]);
+        Ok(())
+    }
 }
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::StringArray;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
+use datafusion_catalog::Session;
+use datafusion_catalog::TableFunctionImpl;
+use datafusion_catalog::TableProvider;
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::{Expr, TableType};
+use datafusion_functions::regex::compile_regex;
+use datafusion_functions::regex::regexpsplit::regexp_split;
+use datafusion_physical_plan::memory::{LazyBatchGenerator, LazyMemoryExec};
+use datafusion_physical_plan::ExecutionPlan;
+use parking_lot::RwLock;
+use std::fmt;
+use std::sync::Arc;
+
+/// Table holding the parts of a string split by a regular expression
+#[derive(Debug, Clone)]
+struct RegexpSplitToTable {
+    schema: SchemaRef,
+    /// The split parts, empty if any of the arguments was null
+    parts: Arc<Vec<String>>,
+}
+
+/// Table state that emits the split parts in batches of `batch_size` rows
+#[derive(Debug, Clone)]
+struct RegexpSplitToTableState {
+    schema: SchemaRef,
+    parts: Arc<Vec<String>>,
+    batch_size: usize,
+    /// Optional projection applied to each generated batch
+    projection: Option<Vec<usize>>,
+
+    /// Tracks current position when generating table
+    current: usize,
+}
+
+/// Detail to display for 'Explain' plan
+impl fmt::Display for RegexpSplitToTableState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "regexp_split_to_table: parts={}, batch_size={}",
+            self.parts.len(),
+            self.batch_size
+        )
+    }
+}
+
+impl LazyBatchGenerator for RegexpSplitToTableState {
+    fn generate_next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        if self.current >= self.parts.len() {
+            return Ok(None);
+        }
+
+        let end = (self.current + self.batch_size).min(self.parts.len());
+        let array = StringArray::from_iter_values(&self.parts[self.current..end]);
+        self.current = end;
+
+        let batch = RecordBatch::try_new(self.schema.clone(), vec![Arc::new(array)])?;
+
+        match &self.projection {
+            Some(projection) => Ok(Some(batch.project(projection)?)),
+            None => Ok(Some(batch)),
+        }
+    }
+}
+
+#[async_trait]
+impl TableProvider for RegexpSplitToTable {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let batch_size = state.config_options().execution.batch_size;
+        let projected_schema = match projection {
+            Some(projection) => Arc::new(self.schema.project(projection)?),
+            None => self.schema.clone(),
+        };
+
+        let state = RegexpSplitToTableState {
+            schema: self.schema.clone(),
+            parts: Arc::clone(&self.parts),
+            batch_size,
+            projection: projection.cloned(),
+            current: 0,
+        };
+
+        Ok(Arc::new(LazyMemoryExec::try_new(
+            projected_schema,
+            vec![Arc::new(RwLock::new(state))],
+        )?))
+    }
+}
+
+/// `regexp_split_to_table(str, regexp[, flags])` returns one row per part of
+/// `str` split by the regular expression `regexp`, in a column named `value`.
+///
+/// All arguments must be string literals.
+#[derive(Debug)]
+pub struct RegexpSplitToTableFunc {}
+
+impl TableFunctionImpl for RegexpSplitToTableFunc {
+    fn call(&self, exprs: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        if exprs.len() < 2 || exprs.len() > 3 {
+            return plan_err!("regexp_split_to_table function requires 2 or 3 arguments");
+        }
+
+        let mut args = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            match expr {
+                Expr::Literal(
+                    ScalarValue::Utf8(value)
+                    | ScalarValue::LargeUtf8(value)
+                    | ScalarValue::Utf8View(value),
+                ) => args.push(value.as_deref()),
+                Expr::Literal(ScalarValue::Null) => args.push(None),
+                _ => {
+                    return plan_err!(
+                        "regexp_split_to_table arguments must be string literals"
+                    )
+                }
+            }
+        }
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "value",
+            DataType::Utf8,
+            false,
+        )]));
+
+        let parts = match args[..] {
+            [Some(value), Some(pattern)] => split(value, pattern, None)?,
+            [Some(value), Some(pattern), Some(flags)] => {
+                split(value, pattern, Some(flags))?
+            }
+            // if args have null, then return 0 row
+            _ => vec![],
+        };
+
+        Ok(Arc::new(RegexpSplitToTable {
+            schema,
+            parts: Arc::new(parts),
+        }))
+    }
+}
+
+fn split(value: &str, pattern: &str, flags: Option<&str>) -> Result<Vec<String>> {
+    let regex = compile_regex("regexp_split_to_table", pattern, flags)?;
+
+    Ok(regexp_split(value, &regex)
+        .into_iter()
+        .map(String::from)
+        .collect())
+}
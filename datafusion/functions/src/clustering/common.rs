@@ -0,0 +1,256 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels shared by the clustering functions
+
+use arrow::array::{Array, ArrayRef, AsArray, BinaryBuilder};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Float64Type, Int64Type, UInt64Type};
+use datafusion_common::{not_impl_err, plan_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+use std::sync::Arc;
+
+const SIGN_BIT: u64 = 1 << 63;
+
+/// Returns the type of the key of the clustering function `name` called with
+/// arguments of `arg_types`
+pub(crate) fn key_return_type(name: &str, arg_types: &[DataType]) -> Result<DataType> {
+    if arg_types.is_empty() {
+        return plan_err!("{name} requires at least one argument");
+    }
+    for arg_type in arg_types {
+        if !is_supported(arg_type) {
+            return plan_err!("{name} does not support arguments of type {arg_type}");
+        }
+    }
+    Ok(DataType::Binary)
+}
+
+fn is_supported(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Dictionary(_, value_type) => is_supported(value_type),
+        DataType::Null | DataType::Boolean => true,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => true,
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => true,
+        DataType::Date32 | DataType::Date64 => true,
+        DataType::Time32(_) | DataType::Time64(_) => true,
+        DataType::Timestamp(_, _) | DataType::Duration(_) => true,
+        data_type => data_type.is_numeric(),
+    }
+}
+
+/// Computes the key of every row of `args`, by mapping the values of each
+/// argument to 64 bit keys with [`sortable_keys`] and combining the keys of
+/// a row with `combine`, which must append `8 * args.len()` bytes.
+///
+/// The keys are returned as big endian binary values, so that they compare
+/// like the combined keys.
+pub(crate) fn clustering_keys<F>(
+    args: &[ColumnarValue],
+    combine: F,
+) -> Result<ColumnarValue>
+where
+    F: Fn(&mut [u64], &mut Vec<u8>),
+{
+    let is_scalar = args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let keys = arrays
+        .iter()
+        .map(sortable_keys)
+        .collect::<Result<Vec<_>>>()?;
+
+    let len = arrays.first().map_or(0, |array| array.len());
+    let key_len = 8 * arrays.len();
+    let mut builder = BinaryBuilder::with_capacity(len, len * key_len);
+    let mut row = vec![0; arrays.len()];
+    let mut key = Vec::with_capacity(key_len);
+    for i in 0..len {
+        row.iter_mut()
+            .zip(&keys)
+            .for_each(|(value, keys)| *value = keys[i]);
+        key.clear();
+        combine(&mut row, &mut key);
+        builder.append_value(&key);
+    }
+    let keys: ArrayRef = Arc::new(builder.finish());
+
+    if is_scalar {
+        return Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &keys, 0,
+        )?));
+    }
+    Ok(ColumnarValue::Array(keys))
+}
+
+/// Maps the values of `array` to unsigned 64 bit keys that compare like the
+/// values, and nulls to 0:
+///
+/// * integers, dates, times and timestamps compare like their integer value
+/// * floating point numbers and decimals compare like their `f64` value, in
+///   the total order of `f64::total_cmp`
+/// * strings and binary values compare like their first 8 bytes
+/// * `false` compares before `true`
+///
+/// The keys are not normalized to the range of the values of `array`, as
+/// the keys of different batches must compare like their values too. The
+/// values of an argument that vary in a small range only differ in the low
+/// bits of their keys, so the arguments with values in larger ranges weigh
+/// more in the combined keys.
+pub(crate) fn sortable_keys(array: &ArrayRef) -> Result<Vec<u64>> {
+    let keys = match array.data_type() {
+        DataType::Dictionary(_, value_type) => {
+            return sortable_keys(&cast(array, value_type)?)
+        }
+        DataType::Null => vec![0; array.len()],
+        DataType::Boolean => array
+            .as_boolean()
+            .iter()
+            .map(|v| v.map_or(0, u64::from))
+            .collect(),
+        DataType::Utf8 => prefix_keys(array.as_string::<i32>().iter().map(as_bytes)),
+        DataType::LargeUtf8 => prefix_keys(array.as_string::<i64>().iter().map(as_bytes)),
+        DataType::Utf8View => prefix_keys(array.as_string_view().iter().map(as_bytes)),
+        DataType::Binary => prefix_keys(array.as_binary::<i32>().iter()),
+        DataType::LargeBinary => prefix_keys(array.as_binary::<i64>().iter()),
+        DataType::BinaryView => prefix_keys(array.as_binary_view().iter()),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            cast(array, &DataType::UInt64)?
+                .as_primitive::<UInt64Type>()
+                .iter()
+                .map(|v| v.unwrap_or(0))
+                .collect()
+        }
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => cast(array, &DataType::Int64)?
+            .as_primitive::<Int64Type>()
+            .iter()
+            .map(|v| v.map_or(0, |v| v as u64 ^ SIGN_BIT))
+            .collect(),
+        data_type if data_type.is_numeric() => cast(array, &DataType::Float64)?
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|v| v.map_or(0, float_key))
+            .collect(),
+        data_type => {
+            return not_impl_err!("Clustering keys of type {data_type} are not supported")
+        }
+    };
+    Ok(keys)
+}
+
+fn as_bytes(value: Option<&str>) -> Option<&[u8]> {
+    value.map(str::as_bytes)
+}
+
+fn prefix_keys<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<u64> {
+    values
+        .map(|v| {
+            v.map_or(0, |v| {
+                let mut prefix = [0; 8];
+                let len = v.len().min(8);
+                prefix[..len].copy_from_slice(&v[..len]);
+                u64::from_be_bytes(prefix)
+            })
+        })
+        .collect()
+}
+
+fn float_key(value: f64) -> u64 {
+    let bits = value.to_bits();
+    if bits & SIGN_BIT != 0 {
+        !bits
+    } else {
+        bits ^ SIGN_BIT
+    }
+}
+
+/// Appends the bits of `keys` interleaved, from the most significant bit of
+/// the first key to the least significant bit of the last key
+pub(crate) fn interleave_bits(keys: &[u64], out: &mut Vec<u8>) {
+    if let [key] = keys {
+        out.extend_from_slice(&key.to_be_bytes());
+        return;
+    }
+    let mut byte = 0u8;
+    let mut filled = 0;
+    for bit in (0..64).rev() {
+        for key in keys {
+            byte = (byte << 1) | ((key >> bit) & 1) as u8;
+            filled += 1;
+            if filled == 8 {
+                out.push(byte);
+                (byte, filled) = (0, 0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float64Array, Int32Array, Int64Array, StringArray};
+
+    fn assert_sorted(keys: &[u64]) {
+        assert!(keys.windows(2).all(|w| w[0] < w[1]), "{keys:?}");
+    }
+
+    #[test]
+    fn test_sortable_keys() -> Result<()> {
+        let ints: ArrayRef =
+            Arc::new(Int32Array::from(vec![i32::MIN, -1, 0, 1, i32::MAX]));
+        assert_sorted(&sortable_keys(&ints)?);
+
+        let floats: ArrayRef = Arc::new(Float64Array::from(vec![
+            f64::NEG_INFINITY,
+            -1.5,
+            -0.0,
+            0.0,
+            1e-10,
+            2.5,
+            f64::INFINITY,
+        ]));
+        assert_sorted(&sortable_keys(&floats)?);
+
+        let strings: ArrayRef =
+            Arc::new(StringArray::from(vec!["", "a", "ab", "b", "bcdefghij"]));
+        assert_sorted(&sortable_keys(&strings)?);
+
+        let nulls: ArrayRef = Arc::new(Int64Array::from(vec![None, Some(i64::MIN)]));
+        assert_eq!(sortable_keys(&nulls)?, vec![0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_interleave_bits() {
+        let mut out = vec![];
+        interleave_bits(&[SIGN_BIT | 1, SIGN_BIT | 2], &mut out);
+        let mut expected = vec![0; 16];
+        expected[0] = 0b1100_0000;
+        expected[15] = 0b0000_0110;
+        assert_eq!(out, expected);
+    }
+}
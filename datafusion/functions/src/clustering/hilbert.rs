@@ -0,0 +1,164 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::clustering::common::{clustering_keys, interleave_bits, key_return_type};
+use arrow::datatypes::DataType;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Clustering Functions"),
+    description = r#"Returns the Hilbert curve key of one or more expressions. Like [`zorder`](#zorder), sorting rows by the key clusters rows with close values of all the expressions, but consecutive keys are always neighbours, so the files and row groups of the rows usually cover smaller ranges of the expressions than with `zorder`.
+
+The values of the expressions are keyed like for `zorder`."#,
+    syntax_example = "hilbert(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select hilbert(1, 2);
++----------------------------------+
+| hilbert(Int64(1),Int64(2))       |
++----------------------------------+
+| 8000000000000000000000000000000d |
++----------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expressions to cluster by. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct HilbertFunc {
+    signature: Signature,
+}
+
+impl Default for HilbertFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HilbertFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for HilbertFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "hilbert"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        key_return_type(self.name(), arg_types)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        clustering_keys(&args.args, |keys, out| {
+            axes_to_transpose(keys);
+            interleave_bits(keys, out)
+        })
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Converts the coordinates `x` of a point to the "transposed" Hilbert index
+/// of the point, whose interleaved bits are the Hilbert index, using the
+/// algorithm of John Skilling, "Programming the Hilbert curve", AIP
+/// Conference Proceedings 707, 2004.
+fn axes_to_transpose(x: &mut [u64]) {
+    let n = x.len();
+    // inverse undo excess work
+    let mut q = 1u64 << 63;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // gray encode
+    for i in 1..n {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = 1u64 << 63;
+    while q > 1 {
+        if x[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    x.iter_mut().for_each(|x| *x ^= t);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, AsArray, Int32Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_hilbert() -> Result<()> {
+        let (a, b): (Vec<i32>, Vec<i32>) = (0..16).map(|i| (i / 4, i % 4)).unzip();
+        let args = ScalarFunctionArgs {
+            args: vec![
+                ColumnarValue::Array(Arc::new(Int32Array::from(a.clone())) as ArrayRef),
+                ColumnarValue::Array(Arc::new(Int32Array::from(b.clone())) as ArrayRef),
+            ],
+            number_rows: 16,
+            return_type: &DataType::Binary,
+        };
+        let ColumnarValue::Array(keys) = HilbertFunc::new().invoke_with_args(args)?
+        else {
+            unreachable!()
+        };
+        let keys = keys.as_binary::<i32>();
+
+        // consecutive points of the curve over the 4x4 grid are neighbours
+        let mut points = (0..16).collect::<Vec<_>>();
+        points.sort_by_key(|&i| keys.value(i));
+        for w in points.windows(2) {
+            let distance = (a[w[0]] - a[w[1]]).abs() + (b[w[0]] - b[w[1]]).abs();
+            assert_eq!(distance, 1, "{points:?}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! "clustering" DataFusion functions, to cluster rows by several columns
+
+use datafusion_expr::ScalarUDF;
+use std::sync::Arc;
+
+pub(crate) mod common;
+pub mod hilbert;
+pub mod zorder;
+
+make_udf_function!(hilbert::HilbertFunc, hilbert);
+make_udf_function!(zorder::ZOrderFunc, zorder);
+
+pub mod expr_fn {
+    export_functions!(
+        (
+            hilbert,
+            "Returns the Hilbert curve key of the expressions, to cluster rows by them.",
+            args,
+        ),
+        (
+            zorder,
+            "Returns the Z-order key of the expressions, to cluster rows by them.",
+            args,
+        )
+    );
+}
+
+/// Returns all DataFusion functions defined in this package
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    vec![hilbert(), zorder()]
+}
@@ -0,0 +1,115 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::clustering::common::{clustering_keys, interleave_bits, key_return_type};
+use arrow::datatypes::DataType;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Clustering Functions"),
+    description = r#"Returns the Z-order key of one or more expressions, made by interleaving the bits of their values. Sorting rows by the key clusters rows with close values of all the expressions, so that the files and row groups of the rows cover small ranges of each of them.
+
+Numbers, dates, times and timestamps are keyed by their value, strings and binary values by their first 8 bytes. Expressions are compared bit by bit from the most significant bit of their keys, so they cluster best when their values vary in similar ranges. The keys are not normalized to the range of the values, so that they compare alike across batches and files: scale expressions with values in much smaller ranges than the others, such as `zorder(a, b * 1000)`, to give them a similar weight. Null values are keyed like the smallest values."#,
+    syntax_example = "zorder(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select zorder(1, 2);
++----------------------------------+
+| zorder(Int64(1),Int64(2))        |
++----------------------------------+
+| c0000000000000000000000000000006 |
++----------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expressions to cluster by. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct ZOrderFunc {
+    signature: Signature,
+}
+
+impl Default for ZOrderFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZOrderFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ZOrderFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "zorder"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        key_return_type(self.name(), arg_types)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        clustering_keys(&args.args, |keys, out| interleave_bits(keys, out))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, AsArray, Int32Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_zorder() -> Result<()> {
+        // the 2x2 grid is visited in a Z
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![0, 0, 1, 1]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 0, 1]));
+        let args = ScalarFunctionArgs {
+            args: vec![ColumnarValue::Array(a), ColumnarValue::Array(b)],
+            number_rows: 4,
+            return_type: &DataType::Binary,
+        };
+        let ColumnarValue::Array(keys) = ZOrderFunc::new().invoke_with_args(args)? else {
+            unreachable!()
+        };
+        let keys = keys.as_binary::<i32>().iter().flatten().collect::<Vec<_>>();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::hash::spark_hash::{update_spark_hashes, Murmur3, SPARK_HASH_SEED};
+use arrow::array::{ArrayRef, AsArray, Int32Array};
+use arrow::datatypes::{DataType, Int64Type};
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Hashing Functions"),
+    description = "Returns the bucket id of one or more expressions when distributed over `num_buckets` buckets, computed as `pmod(murmur3_hash(expression1, ..., expression_n), num_buckets)`. This matches the bucket ids Apache Spark assigns when writing bucketed tables.",
+    syntax_example = "bucket(num_buckets, expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select bucket(16, 'abc');
++-------------------------------+
+| bucket(Int64(16),Utf8("abc")) |
++-------------------------------+
+| 4                             |
++-------------------------------+
+```"#,
+    argument(
+        name = "num_buckets",
+        description = "Number of buckets. Must be a positive integer."
+    ),
+    argument(
+        name = "expression1, expression_n",
+        description = "Expressions to hash. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct BucketFunc {
+    signature: Signature,
+}
+
+impl Default for BucketFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BucketFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for BucketFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "bucket"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let result = bucket(&arrays)?;
+        if is_scalar {
+            ScalarValue::try_from_array(&result, 0).map(ColumnarValue::Scalar)
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.len() < 2 {
+            return plan_err!(
+                "bucket was called with {} arguments. It requires at least 2.",
+                arg_types.len()
+            );
+        }
+        if !arg_types[0].is_integer() && !arg_types[0].is_null() {
+            return plan_err!(
+                "bucket requires the number of buckets to be an integer, got {}",
+                arg_types[0]
+            );
+        }
+
+        let mut coerced = arg_types.to_vec();
+        coerced[0] = DataType::Int64;
+        Ok(coerced)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Computes `pmod(murmur3_hash(args[1..]), args[0])` for every row, where
+/// `args[0]` is the Int64 number of buckets, which must fit in an Int32.
+pub fn bucket(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let num_buckets = args[0].as_primitive::<Int64Type>();
+    let mut hashes = vec![SPARK_HASH_SEED as u32; num_buckets.len()];
+    update_spark_hashes::<Murmur3>(&args[1..], &mut hashes)?;
+
+    let result = num_buckets
+        .iter()
+        .zip(hashes)
+        .map(|(n, hash)| match n {
+            Some(n) if n <= 0 => exec_err!(
+                "bucket() requires the number of buckets to be positive, got {n}"
+            ),
+            Some(n) if n > i32::MAX as i64 => exec_err!(
+                "bucket() requires the number of buckets to be at most {}, got {n}",
+                i32::MAX
+            ),
+            Some(n) => Ok(Some((hash as i32).rem_euclid(n as i32))),
+            None => Ok(None),
+        })
+        .collect::<Result<Int32Array>>()?;
+
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, Int64Array, StringArray};
+    use arrow::datatypes::Int32Type;
+
+    #[test]
+    fn test_bucket() {
+        let num_buckets = Arc::new(Int64Array::from(vec![Some(16), Some(4), None]));
+        let values = Arc::new(Int32Array::from(vec![1, 0, 1]));
+        let result = bucket(&[num_buckets, values]).unwrap();
+        // murmur3_hash(1) = -559580957, murmur3_hash(0) = 933211791
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(3), Some(3), None])
+        );
+    }
+
+    #[test]
+    fn test_bucket_invalid_num_buckets() {
+        let num_buckets = Arc::new(Int64Array::from(vec![0]));
+        let values = Arc::new(StringArray::from(vec!["a"]));
+        let err = bucket(&[num_buckets, values]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("bucket() requires the number of buckets to be positive, got 0"));
+
+        let num_buckets = Arc::new(Int64Array::from(vec![3000000000]));
+        let values = Arc::new(StringArray::from(vec!["a"]));
+        let err = bucket(&[num_buckets, values]).unwrap_err();
+        assert!(err.to_string().contains(
+            "bucket() requires the number of buckets to be at most 2147483647, got 3000000000"
+        ));
+    }
+}
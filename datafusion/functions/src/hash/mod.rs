@@ -0,0 +1,49 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! "hash" DataFusion functions compatible with Apache Spark
+
+use datafusion_expr::ScalarUDF;
+use std::sync::Arc;
+
+pub mod bucket;
+pub mod murmur3_hash;
+pub mod spark_hash;
+pub mod xxhash64;
+make_udf_function!(bucket::BucketFunc, bucket);
+make_udf_function!(murmur3_hash::Murmur3HashFunc, murmur3_hash);
+make_udf_function!(xxhash64::XxHash64Func, xxhash64);
+
+pub mod expr_fn {
+    export_functions!((
+        bucket,
+        "Returns the Spark compatible bucket id of the expressions in `args[1..]` for `args[0]` buckets.",
+        args,
+    ),(
+        murmur3_hash,
+        "Computes the Spark compatible 32 bit Murmur3 hash of the expressions.",
+        args,
+    ),(
+        xxhash64,
+        "Computes the Spark compatible 64 bit xxHash64 hash of the expressions.",
+        args,
+    ));
+}
+
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    vec![bucket(), murmur3_hash(), xxhash64()]
+}
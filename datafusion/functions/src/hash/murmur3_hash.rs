@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::hash::spark_hash::{hash_columnar_values, Murmur3, SPARK_HASH_SEED};
+use arrow::array::Int32Array;
+use arrow::datatypes::DataType;
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Hashing Functions"),
+    description = "Computes the 32 bit Murmur3 hash of one or more expressions, returning the same value as Apache Spark's `hash` function. Null values do not change the hash.",
+    syntax_example = "murmur3_hash(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select murmur3_hash('abc');
++---------------------------+
+| murmur3_hash(Utf8("abc")) |
++---------------------------+
+| 1322437556                |
++---------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expressions to hash. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct Murmur3HashFunc {
+    signature: Signature,
+}
+
+impl Default for Murmur3HashFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Murmur3HashFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for Murmur3HashFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "murmur3_hash"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        if arg_types.is_empty() {
+            return plan_err!("murmur3_hash requires at least one argument");
+        }
+        Ok(DataType::Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let (hashes, is_scalar) =
+            hash_columnar_values::<Murmur3>(&args.args, SPARK_HASH_SEED as u32)?;
+        if is_scalar {
+            return Ok(ColumnarValue::Scalar(ScalarValue::Int32(Some(
+                hashes[0] as i32,
+            ))));
+        }
+        Ok(ColumnarValue::Array(Arc::new(
+            Int32Array::from_iter_values(hashes.into_iter().map(|hash| hash as i32)),
+        )))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -0,0 +1,525 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Apache Spark compatible implementations of the Murmur3 (x86, 32 bit) and
+//! xxHash64 hash functions.
+//!
+//! Unlike the hashes in `datafusion_common::hash_utils`, these produce stable
+//! values that match Spark's `hash` and `xxhash64` expressions (and therefore
+//! Spark and Hive bucketing) for the same input.
+
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimeUnit, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type,
+    UInt8Type,
+};
+use datafusion_common::{exec_err, not_impl_err, Result};
+use datafusion_expr::ColumnarValue;
+
+/// The seed Spark uses for its `hash` and `xxhash64` expressions
+pub const SPARK_HASH_SEED: i64 = 42;
+
+/// A hash function with Spark's semantics for hashing typed values
+pub trait SparkHashFunction {
+    /// The running hash value, also used as the seed of the next value
+    type Hash: Copy;
+
+    /// Hashes `bytes` using `seed`
+    fn hash_bytes(bytes: &[u8], seed: Self::Hash) -> Self::Hash;
+
+    /// Hashes a 32 bit integer, as Spark does for all types of at most 32 bits
+    fn hash_int(value: i32, seed: Self::Hash) -> Self::Hash {
+        Self::hash_bytes(&value.to_le_bytes(), seed)
+    }
+
+    /// Hashes a 64 bit integer
+    fn hash_long(value: i64, seed: Self::Hash) -> Self::Hash {
+        Self::hash_bytes(&value.to_le_bytes(), seed)
+    }
+}
+
+/// Spark's `Murmur3_x86_32`.
+///
+/// This differs from the reference MurmurHash3 in how the trailing bytes of
+/// inputs whose length is not a multiple of 4 are mixed: Spark mixes each
+/// trailing byte (sign extended) as a separate 4 byte block.
+#[derive(Debug)]
+pub struct Murmur3;
+
+impl Murmur3 {
+    #[inline]
+    fn mix_k1(mut k1: u32) -> u32 {
+        k1 = k1.wrapping_mul(0xcc9e2d51);
+        k1 = k1.rotate_left(15);
+        k1.wrapping_mul(0x1b873593)
+    }
+
+    #[inline]
+    fn mix_h1(mut h1: u32, k1: u32) -> u32 {
+        h1 ^= k1;
+        h1 = h1.rotate_left(13);
+        h1.wrapping_mul(5).wrapping_add(0xe6546b64)
+    }
+
+    #[inline]
+    fn fmix(mut h1: u32, len: u32) -> u32 {
+        h1 ^= len;
+        h1 ^= h1 >> 16;
+        h1 = h1.wrapping_mul(0x85ebca6b);
+        h1 ^= h1 >> 13;
+        h1 = h1.wrapping_mul(0xc2b2ae35);
+        h1 ^ (h1 >> 16)
+    }
+}
+
+impl SparkHashFunction for Murmur3 {
+    type Hash = u32;
+
+    fn hash_bytes(bytes: &[u8], seed: u32) -> u32 {
+        let mut chunks = bytes.chunks_exact(4);
+        let mut h1 = seed;
+        for chunk in &mut chunks {
+            let word = u32::from_le_bytes(chunk.try_into().unwrap());
+            h1 = Self::mix_h1(h1, Self::mix_k1(word));
+        }
+        for &byte in chunks.remainder() {
+            let half_word = byte as i8 as i32 as u32;
+            h1 = Self::mix_h1(h1, Self::mix_k1(half_word));
+        }
+        Self::fmix(h1, bytes.len() as u32)
+    }
+}
+
+/// xxHash64, as implemented by Spark's `XxHash64`
+#[derive(Debug)]
+pub struct XxHash64;
+
+const PRIME64_1: u64 = 0x9E3779B185EBCA87;
+const PRIME64_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME64_3: u64 = 0x165667B19E3779F9;
+const PRIME64_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME64_5: u64 = 0x27D4EB2F165667C5;
+
+impl XxHash64 {
+    #[inline]
+    fn round(acc: u64, input: u64) -> u64 {
+        acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+            .rotate_left(31)
+            .wrapping_mul(PRIME64_1)
+    }
+
+    #[inline]
+    fn merge_round(acc: u64, val: u64) -> u64 {
+        (acc ^ Self::round(0, val))
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4)
+    }
+
+    #[inline]
+    fn read_u64(bytes: &[u8]) -> u64 {
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+
+    #[inline]
+    fn read_u32(bytes: &[u8]) -> u64 {
+        u32::from_le_bytes(bytes[..4].try_into().unwrap()) as u64
+    }
+}
+
+impl SparkHashFunction for XxHash64 {
+    type Hash = u64;
+
+    fn hash_bytes(bytes: &[u8], seed: u64) -> u64 {
+        let len = bytes.len();
+        let mut remaining = bytes;
+
+        let mut hash = if len >= 32 {
+            let mut v1 = seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2);
+            let mut v2 = seed.wrapping_add(PRIME64_2);
+            let mut v3 = seed;
+            let mut v4 = seed.wrapping_sub(PRIME64_1);
+            while remaining.len() >= 32 {
+                v1 = Self::round(v1, Self::read_u64(remaining));
+                v2 = Self::round(v2, Self::read_u64(&remaining[8..]));
+                v3 = Self::round(v3, Self::read_u64(&remaining[16..]));
+                v4 = Self::round(v4, Self::read_u64(&remaining[24..]));
+                remaining = &remaining[32..];
+            }
+            let mut hash = v1
+                .rotate_left(1)
+                .wrapping_add(v2.rotate_left(7))
+                .wrapping_add(v3.rotate_left(12))
+                .wrapping_add(v4.rotate_left(18));
+            hash = Self::merge_round(hash, v1);
+            hash = Self::merge_round(hash, v2);
+            hash = Self::merge_round(hash, v3);
+            Self::merge_round(hash, v4)
+        } else {
+            seed.wrapping_add(PRIME64_5)
+        };
+
+        hash = hash.wrapping_add(len as u64);
+
+        while remaining.len() >= 8 {
+            hash ^= Self::round(0, Self::read_u64(remaining));
+            hash = hash
+                .rotate_left(27)
+                .wrapping_mul(PRIME64_1)
+                .wrapping_add(PRIME64_4);
+            remaining = &remaining[8..];
+        }
+        if remaining.len() >= 4 {
+            hash ^= Self::read_u32(remaining).wrapping_mul(PRIME64_1);
+            hash = hash
+                .rotate_left(23)
+                .wrapping_mul(PRIME64_2)
+                .wrapping_add(PRIME64_3);
+            remaining = &remaining[4..];
+        }
+        for &byte in remaining {
+            hash ^= (byte as u64).wrapping_mul(PRIME64_5);
+            hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(PRIME64_2);
+        hash ^= hash >> 29;
+        hash = hash.wrapping_mul(PRIME64_3);
+        hash ^ (hash >> 32)
+    }
+}
+
+/// Updates `hashes` with the hash of every row of every array in `arrays`, in
+/// order, the way Spark hashes multiple expressions.
+///
+/// Null values leave the hash unchanged.
+pub fn update_spark_hashes<F: SparkHashFunction>(
+    arrays: &[ArrayRef],
+    hashes: &mut [F::Hash],
+) -> Result<()> {
+    for array in arrays {
+        // Dictionaries are hashed by value
+        let array = match array.data_type() {
+            DataType::Dictionary(_, value_type) => cast(array, value_type)?,
+            _ => ArrayRef::clone(array),
+        };
+        for (row, hash) in hashes.iter_mut().enumerate() {
+            *hash = hash_value::<F>(array.as_ref(), row, *hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Evaluates `F` over the function arguments `args`, returning one hash per row
+/// and whether all the arguments were scalars.
+pub(crate) fn hash_columnar_values<F: SparkHashFunction>(
+    args: &[ColumnarValue],
+    seed: F::Hash,
+) -> Result<(Vec<F::Hash>, bool)> {
+    let is_scalar = args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let number_rows = arrays.first().map_or(1, |array| array.len());
+    let mut hashes = vec![seed; number_rows];
+    update_spark_hashes::<F>(&arrays, &mut hashes)?;
+    Ok((hashes, is_scalar))
+}
+
+fn primitive_value<T: ArrowPrimitiveType>(array: &dyn Array, row: usize) -> T::Native {
+    array.as_primitive::<T>().value(row)
+}
+
+/// Returns the hash of the value at `row` of `array`, seeded with `seed`
+fn hash_value<F: SparkHashFunction>(
+    array: &dyn Array,
+    row: usize,
+    seed: F::Hash,
+) -> Result<F::Hash> {
+    if array.is_null(row) {
+        return Ok(seed);
+    }
+
+    let hash = match array.data_type() {
+        DataType::Null => seed,
+        DataType::Boolean => F::hash_int(array.as_boolean().value(row) as i32, seed),
+        DataType::Int8 => {
+            F::hash_int(primitive_value::<Int8Type>(array, row) as i32, seed)
+        }
+        DataType::Int16 => {
+            F::hash_int(primitive_value::<Int16Type>(array, row) as i32, seed)
+        }
+        DataType::Int32 => F::hash_int(primitive_value::<Int32Type>(array, row), seed),
+        DataType::Int64 => F::hash_long(primitive_value::<Int64Type>(array, row), seed),
+        DataType::UInt8 => {
+            F::hash_int(primitive_value::<UInt8Type>(array, row) as i32, seed)
+        }
+        DataType::UInt16 => {
+            F::hash_int(primitive_value::<UInt16Type>(array, row) as i32, seed)
+        }
+        DataType::UInt32 => {
+            F::hash_int(primitive_value::<UInt32Type>(array, row) as i32, seed)
+        }
+        DataType::UInt64 => {
+            F::hash_long(primitive_value::<UInt64Type>(array, row) as i64, seed)
+        }
+        DataType::Float16 => {
+            hash_f32::<F>(primitive_value::<Float16Type>(array, row).to_f32(), seed)
+        }
+        DataType::Float32 => {
+            hash_f32::<F>(primitive_value::<Float32Type>(array, row), seed)
+        }
+        DataType::Float64 => {
+            let value = primitive_value::<Float64Type>(array, row);
+            // Spark normalizes -0.0 to 0.0, and Java's doubleToLongBits
+            // canonicalizes NaN
+            let bits = if value == 0.0 {
+                0
+            } else if value.is_nan() {
+                0x7ff8000000000000
+            } else {
+                value.to_bits() as i64
+            };
+            F::hash_long(bits, seed)
+        }
+        DataType::Date32 => F::hash_int(primitive_value::<Date32Type>(array, row), seed),
+        DataType::Date64 => {
+            // Spark dates are days since the epoch
+            let days = primitive_value::<Date64Type>(array, row).div_euclid(86_400_000);
+            F::hash_int(days as i32, seed)
+        }
+        DataType::Time32(TimeUnit::Second) => {
+            F::hash_int(primitive_value::<Time32SecondType>(array, row), seed)
+        }
+        DataType::Time32(_) => {
+            F::hash_int(primitive_value::<Time32MillisecondType>(array, row), seed)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            F::hash_long(primitive_value::<Time64MicrosecondType>(array, row), seed)
+        }
+        DataType::Time64(_) => {
+            F::hash_long(primitive_value::<Time64NanosecondType>(array, row), seed)
+        }
+        // Spark timestamps are microseconds since the epoch
+        DataType::Timestamp(TimeUnit::Second, _) => F::hash_long(
+            primitive_value::<TimestampSecondType>(array, row).wrapping_mul(1_000_000),
+            seed,
+        ),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => F::hash_long(
+            primitive_value::<TimestampMillisecondType>(array, row).wrapping_mul(1_000),
+            seed,
+        ),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => F::hash_long(
+            primitive_value::<TimestampMicrosecondType>(array, row),
+            seed,
+        ),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => F::hash_long(
+            primitive_value::<TimestampNanosecondType>(array, row).div_euclid(1_000),
+            seed,
+        ),
+        DataType::Decimal128(precision, _) => {
+            let value = primitive_value::<Decimal128Type>(array, row);
+            if *precision <= 18 {
+                F::hash_long(value as i64, seed)
+            } else {
+                F::hash_bytes(&java_big_integer_bytes(&value.to_be_bytes()), seed)
+            }
+        }
+        DataType::Decimal256(precision, _) => {
+            let value = primitive_value::<Decimal256Type>(array, row);
+            if *precision <= 18 {
+                F::hash_long(value.as_i128() as i64, seed)
+            } else {
+                F::hash_bytes(&java_big_integer_bytes(&value.to_be_bytes()), seed)
+            }
+        }
+        DataType::Utf8 => {
+            F::hash_bytes(array.as_string::<i32>().value(row).as_bytes(), seed)
+        }
+        DataType::LargeUtf8 => {
+            F::hash_bytes(array.as_string::<i64>().value(row).as_bytes(), seed)
+        }
+        DataType::Utf8View => {
+            F::hash_bytes(array.as_string_view().value(row).as_bytes(), seed)
+        }
+        DataType::Binary => F::hash_bytes(array.as_binary::<i32>().value(row), seed),
+        DataType::LargeBinary => F::hash_bytes(array.as_binary::<i64>().value(row), seed),
+        DataType::BinaryView => F::hash_bytes(array.as_binary_view().value(row), seed),
+        DataType::FixedSizeBinary(_) => {
+            F::hash_bytes(array.as_fixed_size_binary().value(row), seed)
+        }
+        DataType::List(_) => {
+            hash_elements::<F>(&array.as_list::<i32>().value(row), seed)?
+        }
+        DataType::LargeList(_) => {
+            hash_elements::<F>(&array.as_list::<i64>().value(row), seed)?
+        }
+        DataType::FixedSizeList(_, _) => {
+            hash_elements::<F>(&array.as_fixed_size_list().value(row), seed)?
+        }
+        DataType::Struct(_) => {
+            let mut hash = seed;
+            for column in array.as_struct().columns() {
+                hash = hash_value::<F>(column.as_ref(), row, hash)?;
+            }
+            hash
+        }
+        DataType::Map(_, _) => {
+            let entries = array.as_map().value(row);
+            let mut hash = seed;
+            for i in 0..entries.len() {
+                hash = hash_value::<F>(entries.column(0).as_ref(), i, hash)?;
+                hash = hash_value::<F>(entries.column(1).as_ref(), i, hash)?;
+            }
+            hash
+        }
+        DataType::Dictionary(_, _) => {
+            return not_impl_err!("Spark compatible hashing of nested dictionaries")
+        }
+        other => {
+            return exec_err!(
+                "Unsupported data type {other} for Spark compatible hashing"
+            )
+        }
+    };
+    Ok(hash)
+}
+
+/// Hashes the elements of a list value in order
+fn hash_elements<F: SparkHashFunction>(
+    elements: &ArrayRef,
+    seed: F::Hash,
+) -> Result<F::Hash> {
+    let mut hash = seed;
+    for i in 0..elements.len() {
+        hash = hash_value::<F>(elements.as_ref(), i, hash)?;
+    }
+    Ok(hash)
+}
+
+fn hash_f32<F: SparkHashFunction>(value: f32, seed: F::Hash) -> F::Hash {
+    // Spark normalizes -0.0 to 0.0, and Java's floatToIntBits canonicalizes NaN
+    let bits = if value == 0.0 {
+        0
+    } else if value.is_nan() {
+        0x7fc00000
+    } else {
+        value.to_bits() as i32
+    };
+    F::hash_int(bits, seed)
+}
+
+/// Returns the minimal big-endian two's complement representation of the
+/// integer in `bytes`, matching Java's `BigInteger::toByteArray`
+fn java_big_integer_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let redundant = (bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{
+        Decimal128Array, Float64Array, Int32Array, Int64Array, ListArray, StringArray,
+    };
+    use arrow::datatypes::Int32Type;
+    use std::sync::Arc;
+
+    fn murmur3(arrays: &[ArrayRef]) -> Vec<i32> {
+        let mut hashes = vec![SPARK_HASH_SEED as u32; arrays[0].len()];
+        update_spark_hashes::<Murmur3>(arrays, &mut hashes).unwrap();
+        hashes.into_iter().map(|h| h as i32).collect()
+    }
+
+    fn xxhash64(arrays: &[ArrayRef]) -> Vec<i64> {
+        let mut hashes = vec![SPARK_HASH_SEED as u64; arrays[0].len()];
+        update_spark_hashes::<XxHash64>(arrays, &mut hashes).unwrap();
+        hashes.into_iter().map(|h| h as i64).collect()
+    }
+
+    #[test]
+    fn test_murmur3_int() {
+        // Values from Spark: SELECT hash(1), hash(0), hash(-1)
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(0), None]));
+        assert_eq!(murmur3(&[array]), vec![-559580957, 933211791, 42]);
+    }
+
+    #[test]
+    fn test_spark_documentation_examples() {
+        // SELECT hash('Spark', array(123), 2) and
+        // SELECT xxhash64('Spark', array(123), 2)
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["Spark"]));
+        let lists: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(123)]),
+            ]));
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![2]));
+        let arrays = [strings, lists, ints];
+
+        assert_eq!(murmur3(&arrays), vec![-1321691492]);
+        assert_eq!(xxhash64(&arrays), vec![5602566077635097486]);
+    }
+
+    #[test]
+    fn test_xxhash64_reference_values() {
+        // Reference XXH64 values with seed 0
+        assert_eq!(XxHash64::hash_bytes(b"", 0), 0xEF46DB3751D8E999);
+        assert_eq!(XxHash64::hash_bytes(b"a", 0), 0xD24EC4F1A98C6E5B);
+    }
+
+    #[test]
+    fn test_negative_zero_and_nan() {
+        let array: ArrayRef =
+            Arc::new(Float64Array::from(vec![0.0, -0.0, f64::NAN, -f64::NAN]));
+        let hashes = murmur3(&[array]);
+        assert_eq!(hashes[0], hashes[1]);
+        assert_eq!(hashes[2], hashes[3]);
+    }
+
+    #[test]
+    fn test_decimal() {
+        // Decimals with precision <= 18 hash as their unscaled long value
+        let decimals: ArrayRef = Arc::new(
+            Decimal128Array::from(vec![12345])
+                .with_precision_and_scale(10, 2)
+                .unwrap(),
+        );
+        let longs: ArrayRef = Arc::new(Int64Array::from(vec![12345]));
+        assert_eq!(murmur3(&[decimals]), murmur3(&[longs]));
+
+        assert_eq!(java_big_integer_bytes(&0i128.to_be_bytes()), vec![0]);
+        assert_eq!(java_big_integer_bytes(&128i128.to_be_bytes()), vec![0, 128]);
+        assert_eq!(java_big_integer_bytes(&(-1i128).to_be_bytes()), vec![0xff]);
+        assert_eq!(
+            java_big_integer_bytes(&(-129i128).to_be_bytes()),
+            vec![0xff, 0x7f]
+        );
+    }
+}
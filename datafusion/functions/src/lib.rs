@@ -124,20 +124,44 @@ make_stub_package!(encoding, "encoding_expressions");
 pub mod math;
 make_stub_package!(math, "math_expressions");
 
+/// URL and IP address functions.
+/// Enabled via feature flag `net_expressions`
+#[cfg(feature = "net_expressions")]
+pub mod net;
+make_stub_package!(net, "net_expressions");
+
 /// Regular expression functions.
 /// Enabled via feature flag `regex_expressions`
 #[cfg(feature = "regex_expressions")]
 pub mod regex;
 make_stub_package!(regex, "regex_expressions");
 
+/// Functions to cluster rows by several columns, such as `zorder`.
+/// Enabled via feature flag `clustering_expressions`
+#[cfg(feature = "clustering_expressions")]
+pub mod clustering;
+make_stub_package!(clustering, "clustering_expressions");
+
 #[cfg(feature = "crypto_expressions")]
 pub mod crypto;
 make_stub_package!(crypto, "crypto_expressions");
 
+/// Apache Spark compatible hash functions.
+/// Enabled via feature flag `hash_expressions`
+#[cfg(feature = "hash_expressions")]
+pub mod hash;
+make_stub_package!(hash, "hash_expressions");
+
 #[cfg(feature = "unicode_expressions")]
 pub mod unicode;
 make_stub_package!(unicode, "unicode_expressions");
 
+/// Text similarity and normalization functions.
+/// Enabled via feature flag `text_expressions`
+#[cfg(feature = "text_expressions")]
+pub mod text;
+make_stub_package!(text, "text_expressions");
+
 #[cfg(any(feature = "datetime_expressions", feature = "unicode_expressions"))]
 pub mod planner;
 
@@ -147,6 +171,8 @@ pub mod utils;
 
 /// Fluent-style API for creating `Expr`s
 pub mod expr_fn {
+    #[cfg(feature = "clustering_expressions")]
+    pub use super::clustering::expr_fn::*;
     #[cfg(feature = "core_expressions")]
     pub use super::core::expr_fn::*;
     #[cfg(feature = "crypto_expressions")]
@@ -155,12 +181,18 @@ pub mod expr_fn {
     pub use super::datetime::expr_fn::*;
     #[cfg(feature = "encoding_expressions")]
     pub use super::encoding::expr_fn::*;
+    #[cfg(feature = "hash_expressions")]
+    pub use super::hash::expr_fn::*;
     #[cfg(feature = "math_expressions")]
     pub use super::math::expr_fn::*;
+    #[cfg(feature = "net_expressions")]
+    pub use super::net::expr_fn::*;
     #[cfg(feature = "regex_expressions")]
     pub use super::regex::expr_fn::*;
     #[cfg(feature = "string_expressions")]
     pub use super::string::expr_fn::*;
+    #[cfg(feature = "text_expressions")]
+    pub use super::text::expr_fn::*;
     #[cfg(feature = "unicode_expressions")]
     pub use super::unicode::expr_fn::*;
 }
@@ -172,10 +204,14 @@ pub fn all_default_functions() -> Vec<Arc<ScalarUDF>> {
         .chain(datetime::functions())
         .chain(encoding::functions())
         .chain(math::functions())
+        .chain(net::functions())
         .chain(regex::functions())
         .chain(crypto::functions())
+        .chain(hash::functions())
         .chain(unicode::functions())
         .chain(string::functions())
+        .chain(text::functions())
+        .chain(clustering::functions())
         .collect::<Vec<_>>()
 }
 
@@ -0,0 +1,193 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::datatypes::DataType;
+
+use crate::net::common::map_string_rows;
+use crate::utils::make_scalar_function;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns true if an IP address is part of a network in CIDR notation. Both IPv4 and IPv6 are supported; an address is never part of a network of the other address family. A network without a prefix length only contains the address itself. Returns NULL if the network or the address can not be parsed.",
+    syntax_example = "cidr_contains(net, ip)",
+    sql_example = r#"```sql
+> select cidr_contains('10.0.0.0/8', '10.1.2.3');
++----------------------------------------------------+
+| cidr_contains(Utf8("10.0.0.0/8"),Utf8("10.1.2.3")) |
++----------------------------------------------------+
+| true                                               |
++----------------------------------------------------+
+```"#,
+    argument(
+        name = "net",
+        description = "Network in CIDR notation, such as `192.168.0.0/16` or `2001:db8::/32`. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "ip",
+        description = "IP address to test. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct CidrContainsFunc {
+    signature: Signature,
+}
+
+impl Default for CidrContainsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CidrContainsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for CidrContainsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "cidr_contains"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(cidr_contains, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns whether every address of `args[1]` is part of the network of `args[0]`
+pub fn cidr_contains(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_string_rows("cidr_contains", args, |row| {
+        let Some(network) = Network::parse(row[0]) else {
+            return Ok(None);
+        };
+        Ok(row[1]
+            .trim()
+            .parse::<IpAddr>()
+            .ok()
+            .map(|ip| network.contains(ip)))
+    })?;
+    Ok(Arc::new(BooleanArray::from(values)))
+}
+
+/// An IP network, stored as its address and prefix length widened to 128 bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Network {
+    is_ipv4: bool,
+    address: u128,
+    mask: u128,
+}
+
+impl Network {
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (address, prefix) = match value.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (value, None),
+        };
+        let (is_ipv4, address) = ip_to_bits(address.parse().ok()?);
+        let max_len = if is_ipv4 { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.parse::<u32>().ok().filter(|len| *len <= max_len)?,
+            None => max_len,
+        };
+        let mask = match prefix {
+            0 => 0,
+            len => (u128::MAX << (128 - len)) >> (128 - max_len),
+        };
+        Some(Self {
+            is_ipv4,
+            address: address & mask,
+            mask,
+        })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        let (is_ipv4, address) = ip_to_bits(ip);
+        is_ipv4 == self.is_ipv4 && address & self.mask == self.address
+    }
+}
+
+fn ip_to_bits(ip: IpAddr) -> (bool, u128) {
+    match ip {
+        IpAddr::V4(ip) => (true, u32::from(ip) as u128),
+        IpAddr::V6(ip) => (false, u128::from(ip)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contains(network: &str, ip: &str) -> Option<bool> {
+        let network = Network::parse(network)?;
+        Some(network.contains(ip.parse().ok()?))
+    }
+
+    #[test]
+    fn test_ipv4_networks() {
+        assert_eq!(contains("10.0.0.0/8", "10.255.0.1"), Some(true));
+        assert_eq!(contains("10.0.0.0/8", "11.0.0.1"), Some(false));
+        assert_eq!(contains("192.168.1.7/24", "192.168.1.200"), Some(true));
+        assert_eq!(contains("0.0.0.0/0", "8.8.8.8"), Some(true));
+        assert_eq!(contains("8.8.8.8", "8.8.8.8"), Some(true));
+        assert_eq!(contains("8.8.8.8/32", "8.8.8.9"), Some(false));
+        assert_eq!(contains("10.0.0.0/8", "::1"), Some(false));
+    }
+
+    #[test]
+    fn test_ipv6_networks() {
+        assert_eq!(contains("2001:db8::/32", "2001:db8:1::1"), Some(true));
+        assert_eq!(contains("2001:db8::/32", "2001:db9::1"), Some(false));
+        assert_eq!(contains("::/0", "::1"), Some(true));
+        assert_eq!(contains("::/0", "10.0.0.1"), Some(false));
+    }
+
+    #[test]
+    fn test_invalid_networks() {
+        assert_eq!(Network::parse("10.0.0.0/33"), None);
+        assert_eq!(Network::parse("10.0.0.0/"), None);
+        assert_eq!(Network::parse("10.0.0/8"), None);
+        assert_eq!(Network::parse("2001:db8::/129"), None);
+    }
+}
@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels shared by the network functions
+
+use arrow::array::StringArrayType;
+use arrow::array::{Array, ArrayRef, AsArray, LargeStringArray, StringArray};
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, Result};
+use std::sync::Arc;
+
+/// Calls `op` with the values of every row of the string arrays `args`,
+/// which must all have the same string type. Rows where any value is null
+/// are null in the result without calling `op`.
+pub(crate) fn map_string_rows<R, F>(
+    name: &str,
+    args: &[ArrayRef],
+    op: F,
+) -> Result<Vec<Option<R>>>
+where
+    F: FnMut(&[&str]) -> Result<Option<R>>,
+{
+    match args[0].data_type() {
+        DataType::Utf8 => map_string_rows_impl(
+            args.iter().map(|arg| arg.as_string::<i32>()).collect(),
+            op,
+        ),
+        DataType::LargeUtf8 => map_string_rows_impl(
+            args.iter().map(|arg| arg.as_string::<i64>()).collect(),
+            op,
+        ),
+        DataType::Utf8View => map_string_rows_impl(
+            args.iter().map(|arg| arg.as_string_view()).collect(),
+            op,
+        ),
+        other => exec_err!(
+            "{name} was called with {other} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+        ),
+    }
+}
+
+fn map_string_rows_impl<'a, S, R, F>(arrays: Vec<S>, mut op: F) -> Result<Vec<Option<R>>>
+where
+    S: StringArrayType<'a>,
+    F: FnMut(&[&str]) -> Result<Option<R>>,
+{
+    let len = arrays.first().map_or(0, |array| array.len());
+    let mut values = Vec::with_capacity(arrays.len());
+    let mut result = Vec::with_capacity(len);
+    for i in 0..len {
+        if arrays.iter().any(|array| array.is_null(i)) {
+            result.push(None);
+            continue;
+        }
+        values.clear();
+        values.extend(arrays.iter().map(|array| array.value(i)));
+        result.push(op(&values)?);
+    }
+    Ok(result)
+}
+
+/// Returns `values` as a `LargeUtf8` array if `input_type` is `LargeUtf8`,
+/// and as a `Utf8` array otherwise
+pub(crate) fn to_string_array(
+    input_type: &DataType,
+    values: Vec<Option<String>>,
+) -> ArrayRef {
+    if input_type == &DataType::LargeUtf8 {
+        Arc::new(LargeStringArray::from(values))
+    } else {
+        Arc::new(StringArray::from(values))
+    }
+}
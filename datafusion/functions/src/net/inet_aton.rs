@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::DataType;
+
+use crate::net::common::map_string_rows;
+use crate::utils::make_scalar_function;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Converts an IPv4 address in dotted decimal notation to its numeric value. Returns NULL if the string is not a valid IPv4 address.",
+    syntax_example = "inet_aton(str)",
+    sql_example = r#"```sql
+> select inet_aton('192.168.1.1');
++--------------------------------+
+| inet_aton(Utf8("192.168.1.1")) |
++--------------------------------+
+| 3232235777                     |
++--------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    related_udf(name = "inet_ntoa")
+)]
+#[derive(Debug)]
+pub struct InetAtonFunc {
+    signature: Signature,
+}
+
+impl Default for InetAtonFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InetAtonFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for InetAtonFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "inet_aton"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(inet_aton, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Converts every IPv4 address of `args[0]` to its numeric value
+pub fn inet_aton(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_string_rows("inet_aton", args, |row| {
+        Ok(row[0]
+            .parse::<Ipv4Addr>()
+            .ok()
+            .map(|addr| u32::from(addr) as i64))
+    })?;
+    Ok(Arc::new(Int64Array::from(values)))
+}
@@ -0,0 +1,163 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, StringArray};
+use arrow::datatypes::{DataType, Int64Type};
+
+use crate::utils::make_scalar_function;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Converts the numeric value of an IPv4 address to dotted decimal notation. Returns NULL if the value is outside of the range of IPv4 addresses.",
+    syntax_example = "inet_ntoa(expression)",
+    sql_example = r#"```sql
+> select inet_ntoa(3232235777);
++------------------------------+
+| inet_ntoa(Int64(3232235777)) |
++------------------------------+
+| 192.168.1.1                  |
++------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Integer expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    ),
+    related_udf(name = "inet_aton")
+)]
+#[derive(Debug)]
+pub struct InetNtoaFunc {
+    signature: Signature,
+}
+
+impl Default for InetNtoaFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InetNtoaFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for InetNtoaFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "inet_ntoa"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(inet_ntoa, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Converts every numeric IPv4 address of `args[0]` to dotted decimal notation
+pub fn inet_ntoa(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let result = args[0]
+        .as_primitive::<Int64Type>()
+        .iter()
+        .map(|value| {
+            let value = u32::try_from(value?).ok()?;
+            Some(Ipv4Addr::from(value).to_string())
+        })
+        .collect::<StringArray>();
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net::inet_aton::inet_aton;
+    use arrow::array::Int64Array;
+    use datafusion_common::cast::{as_int64_array, as_string_array};
+
+    #[test]
+    fn test_inet_ntoa() -> Result<()> {
+        let values = Arc::new(Int64Array::from(vec![
+            Some(0),
+            Some(3232235777),
+            Some(4294967295),
+            Some(4294967296),
+            Some(-1),
+            None,
+        ])) as ArrayRef;
+        let result = inet_ntoa(&[values])?;
+        assert_eq!(
+            as_string_array(&result)?,
+            &StringArray::from(vec![
+                Some("0.0.0.0"),
+                Some("192.168.1.1"),
+                Some("255.255.255.255"),
+                None,
+                None,
+                None
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_inet_aton_round_trip() -> Result<()> {
+        let addresses = Arc::new(StringArray::from(vec![
+            Some("10.0.0.1"),
+            Some("256.0.0.1"),
+            Some("10.0.1"),
+            Some("::1"),
+        ])) as ArrayRef;
+        let numbers = inet_aton(&[addresses])?;
+        assert_eq!(
+            as_int64_array(&numbers)?,
+            &Int64Array::from(vec![Some(167772161), None, None, None])
+        );
+        let result = inet_ntoa(&[numbers])?;
+        assert_eq!(
+            as_string_array(&result)?,
+            &StringArray::from(vec![Some("10.0.0.1"), None, None, None])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::datatypes::DataType;
+
+use crate::net::common::map_string_rows;
+use crate::utils::make_scalar_function;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns true if a string is an IPv4 address in dotted decimal notation.",
+    syntax_example = "is_ipv4(str)",
+    sql_example = r#"```sql
+> select is_ipv4('192.168.1.1'), is_ipv4('192.168.1.256');
++------------------------------+--------------------------------+
+| is_ipv4(Utf8("192.168.1.1")) | is_ipv4(Utf8("192.168.1.256")) |
++------------------------------+--------------------------------+
+| true                         | false                          |
++------------------------------+--------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String")
+)]
+#[derive(Debug)]
+pub struct IsIpv4Func {
+    signature: Signature,
+}
+
+impl Default for IsIpv4Func {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IsIpv4Func {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for IsIpv4Func {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "is_ipv4"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(is_ipv4, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns whether every string of `args[0]` is an IPv4 address
+pub fn is_ipv4(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_string_rows("is_ipv4", args, |row| {
+        Ok(Some(row[0].parse::<Ipv4Addr>().is_ok()))
+    })?;
+    Ok(Arc::new(BooleanArray::from(values)))
+}
@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! "net" DataFusion functions for URLs and IP addresses
+
+use datafusion_expr::ScalarUDF;
+use std::sync::Arc;
+
+pub mod cidr_contains;
+pub(crate) mod common;
+pub mod inet_aton;
+pub mod inet_ntoa;
+pub mod is_ipv4;
+pub mod parse_url;
+pub mod url_decode;
+pub mod url_encode;
+pub mod url_extract;
+
+make_udf_function!(cidr_contains::CidrContainsFunc, cidr_contains);
+make_udf_function!(inet_aton::InetAtonFunc, inet_aton);
+make_udf_function!(inet_ntoa::InetNtoaFunc, inet_ntoa);
+make_udf_function!(is_ipv4::IsIpv4Func, is_ipv4);
+make_udf_function!(parse_url::ParseUrlFunc, parse_url);
+make_udf_function!(url_decode::UrlDecodeFunc, url_decode);
+make_udf_function!(url_encode::UrlEncodeFunc, url_encode);
+make_udf_function!(url_extract::UrlExtractHostFunc, url_extract_host);
+make_udf_function!(url_extract::UrlExtractParameterFunc, url_extract_parameter);
+make_udf_function!(url_extract::UrlExtractPathFunc, url_extract_path);
+make_udf_function!(url_extract::UrlExtractPortFunc, url_extract_port);
+make_udf_function!(url_extract::UrlExtractQueryFunc, url_extract_query);
+
+pub mod expr_fn {
+    export_functions!((
+        cidr_contains,
+        "Returns true if the IP address `ip` is part of the network `net` in CIDR notation.",
+        net ip
+    ),(
+        inet_aton,
+        "Converts an IPv4 address in dotted decimal notation to its numeric value.",
+        arg1
+    ),(
+        inet_ntoa,
+        "Converts the numeric value of an IPv4 address to dotted decimal notation.",
+        arg1
+    ),(
+        is_ipv4,
+        "Returns true if the string is an IPv4 address.",
+        arg1
+    ),(
+        parse_url,
+        "Extracts a part of a URL, and optionally the value of a query parameter.",
+        args,
+    ),(
+        url_decode,
+        "Decodes an application/x-www-form-urlencoded string.",
+        arg1
+    ),(
+        url_encode,
+        "Encodes a string as application/x-www-form-urlencoded.",
+        arg1
+    ),(
+        url_extract_host,
+        "Returns the host of a URL.",
+        url
+    ),(
+        url_extract_parameter,
+        "Returns the value of the first query parameter of a URL with the given name.",
+        url name
+    ),(
+        url_extract_path,
+        "Returns the path of a URL.",
+        url
+    ),(
+        url_extract_port,
+        "Returns the port of a URL.",
+        url
+    ),(
+        url_extract_query,
+        "Returns the query string of a URL.",
+        url
+    ));
+}
+
+/// Returns all DataFusion functions defined in this package
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    vec![
+        cidr_contains(),
+        inet_aton(),
+        inet_ntoa(),
+        is_ipv4(),
+        parse_url(),
+        url_decode(),
+        url_encode(),
+        url_extract_host(),
+        url_extract_parameter(),
+        url_extract_path(),
+        url_extract_port(),
+        url_extract_query(),
+    ]
+}
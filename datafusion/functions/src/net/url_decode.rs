@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::DataType;
+
+use crate::net::common::{map_string_rows, to_string_array};
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Decodes a string in the `application/x-www-form-urlencoded` format: `+` is decoded as a space and `%XX` escapes as the byte they represent. Returns an error if the string contains an invalid escape or does not decode to valid UTF-8.",
+    syntax_example = "url_decode(str)",
+    sql_example = r#"```sql
+> select url_decode('data+fusion%2F%C3%A4b');
++-------------------------------------------+
+| url_decode(Utf8("data+fusion%2F%C3%A4b")) |
++-------------------------------------------+
+| data fusion/äb                            |
++-------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    related_udf(name = "url_encode")
+)]
+#[derive(Debug)]
+pub struct UrlDecodeFunc {
+    signature: Signature,
+}
+
+impl Default for UrlDecodeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlDecodeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UrlDecodeFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_decode"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "url_decode")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(url_decode, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Decodes every `application/x-www-form-urlencoded` string of `args[0]`
+pub fn url_decode(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values =
+        map_string_rows("url_decode", args, |row| decode_component(row[0]).map(Some))?;
+    Ok(to_string_array(args[0].data_type(), values))
+}
+
+fn decode_component(value: &str) -> Result<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = |offset: usize| {
+                    bytes
+                        .get(i + offset)
+                        .and_then(|&digit| (digit as char).to_digit(16))
+                };
+                let (Some(high), Some(low)) = (hex(1), hex(2)) else {
+                    return exec_err!(
+                        "url_decode() found an invalid escape sequence at position {i} of '{value}'"
+                    );
+                };
+                decoded.push((high * 16 + low) as u8);
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    match String::from_utf8(decoded) {
+        Ok(decoded) => Ok(decoded),
+        Err(_) => exec_err!("url_decode() decoded '{value}' to invalid UTF-8"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_component;
+
+    #[test]
+    fn test_decode_component() {
+        assert_eq!(decode_component("").unwrap(), "");
+        assert_eq!(decode_component("a+b%26c%3dd").unwrap(), "a b&c=d");
+        assert_eq!(decode_component("%C3%A4%D0%B1").unwrap(), "äб");
+        assert_eq!(decode_component("a%2Bb").unwrap(), "a+b");
+    }
+
+    #[test]
+    fn test_decode_component_invalid() {
+        for value in ["100%", "%4", "%zz", "%+1"] {
+            let err = decode_component(value).unwrap_err();
+            assert!(err.to_string().contains("invalid escape sequence"), "{err}");
+        }
+        let err = decode_component("%FF").unwrap_err();
+        assert!(err.to_string().contains("invalid UTF-8"), "{err}");
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::DataType;
+
+use crate::net::common::{map_string_rows, to_string_array};
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Encodes a string using the `application/x-www-form-urlencoded` format, so it can be safely included in a URL query string. Alphanumeric characters and `.`, `-`, `*` and `_` are kept, spaces are encoded as `+` and all other characters are encoded as the `%XX` escapes of their UTF-8 bytes.",
+    syntax_example = "url_encode(str)",
+    sql_example = r#"```sql
+> select url_encode('data fusion/äb');
++------------------------------------+
+| url_encode(Utf8("data fusion/äb")) |
++------------------------------------+
+| data+fusion%2F%C3%A4b              |
++------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    related_udf(name = "url_decode")
+)]
+#[derive(Debug)]
+pub struct UrlEncodeFunc {
+    signature: Signature,
+}
+
+impl Default for UrlEncodeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlEncodeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UrlEncodeFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_encode"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "url_encode")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(url_encode, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Encodes every string of `args[0]` as `application/x-www-form-urlencoded`
+pub fn url_encode(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values =
+        map_string_rows("url_encode", args, |row| Ok(Some(encode_component(row[0]))))?;
+    Ok(to_string_array(args[0].data_type(), values))
+}
+
+fn encode_component(value: &str) -> String {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut encoded = String::with_capacity(value.len());
+    for &byte in value.as_bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'-' | b'*' | b'_' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => {
+                encoded.push('%');
+                encoded.push(HEX[(byte >> 4) as usize] as char);
+                encoded.push(HEX[(byte & 0xF) as usize] as char);
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_component;
+
+    #[test]
+    fn test_encode_component() {
+        assert_eq!(encode_component(""), "");
+        assert_eq!(encode_component("a-b_c.d*e"), "a-b_c.d*e");
+        assert_eq!(encode_component("a b&c=d"), "a+b%26c%3Dd");
+        assert_eq!(encode_component("100%"), "100%25");
+        assert_eq!(encode_component("äб"), "%C3%A4%D0%B1");
+    }
+}
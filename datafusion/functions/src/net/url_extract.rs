@@ -0,0 +1,418 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::DataType;
+use url::Url;
+
+use crate::net::common::{map_string_rows, to_string_array};
+use crate::net::parse_url::{query_parameter, UrlPart};
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns the host of a URL, or NULL if the URL can not be parsed or has no host.",
+    syntax_example = "url_extract_host(url)",
+    sql_example = r#"```sql
+> select url_extract_host('https://example.com:8443/index.html');
++---------------------------------------------------------------+
+| url_extract_host(Utf8("https://example.com:8443/index.html")) |
++---------------------------------------------------------------+
+| example.com                                                   |
++---------------------------------------------------------------+
+```"#,
+    argument(
+        name = "url",
+        description = "URL expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct UrlExtractHostFunc {
+    signature: Signature,
+}
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns the path of a URL, or NULL if the URL can not be parsed.",
+    syntax_example = "url_extract_path(url)",
+    sql_example = r#"```sql
+> select url_extract_path('https://example.com:8443/index.html');
++---------------------------------------------------------------+
+| url_extract_path(Utf8("https://example.com:8443/index.html")) |
++---------------------------------------------------------------+
+| /index.html                                                   |
++---------------------------------------------------------------+
+```"#,
+    argument(
+        name = "url",
+        description = "URL expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct UrlExtractPathFunc {
+    signature: Signature,
+}
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns the query string of a URL without the leading `?`, or NULL if the URL can not be parsed or has no query string.",
+    syntax_example = "url_extract_query(url)",
+    sql_example = r#"```sql
+> select url_extract_query('https://example.com/search?q=datafusion&page=2');
++---------------------------------------------------------------------------+
+| url_extract_query(Utf8("https://example.com/search?q=datafusion&page=2")) |
++---------------------------------------------------------------------------+
+| q=datafusion&page=2                                                       |
++---------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "url",
+        description = "URL expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct UrlExtractQueryFunc {
+    signature: Signature,
+}
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns the port of a URL, or NULL if the URL can not be parsed or has no port. A port that is the default port of the URL's scheme, such as 443 for `https`, is not considered part of the URL.",
+    syntax_example = "url_extract_port(url)",
+    sql_example = r#"```sql
+> select url_extract_port('https://example.com:8443/index.html');
++---------------------------------------------------------------+
+| url_extract_port(Utf8("https://example.com:8443/index.html")) |
++---------------------------------------------------------------+
+| 8443                                                          |
++---------------------------------------------------------------+
+```"#,
+    argument(
+        name = "url",
+        description = "URL expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct UrlExtractPortFunc {
+    signature: Signature,
+}
+
+#[user_doc(
+    doc_section(label = "Network Functions"),
+    description = "Returns the decoded value of the first query parameter of a URL with the given name, or NULL if the URL can not be parsed or has no such parameter.",
+    syntax_example = "url_extract_parameter(url, name)",
+    sql_example = r#"```sql
+> select url_extract_parameter('https://example.com/search?q=data+fusion&page=2', 'q');
++------------------------------------------------------------------------------------------+
+| url_extract_parameter(Utf8("https://example.com/search?q=data+fusion&page=2"),Utf8("q")) |
++------------------------------------------------------------------------------------------+
+| data fusion                                                                              |
++------------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "url",
+        description = "URL expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(name = "name", description = "Name of the query parameter.")
+)]
+#[derive(Debug)]
+pub struct UrlExtractParameterFunc {
+    signature: Signature,
+}
+
+impl Default for UrlExtractHostFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlExtractHostFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for UrlExtractPathFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlExtractPathFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for UrlExtractQueryFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlExtractQueryFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for UrlExtractPortFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlExtractPortFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for UrlExtractParameterFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlExtractParameterFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UrlExtractHostFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_extract_host"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "url_extract_host")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(
+            |args: &[ArrayRef]| extract_part("url_extract_host", args, UrlPart::Host),
+            vec![],
+        )(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+impl ScalarUDFImpl for UrlExtractPathFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_extract_path"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "url_extract_path")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(
+            |args: &[ArrayRef]| extract_part("url_extract_path", args, UrlPart::Path),
+            vec![],
+        )(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+impl ScalarUDFImpl for UrlExtractQueryFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_extract_query"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "url_extract_query")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(
+            |args: &[ArrayRef]| extract_part("url_extract_query", args, UrlPart::Query),
+            vec![],
+        )(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+impl ScalarUDFImpl for UrlExtractPortFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_extract_port"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(url_extract_port, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+impl ScalarUDFImpl for UrlExtractParameterFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_extract_parameter"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "url_extract_parameter")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(url_extract_parameter, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Extracts `part` of the URLs in `args[0]`
+fn extract_part(name: &str, args: &[ArrayRef], part: UrlPart) -> Result<ArrayRef> {
+    let values = map_string_rows(name, args, |row| {
+        Ok(Url::parse(row[0]).ok().and_then(|url| part.extract(&url)))
+    })?;
+    Ok(to_string_array(args[0].data_type(), values))
+}
+
+pub fn url_extract_port(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_string_rows("url_extract_port", args, |row| {
+        Ok(Url::parse(row[0])
+            .ok()
+            .and_then(|url| url.port())
+            .map(i64::from))
+    })?;
+    Ok(Arc::new(Int64Array::from(values)))
+}
+
+pub fn url_extract_parameter(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_string_rows("url_extract_parameter", args, |row| {
+        Ok(Url::parse(row[0])
+            .ok()
+            .and_then(|url| query_parameter(&url, row[1])))
+    })?;
+    Ok(to_string_array(args[0].data_type(), values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{StringArray, StringViewArray};
+    use datafusion_common::cast::{as_int64_array, as_string_array};
+
+    #[test]
+    fn test_url_extract_port() -> Result<()> {
+        let urls = Arc::new(StringViewArray::from(vec![
+            Some("https://example.com:8443/"),
+            Some("https://example.com:443/"),
+            Some("https://example.com/"),
+            Some("not a url"),
+            None,
+        ])) as ArrayRef;
+        let result = url_extract_port(&[urls])?;
+        assert_eq!(
+            as_int64_array(&result)?,
+            &Int64Array::from(vec![Some(8443), None, None, None, None])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_extract_host() -> Result<()> {
+        let urls = Arc::new(StringArray::from(vec![
+            Some("https://Example.COM/"),
+            Some("http://[::1]:8080/"),
+            Some("mailto:someone@example.com"),
+            Some("/relative/path"),
+        ])) as ArrayRef;
+        let result = extract_part("url_extract_host", &[urls], UrlPart::Host)?;
+        assert_eq!(
+            as_string_array(&result)?,
+            &StringArray::from(vec![Some("example.com"), Some("[::1]"), None, None])
+        );
+        Ok(())
+    }
+}
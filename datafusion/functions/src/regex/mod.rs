@@ -20,17 +20,29 @@
 use std::sync::Arc;
 
 pub mod regexpcount;
+pub mod regexpextract;
+pub mod regexpinstr;
 pub mod regexplike;
 pub mod regexpmatch;
 pub mod regexpreplace;
+pub mod regexpsplit;
+pub mod regexpsubstr;
+mod utils;
+
+pub use utils::compile_regex;
 
 // create UDFs
 make_udf_function!(regexpcount::RegexpCountFunc, regexp_count);
 make_udf_function!(regexpmatch::RegexpMatchFunc, regexp_match);
 make_udf_function!(regexplike::RegexpLikeFunc, regexp_like);
 make_udf_function!(regexpreplace::RegexpReplaceFunc, regexp_replace);
+make_udf_function!(regexpsubstr::RegexpSubstrFunc, regexp_substr);
+make_udf_function!(regexpinstr::RegexpInstrFunc, regexp_instr);
+make_udf_function!(regexpextract::RegexpExtractFunc, regexp_extract);
+make_udf_function!(regexpsplit::RegexpSplitToArrayFunc, regexp_split_to_array);
 
 pub mod expr_fn {
+    use datafusion_common::ScalarValue;
     use datafusion_expr::Expr;
 
     /// Returns the number of consecutive occurrences of a regular expression in a string.
@@ -82,6 +94,77 @@ pub mod expr_fn {
         };
         super::regexp_replace().call(args)
     }
+
+    /// Returns the substring that matches a regular expression.
+    pub fn regexp_substr(
+        values: Expr,
+        regex: Expr,
+        start: Option<Expr>,
+        occurrence: Option<Expr>,
+        flags: Option<Expr>,
+        group: Option<Expr>,
+    ) -> Expr {
+        let mut args = vec![values, regex];
+        args.extend(optional_args(vec![
+            (start, ScalarValue::Int64(Some(1))),
+            (occurrence, ScalarValue::Int64(Some(1))),
+            (flags, ScalarValue::from("")),
+            (group, ScalarValue::Int64(Some(0))),
+        ]));
+        super::regexp_substr().call(args)
+    }
+
+    /// Returns the 1 based position of a regular expression match in a string, or 0 if there is no match.
+    pub fn regexp_instr(
+        values: Expr,
+        regex: Expr,
+        start: Option<Expr>,
+        occurrence: Option<Expr>,
+        end_option: Option<Expr>,
+        flags: Option<Expr>,
+        group: Option<Expr>,
+    ) -> Expr {
+        let mut args = vec![values, regex];
+        args.extend(optional_args(vec![
+            (start, ScalarValue::Int64(Some(1))),
+            (occurrence, ScalarValue::Int64(Some(1))),
+            (end_option, ScalarValue::Int64(Some(0))),
+            (flags, ScalarValue::from("")),
+            (group, ScalarValue::Int64(Some(0))),
+        ]));
+        super::regexp_instr().call(args)
+    }
+
+    /// Extracts a capture group of the first regular expression match in a string.
+    pub fn regexp_extract(values: Expr, regex: Expr, group: Option<Expr>) -> Expr {
+        let mut args = vec![values, regex];
+        if let Some(group) = group {
+            args.push(group);
+        };
+        super::regexp_extract().call(args)
+    }
+
+    /// Splits a string using a regular expression as the delimiter.
+    pub fn regexp_split_to_array(values: Expr, regex: Expr, flags: Option<Expr>) -> Expr {
+        let mut args = vec![values, regex];
+        if let Some(flags) = flags {
+            args.push(flags);
+        };
+        super::regexp_split_to_array().call(args)
+    }
+
+    /// Optional arguments are positional, so any argument omitted before the
+    /// last provided one is passed as its default value.
+    fn optional_args(args: Vec<(Option<Expr>, ScalarValue)>) -> Vec<Expr> {
+        let provided = args
+            .iter()
+            .rposition(|(arg, _)| arg.is_some())
+            .map_or(0, |i| i + 1);
+        args.into_iter()
+            .take(provided)
+            .map(|(arg, default)| arg.unwrap_or(Expr::Literal(default)))
+            .collect()
+    }
 }
 
 /// Returns all DataFusion functions defined in this package
@@ -91,5 +174,9 @@ pub fn functions() -> Vec<Arc<datafusion_expr::ScalarUDF>> {
         regexp_match(),
         regexp_like(),
         regexp_replace(),
+        regexp_substr(),
+        regexp_instr(),
+        regexp_extract(),
+        regexp_split_to_array(),
     ]
 }
@@ -0,0 +1,244 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::regex::utils::{
+    coerce_regex_args, int_value_or, is_constant_pattern, RegexCache,
+};
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use arrow::array::{
+    Array, ArrayRef, AsArray, GenericStringBuilder, OffsetSizeTrait, StringArrayType,
+};
+use arrow::datatypes::{DataType, Int64Type};
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Regular Expression Functions"),
+    description = "Extracts a capture group of the first [regular expression](https://docs.rs/regex/latest/regex/#syntax) match in a string. Returns an empty string if the regular expression or the group does not match. Compatible with Apache Spark's `regexp_extract`.",
+    syntax_example = "regexp_extract(str, regexp[, group])",
+    sql_example = r#"```sql
+> select regexp_extract('100-200', '(\d+)-(\d+)', 2);
++--------------------------------------------------------------+
+| regexp_extract(Utf8("100-200"),Utf8("(\d+)-(\d+)"),Int64(2)) |
++--------------------------------------------------------------+
+| 200                                                          |
++--------------------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    standard_argument(name = "regexp", prefix = "Regular"),
+    argument(
+        name = "group",
+        description = "Optional capture group to extract. Defaults to 1; 0 extracts the whole match."
+    )
+)]
+#[derive(Debug)]
+pub struct RegexpExtractFunc {
+    signature: Signature,
+}
+
+impl Default for RegexpExtractFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpExtractFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpExtractFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "regexp_extract")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let constant_pattern = is_constant_pattern(&args.args, &[1]);
+        make_scalar_function(
+            move |args: &[ArrayRef]| regexp_extract(args, constant_pattern),
+            vec![],
+        )(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=3).contains(&arg_types.len()) {
+            return plan_err!(
+                "regexp_extract was called with {} arguments. It requires at least 2 and at most 3.",
+                arg_types.len()
+            );
+        }
+        coerce_regex_args(self.name(), arg_types, &[0, 1])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Evaluates `regexp_extract(str, regexp[, group])` over arrays of equal
+/// length. `constant_pattern` indicates the pattern is the same for every row.
+pub fn regexp_extract(args: &[ArrayRef], constant_pattern: bool) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Utf8 => regexp_extract_inner::<_, i32>(
+            args,
+            |array| array.as_string::<i32>(),
+            constant_pattern,
+        ),
+        DataType::LargeUtf8 => regexp_extract_inner::<_, i64>(
+            args,
+            |array| array.as_string::<i64>(),
+            constant_pattern,
+        ),
+        DataType::Utf8View => regexp_extract_inner::<_, i32>(
+            args,
+            |array| array.as_string_view(),
+            constant_pattern,
+        ),
+        other => {
+            exec_err!("Unsupported data type {other:?} for function regexp_extract")
+        }
+    }
+}
+
+fn regexp_extract_inner<'a, S, O>(
+    args: &'a [ArrayRef],
+    as_string: fn(&'a ArrayRef) -> S,
+    constant_pattern: bool,
+) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+    O: OffsetSizeTrait,
+{
+    let values = as_string(&args[0]);
+    let patterns = as_string(&args[1]);
+    let groups = args.get(2).map(|a| a.as_primitive::<Int64Type>());
+
+    let mut cache = RegexCache::new("regexp_extract", constant_pattern);
+    let mut builder = GenericStringBuilder::<O>::with_capacity(values.len(), 0);
+    for i in 0..values.len() {
+        if args.iter().any(|arg| arg.is_null(i)) {
+            builder.append_null();
+            continue;
+        }
+        let regex = cache.get(patterns.value(i), None)?;
+        let group = int_value_or(groups, i, 1);
+        // `captures_len` includes the implicit group 0 for the whole match
+        let group_count = regex.captures_len() as i64 - 1;
+        if group < 0 || group > group_count {
+            return exec_err!(
+                "regexp_extract() regex group count is {group_count}, but the specified group index is {group}"
+            );
+        }
+
+        let extracted = regex
+            .captures(values.value(i))
+            .and_then(|captures| captures.get(group as usize))
+            .map_or("", |m| m.as_str());
+        builder.append_value(extracted);
+    }
+
+    Ok(Arc::new(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, LargeStringArray, StringArray};
+
+    #[test]
+    fn test_regexp_extract() {
+        let values = StringArray::from(vec![
+            Some("100-200"),
+            Some("100-200"),
+            Some("no digits"),
+            Some("100-200"),
+            None,
+        ]);
+        let patterns = StringArray::from(vec![
+            "(\\d+)-(\\d+)",
+            "(\\d+)-(\\d+)",
+            "(\\d+)",
+            "(\\d+)-(x)?",
+            "(\\d+)",
+        ]);
+        let groups = Int64Array::from(vec![2, 0, 1, 2, 1]);
+
+        let result = regexp_extract(
+            &[Arc::new(values), Arc::new(patterns), Arc::new(groups)],
+            false,
+        )
+        .unwrap();
+        let expected = StringArray::from(vec![
+            Some("200"),
+            Some("100-200"),
+            Some(""),
+            Some(""),
+            None,
+        ]);
+        assert_eq!(result.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_extract_default_group() {
+        let result = regexp_extract(
+            &[
+                Arc::new(LargeStringArray::from(vec!["user=alice"])),
+                Arc::new(LargeStringArray::from(vec!["user=(\\w+)"])),
+            ],
+            true,
+        )
+        .unwrap();
+        assert_eq!(result.as_ref(), &LargeStringArray::from(vec!["alice"]));
+    }
+
+    #[test]
+    fn test_regexp_extract_invalid_group() {
+        let result = regexp_extract(
+            &[
+                Arc::new(StringArray::from(vec!["abc"])),
+                Arc::new(StringArray::from(vec!["(b)"])),
+                Arc::new(Int64Array::from(vec![2])),
+            ],
+            true,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("regex group count is 1, but the specified group index is 2"));
+    }
+}
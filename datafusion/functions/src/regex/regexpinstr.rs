@@ -0,0 +1,290 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::regex::utils::{
+    char_start_to_byte_offset, coerce_regex_args, int_value_or, is_constant_pattern,
+    RegexCache,
+};
+use crate::utils::make_scalar_function;
+use arrow::array::{Array, ArrayRef, AsArray, Int64Builder, StringArrayType};
+use arrow::datatypes::{DataType, Int64Type};
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Regular Expression Functions"),
+    description = "Returns the position in a string where a [regular expression](https://docs.rs/regex/latest/regex/#syntax) matches, or 0 if there is no match. Positions are 1 based character offsets.",
+    syntax_example = "regexp_instr(str, regexp[, start[, occurrence[, endoption[, flags[, group]]]]])",
+    sql_example = r#"```sql
+> select regexp_instr('abc123def456', '[0-9]+', 1, 2);
++---------------------------------------------------------------------+
+| regexp_instr(Utf8("abc123def456"),Utf8("[0-9]+"),Int64(1),Int64(2)) |
++---------------------------------------------------------------------+
+| 10                                                                  |
++---------------------------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    standard_argument(name = "regexp", prefix = "Regular"),
+    argument(
+        name = "start",
+        description = "Optional start position (the first position is 1) to search for the regular expression. Can be a constant, column, or function."
+    ),
+    argument(
+        name = "occurrence",
+        description = "Optional occurrence of the match to locate (the first occurrence is 1). Can be a constant, column, or function."
+    ),
+    argument(
+        name = "endoption",
+        description = "Optional flag selecting which position is returned: 0 (the default) returns the position of the first character of the match, 1 returns the position of the first character after the match."
+    ),
+    argument(
+        name = "flags",
+        description = r#"Optional regular expression flags that control the behavior of the regular expression. The following flags are supported:
+  - **i**: case-insensitive: letters match both upper and lower case
+  - **m**: multi-line mode: ^ and $ match begin/end of line
+  - **s**: allow . to match \n
+  - **R**: enables CRLF mode: when multi-line mode is enabled, \r\n is used
+  - **U**: swap the meaning of x* and x*?"#
+    ),
+    argument(
+        name = "group",
+        description = "Optional capture group to locate. 0 (the default) locates the whole match."
+    )
+)]
+#[derive(Debug)]
+pub struct RegexpInstrFunc {
+    signature: Signature,
+}
+
+impl Default for RegexpInstrFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpInstrFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpInstrFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_instr"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let constant_pattern = is_constant_pattern(&args.args, &[1, 5]);
+        make_scalar_function(
+            move |args: &[ArrayRef]| regexp_instr(args, constant_pattern),
+            vec![],
+        )(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=7).contains(&arg_types.len()) {
+            return plan_err!(
+                "regexp_instr was called with {} arguments. It requires at least 2 and at most 7.",
+                arg_types.len()
+            );
+        }
+        coerce_regex_args(self.name(), arg_types, &[0, 1, 5])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Evaluates `regexp_instr(str, regexp[, start[, occurrence[, endoption[, flags[, group]]]]])`
+/// over arrays of equal length. `constant_pattern` indicates the pattern and
+/// flags are the same for every row.
+pub fn regexp_instr(args: &[ArrayRef], constant_pattern: bool) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Utf8 => {
+            regexp_instr_inner(args, |array| array.as_string::<i32>(), constant_pattern)
+        }
+        DataType::LargeUtf8 => {
+            regexp_instr_inner(args, |array| array.as_string::<i64>(), constant_pattern)
+        }
+        DataType::Utf8View => {
+            regexp_instr_inner(args, |array| array.as_string_view(), constant_pattern)
+        }
+        other => exec_err!("Unsupported data type {other:?} for function regexp_instr"),
+    }
+}
+
+fn regexp_instr_inner<'a, S>(
+    args: &'a [ArrayRef],
+    as_string: fn(&'a ArrayRef) -> S,
+    constant_pattern: bool,
+) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+{
+    let values = as_string(&args[0]);
+    let patterns = as_string(&args[1]);
+    let starts = args.get(2).map(|a| a.as_primitive::<Int64Type>());
+    let occurrences = args.get(3).map(|a| a.as_primitive::<Int64Type>());
+    let end_options = args.get(4).map(|a| a.as_primitive::<Int64Type>());
+    let flags = args.get(5).map(as_string);
+    let groups = args.get(6).map(|a| a.as_primitive::<Int64Type>());
+
+    let mut cache = RegexCache::new("regexp_instr", constant_pattern);
+    let mut builder = Int64Builder::with_capacity(values.len());
+    for i in 0..values.len() {
+        if args.iter().any(|arg| arg.is_null(i)) {
+            builder.append_null();
+            continue;
+        }
+        let regex = cache.get(patterns.value(i), flags.as_ref().map(|f| f.value(i)))?;
+        builder.append_value(find_position(
+            values.value(i),
+            regex,
+            int_value_or(starts, i, 1),
+            int_value_or(occurrences, i, 1),
+            int_value_or(end_options, i, 0),
+            int_value_or(groups, i, 0),
+        )?);
+    }
+
+    Ok(Arc::new(builder.finish()))
+}
+
+fn find_position(
+    value: &str,
+    regex: &regex::Regex,
+    start: i64,
+    occurrence: i64,
+    end_option: i64,
+    group: i64,
+) -> Result<i64> {
+    if occurrence < 1 {
+        return exec_err!("regexp_instr() requires occurrence to be 1 based");
+    }
+    if end_option != 0 && end_option != 1 {
+        return exec_err!("regexp_instr() requires endoption to be 0 or 1");
+    }
+    if group < 0 {
+        return exec_err!("regexp_instr() requires group to be non-negative");
+    }
+    let Some(offset) = char_start_to_byte_offset("regexp_instr", value, start)? else {
+        return Ok(0);
+    };
+
+    let matched = regex
+        .captures_iter(&value[offset..])
+        .nth(occurrence as usize - 1)
+        .and_then(|captures| captures.get(group as usize));
+
+    Ok(match matched {
+        Some(m) => {
+            let byte_position =
+                offset + if end_option == 0 { m.start() } else { m.end() };
+            value[..byte_position].chars().count() as i64 + 1
+        }
+        None => 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, StringArray, StringViewArray};
+
+    #[test]
+    fn test_regexp_instr() {
+        let values = StringArray::from(vec![
+            Some("abc123def456"),
+            Some("abc123def456"),
+            Some("äbc123"),
+            Some("abc"),
+            None,
+        ]);
+        let patterns = StringArray::from(vec!["[0-9]+"; 5]);
+        let starts = Int64Array::from(vec![1, 5, 1, 1, 1]);
+        let occurrences = Int64Array::from(vec![2, 1, 1, 1, 1]);
+        let end_options = Int64Array::from(vec![0, 1, 1, 0, 0]);
+
+        let result = regexp_instr(
+            &[
+                Arc::new(values),
+                Arc::new(patterns),
+                Arc::new(starts),
+                Arc::new(occurrences),
+                Arc::new(end_options),
+            ],
+            true,
+        )
+        .unwrap();
+        let expected = Int64Array::from(vec![Some(10), Some(7), Some(7), Some(0), None]);
+        assert_eq!(result.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_instr_group_and_flags() {
+        let result = regexp_instr(
+            &[
+                Arc::new(StringViewArray::from(vec!["KEY=Value", "key=other"])),
+                Arc::new(StringViewArray::from(vec!["key=(\\w+)", "key=(\\w+)"])),
+                Arc::new(Int64Array::from(vec![1, 1])),
+                Arc::new(Int64Array::from(vec![1, 1])),
+                Arc::new(Int64Array::from(vec![0, 0])),
+                Arc::new(StringViewArray::from(vec!["i", ""])),
+                Arc::new(Int64Array::from(vec![1, 1])),
+            ],
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.as_ref(), &Int64Array::from(vec![5, 5]));
+    }
+
+    #[test]
+    fn test_regexp_instr_invalid_end_option() {
+        let result = regexp_instr(
+            &[
+                Arc::new(StringArray::from(vec!["abc"])),
+                Arc::new(StringArray::from(vec!["b"])),
+                Arc::new(Int64Array::from(vec![1])),
+                Arc::new(Int64Array::from(vec![1])),
+                Arc::new(Int64Array::from(vec![2])),
+            ],
+            true,
+        );
+        assert!(result.is_err());
+    }
+}
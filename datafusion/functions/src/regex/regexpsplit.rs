@@ -0,0 +1,255 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::regex::utils::{coerce_regex_args, is_constant_pattern, RegexCache};
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use arrow::array::{
+    Array, ArrayRef, AsArray, GenericStringBuilder, ListBuilder, OffsetSizeTrait,
+    StringArrayType,
+};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use regex::Regex;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Regular Expression Functions"),
+    description = "Splits a string using a [regular expression](https://docs.rs/regex/latest/regex/#syntax) as the delimiter and returns the parts as an array.",
+    syntax_example = "regexp_split_to_array(str, regexp[, flags])",
+    sql_example = r#"```sql
+> select regexp_split_to_array('hello  world', '\s+');
++---------------------------------------------------------+
+| regexp_split_to_array(Utf8("hello  world"),Utf8("\s+")) |
++---------------------------------------------------------+
+| [hello, world]                                          |
++---------------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    standard_argument(name = "regexp", prefix = "Regular"),
+    argument(
+        name = "flags",
+        description = r#"Optional regular expression flags that control the behavior of the regular expression. The following flags are supported:
+  - **i**: case-insensitive: letters match both upper and lower case
+  - **m**: multi-line mode: ^ and $ match begin/end of line
+  - **s**: allow . to match \n
+  - **R**: enables CRLF mode: when multi-line mode is enabled, \r\n is used
+  - **U**: swap the meaning of x* and x*?"#
+    )
+)]
+#[derive(Debug)]
+pub struct RegexpSplitToArrayFunc {
+    signature: Signature,
+}
+
+impl Default for RegexpSplitToArrayFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpSplitToArrayFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpSplitToArrayFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_split_to_array"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let item_type = utf8_to_str_type(&arg_types[0], "regexp_split_to_array")?;
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            item_type, true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let constant_pattern = is_constant_pattern(&args.args, &[1, 2]);
+        make_scalar_function(
+            move |args: &[ArrayRef]| regexp_split_to_array(args, constant_pattern),
+            vec![],
+        )(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=3).contains(&arg_types.len()) {
+            return plan_err!(
+                "regexp_split_to_array was called with {} arguments. It requires at least 2 and at most 3.",
+                arg_types.len()
+            );
+        }
+        coerce_regex_args(self.name(), arg_types, &[0, 1, 2])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Splits `value` around the matches of `regex`, following PostgreSQL's
+/// `regexp_split_to_array` semantics: a zero-length match at the beginning or
+/// end of the string, or immediately after a previous match, is ignored.
+pub fn regexp_split<'v>(value: &'v str, regex: &Regex) -> Vec<&'v str> {
+    let mut parts = vec![];
+    let mut last = 0;
+    for m in regex.find_iter(value) {
+        if m.start() == m.end() && (m.start() == last || m.start() == value.len()) {
+            continue;
+        }
+        parts.push(&value[last..m.start()]);
+        last = m.end();
+    }
+    parts.push(&value[last..]);
+    parts
+}
+
+/// Evaluates `regexp_split_to_array(str, regexp[, flags])` over arrays of
+/// equal length. `constant_pattern` indicates the pattern and flags are the
+/// same for every row.
+pub fn regexp_split_to_array(
+    args: &[ArrayRef],
+    constant_pattern: bool,
+) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Utf8 => regexp_split_to_array_inner::<_, i32>(
+            args,
+            |array| array.as_string::<i32>(),
+            constant_pattern,
+        ),
+        DataType::LargeUtf8 => regexp_split_to_array_inner::<_, i64>(
+            args,
+            |array| array.as_string::<i64>(),
+            constant_pattern,
+        ),
+        DataType::Utf8View => regexp_split_to_array_inner::<_, i32>(
+            args,
+            |array| array.as_string_view(),
+            constant_pattern,
+        ),
+        other => exec_err!(
+            "Unsupported data type {other:?} for function regexp_split_to_array"
+        ),
+    }
+}
+
+fn regexp_split_to_array_inner<'a, S, O>(
+    args: &'a [ArrayRef],
+    as_string: fn(&'a ArrayRef) -> S,
+    constant_pattern: bool,
+) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+    O: OffsetSizeTrait,
+{
+    let values = as_string(&args[0]);
+    let patterns = as_string(&args[1]);
+    let flags = args.get(2).map(as_string);
+
+    let mut cache = RegexCache::new("regexp_split_to_array", constant_pattern);
+    let mut builder =
+        ListBuilder::with_capacity(GenericStringBuilder::<O>::new(), values.len());
+    for i in 0..values.len() {
+        if args.iter().any(|arg| arg.is_null(i)) {
+            builder.append_null();
+            continue;
+        }
+        let regex = cache.get(patterns.value(i), flags.as_ref().map(|f| f.value(i)))?;
+        for part in regexp_split(values.value(i), regex) {
+            builder.values().append_value(part);
+        }
+        builder.append(true);
+    }
+
+    Ok(Arc::new(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{LargeStringArray, StringArray, StringViewArray};
+
+    fn split(value: &str, pattern: &str) -> Vec<String> {
+        let regex = Regex::new(pattern).unwrap();
+        regexp_split(value, &regex)
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_regexp_split() {
+        assert_eq!(split("hello  world", "\\s+"), vec!["hello", "world"]);
+        assert_eq!(split("a,b,,c", ","), vec!["a", "b", "", "c"]);
+        assert_eq!(split(",a,", ","), vec!["", "a", ""]);
+        assert_eq!(split("abc", ""), vec!["a", "b", "c"]);
+        assert_eq!(split("abc", "x*"), vec!["a", "b", "c"]);
+        assert_eq!(split("", ","), vec![""]);
+    }
+
+    #[test]
+    fn test_regexp_split_to_array() {
+        let values = StringViewArray::from(vec![Some("a1b22c"), Some("A-b"), None]);
+        let patterns = StringViewArray::from(vec!["[0-9]+", "-", "x"]);
+        let result =
+            regexp_split_to_array(&[Arc::new(values), Arc::new(patterns)], false)
+                .unwrap();
+        let list = result.as_list::<i32>();
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.value(0).as_ref(),
+            &StringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(list.value(1).as_ref(), &StringArray::from(vec!["A", "b"]));
+        assert!(list.is_null(2));
+    }
+
+    #[test]
+    fn test_regexp_split_to_array_flags() {
+        let result = regexp_split_to_array(
+            &[
+                Arc::new(LargeStringArray::from(vec!["aXbxc"])),
+                Arc::new(LargeStringArray::from(vec!["x"])),
+                Arc::new(LargeStringArray::from(vec!["i"])),
+            ],
+            true,
+        )
+        .unwrap();
+        let list = result.as_list::<i32>();
+        assert_eq!(
+            list.value(0).as_ref(),
+            &LargeStringArray::from(vec!["a", "b", "c"])
+        );
+    }
+}
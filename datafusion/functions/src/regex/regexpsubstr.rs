@@ -0,0 +1,302 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::regex::utils::{
+    char_start_to_byte_offset, coerce_regex_args, int_value_or, is_constant_pattern,
+    RegexCache,
+};
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use arrow::array::{
+    Array, ArrayRef, AsArray, GenericStringBuilder, OffsetSizeTrait, StringArrayType,
+};
+use arrow::datatypes::{DataType, Int64Type};
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Regular Expression Functions"),
+    description = "Returns the substring that matches a [regular expression](https://docs.rs/regex/latest/regex/#syntax), or NULL if there is no match.",
+    syntax_example = "regexp_substr(str, regexp[, start[, occurrence[, flags[, group]]]])",
+    sql_example = r#"```sql
+> select regexp_substr('abc123def456', '[0-9]+', 1, 2);
++----------------------------------------------------------------------+
+| regexp_substr(Utf8("abc123def456"),Utf8("[0-9]+"),Int64(1),Int64(2)) |
++----------------------------------------------------------------------+
+| 456                                                                  |
++----------------------------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    standard_argument(name = "regexp", prefix = "Regular"),
+    argument(
+        name = "start",
+        description = "Optional start position (the first position is 1) to search for the regular expression. Can be a constant, column, or function."
+    ),
+    argument(
+        name = "occurrence",
+        description = "Optional occurrence of the match to return (the first occurrence is 1). Can be a constant, column, or function."
+    ),
+    argument(
+        name = "flags",
+        description = r#"Optional regular expression flags that control the behavior of the regular expression. The following flags are supported:
+  - **i**: case-insensitive: letters match both upper and lower case
+  - **m**: multi-line mode: ^ and $ match begin/end of line
+  - **s**: allow . to match \n
+  - **R**: enables CRLF mode: when multi-line mode is enabled, \r\n is used
+  - **U**: swap the meaning of x* and x*?"#
+    ),
+    argument(
+        name = "group",
+        description = "Optional capture group to return. 0 (the default) returns the whole match."
+    )
+)]
+#[derive(Debug)]
+pub struct RegexpSubstrFunc {
+    signature: Signature,
+}
+
+impl Default for RegexpSubstrFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpSubstrFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpSubstrFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_substr"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "regexp_substr")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let constant_pattern = is_constant_pattern(&args.args, &[1, 4]);
+        make_scalar_function(
+            move |args: &[ArrayRef]| regexp_substr(args, constant_pattern),
+            vec![],
+        )(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=6).contains(&arg_types.len()) {
+            return plan_err!(
+                "regexp_substr was called with {} arguments. It requires at least 2 and at most 6.",
+                arg_types.len()
+            );
+        }
+        coerce_regex_args(self.name(), arg_types, &[0, 1, 4])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Evaluates `regexp_substr(str, regexp[, start[, occurrence[, flags[, group]]]])`
+/// over arrays of equal length. `constant_pattern` indicates the pattern and
+/// flags are the same for every row.
+pub fn regexp_substr(args: &[ArrayRef], constant_pattern: bool) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Utf8 => regexp_substr_inner::<_, i32>(
+            args,
+            |array| array.as_string::<i32>(),
+            constant_pattern,
+        ),
+        DataType::LargeUtf8 => regexp_substr_inner::<_, i64>(
+            args,
+            |array| array.as_string::<i64>(),
+            constant_pattern,
+        ),
+        DataType::Utf8View => regexp_substr_inner::<_, i32>(
+            args,
+            |array| array.as_string_view(),
+            constant_pattern,
+        ),
+        other => exec_err!("Unsupported data type {other:?} for function regexp_substr"),
+    }
+}
+
+fn regexp_substr_inner<'a, S, O>(
+    args: &'a [ArrayRef],
+    as_string: fn(&'a ArrayRef) -> S,
+    constant_pattern: bool,
+) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+    O: OffsetSizeTrait,
+{
+    let values = as_string(&args[0]);
+    let patterns = as_string(&args[1]);
+    let starts = args.get(2).map(|a| a.as_primitive::<Int64Type>());
+    let occurrences = args.get(3).map(|a| a.as_primitive::<Int64Type>());
+    let flags = args.get(4).map(as_string);
+    let groups = args.get(5).map(|a| a.as_primitive::<Int64Type>());
+
+    let mut cache = RegexCache::new("regexp_substr", constant_pattern);
+    let mut builder = GenericStringBuilder::<O>::with_capacity(values.len(), 0);
+    for i in 0..values.len() {
+        if args.iter().any(|arg| arg.is_null(i)) {
+            builder.append_null();
+            continue;
+        }
+        let regex = cache.get(patterns.value(i), flags.as_ref().map(|f| f.value(i)))?;
+        let matched = find_substr(
+            values.value(i),
+            regex,
+            int_value_or(starts, i, 1),
+            int_value_or(occurrences, i, 1),
+            int_value_or(groups, i, 0),
+        )?;
+        builder.append_option(matched);
+    }
+
+    Ok(Arc::new(builder.finish()))
+}
+
+fn find_substr<'v>(
+    value: &'v str,
+    regex: &regex::Regex,
+    start: i64,
+    occurrence: i64,
+    group: i64,
+) -> Result<Option<&'v str>> {
+    if occurrence < 1 {
+        return exec_err!("regexp_substr() requires occurrence to be 1 based");
+    }
+    if group < 0 {
+        return exec_err!("regexp_substr() requires group to be non-negative");
+    }
+    let Some(offset) = char_start_to_byte_offset("regexp_substr", value, start)? else {
+        return Ok(None);
+    };
+
+    Ok(regex
+        .captures_iter(&value[offset..])
+        .nth(occurrence as usize - 1)
+        .and_then(|captures| captures.get(group as usize))
+        .map(|m| m.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, LargeStringArray, StringArray, StringViewArray};
+
+    #[test]
+    fn test_regexp_substr() {
+        let values = StringArray::from(vec![
+            Some("id=17, id=42"),
+            Some("id=17, id=42"),
+            Some("no ids"),
+            None,
+            Some("ID=5"),
+        ]);
+        let patterns = StringArray::from(vec!["id=(\\d+)"; 5]);
+        let starts = Int64Array::from(vec![1, 4, 1, 1, 1]);
+        let occurrences = Int64Array::from(vec![2, 1, 1, 1, 1]);
+        let flags = StringArray::from(vec!["", "", "", "", "i"]);
+        let groups = Int64Array::from(vec![1, 0, 0, 0, 1]);
+
+        let result = regexp_substr(
+            &[
+                Arc::new(values),
+                Arc::new(patterns),
+                Arc::new(starts),
+                Arc::new(occurrences),
+                Arc::new(flags),
+                Arc::new(groups),
+            ],
+            false,
+        )
+        .unwrap();
+        let expected =
+            StringArray::from(vec![Some("42"), Some("id=42"), None, None, Some("5")]);
+        assert_eq!(result.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_substr_string_types() {
+        let result = regexp_substr(
+            &[
+                Arc::new(LargeStringArray::from(vec!["abc123def"])),
+                Arc::new(LargeStringArray::from(vec!["[0-9]+"])),
+            ],
+            true,
+        )
+        .unwrap();
+        assert_eq!(result.as_ref(), &LargeStringArray::from(vec!["123"]));
+
+        let result = regexp_substr(
+            &[
+                Arc::new(StringViewArray::from(vec!["abc123def"])),
+                Arc::new(StringViewArray::from(vec!["[a-z]+"])),
+                Arc::new(Int64Array::from(vec![3])),
+                Arc::new(Int64Array::from(vec![2])),
+            ],
+            true,
+        )
+        .unwrap();
+        assert_eq!(result.as_ref(), &StringArray::from(vec!["def"]));
+    }
+
+    #[test]
+    fn test_regexp_substr_out_of_range() {
+        let result = regexp_substr(
+            &[
+                Arc::new(StringArray::from(vec!["abc"])),
+                Arc::new(StringArray::from(vec!["(b)"])),
+                Arc::new(Int64Array::from(vec![10])),
+            ],
+            true,
+        )
+        .unwrap();
+        assert!(result.is_null(0));
+
+        let result = regexp_substr(
+            &[
+                Arc::new(StringArray::from(vec!["abc"])),
+                Arc::new(StringArray::from(vec!["(b)"])),
+                Arc::new(Int64Array::from(vec![1])),
+                Arc::new(Int64Array::from(vec![0])),
+            ],
+            true,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers shared by the regex functions that accept optional
+//! position, occurrence and flags arguments.
+
+use arrow::array::Int64Array;
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::ColumnarValue;
+use regex::Regex;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Compiles `regex` with the optional `flags` prepended as an inline flag group.
+///
+/// The global flag `g` is rejected, since none of the functions using this
+/// helper replace or iterate in a way where it would be meaningful.
+pub fn compile_regex(name: &str, regex: &str, flags: Option<&str>) -> Result<Regex> {
+    let pattern = match flags {
+        None | Some("") => regex.to_string(),
+        Some(flags) => {
+            if flags.contains('g') {
+                return exec_err!("{name}() does not support the global flag");
+            }
+            format!("(?{flags}){regex}")
+        }
+    };
+
+    match Regex::new(&pattern) {
+        Ok(regex) => Ok(regex),
+        Err(e) => exec_err!("Regular expression did not compile: {pattern}: {e}"),
+    }
+}
+
+/// Compiled regular expressions for a single invocation of a regex function.
+///
+/// When the pattern and flags are the same for every row, the expression is
+/// compiled once on first use. Otherwise every distinct `(pattern, flags)` pair
+/// is compiled the first time it is seen and reused for later rows.
+pub(crate) struct RegexCache<'a> {
+    name: &'static str,
+    constant: bool,
+    compiled: Option<Regex>,
+    cache: HashMap<(&'a str, Option<&'a str>), Regex>,
+}
+
+impl<'a> RegexCache<'a> {
+    /// Creates a cache for the function `name`. `constant` should be true when
+    /// the pattern and flags arguments were both scalars.
+    pub(crate) fn new(name: &'static str, constant: bool) -> Self {
+        Self {
+            name,
+            constant,
+            compiled: None,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns the compiled regex for `pattern` and `flags`
+    pub(crate) fn get(
+        &mut self,
+        pattern: &'a str,
+        flags: Option<&'a str>,
+    ) -> Result<&Regex> {
+        if self.constant {
+            if self.compiled.is_none() {
+                self.compiled = Some(compile_regex(self.name, pattern, flags)?);
+            }
+            return Ok(self.compiled.as_ref().unwrap());
+        }
+
+        let regex = match self.cache.entry((pattern, flags)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(compile_regex(self.name, pattern, flags)?)
+            }
+        };
+        Ok(regex)
+    }
+}
+
+/// Returns true if every argument in `positions` is a scalar, meaning the
+/// pattern (and flags) are the same for every row.
+pub(crate) fn is_constant_pattern(args: &[ColumnarValue], positions: &[usize]) -> bool {
+    positions
+        .iter()
+        .all(|&i| !matches!(args.get(i), Some(ColumnarValue::Array(_))))
+}
+
+/// Returns the value of the optional integer argument `array` at row `i`, or
+/// `default` if the argument was not provided.
+pub(crate) fn int_value_or(array: Option<&Int64Array>, i: usize, default: i64) -> i64 {
+    array.map_or(default, |array| array.value(i))
+}
+
+/// Coerces the arguments of a regex function whose string arguments are at
+/// `string_positions` and whose remaining arguments are integers.
+///
+/// All string arguments are coerced to the type of the first argument, and the
+/// integer arguments to `Int64`.
+pub(crate) fn coerce_regex_args(
+    name: &str,
+    arg_types: &[DataType],
+    string_positions: &[usize],
+) -> Result<Vec<DataType>> {
+    let string_type = match &arg_types[0] {
+        DataType::Null | DataType::Utf8 => DataType::Utf8,
+        DataType::LargeUtf8 => DataType::LargeUtf8,
+        DataType::Utf8View => DataType::Utf8View,
+        DataType::Dictionary(_, value_type) => match value_type.as_ref() {
+            DataType::LargeUtf8 => DataType::LargeUtf8,
+            DataType::Utf8View => DataType::Utf8View,
+            _ => DataType::Utf8,
+        },
+        other => {
+            return plan_err!(
+                "The first argument of the {name} function can only be a string, but got {other:?}."
+            );
+        }
+    };
+
+    arg_types
+        .iter()
+        .enumerate()
+        .map(|(i, data_type)| {
+            if string_positions.contains(&i) {
+                match data_type {
+                    DataType::Null
+                    | DataType::Utf8
+                    | DataType::LargeUtf8
+                    | DataType::Utf8View
+                    | DataType::Dictionary(_, _) => Ok(string_type.clone()),
+                    other => plan_err!(
+                        "Argument {} of the {name} function can only be a string, but got {other:?}.",
+                        i + 1
+                    ),
+                }
+            } else if data_type.is_integer() || data_type.is_null() {
+                Ok(DataType::Int64)
+            } else {
+                plan_err!(
+                    "Argument {} of the {name} function can only be an integer, but got {data_type:?}.",
+                    i + 1
+                )
+            }
+        })
+        .collect()
+}
+
+/// Returns the byte offset of the 1 based character position `start` in
+/// `value`, or `None` if `start` is past the end of the string.
+pub(crate) fn char_start_to_byte_offset(
+    name: &str,
+    value: &str,
+    start: i64,
+) -> Result<Option<usize>> {
+    if start < 1 {
+        return exec_err!("{name}() requires start to be 1 based");
+    }
+    let skip = (start - 1) as usize;
+    if skip == 0 {
+        return Ok(Some(0));
+    }
+    Ok(value
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(value.len()))
+        .nth(skip))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_regex_flags() {
+        let regex = compile_regex("test", "abc", Some("i")).unwrap();
+        assert!(regex.is_match("ABC"));
+
+        let err = compile_regex("test", "abc", Some("gi")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("test() does not support the global flag"));
+
+        let err = compile_regex("test", "(abc", None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Regular expression did not compile: (abc"));
+    }
+
+    #[test]
+    fn test_char_start_to_byte_offset() {
+        assert_eq!(char_start_to_byte_offset("t", "abc", 1).unwrap(), Some(0));
+        assert_eq!(char_start_to_byte_offset("t", "äbc", 2).unwrap(), Some(2));
+        assert_eq!(char_start_to_byte_offset("t", "abc", 4).unwrap(), Some(3));
+        assert_eq!(char_start_to_byte_offset("t", "abc", 5).unwrap(), None);
+        assert!(char_start_to_byte_offset("t", "abc", 0).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels shared by the text functions
+
+use arrow::array::{
+    ArrayRef, AsArray, GenericStringBuilder, OffsetSizeTrait, PrimitiveArray,
+    StringArrayType,
+};
+use arrow::datatypes::{ArrowPrimitiveType, DataType};
+use datafusion_common::{exec_err, Result};
+use std::sync::Arc;
+
+/// Applies `op` to every pair of non-null strings of `left` and `right`,
+/// which must have the same string type. Rows where either string is null
+/// are null in the result.
+pub(crate) fn map_string_pairs<T, F>(
+    name: &str,
+    left: &ArrayRef,
+    right: &ArrayRef,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(&str, &str) -> Result<T::Native>,
+{
+    match (left.data_type(), right.data_type()) {
+        (DataType::Utf8, DataType::Utf8) => map_string_pairs_impl(
+            left.as_string::<i32>(),
+            right.as_string::<i32>(),
+            op,
+        ),
+        (DataType::LargeUtf8, DataType::LargeUtf8) => map_string_pairs_impl(
+            left.as_string::<i64>(),
+            right.as_string::<i64>(),
+            op,
+        ),
+        (DataType::Utf8View, DataType::Utf8View) => {
+            map_string_pairs_impl(left.as_string_view(), right.as_string_view(), op)
+        }
+        (left, right) => exec_err!(
+            "{name} was called with {left} and {right} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+        ),
+    }
+}
+
+fn map_string_pairs_impl<'a, S, T, F>(
+    left: S,
+    right: S,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    S: StringArrayType<'a>,
+    T: ArrowPrimitiveType,
+    F: Fn(&str, &str) -> Result<T::Native>,
+{
+    left.iter()
+        .zip(right.iter())
+        .map(|(left, right)| match (left, right) {
+            (Some(left), Some(right)) => op(left, right).map(Some),
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+/// Applies `op` to every non-null string of `array`, returning a `LargeUtf8`
+/// array for `LargeUtf8` input and a `Utf8` array otherwise.
+pub(crate) fn map_strings<F>(name: &str, array: &ArrayRef, op: F) -> Result<ArrayRef>
+where
+    F: Fn(&str) -> String,
+{
+    match array.data_type() {
+        DataType::Utf8 => Ok(map_strings_impl::<_, i32, _>(array.as_string::<i32>(), op)),
+        DataType::LargeUtf8 => {
+            Ok(map_strings_impl::<_, i64, _>(array.as_string::<i64>(), op))
+        }
+        DataType::Utf8View => {
+            Ok(map_strings_impl::<_, i32, _>(array.as_string_view(), op))
+        }
+        other => exec_err!(
+            "{name} was called with {other} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+        ),
+    }
+}
+
+fn map_strings_impl<'a, S, O, F>(array: S, op: F) -> ArrayRef
+where
+    S: StringArrayType<'a>,
+    O: OffsetSizeTrait,
+    F: Fn(&str) -> String,
+{
+    let mut builder = GenericStringBuilder::<O>::with_capacity(array.len(), 0);
+    for value in array.iter() {
+        match value {
+            Some(value) => builder.append_value(op(value)),
+            None => builder.append_null(),
+        }
+    }
+    Arc::new(builder.finish())
+}
+
+/// Returns the character n-grams of `value`, in order and including
+/// duplicates. A non-empty string shorter than `n` characters is its own
+/// single n-gram, and an empty string has none.
+pub(crate) fn char_ngrams(value: &str, n: usize) -> Vec<&str> {
+    // byte offsets of every character, plus the end of the string
+    let offsets = value
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(value.len()))
+        .collect::<Vec<_>>();
+    let chars = offsets.len() - 1;
+    if chars == 0 {
+        return vec![];
+    }
+    if chars < n {
+        return vec![value];
+    }
+    (0..=chars - n)
+        .map(|i| &value[offsets[i]..offsets[i + n]])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_ngrams() {
+        assert_eq!(char_ngrams("abcd", 2), vec!["ab", "bc", "cd"]);
+        assert_eq!(char_ngrams("abcd", 4), vec!["abcd"]);
+        assert_eq!(char_ngrams("ab", 3), vec!["ab"]);
+        assert_eq!(char_ngrams("", 2), Vec::<&str>::new());
+        assert_eq!(char_ngrams("äöü", 1), vec!["ä", "ö", "ü"]);
+        assert_eq!(char_ngrams("äöü", 2), vec!["äö", "öü"]);
+    }
+}
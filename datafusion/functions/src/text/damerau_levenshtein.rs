@@ -0,0 +1,202 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Int32Type, Int64Type};
+
+use crate::text::common::map_string_pairs;
+use crate::utils::{make_scalar_function, utf8_to_int_type};
+use datafusion_common::{utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the [`Damerau-Levenshtein distance`](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance) between the two given strings: the number of insertions, deletions, substitutions and transpositions of adjacent characters needed to turn one string into the other.",
+    syntax_example = "damerau_levenshtein(str1, str2)",
+    sql_example = r#"```sql
+> select damerau_levenshtein('ab', 'bca');
++---------------------------------------------+
+| damerau_levenshtein(Utf8("ab"),Utf8("bca")) |
++---------------------------------------------+
+| 2                                           |
++---------------------------------------------+
+```"#,
+    argument(
+        name = "str1",
+        description = "String expression to compute Damerau-Levenshtein distance with str2."
+    ),
+    argument(
+        name = "str2",
+        description = "String expression to compute Damerau-Levenshtein distance with str1."
+    )
+)]
+#[derive(Debug)]
+pub struct DamerauLevenshteinFunc {
+    signature: Signature,
+}
+
+impl Default for DamerauLevenshteinFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DamerauLevenshteinFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for DamerauLevenshteinFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "damerau_levenshtein"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_int_type(&arg_types[0], "damerau_levenshtein")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(damerau_levenshtein, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn damerau_levenshtein(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str1, str2] = take_function_args("damerau_levenshtein", args)?;
+    if str1.data_type() == &DataType::LargeUtf8 {
+        let result = map_string_pairs::<Int64Type, _>(
+            "damerau_levenshtein",
+            str1,
+            str2,
+            |str1, str2| Ok(damerau_levenshtein_distance(str1, str2) as i64),
+        )?;
+        Ok(Arc::new(result) as ArrayRef)
+    } else {
+        let result = map_string_pairs::<Int32Type, _>(
+            "damerau_levenshtein",
+            str1,
+            str2,
+            |str1, str2| Ok(damerau_levenshtein_distance(str1, str2) as i32),
+        )?;
+        Ok(Arc::new(result) as ArrayRef)
+    }
+}
+
+/// Returns the unrestricted Damerau-Levenshtein distance between `a` and
+/// `b`, which unlike the optimal string alignment distance allows a
+/// substring to be edited more than once.
+fn damerau_levenshtein_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    // `distances[i + 1][j + 1]` is the distance between the first `i`
+    // characters of `a` and the first `j` characters of `b`, with an extra
+    // leading row and column holding an upper bound on the distance
+    let max_distance = a.len() + b.len();
+    let mut distances = vec![vec![0; b.len() + 2]; a.len() + 2];
+    distances[0][0] = max_distance;
+    for i in 0..=a.len() {
+        distances[i + 1][0] = max_distance;
+        distances[i + 1][1] = i;
+    }
+    for j in 0..=b.len() {
+        distances[0][j + 1] = max_distance;
+        distances[1][j + 1] = j;
+    }
+
+    // the last row of `a` in which each character was seen
+    let mut last_row = HashMap::<char, usize>::new();
+    for i in 1..=a.len() {
+        // the last column of `b` in this row that matched
+        let mut last_match_column = 0;
+        for j in 1..=b.len() {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_column = j;
+                0
+            } else {
+                1
+            };
+            distances[i + 1][j + 1] = (distances[i][j] + cost)
+                .min(distances[i + 1][j] + 1)
+                .min(distances[i][j + 1] + 1)
+                .min(distances[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+
+    distances[a.len() + 1][b.len() + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, StringArray};
+    use datafusion_common::cast::as_int32_array;
+
+    #[test]
+    fn test_damerau_levenshtein_distance() {
+        assert_eq!(damerau_levenshtein_distance("", ""), 0);
+        assert_eq!(damerau_levenshtein_distance("abc", ""), 3);
+        assert_eq!(damerau_levenshtein_distance("", "abc"), 3);
+        assert_eq!(damerau_levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(damerau_levenshtein_distance("ab", "ba"), 1);
+        // the optimal string alignment distance of these is 3
+        assert_eq!(damerau_levenshtein_distance("ab", "bca"), 2);
+        assert_eq!(damerau_levenshtein_distance("ca", "abc"), 2);
+        assert_eq!(damerau_levenshtein_distance("äöü", "öäü"), 1);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() -> Result<()> {
+        let str1 = Arc::new(StringArray::from(vec![Some("ab"), Some("abc"), None]));
+        let str2 = Arc::new(StringArray::from(vec![Some("ba"), Some("abc"), Some("a")]));
+        let result = damerau_levenshtein(&[str1, str2])?;
+        assert_eq!(
+            as_int32_array(&result)?,
+            &Int32Array::from(vec![Some(1), Some(0), None])
+        );
+        Ok(())
+    }
+}
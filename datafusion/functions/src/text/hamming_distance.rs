@@ -0,0 +1,172 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Int32Type, Int64Type};
+
+use crate::text::common::map_string_pairs;
+use crate::utils::{make_scalar_function, utf8_to_int_type};
+use datafusion_common::{exec_err, utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the [`Hamming distance`](https://en.wikipedia.org/wiki/Hamming_distance) between the two given strings: the number of positions at which their characters differ. The strings must have the same number of characters.",
+    syntax_example = "hamming_distance(str1, str2)",
+    sql_example = r#"```sql
+> select hamming_distance('karolin', 'kathrin');
++---------------------------------------------------+
+| hamming_distance(Utf8("karolin"),Utf8("kathrin")) |
++---------------------------------------------------+
+| 3                                                 |
++---------------------------------------------------+
+```"#,
+    argument(
+        name = "str1",
+        description = "String expression to compute Hamming distance with str2."
+    ),
+    argument(
+        name = "str2",
+        description = "String expression to compute Hamming distance with str1."
+    )
+)]
+#[derive(Debug)]
+pub struct HammingDistanceFunc {
+    signature: Signature,
+}
+
+impl Default for HammingDistanceFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HammingDistanceFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for HammingDistanceFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "hamming_distance"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_int_type(&arg_types[0], "hamming_distance")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(hamming_distance, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn hamming_distance(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str1, str2] = take_function_args("hamming_distance", args)?;
+    if str1.data_type() == &DataType::LargeUtf8 {
+        let result = map_string_pairs::<Int64Type, _>(
+            "hamming_distance",
+            str1,
+            str2,
+            |str1, str2| Ok(hamming(str1, str2)? as i64),
+        )?;
+        Ok(Arc::new(result) as ArrayRef)
+    } else {
+        let result = map_string_pairs::<Int32Type, _>(
+            "hamming_distance",
+            str1,
+            str2,
+            |str1, str2| Ok(hamming(str1, str2)? as i32),
+        )?;
+        Ok(Arc::new(result) as ArrayRef)
+    }
+}
+
+fn hamming(a: &str, b: &str) -> Result<usize> {
+    let mut a = a.chars();
+    let mut b = b.chars();
+    let mut distance = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => distance += (a != b) as usize,
+            (None, None) => return Ok(distance),
+            _ => {
+                return exec_err!(
+                    "hamming_distance() requires strings of the same length"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, LargeStringArray};
+    use datafusion_common::cast::as_int64_array;
+
+    #[test]
+    fn test_hamming_distance() -> Result<()> {
+        let str1 = Arc::new(LargeStringArray::from(vec![
+            Some("karolin"),
+            Some("äbc"),
+            Some(""),
+            None,
+        ]));
+        let str2 = Arc::new(LargeStringArray::from(vec![
+            Some("kathrin"),
+            Some("abc"),
+            Some(""),
+            Some("a"),
+        ]));
+        let result = hamming_distance(&[str1, str2])?;
+        assert_eq!(
+            as_int64_array(&result)?,
+            &Int64Array::from(vec![Some(3), Some(1), Some(0), None])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hamming_distance_different_lengths() {
+        let str1 = Arc::new(LargeStringArray::from(vec!["abc"]));
+        let str2 = Arc::new(LargeStringArray::from(vec!["ab"]));
+        let err = hamming_distance(&[str1, str2]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("hamming_distance() requires strings of the same length"));
+    }
+}
@@ -0,0 +1,206 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, Float64Array, Int64Array, StringArrayType};
+use arrow::datatypes::{DataType, Float64Type, Int64Type};
+
+use crate::text::common::{char_ngrams, map_string_pairs};
+use crate::text::ngrams::ngram_size;
+use crate::utils::make_scalar_function;
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{ColumnarValue, Documentation, TypeSignature};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+/// The default n-gram size of `jaccard`
+const DEFAULT_NGRAM_SIZE: usize = 2;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the [`Jaccard similarity`](https://en.wikipedia.org/wiki/Jaccard_index) of the sets of character n-grams of two strings, from 0.0 (no n-gram in common) to 1.0 (the same n-grams). See `ngrams` for how the n-grams are computed.",
+    syntax_example = "jaccard(str1, str2[, n])",
+    sql_example = r#"```sql
+> select jaccard('night', 'nacht');
++--------------------------------------+
+| jaccard(Utf8("night"),Utf8("nacht")) |
++--------------------------------------+
+| 0.14285714285714285                  |
++--------------------------------------+
+```"#,
+    standard_argument(name = "str1", prefix = "String"),
+    standard_argument(name = "str2", prefix = "String"),
+    argument(
+        name = "n",
+        description = "Optional number of characters of each n-gram. Defaults to 2."
+    )
+)]
+#[derive(Debug)]
+pub struct JaccardFunc {
+    signature: Signature,
+}
+
+impl Default for JaccardFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JaccardFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::String(2),
+                    TypeSignature::Exact(vec![Utf8View, Utf8View, Int64]),
+                    TypeSignature::Exact(vec![Utf8, Utf8, Int64]),
+                    TypeSignature::Exact(vec![LargeUtf8, LargeUtf8, Int64]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JaccardFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "jaccard"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(jaccard, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn jaccard(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args {
+        [str1, str2] => {
+            let result =
+                map_string_pairs::<Float64Type, _>("jaccard", str1, str2, |a, b| {
+                    Ok(jaccard_similarity(a, b, DEFAULT_NGRAM_SIZE))
+                })?;
+            Ok(Arc::new(result))
+        }
+        [str1, str2, n] => {
+            let n = n.as_primitive::<Int64Type>();
+            match (str1.data_type(), str2.data_type()) {
+                (DataType::Utf8, DataType::Utf8) => {
+                    jaccard_impl(str1.as_string::<i32>(), str2.as_string::<i32>(), n)
+                }
+                (DataType::LargeUtf8, DataType::LargeUtf8) => {
+                    jaccard_impl(str1.as_string::<i64>(), str2.as_string::<i64>(), n)
+                }
+                (DataType::Utf8View, DataType::Utf8View) => {
+                    jaccard_impl(str1.as_string_view(), str2.as_string_view(), n)
+                }
+                (str1, str2) => exec_err!(
+                    "jaccard was called with {str1} and {str2} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+                ),
+            }
+        }
+        _ => exec_err!(
+            "jaccard was called with {} arguments. It requires 2 or 3.",
+            args.len()
+        ),
+    }
+}
+
+fn jaccard_impl<'a, S>(str1: S, str2: S, n: &Int64Array) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+{
+    let result = str1
+        .iter()
+        .zip(str2.iter())
+        .zip(n.iter())
+        .map(|((a, b), n)| match (a, b, n) {
+            (Some(a), Some(b), Some(n)) => {
+                Ok(Some(jaccard_similarity(a, b, ngram_size("jaccard", n)?)))
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Float64Array>>()?;
+    Ok(Arc::new(result))
+}
+
+/// Returns the Jaccard similarity of the sets of character n-grams of `a`
+/// and `b`. Two strings without n-grams (both empty) are identical.
+fn jaccard_similarity(a: &str, b: &str, n: usize) -> f64 {
+    let a = char_ngrams(a, n).into_iter().collect::<HashSet<_>>();
+    let b = char_ngrams(b, n).into_iter().collect::<HashSet<_>>();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::StringArray;
+    use datafusion_common::cast::as_float64_array;
+
+    #[test]
+    fn test_jaccard_similarity() {
+        assert_eq!(jaccard_similarity("night", "nacht", 2), 1.0 / 7.0);
+        assert_eq!(jaccard_similarity("abc", "abc", 2), 1.0);
+        assert_eq!(jaccard_similarity("abab", "ab", 2), 0.5);
+        assert_eq!(jaccard_similarity("abc", "cba", 1), 1.0);
+        assert_eq!(jaccard_similarity("abc", "xyz", 2), 0.0);
+        assert_eq!(jaccard_similarity("", "", 2), 1.0);
+        assert_eq!(jaccard_similarity("", "a", 2), 0.0);
+    }
+
+    #[test]
+    fn test_jaccard() -> Result<()> {
+        let str1 = Arc::new(StringArray::from(vec![Some("night"), Some("abc"), None]))
+            as ArrayRef;
+        let str2 = Arc::new(StringArray::from(vec![
+            Some("nacht"),
+            Some("cba"),
+            Some("a"),
+        ])) as ArrayRef;
+        let n = Arc::new(Int64Array::from(vec![2, 1, 1])) as ArrayRef;
+        let result = jaccard(&[str1, str2, n])?;
+        assert_eq!(
+            as_float64_array(&result)?,
+            &Float64Array::from(vec![Some(1.0 / 7.0), Some(1.0), None])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Float64Type};
+
+use crate::text::common::map_string_pairs;
+use crate::utils::make_scalar_function;
+use datafusion_common::{utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the [`Jaro-Winkler similarity`](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance) between the two given strings, from 0.0 (no similarity) to 1.0 (identical).",
+    syntax_example = "jaro_winkler_similarity(str1, str2)",
+    sql_example = r#"```sql
+> select jaro_winkler_similarity('martha', 'marhta');
++--------------------------------------------------------+
+| jaro_winkler_similarity(Utf8("martha"),Utf8("marhta")) |
++--------------------------------------------------------+
+| 0.9611111111111111                                     |
++--------------------------------------------------------+
+```"#,
+    standard_argument(name = "str1", prefix = "String"),
+    standard_argument(name = "str2", prefix = "String")
+)]
+#[derive(Debug)]
+pub struct JaroWinklerSimilarityFunc {
+    signature: Signature,
+}
+
+impl Default for JaroWinklerSimilarityFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JaroWinklerSimilarityFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JaroWinklerSimilarityFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "jaro_winkler_similarity"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(jaro_winkler_similarity, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn jaro_winkler_similarity(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str1, str2] = take_function_args("jaro_winkler_similarity", args)?;
+    let result = map_string_pairs::<Float64Type, _>(
+        "jaro_winkler_similarity",
+        str1,
+        str2,
+        |str1, str2| Ok(jaro_winkler(str1, str2)),
+    )?;
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the Jaro similarity of `a` and `b`
+fn jaro(a: &str, b: &str) -> f64 {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // characters only match if they are no further apart than this
+    let search_range = (a.len().max(b.len()) / 2).saturating_sub(1);
+
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len());
+    for (i, a_char) in a.iter().enumerate() {
+        let start = i.saturating_sub(search_range);
+        let end = (i + search_range + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *a_char {
+                b_matched[j] = true;
+                a_matches.push(*a_char);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(b_matched)
+        .filter_map(|(b_char, matched)| matched.then_some(*b_char));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(a_char, b_char)| *a_char != b_char)
+        .count();
+
+    let matches = a_matches.len() as f64;
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64 / 2.0) / matches)
+        / 3.0
+}
+
+/// Returns the Jaro-Winkler similarity of `a` and `b`, which boosts the Jaro
+/// similarity of strings with a common prefix of up to 4 characters when
+/// the Jaro similarity is above 0.7.
+pub(crate) fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let similarity = jaro(a, b);
+    if similarity <= 0.7 {
+        return similarity;
+    }
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(a, b)| a == b)
+        .count();
+    similarity + 0.1 * prefix as f64 * (1.0 - similarity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        assert_close(jaro_winkler("martha", "marhta"), 0.9611);
+        assert_close(jaro_winkler("dwayne", "duane"), 0.84);
+        assert_close(jaro_winkler("dixon", "dicksonx"), 0.8133);
+        assert_close(jaro_winkler("abc", "abc"), 1.0);
+        assert_close(jaro_winkler("abc", "xyz"), 0.0);
+        assert_close(jaro_winkler("", ""), 1.0);
+        assert_close(jaro_winkler("", "abc"), 0.0);
+    }
+}
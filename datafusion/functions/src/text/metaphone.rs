@@ -0,0 +1,305 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::DataType;
+
+use crate::text::common::map_strings;
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::{utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the [`Metaphone`](https://en.wikipedia.org/wiki/Metaphone) code of a string, following the rules of the original Metaphone algorithm. Characters other than the ASCII letters are ignored.",
+    syntax_example = "metaphone(str)",
+    sql_example = r#"```sql
+> select metaphone('Knight'), metaphone('Thumb');
++---------------------------+--------------------------+
+| metaphone(Utf8("Knight")) | metaphone(Utf8("Thumb")) |
++---------------------------+--------------------------+
+| NT                        | 0M                       |
++---------------------------+--------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String")
+)]
+#[derive(Debug)]
+pub struct MetaphoneFunc {
+    signature: Signature,
+}
+
+impl Default for MetaphoneFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetaphoneFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MetaphoneFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "metaphone"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "metaphone")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(metaphone, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn metaphone(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str] = take_function_args("metaphone", args)?;
+    map_strings("metaphone", str, metaphone_code)
+}
+
+/// The uppercase ASCII letters of a word, with helpers for looking around a
+/// position
+struct Word(Vec<u8>);
+
+impl Word {
+    fn at(&self, i: usize) -> Option<u8> {
+        self.0.get(i).copied()
+    }
+
+    fn is(&self, i: usize, letter: u8) -> bool {
+        self.at(i) == Some(letter)
+    }
+
+    fn is_previous(&self, i: usize, letter: u8) -> bool {
+        i > 0 && self.is(i - 1, letter)
+    }
+
+    fn is_vowel(&self, i: usize) -> bool {
+        matches!(self.at(i), Some(b'A' | b'E' | b'I' | b'O' | b'U'))
+    }
+
+    /// Returns true if the letter at `i` is E, I or Y, which soften C and G
+    fn is_front_vowel(&self, i: usize) -> bool {
+        matches!(self.at(i), Some(b'E' | b'I' | b'Y'))
+    }
+
+    fn starts_with_at(&self, i: usize, prefix: &[u8]) -> bool {
+        self.0[i..].starts_with(prefix)
+    }
+
+    fn is_last(&self, i: usize) -> bool {
+        i + 1 == self.0.len()
+    }
+}
+
+fn metaphone_code(value: &str) -> String {
+    let mut letters = value
+        .bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|letter| letter.to_ascii_uppercase())
+        .collect::<Vec<_>>();
+
+    // initial letter exceptions
+    match letters.as_slice() {
+        [b'K' | b'G' | b'P', b'N', ..] | [b'A', b'E', ..] | [b'W', b'R', ..] => {
+            letters.remove(0);
+        }
+        [b'W', b'H', ..] => {
+            letters.remove(1);
+        }
+        [b'X', ..] => letters[0] = b'S',
+        _ => {}
+    }
+
+    let word = Word(letters);
+    let mut code = String::new();
+    let mut i = 0;
+    while let Some(letter) = word.at(i) {
+        // skip repeated letters, except C
+        if letter != b'C' && word.is_previous(i, letter) {
+            i += 1;
+            continue;
+        }
+
+        match letter {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                // vowels are only kept at the beginning
+                if i == 0 {
+                    code.push(letter as char);
+                }
+            }
+            b'B' => {
+                // silent in a trailing MB
+                if !(word.is_previous(i, b'M') && word.is_last(i)) {
+                    code.push('B');
+                }
+            }
+            b'C' => {
+                if word.is_previous(i, b'S') && word.is_front_vowel(i + 1) {
+                    // silent in SCE, SCI and SCY
+                } else if word.starts_with_at(i, b"CIA") {
+                    code.push('X');
+                } else if word.is_front_vowel(i + 1) {
+                    code.push('S');
+                } else if word.is_previous(i, b'S') && word.is(i + 1, b'H') {
+                    code.push('K');
+                } else if word.is(i + 1, b'H') {
+                    // an initial CH followed by a consonant is hard
+                    if i == 0 && !word.is_vowel(i + 2) {
+                        code.push('K');
+                    } else {
+                        code.push('X');
+                    }
+                } else {
+                    code.push('K');
+                }
+            }
+            b'D' => {
+                if word.is(i + 1, b'G') && word.is_front_vowel(i + 2) {
+                    code.push('J');
+                    i += 2;
+                } else {
+                    code.push('T');
+                }
+            }
+            b'G' => {
+                let silent = if word.is(i + 1, b'H') {
+                    // silent in a trailing GH and before a consonant
+                    !word.is_vowel(i + 2)
+                } else {
+                    // silent in GN and GNED, except at the beginning
+                    i > 0 && word.starts_with_at(i, b"GN")
+                };
+                if !silent {
+                    if word.is_front_vowel(i + 1) {
+                        code.push('J');
+                    } else {
+                        code.push('K');
+                    }
+                }
+            }
+            b'H' => {
+                // silent at the end, after CSPTG and before a consonant
+                let after_varson = i > 0
+                    && matches!(word.at(i - 1), Some(b'C' | b'S' | b'P' | b'T' | b'G'));
+                if !word.is_last(i) && !after_varson && word.is_vowel(i + 1) {
+                    code.push('H');
+                }
+            }
+            b'F' | b'J' | b'L' | b'M' | b'N' | b'R' => code.push(letter as char),
+            b'K' => {
+                // silent after C
+                if !word.is_previous(i, b'C') {
+                    code.push('K');
+                }
+            }
+            b'P' => {
+                if word.is(i + 1, b'H') {
+                    code.push('F');
+                } else {
+                    code.push('P');
+                }
+            }
+            b'Q' => code.push('K'),
+            b'S' => {
+                if word.starts_with_at(i, b"SH")
+                    || word.starts_with_at(i, b"SIO")
+                    || word.starts_with_at(i, b"SIA")
+                {
+                    code.push('X');
+                } else {
+                    code.push('S');
+                }
+            }
+            b'T' => {
+                if word.starts_with_at(i, b"TIA") || word.starts_with_at(i, b"TIO") {
+                    code.push('X');
+                } else if word.starts_with_at(i, b"TCH") {
+                    // silent in TCH
+                } else if word.is(i + 1, b'H') {
+                    code.push('0');
+                } else {
+                    code.push('T');
+                }
+            }
+            b'V' => code.push('F'),
+            b'W' | b'Y' => {
+                // silent unless followed by a vowel
+                if word.is_vowel(i + 1) {
+                    code.push(letter as char);
+                }
+            }
+            b'X' => code.push_str("KS"),
+            b'Z' => code.push('S'),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metaphone_code() {
+        assert_eq!(metaphone_code(""), "");
+        assert_eq!(metaphone_code("Knight"), "NT");
+        assert_eq!(metaphone_code("Thompson"), "0MPSN");
+        assert_eq!(metaphone_code("Thumb"), "0M");
+        assert_eq!(metaphone_code("Wright"), "RT");
+        assert_eq!(metaphone_code("White"), "WT");
+        assert_eq!(metaphone_code("Xavier"), "SFR");
+        assert_eq!(metaphone_code("Aero"), "ER");
+        assert_eq!(metaphone_code("Science"), "SNS");
+        assert_eq!(metaphone_code("Character"), "XRKTR");
+        assert_eq!(metaphone_code("Christ"), "KRST");
+        assert_eq!(metaphone_code("School"), "SKL");
+        assert_eq!(metaphone_code("Church"), "XRX");
+        assert_eq!(metaphone_code("Judge"), "JJ");
+        assert_eq!(metaphone_code("Catch"), "KX");
+        assert_eq!(metaphone_code("Dumb"), "TM");
+        assert_eq!(metaphone_code("Laugh"), "L");
+        assert_eq!(metaphone_code("Ghost"), "KST");
+        assert_eq!(metaphone_code("Sign"), "SN");
+        assert_eq!(metaphone_code("Nation"), "NXN");
+        assert_eq!(metaphone_code("Phone"), "FN");
+        assert_eq!(metaphone_code("Box"), "BKS");
+    }
+}
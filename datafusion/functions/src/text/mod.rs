@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! "text" DataFusion functions for comparing and normalizing text, such as
+//! string similarity measures and phonetic codes
+
+use datafusion_expr::ScalarUDF;
+use std::sync::Arc;
+
+pub(crate) mod common;
+pub mod damerau_levenshtein;
+pub mod hamming_distance;
+pub mod jaccard;
+pub mod jaro_winkler_similarity;
+pub mod metaphone;
+pub mod ngrams;
+pub mod soundex;
+pub mod strip_accents;
+pub mod unicode_normalize;
+
+make_udf_function!(
+    damerau_levenshtein::DamerauLevenshteinFunc,
+    damerau_levenshtein
+);
+make_udf_function!(hamming_distance::HammingDistanceFunc, hamming_distance);
+make_udf_function!(jaccard::JaccardFunc, jaccard);
+make_udf_function!(
+    jaro_winkler_similarity::JaroWinklerSimilarityFunc,
+    jaro_winkler_similarity
+);
+make_udf_function!(metaphone::MetaphoneFunc, metaphone);
+make_udf_function!(ngrams::NgramsFunc, ngrams);
+make_udf_function!(soundex::SoundexFunc, soundex);
+make_udf_function!(strip_accents::StripAccentsFunc, strip_accents);
+make_udf_function!(unicode_normalize::UnicodeNormalizeFunc, unicode_normalize);
+
+pub mod expr_fn {
+    export_functions!((
+        damerau_levenshtein,
+        "Returns the Damerau-Levenshtein distance between the two given strings",
+        arg1 arg2
+    ),(
+        hamming_distance,
+        "Returns the Hamming distance between the two given strings of the same length",
+        arg1 arg2
+    ),(
+        jaccard,
+        "Returns the Jaccard similarity of the character n-grams of two strings, with an optional n-gram size",
+        args,
+    ),(
+        jaro_winkler_similarity,
+        "Returns the Jaro-Winkler similarity between the two given strings",
+        arg1 arg2
+    ),(
+        metaphone,
+        "Returns the Metaphone code of a string",
+        string
+    ),(
+        ngrams,
+        "Returns the character n-grams of a string",
+        string n
+    ),(
+        soundex,
+        "Returns the Soundex code of a string",
+        string
+    ),(
+        strip_accents,
+        "Removes accents and other combining marks from a string",
+        string
+    ),(
+        unicode_normalize,
+        "Converts a string to a Unicode normalization form, NFC by default",
+        args,
+    ));
+}
+
+/// Returns all DataFusion functions defined in this package
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    vec![
+        damerau_levenshtein(),
+        hamming_distance(),
+        jaccard(),
+        jaro_winkler_similarity(),
+        metaphone(),
+        ngrams(),
+        soundex(),
+        strip_accents(),
+        unicode_normalize(),
+    ]
+}
@@ -0,0 +1,183 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, AsArray, GenericStringBuilder, Int64Array, ListBuilder, OffsetSizeTrait,
+    StringArrayType,
+};
+use arrow::datatypes::{DataType, Field, Int64Type};
+
+use crate::text::common::char_ngrams;
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::{exec_err, utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation, TypeSignature::Exact};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the character n-grams of a string as an array, in order and including duplicates. A string shorter than `n` characters is its own single n-gram, and an empty string has none.",
+    syntax_example = "ngrams(str, n)",
+    sql_example = r#"```sql
+> select ngrams('hello', 3);
++--------------------------------+
+| ngrams(Utf8("hello"),Int64(3)) |
++--------------------------------+
+| [hel, ell, llo]                |
++--------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    argument(name = "n", description = "Number of characters of each n-gram.")
+)]
+#[derive(Debug)]
+pub struct NgramsFunc {
+    signature: Signature,
+}
+
+impl Default for NgramsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NgramsFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8View, Int64]),
+                    Exact(vec![Utf8, Int64]),
+                    Exact(vec![LargeUtf8, Int64]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for NgramsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "ngrams"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let item_type = utf8_to_str_type(&arg_types[0], "ngrams")?;
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            item_type, true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(ngrams, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn ngrams(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str, n] = take_function_args("ngrams", args)?;
+    let n = n.as_primitive::<Int64Type>();
+    match str.data_type() {
+        DataType::Utf8 => ngrams_impl::<_, i32>(str.as_string::<i32>(), n),
+        DataType::LargeUtf8 => ngrams_impl::<_, i64>(str.as_string::<i64>(), n),
+        DataType::Utf8View => ngrams_impl::<_, i32>(str.as_string_view(), n),
+        other => exec_err!(
+            "ngrams was called with {other} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+        ),
+    }
+}
+
+fn ngrams_impl<'a, S, O>(str: S, n: &Int64Array) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+    O: OffsetSizeTrait,
+{
+    let mut builder =
+        ListBuilder::with_capacity(GenericStringBuilder::<O>::new(), str.len());
+    for (value, n) in str.iter().zip(n.iter()) {
+        match (value, n) {
+            (Some(value), Some(n)) => {
+                let n = ngram_size("ngrams", n)?;
+                for ngram in char_ngrams(value, n) {
+                    builder.values().append_value(ngram);
+                }
+                builder.append(true);
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Validates the n-gram size argument `n` of the function `name`
+pub(crate) fn ngram_size(name: &str, n: i64) -> Result<usize> {
+    if n <= 0 {
+        return exec_err!("{name}() requires n to be positive, got {n}");
+    }
+    Ok(n as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, LargeStringArray};
+
+    #[test]
+    fn test_ngrams() -> Result<()> {
+        let str = Arc::new(LargeStringArray::from(vec![
+            Some("hello"),
+            Some("hi"),
+            Some(""),
+            None,
+        ])) as ArrayRef;
+        let n = Arc::new(Int64Array::from(vec![3, 3, 3, 3])) as ArrayRef;
+        let result = ngrams(&[str, n])?;
+        let list = result.as_list::<i32>();
+        assert_eq!(
+            list.value(0).as_ref(),
+            &LargeStringArray::from(vec!["hel", "ell", "llo"])
+        );
+        assert_eq!(list.value(1).as_ref(), &LargeStringArray::from(vec!["hi"]));
+        assert_eq!(list.value(2).len(), 0);
+        assert!(list.is_null(3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ngrams_invalid_size() {
+        let str = Arc::new(LargeStringArray::from(vec!["hello"])) as ArrayRef;
+        let n = Arc::new(Int64Array::from(vec![0])) as ArrayRef;
+        let err = ngrams(&[str, n]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("ngrams() requires n to be positive, got 0"));
+    }
+}
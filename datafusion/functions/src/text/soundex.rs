@@ -0,0 +1,157 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::DataType;
+
+use crate::text::common::map_strings;
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::{utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Returns the four character American [`Soundex`](https://en.wikipedia.org/wiki/Soundex) code of a string. Characters other than the ASCII letters are ignored, and a string without letters has an empty code.",
+    syntax_example = "soundex(str)",
+    sql_example = r#"```sql
+> select soundex('Robert'), soundex('Rupert');
++-------------------------+-------------------------+
+| soundex(Utf8("Robert")) | soundex(Utf8("Rupert")) |
++-------------------------+-------------------------+
+| R163                    | R163                    |
++-------------------------+-------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String")
+)]
+#[derive(Debug)]
+pub struct SoundexFunc {
+    signature: Signature,
+}
+
+impl Default for SoundexFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SoundexFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SoundexFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "soundex"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "soundex")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(soundex, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn soundex(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str] = take_function_args("soundex", args)?;
+    map_strings("soundex", str, soundex_code)
+}
+
+/// The Soundex digit of an uppercase ASCII letter. Vowels (and Y) map to
+/// `'0'`, which separates equal digits, while H and W map to `None` and do
+/// not.
+fn letter_digit(letter: u8) -> Option<u8> {
+    match letter {
+        b'B' | b'F' | b'P' | b'V' => Some(b'1'),
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some(b'2'),
+        b'D' | b'T' => Some(b'3'),
+        b'L' => Some(b'4'),
+        b'M' | b'N' => Some(b'5'),
+        b'R' => Some(b'6'),
+        b'H' | b'W' => None,
+        _ => Some(b'0'),
+    }
+}
+
+fn soundex_code(value: &str) -> String {
+    let mut letters = value
+        .bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|letter| letter.to_ascii_uppercase());
+    let Some(first) = letters.next() else {
+        return String::new();
+    };
+
+    let mut code = vec![first];
+    let mut last_digit = letter_digit(first);
+    for letter in letters {
+        let Some(digit) = letter_digit(letter) else {
+            continue;
+        };
+        if digit != b'0' && Some(digit) != last_digit {
+            code.push(digit);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        last_digit = Some(digit);
+    }
+    code.resize(4, b'0');
+
+    String::from_utf8(code).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soundex_code() {
+        assert_eq!(soundex_code("Robert"), "R163");
+        assert_eq!(soundex_code("Rupert"), "R163");
+        assert_eq!(soundex_code("Rubin"), "R150");
+        assert_eq!(soundex_code("Ashcraft"), "A261");
+        assert_eq!(soundex_code("Ashcroft"), "A261");
+        assert_eq!(soundex_code("Tymczak"), "T522");
+        assert_eq!(soundex_code("Pfister"), "P236");
+        assert_eq!(soundex_code("Honeyman"), "H555");
+        assert_eq!(soundex_code("Lee"), "L000");
+        assert_eq!(soundex_code("  o'Hara"), "O600");
+        assert_eq!(soundex_code("123"), "");
+        assert_eq!(soundex_code(""), "");
+    }
+}
@@ -0,0 +1,134 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::DataType;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::text::common::map_strings;
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::{utils::take_function_args, Result};
+use datafusion_expr::{ColumnarValue, Documentation};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Removes accents and other combining marks from a string, by decomposing it to Unicode normalization form NFD and dropping the combining characters.",
+    syntax_example = "strip_accents(str)",
+    sql_example = r#"```sql
+> select strip_accents('Crème Brûlée');
++-------------------------------------+
+| strip_accents(Utf8("Crème Brûlée")) |
++-------------------------------------+
+| Creme Brulee                        |
++-------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String")
+)]
+#[derive(Debug)]
+pub struct StripAccentsFunc {
+    signature: Signature,
+}
+
+impl Default for StripAccentsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StripAccentsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StripAccentsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "strip_accents"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "strip_accents")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(strip_accents, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+pub fn strip_accents(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str] = take_function_args("strip_accents", args)?;
+    map_strings("strip_accents", str, |value| {
+        if value.is_ascii() {
+            return value.to_string();
+        }
+        value
+            .nfd()
+            .filter(|c| !is_combining_mark(*c))
+            .nfc()
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{StringArray, StringViewArray};
+    use datafusion_common::cast::as_string_array;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_strip_accents() -> Result<()> {
+        let input = Arc::new(StringViewArray::from(vec![
+            Some("Crème Brûlée"),
+            Some("Ångström"),
+            Some("ASCII"),
+            Some("한국어"),
+            None,
+        ]));
+        let result = strip_accents(&[input])?;
+        assert_eq!(
+            as_string_array(&result)?,
+            &StringArray::from(vec![
+                Some("Creme Brulee"),
+                Some("Angstrom"),
+                Some("ASCII"),
+                Some("한국어"),
+                None
+            ])
+        );
+        Ok(())
+    }
+}
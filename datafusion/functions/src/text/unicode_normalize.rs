@@ -0,0 +1,244 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, AsArray, GenericStringBuilder, OffsetSizeTrait, StringArrayType,
+};
+use arrow::datatypes::DataType;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::text::common::map_strings;
+use crate::utils::{make_scalar_function, utf8_to_str_type};
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{ColumnarValue, Documentation, TypeSignature};
+use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Text Functions"),
+    description = "Converts a string to the given [Unicode normalization form](https://unicode.org/reports/tr15/).",
+    syntax_example = "unicode_normalize(str[, form])",
+    sql_example = r#"```sql
+> select unicode_normalize('ﬁ', 'NFKC');
++-------------------------------------------+
+| unicode_normalize(Utf8("ﬁ"),Utf8("NFKC")) |
++-------------------------------------------+
+| fi                                        |
++-------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    argument(
+        name = "form",
+        description = r#"Optional normalization form, case insensitive. Defaults to `NFC`. The following forms are supported:
+  - **NFC**: canonical decomposition followed by canonical composition
+  - **NFD**: canonical decomposition
+  - **NFKC**: compatibility decomposition followed by canonical composition
+  - **NFKD**: compatibility decomposition"#
+    )
+)]
+#[derive(Debug)]
+pub struct UnicodeNormalizeFunc {
+    signature: Signature,
+}
+
+impl Default for UnicodeNormalizeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnicodeNormalizeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::String(1), TypeSignature::String(2)],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UnicodeNormalizeFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "unicode_normalize"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "unicode_normalize")
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(unicode_normalize, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// A Unicode normalization form
+#[derive(Debug, Clone, Copy)]
+enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn try_new(form: &str) -> Result<Self> {
+        match form.to_ascii_uppercase().as_str() {
+            "NFC" => Ok(Self::Nfc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFKD" => Ok(Self::Nfkd),
+            _ => exec_err!(
+                "unicode_normalize() does not support the normalization form {form}, expected one of NFC, NFD, NFKC or NFKD"
+            ),
+        }
+    }
+
+    fn normalize(self, value: &str) -> String {
+        match self {
+            Self::Nfc => value.nfc().collect(),
+            Self::Nfd => value.nfd().collect(),
+            Self::Nfkc => value.nfkc().collect(),
+            Self::Nfkd => value.nfkd().collect(),
+        }
+    }
+}
+
+pub fn unicode_normalize(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let (str, form) = match args {
+        [str] => {
+            return map_strings("unicode_normalize", str, |value| {
+                NormalizationForm::Nfc.normalize(value)
+            })
+        }
+        [str, form] => (str, form),
+        _ => {
+            return exec_err!(
+                "unicode_normalize was called with {} arguments. It requires 1 or 2.",
+                args.len()
+            )
+        }
+    };
+
+    match (str.data_type(), form.data_type()) {
+        (DataType::Utf8, DataType::Utf8) => unicode_normalize_impl::<_, i32>(
+            str.as_string::<i32>(),
+            form.as_string::<i32>(),
+        ),
+        (DataType::LargeUtf8, DataType::LargeUtf8) => unicode_normalize_impl::<_, i64>(
+            str.as_string::<i64>(),
+            form.as_string::<i64>(),
+        ),
+        (DataType::Utf8View, DataType::Utf8View) => unicode_normalize_impl::<_, i32>(
+            str.as_string_view(),
+            form.as_string_view(),
+        ),
+        (str, form) => exec_err!(
+            "unicode_normalize was called with {str} and {form} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+        ),
+    }
+}
+
+fn unicode_normalize_impl<'a, S, O>(str: S, form: S) -> Result<ArrayRef>
+where
+    S: StringArrayType<'a>,
+    O: OffsetSizeTrait,
+{
+    let mut builder = GenericStringBuilder::<O>::with_capacity(str.len(), 0);
+    for (value, form) in str.iter().zip(form.iter()) {
+        match (value, form) {
+            (Some(value), Some(form)) => {
+                builder.append_value(NormalizationForm::try_new(form)?.normalize(value))
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::StringArray;
+    use datafusion_common::cast::as_string_array;
+
+    #[test]
+    fn test_unicode_normalize() -> Result<()> {
+        // "é" as a single code point and as "e" followed by a combining acute
+        let composed = "\u{e9}";
+        let decomposed = "e\u{301}";
+
+        let str = Arc::new(StringArray::from(vec![
+            Some(decomposed),
+            Some(composed),
+            Some("\u{fb01}"),
+            Some("\u{fb01}"),
+            None,
+        ])) as ArrayRef;
+        let form = Arc::new(StringArray::from(vec![
+            Some("NFC"),
+            Some("nfd"),
+            Some("NFC"),
+            Some("NFKD"),
+            Some("NFC"),
+        ])) as ArrayRef;
+        let result = unicode_normalize(&[str, form])?;
+        assert_eq!(
+            as_string_array(&result)?,
+            &StringArray::from(vec![
+                Some(composed),
+                Some(decomposed),
+                Some("\u{fb01}"),
+                Some("fi"),
+                None
+            ])
+        );
+
+        let str = Arc::new(StringArray::from(vec![decomposed])) as ArrayRef;
+        let result = unicode_normalize(&[str])?;
+        assert_eq!(
+            as_string_array(&result)?,
+            &StringArray::from(vec![composed])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unicode_normalize_invalid_form() {
+        let str = Arc::new(StringArray::from(vec!["a"])) as ArrayRef;
+        let form = Arc::new(StringArray::from(vec!["NFX"])) as ArrayRef;
+        let err = unicode_normalize(&[str, form]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unicode_normalize() does not support the normalization form NFX"));
+    }
+}
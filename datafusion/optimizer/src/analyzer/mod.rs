@@ -140,6 +140,8 @@ impl Analyzer {
     where
         F: FnMut(&LogicalPlan, &dyn AnalyzerRule),
     {
+        let _span = tracing::info_span!("analyze_logical_plan").entered();
+
         // verify the logical plan required invariants at the start, before analyzer
         plan.check_invariants(InvariantLevel::Always)
             .map_err(|e| e.context("Invalid input plan passed to Analyzer"))?;
@@ -165,6 +167,12 @@ impl Analyzer {
 
         // TODO add common rule executor for Analyzer and Optimizer
         for rule in rules {
+            let _span = tracing::debug_span!(
+                "analyzer_rule",
+                otel.name = rule.name(),
+                rule = rule.name()
+            )
+            .entered();
             new_plan = rule
                 .analyze(new_plan, config)
                 .map_err(|e| e.context(rule.name()))?;
@@ -51,8 +51,10 @@ pub mod eliminate_one_union;
 pub mod eliminate_outer_join;
 pub mod extract_equijoin_predicate;
 pub mod filter_null_join_keys;
+pub mod materialized_view_rewrite;
 pub mod optimize_projections;
 pub mod optimizer;
+pub mod plan_signature;
 pub mod propagate_empty_relation;
 pub mod push_down_filter;
 pub mod push_down_limit;
@@ -70,11 +72,11 @@ pub use analyzer::{Analyzer, AnalyzerRule};
 pub use optimizer::{
     ApplyOrder, Optimizer, OptimizerConfig, OptimizerContext, OptimizerRule,
 };
+pub use plan_signature::LogicalPlanSignature;
 #[allow(deprecated)]
 pub use utils::optimize_children;
 
 pub(crate) mod join_key_set;
-mod plan_signature;
 
 #[cfg(test)]
 #[ctor::ctor]
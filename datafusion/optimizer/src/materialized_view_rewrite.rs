@@ -0,0 +1,748 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MaterializedViewRewrite`] answers queries from the results of
+//! materialized views
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::optimizer::ApplyOrder;
+use crate::{OptimizerConfig, OptimizerRule};
+
+use datafusion_common::tree_node::{Transformed, TreeNode, TreeNodeRecursion};
+use datafusion_common::{Column, DFSchema, Result, TableReference};
+use datafusion_expr::expr::AggregateFunction;
+use datafusion_expr::expr_rewriter::unnormalize_col;
+use datafusion_expr::utils::{conjunction, split_conjunction};
+use datafusion_expr::{
+    cast, lit, when, Aggregate, Expr, ExprSchemable, LogicalPlan, LogicalPlanBuilder,
+    TableScan, TableSource,
+};
+
+/// A materialized view that can be used by [`MaterializedViewRewrite`]
+pub struct MaterializedView {
+    /// The name the view is registered under
+    name: TableReference,
+    /// The source scanning the stored results of the view
+    source: Arc<dyn TableSource>,
+    /// The optimized logical plan of the view definition
+    plan: LogicalPlan,
+    /// The tables the view definition reads
+    tables: HashSet<TableReference>,
+    /// Whether the stored results reflect the current contents of `tables`
+    fresh: AtomicBool,
+}
+
+impl MaterializedView {
+    /// Create a new, fresh, materialized view named `name`, whose results
+    /// are read by `source` and are computed by the optimized `plan`
+    pub fn new(
+        name: TableReference,
+        source: Arc<dyn TableSource>,
+        plan: LogicalPlan,
+    ) -> Self {
+        Self {
+            name,
+            source,
+            plan,
+            tables: HashSet::new(),
+            fresh: AtomicBool::new(true),
+        }
+    }
+
+    /// Set the tables the view definition reads, which are used by
+    /// [`MaterializedViewRegistry::mark_stale`]
+    pub fn with_tables(
+        mut self,
+        tables: impl IntoIterator<Item = TableReference>,
+    ) -> Self {
+        self.tables = tables.into_iter().collect();
+        self
+    }
+
+    /// The name of the view
+    pub fn name(&self) -> &TableReference {
+        &self.name
+    }
+
+    /// The source scanning the stored results of the view
+    pub fn source(&self) -> &Arc<dyn TableSource> {
+        &self.source
+    }
+
+    /// The optimized logical plan of the view definition
+    pub fn plan(&self) -> &LogicalPlan {
+        &self.plan
+    }
+
+    /// The tables the view definition reads
+    pub fn tables(&self) -> &HashSet<TableReference> {
+        &self.tables
+    }
+
+    /// Returns `true` if the stored results reflect the current contents of
+    /// the tables the view reads
+    pub fn is_fresh(&self) -> bool {
+        self.fresh.load(Ordering::Relaxed)
+    }
+
+    /// Mark the stored results as fresh, such as after a refresh, or stale
+    pub fn set_fresh(&self, fresh: bool) {
+        self.fresh.store(fresh, Ordering::Relaxed)
+    }
+}
+
+impl fmt::Debug for MaterializedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaterializedView")
+            .field("name", &self.name)
+            .field("plan", &self.plan)
+            .field("tables", &self.tables)
+            .field("fresh", &self.is_fresh())
+            .finish()
+    }
+}
+
+/// The materialized views of a session, used by [`MaterializedViewRewrite`]
+///
+/// The registry is stored as an extension of the session configuration, so
+/// it is shared by all clones of the session.
+#[derive(Debug, Default)]
+pub struct MaterializedViewRegistry {
+    views: RwLock<HashMap<TableReference, Arc<MaterializedView>>>,
+}
+
+impl MaterializedViewRegistry {
+    /// Register `view`, replacing any view of the same name
+    pub fn register(&self, view: MaterializedView) -> Option<Arc<MaterializedView>> {
+        let mut views = self.views.write().unwrap();
+        views.insert(view.name.clone(), Arc::new(view))
+    }
+
+    /// Remove the view named `name`
+    pub fn deregister(&self, name: &TableReference) -> Option<Arc<MaterializedView>> {
+        self.views.write().unwrap().remove(name)
+    }
+
+    /// Return the view named `name`, if any
+    pub fn get(&self, name: &TableReference) -> Option<Arc<MaterializedView>> {
+        self.views.read().unwrap().get(name).cloned()
+    }
+
+    /// Return all registered views
+    pub fn views(&self) -> Vec<Arc<MaterializedView>> {
+        self.views.read().unwrap().values().cloned().collect()
+    }
+
+    /// Mark all views reading `table` as stale, so they are no longer used
+    /// to answer queries until they are refreshed
+    pub fn mark_stale(&self, table: &TableReference) {
+        for view in self.views.read().unwrap().values() {
+            if view.tables.contains(table) {
+                view.set_fresh(false);
+            }
+        }
+    }
+}
+
+/// Optimizer rule that answers queries, or parts of queries, from the
+/// results of a [`MaterializedView`] whose definition subsumes them.
+///
+/// The rule is enabled by the
+/// `datafusion.optimizer.enable_materialized_view_rewrite` option, and uses
+/// the fresh views of [`OptimizerConfig::materialized_views`]. A part of the
+/// plan is replaced by a scan of a view when:
+///
+/// * it is the same as the view definition
+/// * it is a (filtered) scan of the table the view selects from, whose
+///   filters include all filters of the view, and whose columns and other
+///   filters only use columns of the view. For example `SELECT a FROM t WHERE
+///   b > 1 AND a < 10` from a view defined as `SELECT a, b FROM t WHERE b > 1`
+/// * it is an aggregate of the same input as a view aggregate, grouping by a
+///   subset of the view grouping expressions and computing a subset of the
+///   `sum`, `min`, `max` and `count` aggregates of the view. For example
+///   `SELECT a, sum(c) FROM t GROUP BY a` from a view defined as
+///   `SELECT a, b, sum(c) FROM t GROUP BY a, b`
+///
+/// Views are marked stale by the session when the tables they read are
+/// modified, including by other writers, and are then not used until they
+/// are refreshed.
+#[derive(Default, Debug)]
+pub struct MaterializedViewRewrite {}
+
+impl MaterializedViewRewrite {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for MaterializedViewRewrite {
+    fn name(&self) -> &str {
+        "materialized_view_rewrite"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        None
+    }
+
+    fn supports_rewrite(&self) -> bool {
+        true
+    }
+
+    fn rewrite(
+        &self,
+        plan: LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<Transformed<LogicalPlan>> {
+        if !config.options().optimizer.enable_materialized_view_rewrite {
+            return Ok(Transformed::no(plan));
+        }
+        let Some(registry) = config.materialized_views() else {
+            return Ok(Transformed::no(plan));
+        };
+        let views: Vec<_> = registry
+            .views()
+            .into_iter()
+            .filter(|view| view.is_fresh())
+            .collect();
+        if views.is_empty() {
+            return Ok(Transformed::no(plan));
+        }
+
+        // rewrite the largest parts of the plan first
+        plan.transform_down(|plan| {
+            for view in &views {
+                if let Some(rewritten) = rewrite_with_view(&plan, view, config)? {
+                    return Ok(Transformed::new(
+                        rewritten,
+                        true,
+                        TreeNodeRecursion::Jump,
+                    ));
+                }
+            }
+            Ok(Transformed::no(plan))
+        })
+    }
+}
+
+/// Rewrite `plan` to read from `view`, if the view subsumes it
+fn rewrite_with_view(
+    plan: &LogicalPlan,
+    view: &MaterializedView,
+    config: &dyn OptimizerConfig,
+) -> Result<Option<LogicalPlan>> {
+    if plan == view.plan() {
+        let exprs = (0..plan.schema().fields().len())
+            .map(|i| view_column(view, i))
+            .collect();
+        let scan = scan_view(view)?.build()?;
+        return project_to(scan, exprs, plan.schema()).map(Some);
+    }
+    match plan {
+        LogicalPlan::Filter(_) | LogicalPlan::TableScan(_) => {
+            rewrite_filtered_scan(plan, view)
+        }
+        LogicalPlan::Aggregate(aggregate) => rewrite_aggregate(aggregate, view, config),
+        _ => Ok(None),
+    }
+}
+
+/// A (filtered) table scan
+struct FilteredScan<'a> {
+    scan: &'a TableScan,
+    /// The unqualified conjuncts of the filters
+    predicates: Vec<Expr>,
+}
+
+impl<'a> FilteredScan<'a> {
+    /// Match a `TableScan`, possibly below a `Filter`, without a fetch
+    fn try_new(plan: &'a LogicalPlan) -> Option<Self> {
+        let (scan, filter) = match plan {
+            LogicalPlan::Filter(filter) => match filter.input.as_ref() {
+                LogicalPlan::TableScan(scan) => (scan, Some(&filter.predicate)),
+                _ => return None,
+            },
+            LogicalPlan::TableScan(scan) => (scan, None),
+            _ => return None,
+        };
+        if scan.fetch.is_some() {
+            return None;
+        }
+
+        let mut predicates: Vec<Expr> = vec![];
+        let conjuncts = filter
+            .into_iter()
+            .chain(scan.filters.iter())
+            .flat_map(split_conjunction);
+        for expr in conjuncts {
+            let expr = unnormalize_col(expr.clone());
+            if !predicates.contains(&expr) {
+                predicates.push(expr);
+            }
+        }
+        Some(Self { scan, predicates })
+    }
+
+    /// Returns true if `self` reads the same rows of the same table as `other`
+    fn same_rows(&self, other: &Self) -> bool {
+        self.scan.table_name == other.scan.table_name
+            && self.scan.source.table_version() == other.scan.source.table_version()
+            && self.predicates.len() == other.predicates.len()
+            && self.predicates.iter().all(|p| other.predicates.contains(p))
+    }
+}
+
+/// Rewrite a (filtered) scan to read from a view defined as a (filtered)
+/// scan of the same table, whose filters are a subset of the scan filters
+fn rewrite_filtered_scan(
+    plan: &LogicalPlan,
+    view: &MaterializedView,
+) -> Result<Option<LogicalPlan>> {
+    let Some(query) = FilteredScan::try_new(plan) else {
+        return Ok(None);
+    };
+
+    // the view definition, with the base table column of each view column
+    let (definition, view_columns) = match view.plan() {
+        LogicalPlan::Projection(projection) => {
+            let Some(definition) = FilteredScan::try_new(&projection.input) else {
+                return Ok(None);
+            };
+            let columns = projection
+                .expr
+                .iter()
+                .map(|expr| match expr.clone().unalias() {
+                    Expr::Column(column) => Some(column.name),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            (definition, columns)
+        }
+        plan => {
+            let Some(definition) = FilteredScan::try_new(plan) else {
+                return Ok(None);
+            };
+            let columns = definition
+                .scan
+                .projected_schema
+                .fields()
+                .iter()
+                .map(|field| Some(field.name().clone()))
+                .collect();
+            (definition, columns)
+        }
+    };
+    // an unfiltered view is only used when it is the same as the query
+    if definition.predicates.is_empty()
+        || query.scan.table_name != definition.scan.table_name
+        || !definition
+            .predicates
+            .iter()
+            .all(|p| query.predicates.contains(p))
+    {
+        return Ok(None);
+    }
+
+    let column_of = |name: &str| {
+        view_columns
+            .iter()
+            .position(|c| c.as_deref() == Some(name))
+            .map(|i| view_column(view, i))
+    };
+
+    let mut remaining = vec![];
+    for predicate in query.predicates {
+        if definition.predicates.contains(&predicate) {
+            continue;
+        }
+        let mut missing = false;
+        let predicate = predicate
+            .transform(|expr| match expr {
+                Expr::Column(column) => match column_of(&column.name) {
+                    Some(view_column) => Ok(Transformed::yes(view_column)),
+                    None => {
+                        missing = true;
+                        Ok(Transformed::new(
+                            Expr::Column(column),
+                            false,
+                            TreeNodeRecursion::Stop,
+                        ))
+                    }
+                },
+                expr => Ok(Transformed::no(expr)),
+            })?
+            .data;
+        if missing {
+            return Ok(None);
+        }
+        remaining.push(predicate);
+    }
+
+    let Some(exprs) = query
+        .scan
+        .projected_schema
+        .fields()
+        .iter()
+        .map(|field| column_of(field.name()))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(None);
+    };
+
+    let mut builder = scan_view(view)?;
+    if let Some(predicate) = conjunction(remaining) {
+        builder = builder.filter(predicate)?;
+    }
+    project_to(builder.build()?, exprs, plan.schema()).map(Some)
+}
+
+/// Rewrite an aggregate to aggregate the results of a view computing the
+/// same aggregates of the same input with more grouping expressions
+fn rewrite_aggregate(
+    aggregate: &Aggregate,
+    view: &MaterializedView,
+    config: &dyn OptimizerConfig,
+) -> Result<Option<LogicalPlan>> {
+    // the view aggregate, with the view column of each aggregate output
+    let (definition, view_columns) = match view.plan() {
+        LogicalPlan::Projection(projection) => {
+            let LogicalPlan::Aggregate(definition) = projection.input.as_ref() else {
+                return Ok(None);
+            };
+            let schema = definition.schema.as_ref();
+            let columns = (0..schema.fields().len())
+                .map(|i| {
+                    let column = Column::from(schema.qualified_field(i));
+                    projection.expr.iter().position(|expr| {
+                        matches!(expr.clone().unalias(), Expr::Column(c) if c == column)
+                    })
+                })
+                .collect::<Vec<_>>();
+            (definition, columns)
+        }
+        LogicalPlan::Aggregate(definition) => {
+            let columns = (0..definition.schema.fields().len()).map(Some).collect();
+            (definition, columns)
+        }
+        _ => return Ok(None),
+    };
+
+    let same_input = aggregate.input == definition.input
+        || matches!(
+            (
+                FilteredScan::try_new(&aggregate.input),
+                FilteredScan::try_new(&definition.input)
+            ),
+            (Some(query), Some(definition)) if query.same_rows(&definition)
+        );
+    let has_grouping_set = |exprs: &[Expr]| {
+        exprs
+            .iter()
+            .any(|expr| matches!(expr, Expr::GroupingSet(_)))
+    };
+    if !same_input
+        || has_grouping_set(&aggregate.group_expr)
+        || has_grouping_set(&definition.group_expr)
+    {
+        return Ok(None);
+    }
+
+    let view_column_of = |index: usize| view_columns[index].map(|i| view_column(view, i));
+
+    let mut group_expr = vec![];
+    for expr in &aggregate.group_expr {
+        let Some(index) = definition.group_expr.iter().position(|e| e == expr) else {
+            return Ok(None);
+        };
+        let Some(column) = view_column_of(index) else {
+            return Ok(None);
+        };
+        group_expr.push(column);
+    }
+
+    let num_group_expr = definition.group_expr.len();
+    let mut aggr_expr = vec![];
+    for expr in &aggregate.aggr_expr {
+        let expr = expr.clone().unalias();
+        let Some(index) = definition
+            .aggr_expr
+            .iter()
+            .position(|e| e.clone().unalias() == expr)
+        else {
+            return Ok(None);
+        };
+        let Some(column) = view_column_of(num_group_expr + index) else {
+            return Ok(None);
+        };
+        match rollup(&expr, column, aggregate.group_expr.is_empty(), config)? {
+            Some(rollup) => aggr_expr.push(rollup),
+            None => return Ok(None),
+        }
+    }
+
+    let mut exprs = group_expr.clone();
+    let mut aggregates = vec![];
+    for (aggr, null_as_zero) in aggr_expr {
+        let name = format!("__mv_aggr_{}", aggregates.len());
+        aggregates.push(aggr.alias(name.as_str()));
+        let column = Expr::Column(Column::from_name(name));
+        exprs.push(if null_as_zero {
+            when(column.clone().is_null(), lit(0i64)).otherwise(column)?
+        } else {
+            column
+        });
+    }
+    let plan = scan_view(view)?
+        .aggregate(group_expr, aggregates)?
+        .build()?;
+    project_to(plan, exprs, &aggregate.schema).map(Some)
+}
+
+/// Roll up the partial aggregates in the view `column` of the aggregate
+/// function `expr`, if supported.
+///
+/// Returns the aggregate of `column`, and whether a null result of it means
+/// zero, which is the case for the sum of no counts.
+fn rollup(
+    expr: &Expr,
+    column: Expr,
+    no_groups: bool,
+    config: &dyn OptimizerConfig,
+) -> Result<Option<(Expr, bool)>> {
+    let Expr::AggregateFunction(AggregateFunction { func, params }) = expr else {
+        return Ok(None);
+    };
+    if params.distinct || params.filter.is_some() || params.order_by.is_some() {
+        return Ok(None);
+    }
+
+    let null_as_zero = no_groups && func.name() == "count";
+    let func = match func.name() {
+        "sum" | "min" | "max" => Arc::clone(func),
+        "count" => match config
+            .function_registry()
+            .and_then(|registry| registry.udaf("sum").ok())
+        {
+            Some(sum) => sum,
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let rollup = Expr::AggregateFunction(AggregateFunction::new_udf(
+        func,
+        vec![column],
+        false,
+        None,
+        None,
+        None,
+    ));
+    Ok(Some((rollup, null_as_zero)))
+}
+
+/// Scan the stored results of `view`
+fn scan_view(view: &MaterializedView) -> Result<LogicalPlanBuilder> {
+    LogicalPlanBuilder::scan(view.name().clone(), Arc::clone(view.source()), None)
+}
+
+/// The `index`th column of `view`
+fn view_column(view: &MaterializedView, index: usize) -> Expr {
+    let schema = view.source().schema();
+    Expr::Column(Column::new(
+        Some(view.name().clone()),
+        schema.field(index).name(),
+    ))
+}
+
+/// Project `exprs` of `input` to the names and types of the fields of
+/// `schema`, so the result can replace a plan with that schema
+fn project_to(
+    input: LogicalPlan,
+    exprs: Vec<Expr>,
+    schema: &DFSchema,
+) -> Result<LogicalPlan> {
+    let exprs = exprs
+        .into_iter()
+        .zip(schema.iter())
+        .map(|(expr, (qualifier, field))| {
+            let expr = if &expr.get_type(input.schema().as_ref())? == field.data_type() {
+                expr
+            } else {
+                cast(expr, field.data_type().clone())
+            };
+            Ok(expr.alias_qualified(qualifier.cloned(), field.name()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    LogicalPlanBuilder::from(input).project(exprs)?.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::*;
+
+    use arrow::datatypes::Schema;
+    use chrono::{DateTime, Utc};
+    use datafusion_common::alias::AliasGenerator;
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_expr::builder::table_source;
+    use datafusion_expr::logical_plan::table_scan;
+    use datafusion_expr::{col, lit};
+    use datafusion_functions_aggregate::expr_fn::{max, sum};
+
+    struct TestConfig {
+        alias_generator: Arc<AliasGenerator>,
+        options: ConfigOptions,
+        registry: Arc<MaterializedViewRegistry>,
+    }
+
+    impl OptimizerConfig for TestConfig {
+        fn query_execution_start_time(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+
+        fn alias_generator(&self) -> &Arc<AliasGenerator> {
+            &self.alias_generator
+        }
+
+        fn options(&self) -> &ConfigOptions {
+            &self.options
+        }
+
+        fn materialized_views(&self) -> Option<Arc<MaterializedViewRegistry>> {
+            Some(Arc::clone(&self.registry))
+        }
+    }
+
+    /// A registry with a view named `mv` defined by `plan`
+    fn registry(plan: LogicalPlan) -> Arc<MaterializedViewRegistry> {
+        let source = table_source(plan.schema().as_arrow());
+        let registry = MaterializedViewRegistry::default();
+        registry.register(MaterializedView::new(
+            TableReference::bare("mv"),
+            source,
+            plan,
+        ));
+        Arc::new(registry)
+    }
+
+    fn rewrite(registry: Arc<MaterializedViewRegistry>, plan: LogicalPlan) -> String {
+        let mut options = ConfigOptions::default();
+        options.optimizer.enable_materialized_view_rewrite = true;
+        let config = TestConfig {
+            alias_generator: Arc::new(AliasGenerator::new()),
+            options,
+            registry,
+        };
+        let plan = MaterializedViewRewrite::new()
+            .rewrite(plan, &config)
+            .unwrap()
+            .data;
+        format!("{plan}")
+    }
+
+    #[test]
+    fn exact_match() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(vec![col("a")], vec![sum(col("b"))])?
+            .build()?;
+
+        let expected = "Projection: mv.a AS a, mv.sum(test.b) AS sum(test.b)\
+        \n  TableScan: mv";
+        assert_eq!(rewrite(registry(plan.clone()), plan), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn filter_subsumed_by_view() -> Result<()> {
+        let view = LogicalPlanBuilder::from(test_table_scan()?)
+            .filter(col("b").gt(lit(1u32)))?
+            .project(vec![col("a"), col("b")])?
+            .build()?;
+        let schema = Schema::new(test_table_scan_fields());
+        let query = table_scan(Some("test"), &schema, Some(vec![0, 1]))?
+            .filter(col("b").gt(lit(1u32)).and(col("a").lt(lit(10u32))))?
+            .project(vec![col("a")])?
+            .build()?;
+
+        let expected = "Projection: test.a\
+        \n  Projection: mv.a AS a, mv.b AS b\
+        \n    Filter: mv.a < UInt32(10)\
+        \n      TableScan: mv";
+        assert_eq!(rewrite(registry(view.clone()), query), expected);
+
+        // the query must include the filters of the view
+        let query = table_scan(Some("test"), &schema, Some(vec![0, 1]))?
+            .filter(col("a").lt(lit(10u32)))?
+            .build()?;
+        let expected = "Filter: test.a < UInt32(10)\
+        \n  TableScan: test projection=[a, b]";
+        assert_eq!(rewrite(registry(view.clone()), query), expected);
+
+        // and only use columns of the view
+        let query = test_table_scan_with_name("test")?;
+        let query = LogicalPlanBuilder::from(query)
+            .filter(col("b").gt(lit(1u32)))?
+            .build()?;
+        let expected = "Filter: test.b > UInt32(1)\
+        \n  TableScan: test";
+        assert_eq!(rewrite(registry(view), query), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn aggregate_rollup() -> Result<()> {
+        let view = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(vec![col("a"), col("b")], vec![sum(col("c")), max(col("c"))])?
+            .build()?;
+        let query = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(vec![col("a")], vec![max(col("c")), sum(col("c"))])?
+            .build()?;
+
+        let expected = "Projection: mv.a AS a, __mv_aggr_0 AS max(test.c), __mv_aggr_1 AS sum(test.c)\
+        \n  Aggregate: groupBy=[[mv.a]], aggr=[[max(mv.max(test.c)) AS __mv_aggr_0, sum(mv.sum(test.c)) AS __mv_aggr_1]]\
+        \n    TableScan: mv";
+        assert_eq!(rewrite(registry(view.clone()), query), expected);
+
+        // grouping by an expression the view does not group by
+        let query = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(vec![col("c")], vec![sum(col("c"))])?
+            .build()?;
+        let expected = "Aggregate: groupBy=[[test.c]], aggr=[[sum(test.c)]]\
+        \n  TableScan: test";
+        assert_eq!(rewrite(registry(view), query), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn stale_views_are_not_used() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .project(vec![col("a")])?
+            .build()?;
+        let registry = registry(plan.clone());
+        for view in registry.views() {
+            view.set_fresh(false);
+        }
+
+        let expected = "Projection: test.a\
+        \n  TableScan: test";
+        assert_eq!(rewrite(registry, plan), expected);
+        Ok(())
+    }
+}
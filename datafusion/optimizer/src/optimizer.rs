@@ -45,6 +45,9 @@ use crate::eliminate_one_union::EliminateOneUnion;
 use crate::eliminate_outer_join::EliminateOuterJoin;
 use crate::extract_equijoin_predicate::ExtractEquijoinPredicate;
 use crate::filter_null_join_keys::FilterNullJoinKeys;
+use crate::materialized_view_rewrite::{
+    MaterializedViewRegistry, MaterializedViewRewrite,
+};
 use crate::optimize_projections::OptimizeProjections;
 use crate::plan_signature::LogicalPlanSignature;
 use crate::propagate_empty_relation::PropagateEmptyRelation;
@@ -132,6 +135,11 @@ pub trait OptimizerConfig {
     fn function_registry(&self) -> Option<&dyn FunctionRegistry> {
         None
     }
+
+    /// Return the materialized views that may be used to answer queries
+    fn materialized_views(&self) -> Option<Arc<MaterializedViewRegistry>> {
+        None
+    }
 }
 
 /// A standalone [`OptimizerConfig`] that can be used independently
@@ -270,6 +278,9 @@ impl Optimizer {
             Arc::new(CommonSubexprEliminate::new()),
             Arc::new(EliminateGroupByConstant::new()),
             Arc::new(OptimizeProjections::new()),
+            // Runs last, so that queries are compared to the optimized
+            // definitions of the materialized views
+            Arc::new(MaterializedViewRewrite::new()),
         ];
 
         Self::with_rules(rules)
@@ -356,6 +367,8 @@ impl Optimizer {
     where
         F: FnMut(&LogicalPlan, &dyn OptimizerRule),
     {
+        let _span = tracing::info_span!("optimize_logical_plan").entered();
+
         // verify LP is valid, before the first LP optimizer pass.
         plan.check_invariants(InvariantLevel::Executable)
             .map_err(|e| e.context("Invalid input plan before LP Optimizers"))?;
@@ -374,6 +387,14 @@ impl Optimizer {
             log_plan(&format!("Optimizer input (pass {i})"), &new_plan);
 
             for rule in &self.rules {
+                let _span = tracing::debug_span!(
+                    "optimizer_rule",
+                    otel.name = rule.name(),
+                    rule = rule.name(),
+                    pass = i
+                )
+                .entered();
+
                 // If skipping failed rules, copy plan before attempting to rewrite
                 // as rewriting is destructive
                 let prev_plan = options
@@ -27,7 +27,7 @@ use datafusion_expr::LogicalPlan;
 /// Non-unique identifier of a [`LogicalPlan`].
 ///
 /// See [`LogicalPlanSignature::new`] for details.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LogicalPlanSignature {
     node_number: NonZeroUsize,
     plan_hash: u64,
@@ -21,6 +21,7 @@
 //! according to the configuration), this rule increases partition counts in
 //! the physical plan.
 
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -881,6 +882,12 @@ fn add_roundrobin_on_top(
 /// * `hash_exprs`: Stores Physical Exprs that are used during hashing.
 /// * `n_target`: desired target partition number, if partition number of the
 ///    current executor is less than this value. Partition number will be increased.
+/// * `exact_partition_count`: whether the result must have exactly `n_target`
+///    partitions, even if the current executor already satisfies the hash
+///    distribution with a different number of partitions.
+/// * `rehash`: whether the result must be hash partitioned by a
+///    `RepartitionExec`, even if the current executor already satisfies the
+///    hash distribution, see [`is_hash_partitioned_by_source`].
 ///
 /// # Returns
 ///
@@ -890,6 +897,8 @@ fn add_hash_on_top(
     input: DistributionContext,
     hash_exprs: Vec<Arc<dyn PhysicalExpr>>,
     n_target: usize,
+    exact_partition_count: bool,
+    rehash: bool,
 ) -> Result<DistributionContext> {
     // Early return if hash repartition is unnecessary
     // `RepartitionExec: partitioning=Hash([...], 1), input_partitions=1` is unnecessary.
@@ -905,8 +914,15 @@ fn add_hash_on_top(
 
     // Add hash repartitioning when:
     // - The hash distribution requirement is not satisfied, or
-    // - We can increase parallelism by adding hash partitioning.
-    if !satisfied || n_target > input.plan.output_partitioning().partition_count() {
+    // - We can increase parallelism by adding hash partitioning, or
+    // - The partition count must be changed to `n_target`, or
+    // - The hash function of the partitioning must be that of `RepartitionExec`.
+    let partition_count = input.plan.output_partitioning().partition_count();
+    if !satisfied
+        || n_target > partition_count
+        || (exact_partition_count && n_target != partition_count)
+        || rehash
+    {
         // When there is an existing ordering, we preserve ordering during
         // repartition. This will be rolled back in the future if any of the
         // following conditions is true:
@@ -1064,6 +1080,14 @@ struct RepartitionRequirementStatus {
     roundrobin_beneficial_stats: bool,
     /// Designates whether hash partitioning is necessary.
     hash_necessary: bool,
+    /// Designates whether hash partitioning must produce exactly
+    /// `target_partitions` partitions, as the children that require hash
+    /// partitioning have different numbers of partitions.
+    exact_partition_count: bool,
+    /// Designates whether the child must be hash partitioned by a
+    /// `RepartitionExec`, as it is hash partitioned by a data source while
+    /// other children are not.
+    rehash: bool,
 }
 
 /// Calculates the `RepartitionRequirementStatus` for each children to generate
@@ -1097,12 +1121,23 @@ struct RepartitionRequirementStatus {
 ///     hash_necessary: true
 /// }
 /// ```
+///
+/// Similarly, children that are already hash partitioned (e.g. scans of
+/// bucketed tables) may have different numbers of partitions, in which case
+/// all children must be hash partitioned into `target_partitions` partitions
+/// so that partition `i` of each child contains the same hash values. And
+/// as a data source may hash partition with another hash function than
+/// `RepartitionExec`, children hash partitioned by a data source are
+/// repartitioned unless all children are, on exactly the required
+/// expressions in the same order, into the same number of partitions.
 fn get_repartition_requirement_status(
     plan: &Arc<dyn ExecutionPlan>,
     batch_size: usize,
     should_use_estimates: bool,
 ) -> Result<Vec<RepartitionRequirementStatus>> {
     let mut needs_alignment = false;
+    let mut hash_partition_counts = HashSet::new();
+    let mut hashed_by_source = vec![];
     let children = plan.children();
     let rr_beneficial = plan.benefits_from_input_partitioning();
     let requirements = plan.required_input_distribution();
@@ -1120,7 +1155,15 @@ fn get_repartition_requirement_status(
         let is_hash = matches!(requirement, Distribution::HashPartitioned(_));
         // Hash re-partitioning is necessary when the input has more than one
         // partitions:
-        let multi_partitions = child.output_partitioning().partition_count() > 1;
+        let partition_count = child.output_partitioning().partition_count();
+        let multi_partitions = partition_count > 1;
+        if let Distribution::HashPartitioned(exprs) = &requirement {
+            hash_partition_counts.insert(partition_count);
+            hashed_by_source.push((
+                is_hash_partitioned_by_source(child),
+                is_hash_partitioned_on(child, exprs),
+            ));
+        }
         let roundrobin_sensible = roundrobin_beneficial && roundrobin_beneficial_stats;
         needs_alignment |= is_hash && (multi_partitions || roundrobin_sensible);
         repartition_status_flags.push((
@@ -1130,18 +1173,38 @@ fn get_repartition_requirement_status(
                 roundrobin_beneficial,
                 roundrobin_beneficial_stats,
                 hash_necessary: is_hash && multi_partitions,
+                exact_partition_count: false,
+                rehash: false,
             },
         ));
     }
+    // Children hash partitioned by a data source can only be combined with
+    // each other, and only if all of them are partitioned on exactly the
+    // required expressions into the same number of partitions. Otherwise
+    // some of the children are repartitioned, so all of them must be:
+    let keep_sources = hash_partition_counts.len() == 1
+        && hashed_by_source
+            .iter()
+            .all(|(by_source, on_exprs)| *by_source && *on_exprs);
+    let rehash_sources = hashed_by_source.len() > 1
+        && hashed_by_source.iter().any(|(by_source, _)| *by_source)
+        && !keep_sources;
     // Align hash necessary flags for hash partitions to generate consistent
     // hash partitions at each children:
-    if needs_alignment {
+    if needs_alignment || rehash_sources {
         // When there is at least one hash requirement that is necessary or
         // beneficial according to statistics, make all children require hash
         // repartitioning:
+        let mut hashed_by_source = hashed_by_source.into_iter();
         for (is_hash, status) in &mut repartition_status_flags {
             if *is_hash {
                 status.hash_necessary = true;
+                status.exact_partition_count =
+                    hash_partition_counts.len() > 1 || rehash_sources;
+                status.rehash = rehash_sources
+                    && hashed_by_source
+                        .next()
+                        .is_some_and(|(by_source, _)| by_source);
             }
         }
     }
@@ -1233,6 +1296,8 @@ pub fn ensure_distribution(
                 roundrobin_beneficial,
                 roundrobin_beneficial_stats,
                 hash_necessary,
+                exact_partition_count,
+                rehash,
             },
         )| {
             let add_roundrobin = enable_round_robin
@@ -1265,8 +1330,13 @@ pub fn ensure_distribution(
                     }
                     // When inserting hash is necessary to satisfy hash requirement, insert hash repartition.
                     if hash_necessary {
-                        child =
-                            add_hash_on_top(child, exprs.to_vec(), target_partitions)?;
+                        child = add_hash_on_top(
+                            child,
+                            exprs.to_vec(),
+                            target_partitions,
+                            exact_partition_count,
+                            rehash,
+                        )?;
                     }
                 }
                 Distribution::UnspecifiedDistribution => {
@@ -1333,6 +1403,7 @@ pub fn ensure_distribution(
     plan = if plan.as_any().is::<UnionExec>()
         && !config.optimizer.prefer_existing_union
         && can_interleave(children_plans.iter())
+        && !mixes_hash_functions(&children_plans)
     {
         // Add a special case for [`UnionExec`] since we want to "bubble up"
         // hash-partitioned data. So instead of
@@ -1367,6 +1438,43 @@ pub fn ensure_distribution(
     )))
 }
 
+/// Returns `true` if `plan` is hash partitioned by a data source, such as a
+/// scan of a bucketed table, rather than by a [`RepartitionExec`]. The data
+/// source may hash with another hash function than [`RepartitionExec`], so
+/// its partitions can not be combined with those of a [`RepartitionExec`].
+fn is_hash_partitioned_by_source(plan: &Arc<dyn ExecutionPlan>) -> bool {
+    if !matches!(plan.output_partitioning(), Partitioning::Hash(..))
+        || plan.as_any().is::<RepartitionExec>()
+    {
+        return false;
+    }
+    let children = plan.children();
+    children.is_empty() || children.into_iter().any(is_hash_partitioned_by_source)
+}
+
+/// Returns `true` if `plan` is hash partitioned on exactly `exprs`, in the
+/// same order, rather than on expressions that merely satisfy the hash
+/// distribution of `exprs`, such as a subset of them
+fn is_hash_partitioned_on(
+    plan: &Arc<dyn ExecutionPlan>,
+    exprs: &[Arc<dyn PhysicalExpr>],
+) -> bool {
+    match plan.output_partitioning() {
+        Partitioning::Hash(hash_exprs, _) => physical_exprs_equal(hash_exprs, exprs),
+        _ => false,
+    }
+}
+
+/// Returns `true` if some but not all of `plans` are hash partitioned by a
+/// data source, see [`is_hash_partitioned_by_source`]
+fn mixes_hash_functions(plans: &[Arc<dyn ExecutionPlan>]) -> bool {
+    let by_source = plans
+        .iter()
+        .filter(|plan| is_hash_partitioned_by_source(plan));
+    let count = by_source.count();
+    count > 0 && count < plans.len()
+}
+
 /// Keeps track of distribution changing operators (like `RepartitionExec`,
 /// `SortPreservingMergeExec`, `CoalescePartitionsExec`) and their ancestors.
 /// Using this information, we can optimize distribution of the plan if/when
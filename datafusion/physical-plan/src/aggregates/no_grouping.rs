@@ -21,7 +21,8 @@ use crate::aggregates::{
     aggregate_expressions, create_accumulators, finalize_aggregation, AccumulatorItem,
     AggregateMode,
 };
-use crate::metrics::{BaselineMetrics, RecordOutput};
+use crate::instrument::instrumented_execute;
+use crate::metrics::{BaselineMetrics, Gauge, MetricBuilder, RecordOutput};
 use crate::{RecordBatchStream, SendableRecordBatchStream};
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
@@ -34,6 +35,7 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use crate::filter::batch_filter;
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use futures::stream::{Stream, StreamExt};
 
@@ -61,6 +63,8 @@ struct AggregateStreamInner {
     filter_expressions: Vec<Option<Arc<dyn PhysicalExpr>>>,
     accumulators: Vec<AccumulatorItem>,
     reservation: MemoryReservation,
+    peak_mem_used: Gauge,
+    cancellation: CancellationToken,
     finished: bool,
 }
 
@@ -75,7 +79,7 @@ impl AggregateStream {
         let agg_filter_expr = agg.filter_expr.clone();
 
         let baseline_metrics = BaselineMetrics::new(&agg.metrics, partition);
-        let input = agg.input.execute(partition, Arc::clone(&context))?;
+        let input = instrumented_execute(&agg.input, partition, Arc::clone(&context))?;
 
         let aggregate_expressions = aggregate_expressions(&agg.aggr_expr, &agg.mode, 0)?;
         let filter_expressions = match agg.mode {
@@ -90,6 +94,8 @@ impl AggregateStream {
 
         let reservation = MemoryConsumer::new(format!("AggregateStream[{partition}]"))
             .register(context.memory_pool());
+        let peak_mem_used =
+            MetricBuilder::new(&agg.metrics).gauge("peak_mem_used", partition);
 
         let inner = AggregateStreamInner {
             schema: Arc::clone(&agg.schema),
@@ -100,6 +106,8 @@ impl AggregateStream {
             filter_expressions,
             accumulators,
             reservation,
+            peak_mem_used,
+            cancellation: context.cancellation().clone(),
             finished: false,
         };
         let stream = futures::stream::unfold(inner, |mut this| async move {
@@ -112,6 +120,10 @@ impl AggregateStream {
             loop {
                 let result = match this.input.next().await {
                     Some(Ok(batch)) => {
+                        if let Err(e) = this.cancellation.check() {
+                            this.finished = true;
+                            return Some((Err(e), this));
+                        }
                         let timer = elapsed_compute.timer();
                         let result = aggregate_batch(
                             &this.mode,
@@ -129,7 +141,10 @@ impl AggregateStream {
                         match result
                             .and_then(|allocated| this.reservation.try_grow(allocated))
                         {
-                            Ok(_) => continue,
+                            Ok(_) => {
+                                this.peak_mem_used.set_max(this.reservation.size());
+                                continue;
+                            }
                             Err(e) => Err(e),
                         }
                     }
@@ -27,6 +27,7 @@ use crate::aggregates::{
     create_schema, evaluate_group_by, evaluate_many, evaluate_optional, AggregateMode,
     PhysicalGroupBy,
 };
+use crate::instrument::instrumented_execute;
 use crate::metrics::{BaselineMetrics, MetricBuilder, RecordOutput};
 use crate::sorts::sort::sort_batch;
 use crate::sorts::streaming_merge::StreamingMergeBuilder;
@@ -38,7 +39,9 @@ use crate::{RecordBatchStream, SendableRecordBatchStream};
 use arrow::array::*;
 use arrow::compute::SortOptions;
 use arrow::datatypes::SchemaRef;
+use datafusion_common::config::SpillCompression;
 use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::disk_manager::RefCountedTempFile;
 use datafusion_execution::memory_pool::proxy::VecAllocExt;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
@@ -85,6 +88,9 @@ struct SpillState {
     /// Schema for spilling batches
     spill_schema: SchemaRef,
 
+    /// Compression codec of the spill files
+    spill_compression: SpillCompression,
+
     /// aggregate_arguments for merging spilled data
     merging_aggregate_arguments: Vec<Vec<Arc<dyn PhysicalExpr>>>,
 
@@ -115,6 +121,8 @@ struct SpillState {
     spilled_bytes: metrics::Count,
     /// total spilled rows during the execution of the operator
     spilled_rows: metrics::Count,
+    /// total size of the spill files written by the operator
+    spilled_disk_bytes: metrics::Count,
 }
 
 /// Tracks if the aggregate should skip partial aggregations
@@ -438,6 +446,10 @@ pub(crate) struct GroupedHashAggregateStream {
 
     /// The [`RuntimeEnv`] associated with the [`TaskContext`] argument
     runtime: Arc<RuntimeEnv>,
+
+    /// The [`CancellationToken`] of the [`TaskContext`] argument, checked
+    /// before reading each input batch
+    cancellation: CancellationToken,
 }
 
 impl GroupedHashAggregateStream {
@@ -453,7 +465,7 @@ impl GroupedHashAggregateStream {
         let agg_filter_expr = agg.filter_expr.clone();
 
         let batch_size = context.session_config().batch_size();
-        let input = agg.input.execute(partition, Arc::clone(&context))?;
+        let input = instrumented_execute(&agg.input, partition, Arc::clone(&context))?;
         let baseline_metrics = BaselineMetrics::new(&agg.metrics, partition);
 
         let timer = baseline_metrics.elapsed_compute().timer();
@@ -548,6 +560,11 @@ impl GroupedHashAggregateStream {
             spills: vec![],
             spill_expr,
             spill_schema: partial_agg_schema,
+            spill_compression: context
+                .session_config()
+                .options()
+                .execution
+                .spill_compression,
             is_stream_merging: false,
             merging_aggregate_arguments,
             merging_group_by: PhysicalGroupBy::new_single(agg_group_by.expr.clone()),
@@ -556,6 +573,8 @@ impl GroupedHashAggregateStream {
             spill_count: MetricBuilder::new(&agg.metrics).spill_count(partition),
             spilled_bytes: MetricBuilder::new(&agg.metrics).spilled_bytes(partition),
             spilled_rows: MetricBuilder::new(&agg.metrics).spilled_rows(partition),
+            spilled_disk_bytes: MetricBuilder::new(&agg.metrics)
+                .spilled_disk_bytes(partition),
         };
 
         // Skip aggregation is supported if:
@@ -605,6 +624,7 @@ impl GroupedHashAggregateStream {
             group_ordering,
             input_done: false,
             runtime: context.runtime_env(),
+            cancellation: context.cancellation().clone(),
             spill_state,
             group_values_soft_limit: agg.limit,
             skip_aggregation_probe,
@@ -644,6 +664,7 @@ impl Stream for GroupedHashAggregateStream {
         loop {
             match &self.exec_state {
                 ExecutionState::ReadingInput => 'reading_input: {
+                    self.cancellation.check()?;
                     match ready!(self.input.poll_next_unpin(cx)) {
                         // New batch to aggregate in partial aggregation operator
                         Some(Ok(batch)) if self.mode == AggregateMode::Partial => {
@@ -991,10 +1012,12 @@ impl GroupedHashAggregateStream {
         // TODO: slice large `sorted` and write to multiple files in parallel
         spill_record_batch_by_size(
             &sorted,
-            spillfile.path().into(),
+            &spillfile,
             sorted.schema(),
             self.batch_size,
+            self.spill_state.spill_compression,
         )?;
+        let spilled_disk_bytes = spillfile.current_disk_usage();
         self.spill_state.spills.push(spillfile);
 
         // Update metrics
@@ -1003,6 +1026,9 @@ impl GroupedHashAggregateStream {
             .spilled_bytes
             .add(sorted.get_array_memory_size());
         self.spill_state.spilled_rows.add(sorted.num_rows());
+        self.spill_state
+            .spilled_disk_bytes
+            .add(spilled_disk_bytes as usize);
 
         Ok(())
     }
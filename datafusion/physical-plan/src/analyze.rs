@@ -29,8 +29,12 @@ use crate::display::DisplayableExecutionPlan;
 use crate::{DisplayFormatType, ExecutionPlan, Partitioning};
 
 use arrow::{array::StringBuilder, datatypes::SchemaRef, record_batch::RecordBatch};
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::instant::Instant;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_execution::memory_pool::{human_readable_size, PeakTrackingPool};
+use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::EquivalenceProperties;
 
@@ -38,12 +42,20 @@ use futures::StreamExt;
 
 /// `EXPLAIN ANALYZE` execution plan operator. This operator runs its input,
 /// discards the results, and then prints out an annotated plan with metrics
+///
+/// In verbose mode the output also contains a memory summary of the whole
+/// query: the peak memory reserved from the [`MemoryPool`], the amount of
+/// data spilled and the consumers with the largest peak reservation.
+///
+/// [`MemoryPool`]: datafusion_execution::memory_pool::MemoryPool
 #[derive(Debug, Clone)]
 pub struct AnalyzeExec {
     /// Control how much extra to print
     verbose: bool,
     /// If statistics should be displayed
     show_statistics: bool,
+    /// The format of the annotated plan
+    explain_format: ExplainFormat,
     /// The input plan (the plan being analyzed)
     pub(crate) input: Arc<dyn ExecutionPlan>,
     /// The output schema for RecordBatches of this exec node
@@ -63,12 +75,20 @@ impl AnalyzeExec {
         AnalyzeExec {
             verbose,
             show_statistics,
+            explain_format: ExplainFormat::Indent,
             input,
             schema,
             cache,
         }
     }
 
+    /// Set the format of the annotated plan, [`ExplainFormat::Indent`] by
+    /// default
+    pub fn with_explain_format(mut self, explain_format: ExplainFormat) -> Self {
+        self.explain_format = explain_format;
+        self
+    }
+
     /// Access to verbose
     pub fn verbose(&self) -> bool {
         self.verbose
@@ -79,6 +99,11 @@ impl AnalyzeExec {
         self.show_statistics
     }
 
+    /// Access to explain_format
+    pub fn explain_format(&self) -> ExplainFormat {
+        self.explain_format
+    }
+
     /// The input plan
     pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
         &self.input
@@ -139,12 +164,15 @@ impl ExecutionPlan for AnalyzeExec {
         self: Arc<Self>,
         mut children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        Ok(Arc::new(Self::new(
-            self.verbose,
-            self.show_statistics,
-            children.pop().unwrap(),
-            Arc::clone(&self.schema),
-        )))
+        Ok(Arc::new(
+            Self::new(
+                self.verbose,
+                self.show_statistics,
+                children.pop().unwrap(),
+                Arc::clone(&self.schema),
+            )
+            .with_explain_format(self.explain_format),
+        ))
     }
 
     fn execute(
@@ -158,6 +186,33 @@ impl ExecutionPlan for AnalyzeExec {
             );
         }
 
+        // In verbose mode, track the memory reserved by the input through a
+        // dedicated pool so that its peak usage can be reported
+        let (context, memory_tracker) = if self.verbose {
+            let pool = Arc::new(PeakTrackingPool::new(Arc::clone(context.memory_pool())));
+            let runtime = context.runtime_env();
+            let runtime = Arc::new(RuntimeEnv {
+                memory_pool: Arc::clone(&pool) as _,
+                disk_manager: Arc::clone(&runtime.disk_manager),
+                cache_manager: Arc::clone(&runtime.cache_manager),
+                object_store_registry: Arc::clone(&runtime.object_store_registry),
+                admission_controller: runtime.admission_controller.clone(),
+            });
+            let context = TaskContext::new(
+                context.task_id(),
+                context.session_id(),
+                context.session_config().clone(),
+                context.scalar_functions().clone(),
+                context.aggregate_functions().clone(),
+                context.window_functions().clone(),
+                runtime,
+            )
+            .with_cancellation(context.cancellation().clone());
+            (Arc::new(context), Some(pool))
+        } else {
+            (context, None)
+        };
+
         // Gather futures that will run each input partition in
         // parallel (on a separate tokio task) using a JoinSet to
         // cancel outstanding futures on drop
@@ -179,6 +234,7 @@ impl ExecutionPlan for AnalyzeExec {
         let captured_schema = Arc::clone(&self.schema);
         let verbose = self.verbose;
         let show_statistics = self.show_statistics;
+        let explain_format = self.explain_format;
 
         // future that gathers the results from all the tasks in the
         // JoinSet that computes the overall row count and final
@@ -194,8 +250,10 @@ impl ExecutionPlan for AnalyzeExec {
             create_output_batch(
                 verbose,
                 show_statistics,
+                explain_format,
                 total_rows,
                 duration,
+                memory_tracker.as_deref(),
                 captured_input,
                 captured_schema,
             )
@@ -209,11 +267,14 @@ impl ExecutionPlan for AnalyzeExec {
 }
 
 /// Creates the output of AnalyzeExec as a RecordBatch
+#[allow(clippy::too_many_arguments)]
 fn create_output_batch(
     verbose: bool,
     show_statistics: bool,
+    explain_format: ExplainFormat,
     total_rows: usize,
     duration: std::time::Duration,
+    memory_tracker: Option<&PeakTrackingPool>,
     input: Arc<dyn ExecutionPlan>,
     schema: SchemaRef,
 ) -> Result<RecordBatch> {
@@ -225,8 +286,7 @@ fn create_output_batch(
 
     let annotated_plan = DisplayableExecutionPlan::with_metrics(input.as_ref())
         .set_show_statistics(show_statistics)
-        .indent(verbose)
-        .to_string();
+        .to_string_with_format(verbose, explain_format);
     plan_builder.append_value(annotated_plan);
 
     // Verbose output
@@ -236,8 +296,7 @@ fn create_output_batch(
 
         let annotated_plan = DisplayableExecutionPlan::with_full_metrics(input.as_ref())
             .set_show_statistics(show_statistics)
-            .indent(verbose)
-            .to_string();
+            .to_string_with_format(verbose, explain_format);
         plan_builder.append_value(annotated_plan);
 
         type_builder.append_value("Output Rows");
@@ -247,6 +306,11 @@ fn create_output_batch(
         plan_builder.append_value(format!("{duration:?}"));
     }
 
+    if let Some(memory_tracker) = memory_tracker {
+        type_builder.append_value("Memory Summary");
+        plan_builder.append_value(memory_summary(memory_tracker, &input)?);
+    }
+
     RecordBatch::try_new(
         schema,
         vec![
@@ -257,6 +321,44 @@ fn create_output_batch(
     .map_err(DataFusionError::from)
 }
 
+/// Number of consumers listed in the memory summary
+const MEMORY_SUMMARY_TOP_CONSUMERS: usize = 5;
+
+/// Formats the peak memory usage recorded by `memory_tracker` and the spill
+/// metrics of all operators in `input`
+fn memory_summary(
+    memory_tracker: &PeakTrackingPool,
+    input: &Arc<dyn ExecutionPlan>,
+) -> Result<String> {
+    let (mut spill_count, mut spilled_bytes, mut spilled_rows) = (0, 0, 0);
+    let mut spilled_disk_bytes = 0;
+    input.apply(|plan| {
+        if let Some(metrics) = plan.metrics() {
+            spill_count += metrics.spill_count().unwrap_or_default();
+            spilled_bytes += metrics.spilled_bytes().unwrap_or_default();
+            spilled_rows += metrics.spilled_rows().unwrap_or_default();
+            spilled_disk_bytes += metrics.spilled_disk_bytes().unwrap_or_default();
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+
+    let top_consumers = memory_tracker
+        .top_consumers(MEMORY_SUMMARY_TOP_CONSUMERS)
+        .into_iter()
+        .map(|(consumer, peak)| {
+            format!("{}={}", consumer.name(), human_readable_size(peak))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(format!(
+        "peak_mem_used={}, spill_count={spill_count}, spilled_bytes={}, spilled_rows={spilled_rows}, spilled_disk_bytes={}\ntop_consumers=[{top_consumers}]",
+        human_readable_size(memory_tracker.peak_reserved()),
+        human_readable_size(spilled_bytes),
+        human_readable_size(spilled_disk_bytes),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
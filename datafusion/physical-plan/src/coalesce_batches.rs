@@ -24,6 +24,7 @@ use std::task::{Context, Poll};
 
 use super::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
 use super::{DisplayAs, ExecutionPlanProperties, PlanProperties, Statistics};
+use crate::instrument::instrumented_execute;
 use crate::{
     DisplayFormatType, ExecutionPlan, RecordBatchStream, SendableRecordBatchStream,
 };
@@ -168,7 +169,7 @@ impl ExecutionPlan for CoalesceBatchesExec {
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
         Ok(Box::pin(CoalesceBatchesStream {
-            input: self.input.execute(partition, context)?,
+            input: instrumented_execute(&self.input, partition, context)?,
             coalescer: BatchCoalescer::new(
                 self.input.schema(),
                 self.target_batch_size,
@@ -28,6 +28,7 @@ use super::{
     Statistics,
 };
 use crate::execution_plan::CardinalityEffect;
+use crate::instrument::instrumented_execute;
 use crate::projection::{make_with_child, ProjectionExec};
 use crate::{DisplayFormatType, ExecutionPlan, Partitioning};
 
@@ -144,7 +145,7 @@ impl ExecutionPlan for CoalescePartitionsExec {
             ),
             1 => {
                 // bypass any threading / metrics if there is a single partition
-                self.input.execute(0, context)
+                instrumented_execute(&self.input, 0, context)
             }
             _ => {
                 let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
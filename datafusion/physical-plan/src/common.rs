@@ -19,6 +19,7 @@
 
 use std::fs;
 use std::fs::{metadata, File};
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -181,11 +182,11 @@ pub fn compute_record_batch_statistics(
 }
 
 /// Write in Arrow IPC format.
-pub struct IPCWriter {
+pub struct IPCWriter<W: Write = File> {
     /// Path
     pub path: PathBuf,
     /// Inner writer
-    pub writer: FileWriter<File>,
+    pub writer: FileWriter<W>,
     /// Batches written
     pub num_batches: usize,
     /// Rows written
@@ -230,6 +231,28 @@ impl IPCWriter {
             writer: FileWriter::try_new_with_options(file, schema, write_options)?,
         })
     }
+}
+
+impl<W: Write> IPCWriter<W> {
+    /// Create new writer of the file at `path` that writes with `writer`,
+    /// such as a [`TempFileWriter`], and IPC write options
+    ///
+    /// [`TempFileWriter`]: datafusion_execution::disk_manager::TempFileWriter
+    pub fn new_with_writer(
+        path: &Path,
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self> {
+        Ok(Self {
+            num_batches: 0,
+            num_rows: 0,
+            num_bytes: 0,
+            path: path.into(),
+            writer: FileWriter::try_new_with_options(writer, schema, write_options)?,
+        })
+    }
+
     /// Write one single batch
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         self.writer.write(batch)?;
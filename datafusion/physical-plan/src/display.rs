@@ -23,10 +23,14 @@ use std::fmt::Formatter;
 
 use arrow::datatypes::SchemaRef;
 
-use datafusion_common::display::{GraphvizBuilder, PlanType, StringifiedPlan};
+use datafusion_common::display::{
+    split_node_description, GraphvizBuilder, PlanType, StringifiedPlan, TreeRenderNode,
+};
+use datafusion_common::format::ExplainFormat;
 use datafusion_expr::display_schema;
 use datafusion_physical_expr::LexOrdering;
 
+use super::metrics::MetricsSet;
 use super::{accept, ExecutionPlan, ExecutionPlanVisitor};
 
 /// Options for controlling how each [`ExecutionPlan`] should format itself
@@ -224,6 +228,75 @@ impl<'a> DisplayableExecutionPlan<'a> {
         }
     }
 
+    /// Returns a `format`able structure that draws the plan as a tree of
+    /// boxes, one per node, with the key properties of each node inside its
+    /// box. Metrics, statistics and schema are added as further properties
+    /// if enabled.
+    ///
+    /// An example is
+    /// ```text
+    /// ┌───────────────────────────┐
+    /// │         FilterExec        │
+    /// │    ────────────────────   │
+    /// │          i@0 = 1          │
+    /// └─────────────┬─────────────┘
+    /// ┌─────────────┴─────────────┐
+    /// │         EmptyExec         │
+    /// └───────────────────────────┘
+    /// ```
+    pub fn tree_render(&self) -> impl fmt::Display + 'a {
+        struct Wrapper<'a>(DisplayableExecutionPlan<'a>);
+        impl fmt::Display for Wrapper<'_> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "{}", self.0.tree_render_node(self.0.inner)?)
+            }
+        }
+
+        Wrapper(self.clone())
+    }
+
+    /// Returns a `format`able structure that produces a JSON document for
+    /// the plan, for consumption by other programs.
+    ///
+    /// The document uses the same keys as the JSON format of logical plans,
+    /// the PostgreSQL plan JSON format: each node is an object with its
+    /// `Node Type`, its `key=value` `Properties`, any other `Details` and its
+    /// child `Plans`. If enabled, nodes also have their `Statistics`,
+    /// `Metrics` and `Output` columns. Times in metrics are in nanoseconds.
+    ///
+    /// An example is
+    /// ```json
+    /// [
+    ///   {
+    ///     "Plan": {
+    ///       "Details": "i@0 = 1",
+    ///       "Node Type": "FilterExec",
+    ///       "Plans": [
+    ///         {
+    ///           "Node Type": "EmptyExec",
+    ///           "Plans": [],
+    ///           "Properties": {}
+    ///         }
+    ///       ],
+    ///       "Properties": {}
+    ///     }
+    ///   }
+    /// ]
+    /// ```
+    pub fn json(&self) -> impl fmt::Display + 'a {
+        struct Wrapper<'a>(DisplayableExecutionPlan<'a>);
+        impl fmt::Display for Wrapper<'_> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let node = self.0.json_node(self.0.inner)?;
+                let plan = serde_json::json!([{ "Plan": node }]);
+                let json = serde_json::to_string_pretty(&plan).map_err(|_| fmt::Error)?;
+                write!(f, "{json}")
+            }
+        }
+
+        Wrapper(self.clone())
+    }
+
     /// Return a single-line summary of the root of the plan
     /// Example: `ProjectionExec: expr=[a@0 as a]`.
     pub fn one_line(&self) -> impl fmt::Display + 'a {
@@ -261,6 +334,152 @@ impl<'a> DisplayableExecutionPlan<'a> {
     pub fn to_stringified(&self, verbose: bool, plan_type: PlanType) -> StringifiedPlan {
         StringifiedPlan::new(plan_type, self.indent(verbose).to_string())
     }
+
+    /// format as a `StringifiedPlan` in the given [`ExplainFormat`]
+    ///
+    /// `verbose` only applies to [`ExplainFormat::Indent`]
+    pub fn to_stringified_with_format(
+        &self,
+        verbose: bool,
+        plan_type: PlanType,
+        format: ExplainFormat,
+    ) -> StringifiedPlan {
+        StringifiedPlan::new(plan_type, self.to_string_with_format(verbose, format))
+    }
+
+    /// format as a `String` in the given [`ExplainFormat`]
+    ///
+    /// `verbose` only applies to [`ExplainFormat::Indent`]
+    pub fn to_string_with_format(&self, verbose: bool, format: ExplainFormat) -> String {
+        match format {
+            ExplainFormat::Indent => self.indent(verbose).to_string(),
+            ExplainFormat::Tree => self.tree_render().to_string(),
+            ExplainFormat::Json => self.json().to_string(),
+        }
+    }
+
+    /// The metrics of `plan` to display, if any
+    fn metrics_to_display(&self, plan: &dyn ExecutionPlan) -> Option<MetricsSet> {
+        match self.show_metrics {
+            ShowMetrics::None => None,
+            ShowMetrics::Aggregated => Some(
+                plan.metrics()
+                    .unwrap_or_default()
+                    .aggregate_by_name()
+                    .sorted_for_display()
+                    .timestamps_removed(),
+            ),
+            ShowMetrics::Full => Some(plan.metrics().unwrap_or_default()),
+        }
+    }
+
+    /// Build the [`TreeRenderNode`] for `plan` and its children
+    fn tree_render_node(
+        &self,
+        plan: &dyn ExecutionPlan,
+    ) -> Result<TreeRenderNode, fmt::Error> {
+        let description = DisplayableExecutionPlan::new(plan).one_line().to_string();
+        let mut node =
+            TreeRenderNode::from_description(description.trim_end(), Some(plan.name()));
+        if let Some(metrics) = self.metrics_to_display(plan) {
+            node.properties
+                .extend(metrics.iter().map(|metric| metric.to_string()));
+        }
+        if self.show_statistics {
+            let stats = plan.statistics().map_err(|_e| fmt::Error)?;
+            node.properties.push(format!("statistics=[{stats}]"));
+        }
+        if self.show_schema {
+            let schema = plan.schema();
+            node.properties
+                .push(format!("schema={}", display_schema(schema.as_ref())));
+        }
+        node.children = plan
+            .children()
+            .into_iter()
+            .map(|child| self.tree_render_node(child.as_ref()))
+            .collect::<Result<_, _>>()?;
+        Ok(node)
+    }
+
+    /// Build the JSON object for `plan` and its children
+    fn json_node(
+        &self,
+        plan: &dyn ExecutionPlan,
+    ) -> Result<serde_json::Value, fmt::Error> {
+        let description = DisplayableExecutionPlan::new(plan).one_line().to_string();
+        let (name, parts) =
+            split_node_description(description.trim_end(), Some(plan.name()));
+
+        let mut properties = serde_json::Map::new();
+        let mut details = vec![];
+        for part in parts {
+            match part.split_once('=') {
+                Some((key, value)) if is_property_key(key) => {
+                    properties.insert(key.to_string(), value.into());
+                }
+                _ => details.push(part),
+            }
+        }
+
+        let mut node = serde_json::Map::new();
+        node.insert("Node Type".to_string(), name.into());
+        node.insert("Properties".to_string(), properties.into());
+        if !details.is_empty() {
+            node.insert("Details".to_string(), details.join(", ").into());
+        }
+        if self.show_statistics {
+            let stats = plan.statistics().map_err(|_e| fmt::Error)?;
+            node.insert(
+                "Statistics".to_string(),
+                serde_json::json!({
+                    "Rows": stats.num_rows.to_string(),
+                    "Bytes": stats.total_byte_size.to_string(),
+                }),
+            );
+        }
+        if let Some(metrics) = self.metrics_to_display(plan) {
+            let metrics = metrics
+                .iter()
+                .map(|metric| {
+                    let mut json = serde_json::json!({
+                        "Name": metric.value().name(),
+                        "Value": metric.value().as_usize(),
+                    });
+                    if let Some(partition) = metric.partition() {
+                        json["Partition"] = partition.into();
+                    }
+                    json
+                })
+                .collect::<Vec<_>>();
+            node.insert("Metrics".to_string(), metrics.into());
+        }
+        if self.show_schema {
+            let output = plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| field.name().to_string().into())
+                .collect::<Vec<serde_json::Value>>();
+            node.insert("Output".to_string(), output.into());
+        }
+        let children = plan
+            .children()
+            .into_iter()
+            .map(|child| self.json_node(child.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        node.insert("Plans".to_string(), children.into());
+        Ok(node.into())
+    }
+}
+
+/// Returns true if `key` looks like the key of a `key=value` property, such
+/// as `projection` in `projection=[a@0]`, rather than part of an expression
+fn is_property_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
 }
 
 /// Enum representing the different levels of metrics to display
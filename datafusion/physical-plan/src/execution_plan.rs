@@ -36,11 +36,12 @@ use std::sync::Arc;
 
 use crate::coalesce_partitions::CoalescePartitionsExec;
 use crate::display::DisplayableExecutionPlan;
+use crate::instrument::instrumented_execute;
 use crate::metrics::MetricsSet;
 use crate::projection::ProjectionExec;
 use crate::repartition::RepartitionExec;
 use crate::sorts::sort_preserving_merge::SortPreservingMergeExec;
-use crate::stream::RecordBatchStreamAdapter;
+use crate::stream::{CancellableStream, RecordBatchStreamAdapter};
 
 use arrow::array::{Array, RecordBatch};
 use arrow::datatypes::SchemaRef;
@@ -52,6 +53,7 @@ use datafusion_physical_expr_common::sort_expr::LexRequirement;
 
 use futures::stream::{StreamExt, TryStreamExt};
 use tokio::task::JoinSet;
+use tracing::Instrument;
 
 /// Represent nodes in the DataFusion Physical Plan.
 ///
@@ -853,25 +855,35 @@ pub async fn collect(
 ///
 /// See [collect] to buffer the `RecordBatch`es in memory.
 ///
+/// If a `tracing` subscriber is interested, every operator is executed in
+/// its own span, see [`instrumented_execute`].
+///
 /// # Aborting Execution
 ///
 /// Dropping the stream will abort the execution of the query, and free up
-/// any allocated resources
+/// any allocated resources. The query also stops with a
+/// [`DataFusionError::Cancelled`] error once the [`TaskContext::cancellation`]
+/// token is cancelled or times out.
+///
+/// [`DataFusionError::Cancelled`]: datafusion_common::DataFusionError::Cancelled
 pub fn execute_stream(
     plan: Arc<dyn ExecutionPlan>,
     context: Arc<TaskContext>,
 ) -> Result<SendableRecordBatchStream> {
-    match plan.output_partitioning().partition_count() {
-        0 => Ok(Box::pin(EmptyRecordBatchStream::new(plan.schema()))),
-        1 => plan.execute(0, context),
+    let cancellation = context.cancellation().clone();
+    let stream = match plan.output_partitioning().partition_count() {
+        0 => Box::pin(EmptyRecordBatchStream::new(plan.schema())),
+        1 => instrumented_execute(&plan, 0, context)?,
         2.. => {
             // merge into a single partition
-            let plan = CoalescePartitionsExec::new(Arc::clone(&plan));
+            let plan: Arc<dyn ExecutionPlan> =
+                Arc::new(CoalescePartitionsExec::new(Arc::clone(&plan)));
             // CoalescePartitionsExec must produce a single partition
             assert_eq!(1, plan.properties().output_partitioning().partition_count());
-            plan.execute(0, context)
+            instrumented_execute(&plan, 0, context)?
         }
-    }
+    };
+    Ok(Box::pin(CancellableStream::new(stream, cancellation)))
 }
 
 /// Execute the [ExecutionPlan] and collect the results in memory
@@ -884,10 +896,13 @@ pub async fn collect_partitioned(
     let mut join_set = JoinSet::new();
     // Execute the plan and collect the results into batches.
     streams.into_iter().enumerate().for_each(|(idx, stream)| {
-        join_set.spawn(async move {
-            let result: Result<Vec<RecordBatch>> = stream.try_collect().await;
-            (idx, result)
-        });
+        join_set.spawn(
+            async move {
+                let result: Result<Vec<RecordBatch>> = stream.try_collect().await;
+                (idx, result)
+            }
+            .in_current_span(),
+        );
     });
 
     let mut batches = vec![];
@@ -917,10 +932,14 @@ pub async fn collect_partitioned(
 /// Execute the [ExecutionPlan] and return a vec with one stream per output
 /// partition
 ///
+/// If a `tracing` subscriber is interested, every operator is executed in
+/// its own span, see [`instrumented_execute`].
+///
 /// # Aborting Execution
 ///
-/// Dropping the stream will abort the execution of the query, and free up
-/// any allocated resources
+/// Dropping the streams will abort the execution of the query, and free up
+/// any allocated resources. Each stream also stops with an error once the
+/// [`TaskContext::cancellation`] token is cancelled or times out.
 pub fn execute_stream_partitioned(
     plan: Arc<dyn ExecutionPlan>,
     context: Arc<TaskContext>,
@@ -928,7 +947,11 @@ pub fn execute_stream_partitioned(
     let num_partitions = plan.output_partitioning().partition_count();
     let mut streams = Vec::with_capacity(num_partitions);
     for i in 0..num_partitions {
-        streams.push(plan.execute(i, Arc::clone(&context))?);
+        let stream = instrumented_execute(&plan, i, Arc::clone(&context))?;
+        streams.push(Box::pin(CancellableStream::new(
+            stream,
+            context.cancellation().clone(),
+        )) as SendableRecordBatchStream);
     }
     Ok(streams)
 }
@@ -958,7 +981,7 @@ pub fn execute_input_stream(
     partition: usize,
     context: Arc<TaskContext>,
 ) -> Result<SendableRecordBatchStream> {
-    let input_stream = input.execute(partition, context)?;
+    let input_stream = instrumented_execute(&input, partition, context)?;
 
     debug_assert_eq!(sink_schema.fields().len(), input.schema().fields().len());
 
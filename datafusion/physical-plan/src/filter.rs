@@ -26,6 +26,7 @@ use super::{
 };
 use crate::common::can_project;
 use crate::execution_plan::CardinalityEffect;
+use crate::instrument::instrumented_execute;
 use crate::projection::{
     make_with_child, try_embed_projection, update_expr, EmbeddedProjection,
     ProjectionExec,
@@ -379,7 +380,7 @@ impl ExecutionPlan for FilterExec {
         Ok(Box::pin(FilterExecStream {
             schema: self.schema(),
             predicate: Arc::clone(&self.predicate),
-            input: self.input.execute(partition, context)?,
+            input: instrumented_execute(&self.input, partition, context)?,
             baseline_metrics,
             projection: self.projection.clone(),
         }))
@@ -0,0 +1,165 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Instruments the streams of [`ExecutionPlan`]s with [`tracing`] spans.
+//!
+//! When a `tracing` subscriber is interested in `DEBUG` spans of this module,
+//! [`instrumented_execute`] executes a partition of a plan in an `execute`
+//! span, and returns a stream that enters the span whenever it is polled, so
+//! the busy and idle time a subscriber reports for the span is the poll
+//! timing of the operator. [`execute_stream`] and friends use it for the
+//! root of the plan, and the built-in operators use it to execute their
+//! inputs, so each operator is executed inside its parent's span. Tasks
+//! spawned through [`SpawnedTask`] or [`RecordBatchReceiverStream`] inherit
+//! the current span, so the spans form the same tree as the plan.
+//!
+//! The plan itself is not changed, so its metrics are those of the executed
+//! operators.
+//!
+//! [`execute_stream`]: crate::execute_stream
+//! [`SpawnedTask`]: datafusion_common_runtime::SpawnedTask
+//! [`RecordBatchReceiverStream`]: crate::stream::RecordBatchReceiverStream
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::{ExecutionPlan, RecordBatchStream, SendableRecordBatchStream};
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::Result;
+use datafusion_execution::TaskContext;
+use futures::{Stream, StreamExt};
+use tracing::field::Empty;
+use tracing::{Level, Span};
+
+/// Returns true if the current `tracing` subscriber records the per-operator
+/// spans created by [`instrumented_execute`]
+pub fn is_enabled() -> bool {
+    tracing::enabled!(Level::DEBUG)
+}
+
+/// Executes `partition` of `plan`, in its own `execute` span if
+/// [`is_enabled`].
+///
+/// Operators should execute their inputs with this function rather than
+/// [`ExecutionPlan::execute`], so that the inputs get their own spans.
+pub fn instrumented_execute(
+    plan: &Arc<dyn ExecutionPlan>,
+    partition: usize,
+    context: Arc<TaskContext>,
+) -> Result<SendableRecordBatchStream> {
+    if !is_enabled() {
+        return plan.execute(partition, context);
+    }
+
+    let name = plan.name();
+    let span = tracing::debug_span!(
+        "execute",
+        otel.name = name,
+        operator = name,
+        partition,
+        output_rows = Empty,
+        output_batches = Empty,
+        error = Empty,
+    );
+    let stream = span.in_scope(|| plan.execute(partition, context))?;
+    Ok(Box::pin(InstrumentedStream {
+        inner: stream,
+        span,
+        output_rows: 0,
+        output_batches: 0,
+    }))
+}
+
+/// Polls the stream of one partition inside the partition's span, and
+/// records the rows and batches it produced when it is dropped
+struct InstrumentedStream {
+    inner: SendableRecordBatchStream,
+    span: Span,
+    output_rows: usize,
+    output_batches: usize,
+}
+
+impl Stream for InstrumentedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let _entered = this.span.enter();
+        let poll = this.inner.poll_next_unpin(cx);
+        match &poll {
+            Poll::Ready(Some(Ok(batch))) => {
+                this.output_rows += batch.num_rows();
+                this.output_batches += 1;
+            }
+            Poll::Ready(Some(Err(e))) => {
+                this.span.record("error", tracing::field::display(e));
+            }
+            _ => {}
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl RecordBatchStream for InstrumentedStream {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+impl Drop for InstrumentedStream {
+    fn drop(&mut self) {
+        self.span.record("output_rows", self.output_rows);
+        self.span.record("output_batches", self.output_batches);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coalesce_batches::CoalesceBatchesExec;
+    use crate::{collect, test};
+
+    use tracing_subscriber::Registry;
+
+    #[tokio::test]
+    async fn instrumented_plan_keeps_metrics() -> Result<()> {
+        let _guard = tracing::subscriber::set_default(Registry::default());
+        assert!(is_enabled());
+
+        let plan: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalesceBatchesExec::new(test::scan_partitioned(2), 1024));
+        let batches =
+            collect(Arc::clone(&plan), Arc::new(TaskContext::default())).await?;
+        let rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(rows, 200);
+
+        // the executed operators are those of the plan
+        let metrics = plan.metrics().unwrap();
+        assert_eq!(metrics.output_rows(), Some(200));
+        Ok(())
+    }
+}
@@ -27,6 +27,7 @@ use super::utils::{
 };
 use crate::coalesce_partitions::CoalescePartitionsExec;
 use crate::execution_plan::{boundedness_from_children, EmissionType};
+use crate::instrument::instrumented_execute;
 use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::projection::{
     join_allows_pushdown, join_table_borders, new_join_children,
@@ -201,10 +202,10 @@ async fn load_left_input(
     } else {
         left
     };
-    let stream = merge.execute(0, context)?;
+    let stream = instrumented_execute(&merge, 0, context)?;
 
     // Load all batches and count the rows
-    let (batches, _metrics, reservation) = stream
+    let (batches, metrics, reservation) = stream
         .try_fold(
             (Vec::new(), metrics, reservation),
             |(mut batches, metrics, mut reservation), batch| async {
@@ -224,6 +225,7 @@ async fn load_left_input(
 
     let merged_batch = concat_batches(&left_schema, &batches)?;
 
+    metrics.peak_mem_used.set_max(reservation.size());
     Ok(JoinLeftData {
         merged_batch,
         _reservation: reservation,
@@ -287,7 +289,7 @@ impl ExecutionPlan for CrossJoinExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
-        let stream = self.right.execute(partition, Arc::clone(&context))?;
+        let stream = instrumented_execute(&self.right, partition, Arc::clone(&context))?;
 
         let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
 
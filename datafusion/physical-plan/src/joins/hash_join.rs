@@ -33,6 +33,7 @@ use super::{
     PartitionMode, SharedBitmapBuilder,
 };
 use crate::execution_plan::{boundedness_from_children, EmissionType};
+use crate::instrument::instrumented_execute;
 use crate::projection::{
     try_embed_projection, try_pushdown_through_join, EmbeddedProjection, JoinData,
     ProjectionExec,
@@ -821,7 +822,7 @@ impl ExecutionPlan for HashJoinExec {
 
         // we have the batches and the hash map with their keys. We can how create a stream
         // over the right that uses this information to issue new batches.
-        let right_stream = self.right.execute(partition, context)?;
+        let right_stream = instrumented_execute(&self.right, partition, context)?;
 
         // update column indices to reflect the projection
         let column_indices_after_projection = match &self.projection {
@@ -936,14 +937,17 @@ async fn collect_left_input(
     };
 
     // Depending on partition argument load single partition or whole left side in memory
-    let stream = left_input.execute(left_input_partition, Arc::clone(&context))?;
+    let stream =
+        instrumented_execute(&left_input, left_input_partition, Arc::clone(&context))?;
 
     // This operation performs 2 steps at once:
     // 1. creates a [JoinHashMap] of all batches from the stream
     // 2. stores the batches in a vector.
+    let cancellation = context.cancellation();
     let initial = (Vec::new(), 0, metrics, reservation);
     let (batches, num_rows, metrics, mut reservation) = stream
         .try_fold(initial, |mut acc, batch| async {
+            cancellation.check()?;
             let batch_size = get_record_batch_memory_size(&batch);
             // Reserve memory for incoming batch
             acc.3.try_grow(batch_size)?;
@@ -975,6 +979,7 @@ async fn collect_left_input(
     // Updating hashmap starting from the last batch
     let batches_iter = batches.iter().rev();
     for batch in batches_iter.clone() {
+        cancellation.check()?;
         hashes_buffer.clear();
         hashes_buffer.resize(batch.num_rows(), 0);
         update_hash(
@@ -1013,6 +1018,7 @@ async fn collect_left_input(
         })
         .collect::<Result<Vec<_>>>()?;
 
+    metrics.peak_mem_used.set_max(reservation.size());
     let data = JoinLeftData::new(
         hashmap,
         single_batch,
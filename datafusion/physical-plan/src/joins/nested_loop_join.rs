@@ -31,6 +31,7 @@ use super::utils::{
 use crate::coalesce_partitions::CoalescePartitionsExec;
 use crate::common::can_project;
 use crate::execution_plan::{boundedness_from_children, EmissionType};
+use crate::instrument::instrumented_execute;
 use crate::joins::utils::{
     adjust_indices_by_join_type, apply_join_filter_to_indices, build_batch_from_indices,
     build_join_schema, check_join_is_valid, estimate_join_statistics,
@@ -496,7 +497,7 @@ impl ExecutionPlan for NestedLoopJoinExec {
         let enforce_batch_size_in_joins =
             context.session_config().enforce_batch_size_in_joins();
 
-        let outer_table = self.right.execute(partition, context)?;
+        let outer_table = instrumented_execute(&self.right, partition, context)?;
 
         let indices_cache = (UInt64Array::new_null(0), UInt32Array::new_null(0));
 
@@ -614,7 +615,7 @@ async fn collect_left_input(
     } else {
         input
     };
-    let stream = merge.execute(0, context)?;
+    let stream = instrumented_execute(&merge, 0, context)?;
 
     // Load all batches and count the rows
     let (batches, metrics, mut reservation) = stream
@@ -651,6 +652,7 @@ async fn collect_left_input(
         BooleanBufferBuilder::new(0)
     };
 
+    metrics.peak_mem_used.set_max(reservation.size());
     Ok(JoinLeftData::new(
         merged_batch,
         Mutex::new(visited_left_side),
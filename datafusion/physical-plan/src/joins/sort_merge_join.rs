@@ -36,6 +36,7 @@ use std::task::{Context, Poll};
 
 use crate::execution_plan::{boundedness_from_children, EmissionType};
 use crate::expressions::PhysicalSortExpr;
+use crate::instrument::instrumented_execute;
 use crate::joins::utils::{
     build_join_schema, check_join_is_valid, estimate_join_statistics,
     reorder_output_after_swap, symmetric_join_output_partitioning, JoinFilter, JoinOn,
@@ -60,6 +61,7 @@ use arrow::compute::{
 use arrow::datatypes::{DataType, SchemaRef, TimeUnit};
 use arrow::error::ArrowError;
 use arrow::ipc::reader::FileReader;
+use datafusion_common::config::SpillCompression;
 use datafusion_common::{
     exec_err, internal_err, not_impl_err, plan_err, DataFusionError, HashSet, JoinSide,
     JoinType, Result,
@@ -463,8 +465,8 @@ impl ExecutionPlan for SortMergeJoinExec {
             };
 
         // execute children plans
-        let streamed = streamed.execute(partition, Arc::clone(&context))?;
-        let buffered = buffered.execute(partition, Arc::clone(&context))?;
+        let streamed = instrumented_execute(&streamed, partition, Arc::clone(&context))?;
+        let buffered = instrumented_execute(&buffered, partition, Arc::clone(&context))?;
 
         // create output buffer
         let batch_size = context.session_config().batch_size();
@@ -488,6 +490,11 @@ impl ExecutionPlan for SortMergeJoinExec {
             SortMergeJoinMetrics::new(partition, &self.metrics),
             reservation,
             context.runtime_env(),
+            context
+                .session_config()
+                .options()
+                .execution
+                .spill_compression,
         )?))
     }
 
@@ -586,6 +593,8 @@ struct SortMergeJoinMetrics {
     spilled_bytes: Count,
     /// total spilled rows during the execution of the operator
     spilled_rows: Count,
+    /// total size of the spill files written by the operator
+    spilled_disk_bytes: Count,
 }
 
 impl SortMergeJoinMetrics {
@@ -602,6 +611,8 @@ impl SortMergeJoinMetrics {
         let spill_count = MetricBuilder::new(metrics).spill_count(partition);
         let spilled_bytes = MetricBuilder::new(metrics).spilled_bytes(partition);
         let spilled_rows = MetricBuilder::new(metrics).spilled_rows(partition);
+        let spilled_disk_bytes =
+            MetricBuilder::new(metrics).spilled_disk_bytes(partition);
 
         Self {
             join_time,
@@ -613,6 +624,7 @@ impl SortMergeJoinMetrics {
             spill_count,
             spilled_bytes,
             spilled_rows,
+            spilled_disk_bytes,
         }
     }
 }
@@ -868,6 +880,8 @@ struct SortMergeJoinStream {
     pub reservation: MemoryReservation,
     /// Runtime env
     pub runtime_env: Arc<RuntimeEnv>,
+    /// Compression codec of the spill files
+    pub spill_compression: SpillCompression,
     /// A unique number for each batch
     pub streamed_batch_counter: AtomicUsize,
 }
@@ -1282,6 +1296,7 @@ impl SortMergeJoinStream {
         join_metrics: SortMergeJoinMetrics,
         reservation: MemoryReservation,
         runtime_env: Arc<RuntimeEnv>,
+        spill_compression: SpillCompression,
     ) -> Result<Self> {
         let streamed_schema = streamed.schema();
         let buffered_schema = buffered.schema();
@@ -1317,6 +1332,7 @@ impl SortMergeJoinStream {
             join_metrics,
             reservation,
             runtime_env,
+            spill_compression,
             streamed_batch_counter: AtomicUsize::new(0),
         })
     }
@@ -1395,9 +1411,11 @@ impl SortMergeJoinStream {
                 if let Some(batch) = buffered_batch.batch {
                     spill_record_batches(
                         vec![batch],
-                        spill_file.path().into(),
+                        &spill_file,
                         Arc::clone(&self.buffered_schema),
+                        self.spill_compression,
                     )?;
+                    let spilled_disk_bytes = spill_file.current_disk_usage();
                     buffered_batch.spill_file = Some(spill_file);
                     buffered_batch.batch = None;
 
@@ -1407,6 +1425,9 @@ impl SortMergeJoinStream {
                         .spilled_bytes
                         .add(buffered_batch.size_estimation);
                     self.join_metrics.spilled_rows.add(buffered_batch.num_rows);
+                    self.join_metrics
+                        .spilled_disk_bytes
+                        .add(spilled_disk_bytes as usize);
                     Ok(())
                 } else {
                     internal_err!("Buffered batch has empty body")
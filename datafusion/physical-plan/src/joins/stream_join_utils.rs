@@ -651,6 +651,8 @@ pub struct StreamJoinMetrics {
     pub(crate) right: StreamJoinSideMetrics,
     /// Memory used by sides in bytes
     pub(crate) stream_memory_usage: metrics::Gauge,
+    /// Peak memory used by sides in bytes
+    pub(crate) peak_mem_used: metrics::Gauge,
     /// Number of batches produced by this operator
     pub(crate) output_batches: metrics::Count,
     /// Number of rows produced by this operator
@@ -677,6 +679,7 @@ impl StreamJoinMetrics {
 
         let stream_memory_usage =
             MetricBuilder::new(metrics).gauge("stream_memory_usage", partition);
+        let peak_mem_used = MetricBuilder::new(metrics).gauge("peak_mem_used", partition);
 
         let output_batches =
             MetricBuilder::new(metrics).counter("output_batches", partition);
@@ -688,6 +691,7 @@ impl StreamJoinMetrics {
             right,
             output_batches,
             stream_memory_usage,
+            peak_mem_used,
             output_rows,
         }
     }
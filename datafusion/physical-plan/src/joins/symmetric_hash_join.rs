@@ -34,6 +34,7 @@ use std::vec;
 
 use crate::common::SharedMemoryReservation;
 use crate::execution_plan::{boundedness_from_children, emission_type_from_children};
+use crate::instrument::instrumented_execute;
 use crate::joins::hash_join::{equal_rows_arr, update_hash};
 use crate::joins::stream_join_utils::{
     calculate_filter_expr_intervals, combine_two_batches,
@@ -501,9 +502,11 @@ impl ExecutionPlan for SymmetricHashJoinExec {
         let right_side_joiner =
             OneSideHashJoiner::new(JoinSide::Right, on_right, self.right.schema());
 
-        let left_stream = self.left.execute(partition, Arc::clone(&context))?;
+        let left_stream =
+            instrumented_execute(&self.left, partition, Arc::clone(&context))?;
 
-        let right_stream = self.right.execute(partition, Arc::clone(&context))?;
+        let right_stream =
+            instrumented_execute(&self.right, partition, Arc::clone(&context))?;
 
         let batch_size = context.session_config().batch_size();
         let enforce_batch_size_in_joins =
@@ -1675,6 +1678,7 @@ impl<T: BatchTransformer> SymmetricHashJoinStream<T> {
         let capacity = self.size();
         self.metrics.stream_memory_usage.set(capacity);
         self.reservation.lock().try_resize(capacity)?;
+        self.metrics.peak_mem_used.set_max(capacity);
         Ok(result)
     }
 }
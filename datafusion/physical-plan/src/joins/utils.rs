@@ -1512,6 +1512,8 @@ pub(crate) struct BuildProbeJoinMetrics {
     pub(crate) build_input_rows: metrics::Count,
     /// Memory used by build-side in bytes
     pub(crate) build_mem_used: metrics::Gauge,
+    /// Peak memory reserved by this operator in bytes
+    pub(crate) peak_mem_used: metrics::Gauge,
     /// Total time for joining probe-side batches to the build-side batches
     pub(crate) join_time: metrics::Time,
     /// Number of batches consumed by probe-side of this operator
@@ -1539,6 +1541,8 @@ impl BuildProbeJoinMetrics {
         let build_mem_used =
             MetricBuilder::new(metrics).gauge("build_mem_used", partition);
 
+        let peak_mem_used = MetricBuilder::new(metrics).gauge("peak_mem_used", partition);
+
         let input_batches =
             MetricBuilder::new(metrics).counter("input_batches", partition);
 
@@ -1554,6 +1558,7 @@ impl BuildProbeJoinMetrics {
             build_input_batches,
             build_input_rows,
             build_mem_used,
+            peak_mem_used,
             join_time,
             input_batches,
             input_rows,
@@ -65,6 +65,7 @@ pub mod execution_plan;
 pub mod explain;
 pub mod filter;
 pub mod insert;
+pub mod instrument;
 pub mod joins;
 pub mod limit;
 pub mod memory;
@@ -28,6 +28,7 @@ use super::{
     SendableRecordBatchStream, Statistics,
 };
 use crate::execution_plan::{Boundedness, CardinalityEffect};
+use crate::instrument::instrumented_execute;
 use crate::{DisplayFormatType, Distribution, ExecutionPlan, Partitioning};
 
 use arrow::datatypes::SchemaRef;
@@ -173,7 +174,7 @@ impl ExecutionPlan for GlobalLimitExec {
         }
 
         let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
-        let stream = self.input.execute(0, context)?;
+        let stream = instrumented_execute(&self.input, 0, context)?;
         Ok(Box::pin(LimitStream::new(
             stream,
             self.skip,
@@ -311,7 +312,7 @@ impl ExecutionPlan for LocalLimitExec {
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start LocalLimitExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
         let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
-        let stream = self.input.execute(partition, context)?;
+        let stream = instrumented_execute(&self.input, partition, context)?;
         Ok(Box::pin(LimitStream::new(
             stream,
             0,
@@ -123,6 +123,13 @@ impl<'a> MetricBuilder<'a> {
         count
     }
 
+    /// Consume self and create a new counter for recording the total size of
+    /// the spill files written by an operator, which is smaller than the
+    /// spilled bytes when spill files are compressed
+    pub fn spilled_disk_bytes(self, partition: usize) -> Count {
+        self.counter("spilled_disk_bytes", partition)
+    }
+
     /// Consume self and create a new counter for recording the total spilled rows
     /// triggered by an operator
     pub fn spilled_rows(self, partition: usize) -> Count {
@@ -209,6 +209,12 @@ impl MetricsSet {
             .map(|v| v.as_usize())
     }
 
+    /// Convenience: return the total size of spill files, aggregated
+    /// across partitions or `None` if no metric is present
+    pub fn spilled_disk_bytes(&self) -> Option<usize> {
+        self.sum_by_name("spilled_disk_bytes").map(|v| v.as_usize())
+    }
+
     /// Convenience: return the total rows of spills, aggregated
     /// across partitions or `None` if no metric is present
     pub fn spilled_rows(&self) -> Option<usize> {
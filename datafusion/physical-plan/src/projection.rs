@@ -33,6 +33,7 @@ use super::{
     SendableRecordBatchStream, Statistics,
 };
 use crate::execution_plan::CardinalityEffect;
+use crate::instrument::instrumented_execute;
 use crate::joins::utils::{ColumnIndex, JoinFilter};
 use crate::{ColumnStatistics, DisplayFormatType, ExecutionPlan, PhysicalExpr};
 
@@ -221,7 +222,7 @@ impl ExecutionPlan for ProjectionExec {
         Ok(Box::pin(ProjectionStream {
             schema: Arc::clone(&self.schema),
             expr: self.expr.iter().map(|x| Arc::clone(&x.0)).collect(),
-            input: self.input.execute(partition, context)?,
+            input: instrumented_execute(&self.input, partition, context)?,
             baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
         }))
     }
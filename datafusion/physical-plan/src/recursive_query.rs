@@ -23,6 +23,7 @@ use std::task::{Context, Poll};
 
 use super::work_table::{ReservedBatches, WorkTable, WorkTableExec};
 use crate::execution_plan::{Boundedness, EmissionType};
+use crate::instrument::instrumented_execute;
 use crate::{
     metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet},
     PlanProperties, RecordBatchStream, SendableRecordBatchStream, Statistics,
@@ -189,7 +190,8 @@ impl ExecutionPlan for RecursiveQueryExec {
             )));
         }
 
-        let static_stream = self.static_term.execute(partition, Arc::clone(&context))?;
+        let static_stream =
+            instrumented_execute(&self.static_term, partition, Arc::clone(&context))?;
         let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
         Ok(Box::pin(RecursiveQueryStream::new(
             context,
@@ -336,8 +338,11 @@ impl RecursiveQueryStream {
         let partition = 0;
 
         let recursive_plan = reset_plan_states(Arc::clone(&self.recursive_term))?;
-        self.recursive_stream =
-            Some(recursive_plan.execute(partition, Arc::clone(&self.task_context))?);
+        self.recursive_stream = Some(instrumented_execute(
+            &recursive_plan,
+            partition,
+            Arc::clone(&self.task_context),
+        )?);
         self.poll_next(cx)
     }
 }
@@ -31,6 +31,7 @@ use super::{
 };
 use crate::execution_plan::CardinalityEffect;
 use crate::hash_utils::create_hashes;
+use crate::instrument::instrumented_execute;
 use crate::metrics::BaselineMetrics;
 use crate::projection::{all_columns, make_with_child, update_expr, ProjectionExec};
 use crate::repartition::distributor_channels::{
@@ -114,6 +115,13 @@ impl RepartitionExecState {
             (txs, rxs)
         };
 
+        // Peak memory buffered for each output partition, shared by all input tasks
+        let peak_mem_used = (0..num_output_partitions)
+            .map(|partition| {
+                MetricBuilder::new(&metrics).gauge("peak_mem_used", partition)
+            })
+            .collect::<Vec<_>>();
+
         let mut channels = HashMap::with_capacity(txs.len());
         for (partition, (tx, rx)) in txs.into_iter().zip(rxs).enumerate() {
             let reservation = Arc::new(Mutex::new(
@@ -133,7 +141,12 @@ impl RepartitionExecState {
                 })
                 .collect();
 
-            let r_metrics = RepartitionMetrics::new(i, num_output_partitions, &metrics);
+            let r_metrics = RepartitionMetrics::new(
+                i,
+                num_output_partitions,
+                peak_mem_used.clone(),
+                &metrics,
+            );
 
             let input_task = SpawnedTask::spawn(RepartitionExec::pull_from_input(
                 Arc::clone(&input),
@@ -423,12 +436,17 @@ struct RepartitionMetrics {
     ///
     /// One metric per output partition.
     send_time: Vec<metrics::Time>,
+    /// Peak memory reserved for batches buffered in the channels.
+    ///
+    /// One metric per output partition.
+    peak_mem_used: Vec<metrics::Gauge>,
 }
 
 impl RepartitionMetrics {
     pub fn new(
         input_partition: usize,
         num_output_partitions: usize,
+        peak_mem_used: Vec<metrics::Gauge>,
         metrics: &ExecutionPlanMetricsSet,
     ) -> Self {
         // Time in nanos to execute child operator and fetch batches
@@ -454,6 +472,7 @@ impl RepartitionMetrics {
             fetch_time,
             repartition_time,
             send_time,
+            peak_mem_used,
         }
     }
 }
@@ -821,7 +840,7 @@ impl RepartitionExec {
 
         // execute the child operator
         let timer = metrics.fetch_time.timer();
-        let mut stream = input.execute(partition, context)?;
+        let mut stream = instrumented_execute(&input, partition, context)?;
         timer.done();
 
         // While there are still outputs to send to, keep pulling inputs
@@ -845,7 +864,11 @@ impl RepartitionExec {
                 let timer = metrics.send_time[partition].timer();
                 // if there is still a receiver, send to it
                 if let Some((tx, reservation)) = output_channels.get_mut(&partition) {
-                    reservation.lock().try_grow(size)?;
+                    {
+                        let mut reservation = reservation.lock();
+                        reservation.try_grow(size)?;
+                        metrics.peak_mem_used[partition].set_max(reservation.size());
+                    }
 
                     if tx.send(Some(Ok(batch))).await.is_err() {
                         // If the other end has hung up, it was an early shutdown (e.g. LIMIT)
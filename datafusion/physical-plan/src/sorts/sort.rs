@@ -27,14 +27,16 @@ use std::sync::Arc;
 use crate::common::{spawn_buffered, IPCWriter};
 use crate::execution_plan::{Boundedness, CardinalityEffect, EmissionType};
 use crate::expressions::PhysicalSortExpr;
+use crate::instrument::instrumented_execute;
 use crate::limit::LimitStream;
 use crate::metrics::{
-    BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
+    BaselineMetrics, Count, ExecutionPlanMetricsSet, Gauge, MetricBuilder, MetricsSet,
 };
 use crate::projection::{make_with_child, update_expr, ProjectionExec};
 use crate::sorts::streaming_merge::StreamingMergeBuilder;
 use crate::spill::{
-    get_record_batch_memory_size, read_spill_as_stream, spill_record_batches,
+    create_spill_writer, get_record_batch_memory_size, read_spill_as_stream,
+    spill_record_batches,
 };
 use crate::stream::RecordBatchStreamAdapter;
 use crate::topk::TopK;
@@ -48,8 +50,9 @@ use arrow::array::{Array, RecordBatch, RecordBatchOptions, UInt32Array};
 use arrow::compute::{concat_batches, lexsort_to_indices, take_arrays, SortColumn};
 use arrow::datatypes::{DataType, SchemaRef};
 use arrow::row::{RowConverter, SortField};
+use datafusion_common::config::SpillCompression;
 use datafusion_common::{internal_err, Result};
-use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::disk_manager::{RefCountedTempFile, TempFileWriter};
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_execution::TaskContext;
@@ -71,6 +74,12 @@ struct ExternalSorterMetrics {
 
     /// total spilled rows during the execution of the operator
     spilled_rows: Count,
+
+    /// total size of the spill files written by the operator
+    spilled_disk_bytes: Count,
+
+    /// peak memory reserved for buffering and merging batches
+    peak_mem_used: Gauge,
 }
 
 impl ExternalSorterMetrics {
@@ -80,6 +89,8 @@ impl ExternalSorterMetrics {
             spill_count: MetricBuilder::new(metrics).spill_count(partition),
             spilled_bytes: MetricBuilder::new(metrics).spilled_bytes(partition),
             spilled_rows: MetricBuilder::new(metrics).spilled_rows(partition),
+            spilled_disk_bytes: MetricBuilder::new(metrics).spilled_disk_bytes(partition),
+            peak_mem_used: MetricBuilder::new(metrics).gauge("peak_mem_used", partition),
         }
     }
 }
@@ -218,6 +229,8 @@ struct ExternalSorter {
     /// the data will be concatenated and sorted in place rather than
     /// sort/merged.
     sort_in_place_threshold_bytes: usize,
+    /// Compression codec of the spill files
+    spill_compression: SpillCompression,
 
     // ========================================================================
     // STATE BUFFERS:
@@ -264,6 +277,7 @@ impl ExternalSorter {
         fetch: Option<usize>,
         sort_spill_reservation_bytes: usize,
         sort_in_place_threshold_bytes: usize,
+        spill_compression: SpillCompression,
         metrics: &ExecutionPlanMetricsSet,
         runtime: Arc<RuntimeEnv>,
     ) -> Self {
@@ -290,6 +304,7 @@ impl ExternalSorter {
             batch_size,
             sort_spill_reservation_bytes,
             sort_in_place_threshold_bytes,
+            spill_compression,
         }
     }
 
@@ -313,6 +328,7 @@ impl ExternalSorter {
 
         self.in_mem_batches.push(input);
         self.in_mem_batches_sorted = false;
+        self.update_peak_mem_used();
         Ok(())
     }
 
@@ -403,13 +419,18 @@ impl ExternalSorter {
         let batches = std::mem::take(&mut self.in_mem_batches);
         let (spilled_rows, spilled_bytes) = spill_record_batches(
             batches,
-            spill_file.path().into(),
+            &spill_file,
             Arc::clone(&self.schema),
+            self.spill_compression,
         )?;
+        let spilled_disk_bytes = spill_file.current_disk_usage();
         let used = self.reservation.free();
         self.metrics.spill_count.add(1);
         self.metrics.spilled_bytes.add(spilled_bytes);
         self.metrics.spilled_rows.add(spilled_rows);
+        self.metrics
+            .spilled_disk_bytes
+            .add(spilled_disk_bytes as usize);
         self.spills.push(spill_file);
         Ok(used)
     }
@@ -439,7 +460,7 @@ impl ExternalSorter {
         // `self.in_mem_batches` is already taken away by the sort_stream, now it is empty.
         // We'll gradually collect the sorted stream into self.in_mem_batches, or directly
         // write sorted batches to disk when the memory is insufficient.
-        let mut spill_writer: Option<IPCWriter> = None;
+        let mut spill_writer: Option<IPCWriter<TempFileWriter>> = None;
         while let Some(batch) = sorted_stream.next().await {
             let batch = batch?;
             match &mut spill_writer {
@@ -451,7 +472,11 @@ impl ExternalSorter {
                         // be handled by the `Some(writer)` matching arm.
                         let spill_file =
                             self.runtime.disk_manager.create_tmp_file("Sorting")?;
-                        let mut writer = IPCWriter::new(spill_file.path(), &self.schema)?;
+                        let mut writer = create_spill_writer(
+                            &spill_file,
+                            &self.schema,
+                            self.spill_compression,
+                        )?;
                         // Flush everything in memory to the spill file
                         for batch in self.in_mem_batches.drain(..) {
                             writer.write(&batch)?;
@@ -464,6 +489,7 @@ impl ExternalSorter {
                     } else {
                         self.in_mem_batches.push(batch);
                         self.in_mem_batches_sorted = true;
+                        self.update_peak_mem_used();
                     }
                 }
                 Some(writer) => {
@@ -478,9 +504,14 @@ impl ExternalSorter {
 
         if let Some(writer) = &mut spill_writer {
             writer.finish()?;
+            let spill_file = self.spills.last().expect("spill file was created");
+            let spilled_disk_bytes = spill_file.current_disk_usage();
             self.metrics.spill_count.add(1);
             self.metrics.spilled_rows.add(writer.num_rows);
             self.metrics.spilled_bytes.add(writer.num_bytes);
+            self.metrics
+                .spilled_disk_bytes
+                .add(spilled_disk_bytes as usize);
         }
 
         // Sorting may free up some memory especially when fetch is `Some`. If we have
@@ -649,6 +680,14 @@ impl ExternalSorter {
         Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
     }
 
+    /// Records the memory currently reserved by this sorter in the
+    /// `peak_mem_used` metric
+    fn update_peak_mem_used(&self) {
+        self.metrics
+            .peak_mem_used
+            .set_max(self.reservation.size() + self.merge_reservation.size());
+    }
+
     /// If this sort may spill, pre-allocates
     /// `sort_spill_reservation_bytes` of memory to guarantee memory
     /// left for the in memory sort/merge.
@@ -1007,7 +1046,8 @@ impl ExecutionPlan for SortExec {
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start SortExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
 
-        let mut input = self.input.execute(partition, Arc::clone(&context))?;
+        let mut input =
+            instrumented_execute(&self.input, partition, Arc::clone(&context))?;
 
         let execution_options = &context.session_config().options().execution;
 
@@ -1036,11 +1076,13 @@ impl ExecutionPlan for SortExec {
                     context.runtime_env(),
                     &self.metrics_set,
                 )?;
+                let cancellation = context.cancellation().clone();
                 Ok(Box::pin(RecordBatchStreamAdapter::new(
                     self.schema(),
                     futures::stream::once(async move {
                         while let Some(batch) = input.next().await {
                             let batch = batch?;
+                            cancellation.check()?;
                             topk.insert_batch(batch)?;
                         }
                         topk.emit()
@@ -1057,14 +1099,17 @@ impl ExecutionPlan for SortExec {
                     self.fetch,
                     execution_options.sort_spill_reservation_bytes,
                     execution_options.sort_in_place_threshold_bytes,
+                    execution_options.spill_compression,
                     &self.metrics_set,
                     context.runtime_env(),
                 );
+                let cancellation = context.cancellation().clone();
                 Ok(Box::pin(RecordBatchStreamAdapter::new(
                     self.schema(),
                     futures::stream::once(async move {
                         while let Some(batch) = input.next().await {
                             let batch = batch?;
+                            cancellation.check()?;
                             sorter.insert_batch(batch).await?;
                         }
                         sorter.sort()
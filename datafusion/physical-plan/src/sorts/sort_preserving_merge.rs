@@ -21,6 +21,7 @@ use std::any::Any;
 use std::sync::Arc;
 
 use crate::common::spawn_buffered;
+use crate::instrument::instrumented_execute;
 use crate::limit::LimitStream;
 use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
 use crate::projection::{make_with_child, update_expr, ProjectionExec};
@@ -281,7 +282,7 @@ impl ExecutionPlan for SortPreservingMergeExec {
             ),
             1 => match self.fetch {
                 Some(fetch) => {
-                    let stream = self.input.execute(0, context)?;
+                    let stream = instrumented_execute(&self.input, 0, context)?;
                     debug!("Done getting stream for SortPreservingMergeExec::execute with 1 input with {fetch}");
                     Ok(Box::pin(LimitStream::new(
                         stream,
@@ -291,7 +292,7 @@ impl ExecutionPlan for SortPreservingMergeExec {
                     )))
                 }
                 None => {
-                    let stream = self.input.execute(0, context);
+                    let stream = instrumented_execute(&self.input, 0, context);
                     debug!("Done getting stream for SortPreservingMergeExec::execute with 1 input without fetch");
                     stream
                 }
@@ -299,8 +300,11 @@ impl ExecutionPlan for SortPreservingMergeExec {
             _ => {
                 let receivers = (0..input_partitions)
                     .map(|partition| {
-                        let stream =
-                            self.input.execute(partition, Arc::clone(&context))?;
+                        let stream = instrumented_execute(
+                            &self.input,
+                            partition,
+                            Arc::clone(&context),
+                        )?;
                         Ok(spawn_buffered(stream, 1))
                     })
                     .collect::<Result<_>>()?;
@@ -19,18 +19,21 @@
 
 use std::fs::File;
 use std::io::BufReader;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::ptr::NonNull;
 
 use arrow::array::ArrayData;
-use arrow::datatypes::SchemaRef;
+use arrow::datatypes::{Schema, SchemaRef};
 use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::ipc::CompressionType;
 use arrow::record_batch::RecordBatch;
 use log::debug;
 use tokio::sync::mpsc::Sender;
 
+use datafusion_common::config::SpillCompression;
 use datafusion_common::{exec_datafusion_err, HashSet, Result};
-use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::disk_manager::{RefCountedTempFile, TempFileWriter};
 use datafusion_execution::memory_pool::human_readable_size;
 use datafusion_execution::SendableRecordBatchStream;
 
@@ -55,15 +58,36 @@ pub(crate) fn read_spill_as_stream(
     Ok(builder.build())
 }
 
+/// Creates an [`IPCWriter`] for a spill file compressed with `compression`
+///
+/// The bytes written count towards the disk usage of the [`DiskManager`]
+/// of the file as they are written.
+///
+/// [`DiskManager`]: datafusion_execution::DiskManager
+pub(crate) fn create_spill_writer(
+    file: &RefCountedTempFile,
+    schema: &Schema,
+    compression: SpillCompression,
+) -> Result<IPCWriter<TempFileWriter>> {
+    let compression = match compression {
+        SpillCompression::Uncompressed => None,
+        SpillCompression::Lz4Frame => Some(CompressionType::LZ4_FRAME),
+        SpillCompression::Zstd => Some(CompressionType::ZSTD),
+    };
+    let write_options = IpcWriteOptions::default().try_with_compression(compression)?;
+    IPCWriter::new_with_writer(file.path(), file.writer()?, schema, write_options)
+}
+
 /// Spills in-memory `batches` to disk.
 ///
-/// Returns total number of the rows spilled to disk.
+/// Returns total number of the rows spilled to disk, and their size in memory.
 pub(crate) fn spill_record_batches(
     batches: Vec<RecordBatch>,
-    path: PathBuf,
+    file: &RefCountedTempFile,
     schema: SchemaRef,
+    compression: SpillCompression,
 ) -> Result<(usize, usize)> {
-    let mut writer = IPCWriter::new(path.as_ref(), schema.as_ref())?;
+    let mut writer = create_spill_writer(file, schema.as_ref(), compression)?;
     for batch in batches {
         writer.write(&batch)?;
     }
@@ -89,16 +113,17 @@ fn read_spill(sender: Sender<Result<RecordBatch>>, path: &Path) -> Result<()> {
 }
 
 /// Spill the `RecordBatch` to disk as smaller batches
-/// split by `batch_size_rows`
+/// split by `batch_size_rows`, compressed with `compression`
 pub fn spill_record_batch_by_size(
     batch: &RecordBatch,
-    path: PathBuf,
+    file: &RefCountedTempFile,
     schema: SchemaRef,
     batch_size_rows: usize,
+    compression: SpillCompression,
 ) -> Result<()> {
     let mut offset = 0;
     let total_rows = batch.num_rows();
-    let mut writer = IPCWriter::new(&path, schema.as_ref())?;
+    let mut writer = create_spill_writer(file, schema.as_ref(), compression)?;
 
     while offset < total_rows {
         let length = std::cmp::min(total_rows - offset, batch_size_rows);
@@ -215,8 +240,9 @@ mod tests {
         let num_rows = batch1.num_rows() + batch2.num_rows();
         let (spilled_rows, _) = spill_record_batches(
             vec![batch1, batch2],
-            spill_file.path().into(),
+            &spill_file,
             Arc::clone(&schema),
+            SpillCompression::Uncompressed,
         )?;
         assert_eq!(spilled_rows, num_rows);
 
@@ -243,9 +269,10 @@ mod tests {
         let schema = batch1.schema();
         spill_record_batch_by_size(
             &batch1,
-            spill_file.path().into(),
+            &spill_file,
             Arc::clone(&schema),
             1,
+            SpillCompression::Uncompressed,
         )?;
 
         let file = BufReader::new(File::open(spill_file.path())?);
@@ -257,6 +284,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_compressed_spill() -> Result<()> {
+        let values = vec![42; 10_000];
+        let batch = build_table_i32(("a", &values), ("b", &values), ("c", &values));
+        let schema = batch.schema();
+        let disk_manager = DiskManager::try_new(DiskManagerConfig::NewOs)?;
+
+        let mut file_sizes = vec![];
+        for compression in [
+            SpillCompression::Uncompressed,
+            SpillCompression::Lz4Frame,
+            SpillCompression::Zstd,
+        ] {
+            let spill_file = disk_manager.create_tmp_file("Test Spill")?;
+            let (spilled_rows, spilled_bytes) = spill_record_batches(
+                vec![batch.clone()],
+                &spill_file,
+                Arc::clone(&schema),
+                compression,
+            )?;
+            assert_eq!(spilled_rows, 10_000);
+            assert_eq!(spilled_bytes, batch.get_array_memory_size());
+            let file_size = std::fs::metadata(spill_file.path())?.len();
+            assert_eq!(spill_file.current_disk_usage(), file_size);
+            file_sizes.push(file_size);
+
+            let file = BufReader::new(File::open(spill_file.path())?);
+            let reader = FileReader::try_new(file, None)?;
+            let batches = reader.collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(batches, vec![batch.clone()]);
+        }
+
+        assert!(file_sizes[1] < file_sizes[0], "{file_sizes:?}");
+        assert!(file_sizes[2] < file_sizes[0], "{file_sizes:?}");
+        assert_eq!(disk_manager.used_disk_space(), 0);
+        Ok(())
+    }
+
     #[test]
     fn test_get_record_batch_memory_size() {
         // Create a simple record batch with two columns
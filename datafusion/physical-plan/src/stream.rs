@@ -25,17 +25,21 @@ use std::task::Poll;
 use super::metrics::BaselineMetrics;
 use super::{ExecutionPlan, RecordBatchStream, SendableRecordBatchStream};
 use crate::displayable;
+use crate::instrument::instrumented_execute;
 
 use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
 use datafusion_common::{internal_err, Result};
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::TaskContext;
 
+use futures::future::BoxFuture;
 use futures::stream::BoxStream;
-use futures::{Future, Stream, StreamExt};
+use futures::{Future, FutureExt, Stream, StreamExt};
 use log::debug;
 use pin_project_lite::pin_project;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::task::JoinSet;
+use tracing::{Instrument, Span};
 
 /// Creates a stream from a collection of producing tasks, routing panics to the stream.
 ///
@@ -47,6 +51,8 @@ use tokio::task::JoinSet;
 ///
 /// 3. Automatically cancels any outstanding tasks when the receiver stream is dropped.
 ///
+/// 4. Runs the tasks in the `tracing` span that was current when they were spawned.
+///
 /// [`ReceiverStream` from tokio-stream]: https://docs.rs/tokio-stream/latest/tokio_stream/wrappers/struct.ReceiverStream.html
 pub(crate) struct ReceiverStreamBuilder<O> {
     tx: Sender<Result<O>>,
@@ -78,7 +84,7 @@ impl<O: Send + 'static> ReceiverStreamBuilder<O> {
         F: Future<Output = Result<()>>,
         F: Send + 'static,
     {
-        self.join_set.spawn(task);
+        self.join_set.spawn(task.in_current_span());
     }
 
     /// Spawn a blocking task that will be aborted if this builder (or the stream
@@ -91,7 +97,8 @@ impl<O: Send + 'static> ReceiverStreamBuilder<O> {
         F: FnOnce() -> Result<()>,
         F: Send + 'static,
     {
-        self.join_set.spawn_blocking(f);
+        let span = Span::current();
+        self.join_set.spawn_blocking(move || span.in_scope(f));
     }
 
     /// Create a stream of all data written to `tx`
@@ -269,7 +276,7 @@ impl RecordBatchReceiverStreamBuilder {
         let output = self.tx();
 
         self.inner.spawn(async move {
-            let mut stream = match input.execute(partition, context) {
+            let mut stream = match instrumented_execute(&input, partition, context) {
                 Err(e) => {
                     // If send fails, the plan being torn down, there
                     // is no place to send the error and no reason to continue.
@@ -505,6 +512,60 @@ impl Stream for ObservedStream {
     }
 }
 
+/// Stream wrapper that ends with a [`DataFusionError::Cancelled`] error once
+/// its [`CancellationToken`] is cancelled or has timed out.
+///
+/// The token is checked each time the stream is polled, and waited for while
+/// the inner stream is pending, so that the stream ends even if the inner
+/// stream never produces another batch.
+///
+/// [`DataFusionError::Cancelled`]: datafusion_common::DataFusionError::Cancelled
+pub(crate) struct CancellableStream {
+    inner: SendableRecordBatchStream,
+    cancellation: CancellationToken,
+    /// Resolves once `cancellation` is cancelled or has timed out
+    on_cancel: BoxFuture<'static, ()>,
+    cancelled: bool,
+}
+
+impl CancellableStream {
+    pub fn new(
+        inner: SendableRecordBatchStream,
+        cancellation: CancellationToken,
+    ) -> Self {
+        Self {
+            inner,
+            on_cancel: cancellation.cancelled(),
+            cancellation,
+            cancelled: false,
+        }
+    }
+}
+
+impl RecordBatchStream for CancellableStream {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+impl Stream for CancellableStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.cancelled {
+            return Poll::Ready(None);
+        }
+        if self.cancellation.is_cancelled() || self.on_cancel.poll_unpin(cx).is_ready() {
+            self.cancelled = true;
+            return Poll::Ready(self.cancellation.check().err().map(Err));
+        }
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -519,6 +580,44 @@ mod test {
         Arc::new(Schema::new(vec![Field::new("a", DataType::Float32, true)]))
     }
 
+    #[tokio::test]
+    async fn cancellable_stream_stops_when_cancelled() {
+        let schema = schema();
+        let batch = RecordBatch::new_empty(Arc::clone(&schema));
+        let input = MockExec::new(vec![Ok(batch.clone()), Ok(batch)], schema)
+            .with_use_task(false);
+        let task_ctx = Arc::new(TaskContext::default());
+        let mut stream =
+            crate::execute_stream(Arc::new(input), Arc::clone(&task_ctx)).unwrap();
+
+        assert!(stream.next().await.unwrap().is_ok());
+        task_ctx.cancellation().cancel();
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Query cancelled: query was cancelled"
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn cancellable_stream_stops_pending_input_on_timeout() {
+        let schema = schema();
+        let input = BlockingExec::new(Arc::clone(&schema), 1);
+        let cancellation = CancellationToken::new()
+            .child_token_with_timeout(std::time::Duration::from_millis(10));
+        let task_ctx = Arc::new(TaskContext::default().with_cancellation(cancellation));
+        let mut stream = crate::execute_stream(Arc::new(input), task_ctx).unwrap();
+
+        // the input never produces a batch, but the stream times out
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Query cancelled: query exceeded the statement timeout of 10ms"
+        );
+        assert!(stream.next().await.is_none());
+    }
+
     #[tokio::test]
     #[should_panic(expected = "PanickingStream did panic")]
     async fn record_batch_receiver_stream_propagates_panics() {
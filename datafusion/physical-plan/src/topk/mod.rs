@@ -24,7 +24,9 @@ use arrow::{
 use std::mem::size_of;
 use std::{cmp::Ordering, collections::BinaryHeap, sync::Arc};
 
-use super::metrics::{BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder};
+use super::metrics::{
+    BaselineMetrics, Count, ExecutionPlanMetricsSet, Gauge, MetricBuilder,
+};
 use crate::spill::get_record_batch_memory_size;
 use crate::{stream::RecordBatchStreamAdapter, SendableRecordBatchStream};
 use arrow::array::{Array, ArrayRef, RecordBatch};
@@ -183,6 +185,7 @@ impl TopK {
 
         // update memory reservation
         self.reservation.try_resize(self.size())?;
+        self.metrics.peak_mem_used.set_max(self.reservation.size());
         Ok(())
     }
 
@@ -237,6 +240,9 @@ struct TopKMetrics {
 
     /// count of how many rows were replaced in the heap
     pub row_replacements: Count,
+
+    /// peak memory reserved for the heap
+    pub peak_mem_used: Gauge,
 }
 
 impl TopKMetrics {
@@ -245,6 +251,7 @@ impl TopKMetrics {
             baseline: BaselineMetrics::new(metrics, partition),
             row_replacements: MetricBuilder::new(metrics)
                 .counter("row_replacements", partition),
+            peak_mem_used: MetricBuilder::new(metrics).gauge("peak_mem_used", partition),
         }
     }
 }
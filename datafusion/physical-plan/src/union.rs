@@ -35,6 +35,7 @@ use super::{
 use crate::execution_plan::{
     boundedness_from_children, emission_type_from_children, InvariantLevel,
 };
+use crate::instrument::instrumented_execute;
 use crate::metrics::BaselineMetrics;
 use crate::projection::{make_with_child, ProjectionExec};
 use crate::stream::ObservedStream;
@@ -235,7 +236,7 @@ impl ExecutionPlan for UnionExec {
         for input in self.inputs.iter() {
             // Calculate whether partition belongs to the current partition
             if partition < input.output_partitioning().partition_count() {
-                let stream = input.execute(partition, context)?;
+                let stream = instrumented_execute(input, partition, context)?;
                 debug!("Found a Union partition to execute");
                 return Ok(Box::pin(ObservedStream::new(
                     stream,
@@ -441,7 +442,11 @@ impl ExecutionPlan for InterleaveExec {
         let mut input_stream_vec = vec![];
         for input in self.inputs.iter() {
             if partition < input.output_partitioning().partition_count() {
-                input_stream_vec.push(input.execute(partition, Arc::clone(&context))?);
+                input_stream_vec.push(instrumented_execute(
+                    input,
+                    partition,
+                    Arc::clone(&context),
+                )?);
             } else {
                 // Do not find a partition to execute
                 break;
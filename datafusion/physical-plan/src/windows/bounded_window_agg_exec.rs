@@ -28,7 +28,11 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use super::utils::create_schema;
-use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::instrument::instrumented_execute;
+use crate::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, Gauge, MetricBuilder, MetricsSet,
+};
+use crate::spill::get_record_batch_memory_size;
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
     window_equivalence_properties,
@@ -315,13 +319,14 @@ impl ExecutionPlan for BoundedWindowAggExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
-        let input = self.input.execute(partition, context)?;
+        let input = instrumented_execute(&self.input, partition, context)?;
         let search_mode = self.get_search_algo()?;
         let stream = Box::pin(BoundedWindowAggStream::new(
             Arc::clone(&self.schema),
             self.window_expr.clone(),
             input,
             BaselineMetrics::new(&self.metrics, partition),
+            MetricBuilder::new(&self.metrics).gauge("peak_mem_used", partition),
             search_mode,
         )?);
         Ok(stream)
@@ -894,6 +899,8 @@ pub struct BoundedWindowAggStream {
     finished: bool,
     window_expr: Vec<Arc<dyn WindowExpr>>,
     baseline_metrics: BaselineMetrics,
+    /// Peak memory used by the buffered input batches
+    peak_mem_used: Gauge,
     /// Search mode for partition columns. This determines the algorithm with
     /// which we group each partition.
     search_mode: Box<dyn PartitionSearcher>,
@@ -939,6 +946,7 @@ impl BoundedWindowAggStream {
         window_expr: Vec<Arc<dyn WindowExpr>>,
         input: SendableRecordBatchStream,
         baseline_metrics: BaselineMetrics,
+        peak_mem_used: Gauge,
         search_mode: Box<dyn PartitionSearcher>,
     ) -> Result<Self> {
         let state = window_expr.iter().map(|_| IndexMap::new()).collect();
@@ -952,10 +960,24 @@ impl BoundedWindowAggStream {
             finished: false,
             window_expr,
             baseline_metrics,
+            peak_mem_used,
             search_mode,
         })
     }
 
+    /// Records the memory of the buffered input batches in the
+    /// `peak_mem_used` metric
+    fn update_peak_mem_used(&self) {
+        let partition_buffers_size: usize = self
+            .partition_buffers
+            .values()
+            .map(|state| get_record_batch_memory_size(&state.record_batch))
+            .sum();
+        self.peak_mem_used.set_max(
+            get_record_batch_memory_size(&self.input_buffer) + partition_buffers_size,
+        );
+    }
+
     fn compute_aggregates(&mut self) -> Result<Option<RecordBatch>> {
         // calculate window cols
         for (cur_window_expr, state) in
@@ -1011,6 +1033,7 @@ impl BoundedWindowAggStream {
                     &self.window_expr,
                     &mut self.partition_buffers,
                 )?;
+                self.update_peak_mem_used();
                 if let Some(batch) = self.compute_aggregates()? {
                     return Poll::Ready(Some(Ok(batch)));
                 }
@@ -24,7 +24,11 @@ use std::task::{Context, Poll};
 
 use super::utils::create_schema;
 use crate::execution_plan::EmissionType;
-use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::instrument::instrumented_execute;
+use crate::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, Gauge, MetricBuilder, MetricsSet,
+};
+use crate::spill::get_record_batch_memory_size;
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
     window_equivalence_properties,
@@ -246,12 +250,13 @@ impl ExecutionPlan for WindowAggExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
-        let input = self.input.execute(partition, context)?;
+        let input = instrumented_execute(&self.input, partition, context)?;
         let stream = Box::pin(WindowAggStream::new(
             Arc::clone(&self.schema),
             self.window_expr.clone(),
             input,
             BaselineMetrics::new(&self.metrics, partition),
+            MetricBuilder::new(&self.metrics).gauge("peak_mem_used", partition),
             self.partition_by_sort_keys()?,
             self.ordered_partition_by_indices.clone(),
         )?);
@@ -301,6 +306,10 @@ pub struct WindowAggStream {
     window_expr: Vec<Arc<dyn WindowExpr>>,
     partition_by_sort_keys: LexOrdering,
     baseline_metrics: BaselineMetrics,
+    /// Peak memory used by the buffered input batches
+    peak_mem_used: Gauge,
+    /// Memory used by `batches`
+    batches_size: usize,
     ordered_partition_by_indices: Vec<usize>,
 }
 
@@ -311,6 +320,7 @@ impl WindowAggStream {
         window_expr: Vec<Arc<dyn WindowExpr>>,
         input: SendableRecordBatchStream,
         baseline_metrics: BaselineMetrics,
+        peak_mem_used: Gauge,
         partition_by_sort_keys: LexOrdering,
         ordered_partition_by_indices: Vec<usize>,
     ) -> Result<Self> {
@@ -325,6 +335,8 @@ impl WindowAggStream {
             finished: false,
             window_expr,
             baseline_metrics,
+            peak_mem_used,
+            batches_size: 0,
             partition_by_sort_keys,
             ordered_partition_by_indices,
         })
@@ -401,6 +413,8 @@ impl WindowAggStream {
         loop {
             return Poll::Ready(Some(match ready!(self.input.poll_next_unpin(cx)) {
                 Some(Ok(batch)) => {
+                    self.batches_size += get_record_batch_memory_size(&batch);
+                    self.peak_mem_used.set_max(self.batches_size);
                     self.batches.push(batch);
                     continue;
                 }
@@ -619,6 +619,8 @@ impl AsLogicalPlan for LogicalPlanNode {
                         table_partition_cols: create_extern_table
                             .table_partition_cols
                             .clone(),
+                        clustered_by: vec![],
+                        num_buckets: None,
                         order_exprs,
                         if_not_exists: create_extern_table.if_not_exists,
                         temporary: create_extern_table.temporary,
@@ -1043,6 +1045,11 @@ impl AsLogicalPlan for LogicalPlanNode {
                     serialize_exprs(filters, extension_codec)?;
 
                 if let Some(listing_table) = source.downcast_ref::<ListingTable>() {
+                    if listing_table.file_index().is_some() {
+                        return not_impl_err!(
+                            "LogicalPlan serde does not yet support ListingTable with a FileIndex"
+                        );
+                    }
                     let any = listing_table.options().format.as_any();
                     let file_format_type = {
                         let mut maybe_some_type = None;
@@ -1447,6 +1454,8 @@ impl AsLogicalPlan for LogicalPlanNode {
                     file_type,
                     schema: df_schema,
                     table_partition_cols,
+                    clustered_by,
+                    num_buckets,
                     if_not_exists,
                     definition,
                     order_exprs,
@@ -1457,6 +1466,11 @@ impl AsLogicalPlan for LogicalPlanNode {
                     temporary,
                 },
             )) => {
+                if !clustered_by.is_empty() || num_buckets.is_some() {
+                    return not_impl_err!(
+                        "LogicalPlan serde does not yet support CLUSTERED BY"
+                    );
+                }
                 let mut converted_order_exprs: Vec<SortExprNodeCollection> = vec![];
                 for order in order_exprs {
                     let temp = SortExprNodeCollection {
@@ -1664,6 +1678,16 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(_)) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for CreateMemoryTable",
             )),
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(_)) => {
+                Err(proto_error(
+                    "LogicalPlan serde is not yet implemented for CreateMaterializedView",
+                ))
+            }
+            LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(_)) => {
+                Err(proto_error(
+                    "LogicalPlan serde is not yet implemented for RefreshMaterializedView",
+                ))
+            }
             LogicalPlan::Ddl(DdlStatement::CreateIndex(_)) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for CreateIndex",
             )),
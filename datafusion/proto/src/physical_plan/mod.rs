@@ -65,6 +65,7 @@ use datafusion::physical_plan::{
     ExecutionPlan, InputOrderMode, PhysicalExpr, WindowExpr,
 };
 use datafusion_common::config::TableParquetOptions;
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::{internal_err, not_impl_err, DataFusionError, Result};
 use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
 
@@ -1043,12 +1044,29 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
                     runtime,
                     extension_codec,
                 )?;
-                Ok(Arc::new(AnalyzeExec::new(
-                    analyze.verbose,
-                    analyze.show_statistics,
-                    input,
-                    Arc::new(convert_required!(analyze.schema)?),
-                )))
+                let explain_format = protobuf::ExplainFormat::try_from(
+                    analyze.explain_format,
+                )
+                .map_err(|_| {
+                    proto_error(format!(
+                        "Received an AnalyzeExec message with unknown ExplainFormat {}",
+                        analyze.explain_format
+                    ))
+                })?;
+                let explain_format = match explain_format {
+                    protobuf::ExplainFormat::Indent => ExplainFormat::Indent,
+                    protobuf::ExplainFormat::Tree => ExplainFormat::Tree,
+                    protobuf::ExplainFormat::Json => ExplainFormat::Json,
+                };
+                Ok(Arc::new(
+                    AnalyzeExec::new(
+                        analyze.verbose,
+                        analyze.show_statistics,
+                        input,
+                        Arc::new(convert_required!(analyze.schema)?),
+                    )
+                    .with_explain_format(explain_format),
+                ))
             }
             PhysicalPlanType::JsonSink(sink) => {
                 let input =
@@ -1226,6 +1244,11 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
                 exec.input().to_owned(),
                 extension_codec,
             )?;
+            let explain_format = match exec.explain_format() {
+                ExplainFormat::Indent => protobuf::ExplainFormat::Indent,
+                ExplainFormat::Tree => protobuf::ExplainFormat::Tree,
+                ExplainFormat::Json => protobuf::ExplainFormat::Json,
+            };
             return Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::Analyze(Box::new(
                     protobuf::AnalyzeExecNode {
@@ -1233,6 +1256,7 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
                         show_statistics: exec.show_statistics(),
                         input: Some(Box::new(input)),
                         schema: Some(exec.schema().as_ref().try_into()?),
+                        explain_format: explain_format.into(),
                     },
                 ))),
             });
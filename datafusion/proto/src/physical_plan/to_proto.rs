@@ -433,6 +433,11 @@ impl TryFrom<&PartitionedFile> for protobuf::PartitionedFile {
     type Error = DataFusionError;
 
     fn try_from(pf: &PartitionedFile) -> Result<Self> {
+        if pf.deletes.is_some() {
+            return not_impl_err!(
+                "Serializing a PartitionedFile with deletes is not supported"
+            );
+        }
         let last_modified = pf.object_meta.last_modified;
         let last_modified_ns = last_modified.timestamp_nanos_opt().ok_or_else(|| {
             DataFusionError::Plan(format!(
@@ -583,6 +588,16 @@ impl TryFrom<&FileSinkConfig> for protobuf::FileSinkConfig {
     type Error = DataFusionError;
 
     fn try_from(conf: &FileSinkConfig) -> Result<Self, Self::Error> {
+        if conf.bucket_spec.is_some() {
+            return not_impl_err!(
+                "Serializing a FileSinkConfig with a bucket spec is not supported"
+            );
+        }
+        if conf.rolling_policy.is_some() {
+            return not_impl_err!(
+                "Serializing a FileSinkConfig with a rolling policy is not supported"
+            );
+        }
         let file_groups = conf
             .file_groups
             .iter()
@@ -44,7 +44,7 @@ use datafusion::datasource::file_format::csv::CsvSink;
 use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
 use datafusion::datasource::file_format::json::JsonSink;
 use datafusion::datasource::file_format::parquet::ParquetSink;
-use datafusion::datasource::listing::{ListingTableUrl, PartitionedFile};
+use datafusion::datasource::listing::{FileDeletes, ListingTableUrl, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::datasource::physical_plan::{
     wrap_partition_type_in_dict, wrap_partition_value_in_dict, FileScanConfig,
@@ -92,10 +92,12 @@ use datafusion::scalar::ScalarValue;
 use datafusion_common::config::TableParquetOptions;
 use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
 use datafusion_common::{
-    internal_err, not_impl_err, Constraints, DataFusionError, Result, UnnestOptions,
+    assert_contains, internal_err, not_impl_err, Constraints, DataFusionError, Result,
+    UnnestOptions,
 };
 use datafusion_expr::{
     Accumulator, AccumulatorFactoryFunction, AggregateUDF, ColumnarValue, ScalarUDF,
@@ -739,11 +741,34 @@ fn roundtrip_parquet_exec_with_pruning_predicate() -> Result<()> {
         file_compression_type: FileCompressionType::UNCOMPRESSED,
         new_lines_in_values: false,
         source,
+        hash_partition_cols: vec![],
     };
 
     roundtrip_test(scan_config.build())
 }
 
+#[test]
+fn parquet_exec_with_deletes_is_not_serialized() {
+    let file_schema =
+        Arc::new(Schema::new(vec![Field::new("col", DataType::Utf8, false)]));
+    let source = Arc::new(ParquetSource::new(TableParquetOptions::new()));
+    let file = PartitionedFile::new("/path/to/file.parquet".to_string(), 1024)
+        .with_deletes(FileDeletes::new().with_positions([0]));
+    let scan_config =
+        FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema, source)
+            .with_file(file);
+
+    let err = PhysicalPlanNode::try_from_physical_plan(
+        scan_config.build(),
+        &DefaultPhysicalExtensionCodec {},
+    )
+    .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Serializing a PartitionedFile with deletes is not supported"
+    );
+}
+
 #[tokio::test]
 async fn roundtrip_parquet_exec_with_table_partition_cols() -> Result<()> {
     let mut file_group =
@@ -770,6 +795,7 @@ async fn roundtrip_parquet_exec_with_table_partition_cols() -> Result<()> {
         file_compression_type: FileCompressionType::UNCOMPRESSED,
         new_lines_in_values: false,
         source,
+        hash_partition_cols: vec![],
     };
 
     roundtrip_test(scan_config.build())
@@ -811,6 +837,7 @@ fn roundtrip_parquet_exec_with_custom_predicate_expr() -> Result<()> {
         file_compression_type: FileCompressionType::UNCOMPRESSED,
         new_lines_in_values: false,
         source,
+        hash_partition_cols: vec![],
     };
 
     #[derive(Debug, Clone, Eq)]
@@ -1272,9 +1299,13 @@ fn roundtrip_analyze() -> Result<()> {
     roundtrip_test(Arc::new(AnalyzeExec::new(
         false,
         false,
-        input,
-        Arc::new(schema),
-    )))
+        Arc::clone(&input) as _,
+        Arc::new(schema.clone()),
+    )))?;
+    roundtrip_test(Arc::new(
+        AnalyzeExec::new(true, false, input, Arc::new(schema))
+            .with_explain_format(ExplainFormat::Json),
+    ))
 }
 
 #[test]
@@ -1293,6 +1324,8 @@ fn roundtrip_json_sink() -> Result<()> {
         insert_op: InsertOp::Overwrite,
         keep_partition_by_columns: true,
         file_extension: "json".into(),
+        bucket_spec: None,
+        rolling_policy: None,
     };
     let data_sink = Arc::new(JsonSink::new(
         file_sink_config,
@@ -1329,6 +1362,8 @@ fn roundtrip_csv_sink() -> Result<()> {
         insert_op: InsertOp::Overwrite,
         keep_partition_by_columns: true,
         file_extension: "csv".into(),
+        bucket_spec: None,
+        rolling_policy: None,
     };
     let data_sink = Arc::new(CsvSink::new(
         file_sink_config,
@@ -1384,6 +1419,8 @@ fn roundtrip_parquet_sink() -> Result<()> {
         insert_op: InsertOp::Overwrite,
         keep_partition_by_columns: true,
         file_extension: "parquet".into(),
+        bucket_spec: None,
+        rolling_policy: None,
     };
     let data_sink = Arc::new(ParquetSink::new(
         file_sink_config,
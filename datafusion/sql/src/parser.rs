@@ -24,7 +24,7 @@ use sqlparser::ast::ExprWithAlias;
 use sqlparser::tokenizer::TokenWithSpan;
 use sqlparser::{
     ast::{
-        ColumnDef, ColumnOptionDef, ObjectName, OrderByExpr, Query,
+        ColumnDef, ColumnOptionDef, Ident, ObjectName, OrderByExpr, Query,
         Statement as SQLStatement, TableConstraint, Value,
     },
     dialect::{keywords::Keyword, Dialect, GenericDialect},
@@ -49,6 +49,8 @@ fn parse_file_type(s: &str) -> Result<String, ParserError> {
 pub struct ExplainStatement {
     pub analyze: bool,
     pub verbose: bool,
+    /// The output format given with `FORMAT <format>`, if any
+    pub format: Option<String>,
     pub statement: Box<Statement>,
 }
 
@@ -57,6 +59,7 @@ impl fmt::Display for ExplainStatement {
         let Self {
             analyze,
             verbose,
+            format,
             statement,
         } = self;
 
@@ -67,6 +70,9 @@ impl fmt::Display for ExplainStatement {
         if *verbose {
             write!(f, "VERBOSE ")?;
         }
+        if let Some(format) = format {
+            write!(f, "FORMAT {format} ")?;
+        }
 
         write!(f, "{statement}")
     }
@@ -168,6 +174,7 @@ pub(crate) type LexOrdering = Vec<OrderByExpr>;
 /// <TABLE_NAME>[ (<column_definition>) ]
 /// STORED AS <file_type>
 /// [ PARTITIONED BY (<column_definition list> | <column list>) ]
+/// [ CLUSTERED BY (<column list>) INTO <num_buckets> BUCKETS ]
 /// [ WITH ORDER (<ordered column list>)
 /// [ OPTIONS (<key_value_list>) ]
 /// LOCATION <literal>
@@ -192,6 +199,10 @@ pub struct CreateExternalTable {
     pub location: String,
     /// Partition Columns
     pub table_partition_cols: Vec<String>,
+    /// Bucket columns of `CLUSTERED BY`
+    pub clustered_by: Vec<String>,
+    /// Number of buckets of `CLUSTERED BY`
+    pub num_buckets: Option<u64>,
     /// Ordered expressions
     pub order_exprs: Vec<LexOrdering>,
     /// Option to not error if table already exists
@@ -218,6 +229,82 @@ impl fmt::Display for CreateExternalTable {
     }
 }
 
+/// DataFusion extension DDL for `CREATE MATERIALIZED VIEW`
+///
+/// Syntax:
+///
+/// ```text
+/// CREATE [ OR REPLACE ] MATERIALIZED VIEW
+/// [ IF NOT EXISTS ]
+/// <view_name>[ (<column_list>) ]
+/// [ STORED AS <file_type> LOCATION <literal> ]
+/// AS <query>
+/// ```
+///
+/// Without `STORED AS` and `LOCATION` the results of the query are kept in
+/// memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMaterializedView {
+    /// View name
+    pub name: ObjectName,
+    /// Optional names for the columns of the view
+    pub columns: Vec<Ident>,
+    /// The query that defines the contents of the view
+    pub query: Box<Query>,
+    /// Option to replace an existing view of the same name
+    pub or_replace: bool,
+    /// Option to not error if the view already exists
+    pub if_not_exists: bool,
+    /// File type (Parquet, NDJSON, CSV, etc) of the stored results
+    pub file_type: Option<String>,
+    /// Path to the stored results
+    pub location: Option<String>,
+}
+
+impl fmt::Display for CreateMaterializedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE ")?;
+        if self.or_replace {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "MATERIALIZED VIEW ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if !self.columns.is_empty() {
+            let columns: Vec<_> = self.columns.iter().map(|c| c.to_string()).collect();
+            write!(f, " ({})", columns.join(", "))?;
+        }
+        if let Some(file_type) = &self.file_type {
+            write!(f, " STORED AS {file_type}")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " LOCATION '{location}'")?;
+        }
+        write!(f, " AS {}", self.query)
+    }
+}
+
+/// DataFusion extension DDL for `REFRESH MATERIALIZED VIEW`
+///
+/// Syntax:
+///
+/// ```text
+/// REFRESH MATERIALIZED VIEW <view_name>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshMaterializedView {
+    /// View name
+    pub name: ObjectName,
+}
+
+impl fmt::Display for RefreshMaterializedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW {}", self.name)
+    }
+}
+
 /// DataFusion SQL Statement.
 ///
 /// This can either be a [`Statement`] from [`sqlparser`] from a
@@ -230,9 +317,13 @@ pub enum Statement {
     /// ANSI SQL AST node (from sqlparser-rs)
     Statement(Box<SQLStatement>),
     /// Extension: `CREATE EXTERNAL TABLE`
-    CreateExternalTable(CreateExternalTable),
+    CreateExternalTable(Box<CreateExternalTable>),
     /// Extension: `COPY TO`
     CopyTo(CopyToStatement),
+    /// Extension: `CREATE MATERIALIZED VIEW`
+    CreateMaterializedView(CreateMaterializedView),
+    /// Extension: `REFRESH MATERIALIZED VIEW`
+    RefreshMaterializedView(RefreshMaterializedView),
     /// EXPLAIN for extensions
     Explain(ExplainStatement),
 }
@@ -243,6 +334,8 @@ impl fmt::Display for Statement {
             Statement::Statement(stmt) => write!(f, "{stmt}"),
             Statement::CreateExternalTable(stmt) => write!(f, "{stmt}"),
             Statement::CopyTo(stmt) => write!(f, "{stmt}"),
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}"),
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}"),
             Statement::Explain(stmt) => write!(f, "{stmt}"),
         }
     }
@@ -370,6 +463,10 @@ impl<'a> DFParser<'a> {
                         self.parser.next_token(); // EXPLAIN
                         self.parse_explain()
                     }
+                    _ if w.value.eq_ignore_ascii_case("REFRESH") => {
+                        self.parser.next_token(); // REFRESH
+                        self.parse_refresh()
+                    }
                     _ => {
                         // use sqlparser-rs parser
                         Ok(Statement::Statement(Box::from(
@@ -537,30 +634,130 @@ impl<'a> DFParser<'a> {
     }
 
     /// Parse a SQL `EXPLAIN`
+    ///
+    /// ```sql
+    /// EXPLAIN [ANALYZE] [VERBOSE] [FORMAT <format>] <statement>
+    /// ```
     pub fn parse_explain(&mut self) -> Result<Statement, ParserError> {
         let analyze = self.parser.parse_keyword(Keyword::ANALYZE);
         let verbose = self.parser.parse_keyword(Keyword::VERBOSE);
+        let format = if self.parser.parse_keyword(Keyword::FORMAT) {
+            Some(self.parser.parse_identifier()?.value)
+        } else {
+            None
+        };
         let statement = self.parse_statement()?;
 
         Ok(Statement::Explain(ExplainStatement {
             statement: Box::new(statement),
             analyze,
             verbose,
+            format,
         }))
     }
 
-    /// Parse a SQL `CREATE` statement handling `CREATE EXTERNAL TABLE`
+    /// Parse a SQL `CREATE` statement handling `CREATE EXTERNAL TABLE` and
+    /// `CREATE MATERIALIZED VIEW`
     pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
         if self.parser.parse_keyword(Keyword::EXTERNAL) {
             self.parse_create_external_table(false)
         } else if self.parser.parse_keyword(Keyword::UNBOUNDED) {
             self.parser.expect_keyword(Keyword::EXTERNAL)?;
             self.parse_create_external_table(true)
+        } else if self.parser.parse_keyword(Keyword::MATERIALIZED) {
+            self.parse_create_materialized_view(false)
+        } else if self.parser.parse_keywords(&[
+            Keyword::OR,
+            Keyword::REPLACE,
+            Keyword::MATERIALIZED,
+        ]) {
+            self.parse_create_materialized_view(true)
         } else {
             Ok(Statement::Statement(Box::from(self.parser.parse_create()?)))
         }
     }
 
+    /// Parse a SQL `CREATE MATERIALIZED VIEW` statement, after the
+    /// `MATERIALIZED` keyword
+    fn parse_create_materialized_view(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<Statement, ParserError> {
+        self.parser.expect_keyword(Keyword::VIEW)?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        if or_replace && if_not_exists {
+            return parser_err!(
+                "'IF NOT EXISTS' cannot coexist with 'REPLACE' in CREATE MATERIALIZED VIEW"
+            );
+        }
+        let name = self.parser.parse_object_name(false)?;
+
+        let mut columns = vec![];
+        if self.parser.consume_token(&Token::LParen) {
+            loop {
+                columns.push(self.parser.parse_identifier()?);
+                if self.parser.consume_token(&Token::RParen) {
+                    break;
+                }
+                self.parser.expect_token(&Token::Comma)?;
+            }
+        }
+
+        let mut file_type = None;
+        let mut location = None;
+        loop {
+            match self.parser.parse_one_of_keywords(&[
+                Keyword::STORED,
+                Keyword::LOCATION,
+                Keyword::AS,
+            ]) {
+                Some(Keyword::STORED) => {
+                    self.parser.expect_keyword(Keyword::AS)?;
+                    ensure_not_set(&file_type, "STORED AS")?;
+                    file_type = Some(self.parse_file_format()?);
+                }
+                Some(Keyword::LOCATION) => {
+                    ensure_not_set(&location, "LOCATION")?;
+                    location = Some(self.parser.parse_literal_string()?);
+                }
+                Some(Keyword::AS) => break,
+                _ => {
+                    return self
+                        .expected("STORED AS, LOCATION or AS", self.parser.peek_token())
+                }
+            }
+        }
+        if file_type.is_some() != location.is_some() {
+            return parser_err!(
+                "STORED AS and LOCATION must be specified together in CREATE MATERIALIZED VIEW"
+            );
+        }
+        let query = self.parser.parse_query()?;
+
+        Ok(Statement::CreateMaterializedView(CreateMaterializedView {
+            name,
+            columns,
+            query,
+            or_replace,
+            if_not_exists,
+            file_type,
+            location,
+        }))
+    }
+
+    /// Parse a SQL `REFRESH MATERIALIZED VIEW` statement, after the
+    /// `REFRESH` keyword
+    pub fn parse_refresh(&mut self) -> Result<Statement, ParserError> {
+        self.parser.expect_keyword(Keyword::MATERIALIZED)?;
+        self.parser.expect_keyword(Keyword::VIEW)?;
+        let name = self.parser.parse_object_name(false)?;
+        Ok(Statement::RefreshMaterializedView(
+            RefreshMaterializedView { name },
+        ))
+    }
+
     fn parse_partitions(&mut self) -> Result<Vec<String>, ParserError> {
         let mut partitions: Vec<String> = vec![];
         if !self.parser.consume_token(&Token::LParen)
@@ -727,6 +924,7 @@ impl<'a> DFParser<'a> {
             file_type: Option<String>,
             location: Option<String>,
             table_partition_cols: Option<Vec<String>>,
+            clustered_by: Option<(Vec<String>, u64)>,
             order_exprs: Vec<LexOrdering>,
             options: Option<Vec<(String, Value)>>,
         }
@@ -740,6 +938,7 @@ impl<'a> DFParser<'a> {
                 Keyword::DELIMITER,
                 Keyword::COMPRESSION,
                 Keyword::PARTITIONED,
+                Keyword::CLUSTERED,
                 Keyword::OPTIONS,
             ]) {
                 match keyword {
@@ -796,6 +995,15 @@ impl<'a> DFParser<'a> {
                             }
                         }
                     }
+                    Keyword::CLUSTERED => {
+                        self.parser.expect_keyword(Keyword::BY)?;
+                        ensure_not_set(&builder.clustered_by, "CLUSTERED BY")?;
+                        let columns = self.parse_partitions()?;
+                        self.parser.expect_keyword(Keyword::INTO)?;
+                        let num_buckets = self.parser.parse_literal_uint()?;
+                        self.parser.expect_keyword(Keyword::BUCKETS)?;
+                        builder.clustered_by = Some((columns, num_buckets));
+                    }
                     Keyword::OPTIONS => {
                         ensure_not_set(&builder.options, "OPTIONS")?;
                         builder.options = Some(self.parse_value_options()?);
@@ -828,12 +1036,18 @@ impl<'a> DFParser<'a> {
             ));
         }
 
+        let (clustered_by, num_buckets) = match builder.clustered_by {
+            Some((columns, num_buckets)) => (columns, Some(num_buckets)),
+            None => (vec![], None),
+        };
         let create = CreateExternalTable {
             name: table_name,
             columns,
             file_type: builder.file_type.unwrap(),
             location: builder.location.unwrap(),
             table_partition_cols: builder.table_partition_cols.unwrap_or(vec![]),
+            clustered_by,
+            num_buckets,
             order_exprs: builder.order_exprs,
             if_not_exists,
             temporary,
@@ -841,7 +1055,7 @@ impl<'a> DFParser<'a> {
             options: builder.options.unwrap_or(Vec::new()),
             constraints,
         };
-        Ok(Statement::CreateExternalTable(create))
+        Ok(Statement::CreateExternalTable(Box::new(create)))
     }
 
     /// Parses the set of valid formats
@@ -936,65 +1150,73 @@ mod tests {
         let sql = "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV LOCATION 'foo.csv'";
         let display = None;
         let name = ObjectName(vec![Ident::from("t")]);
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![make_column_def("c1", DataType::Int(display))],
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: leading space
         let sql = "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV LOCATION 'foo.csv'     ";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![make_column_def("c1", DataType::Int(None))],
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: leading space + semicolon
         let sql =
             "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV LOCATION 'foo.csv'      ;";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![make_column_def("c1", DataType::Int(None))],
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case with delimiter
         let sql = "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV LOCATION 'foo.csv' OPTIONS (format.delimiter '|')";
         let display = None;
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![make_column_def("c1", DataType::Int(display))],
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
@@ -1004,25 +1226,27 @@ mod tests {
                 Value::SingleQuotedString("|".into()),
             )],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: partitioned by
         let sql = "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV PARTITIONED BY (p1, p2) LOCATION 'foo.csv'";
         let display = None;
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![make_column_def("c1", DataType::Int(display))],
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec!["p1".to_string(), "p2".to_string()],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: it is ok for sql stmt with `COMPRESSION TYPE GZIP` tokens
@@ -1038,98 +1262,109 @@ mod tests {
              ('format.compression' 'ZSTD')", "ZSTD"),
          ];
         for (sql, compression) in sqls {
-            let expected = Statement::CreateExternalTable(CreateExternalTable {
-                name: name.clone(),
-                columns: vec![make_column_def("c1", DataType::Int(display))],
-                file_type: "CSV".to_string(),
-                location: "foo.csv".into(),
-                table_partition_cols: vec![],
-                order_exprs: vec![],
-                if_not_exists: false,
-                temporary: false,
-                unbounded: false,
-                options: vec![(
-                    "format.compression".into(),
-                    Value::SingleQuotedString(compression.into()),
-                )],
-                constraints: vec![],
-            });
+            let expected =
+                Statement::CreateExternalTable(Box::new(CreateExternalTable {
+                    name: name.clone(),
+                    columns: vec![make_column_def("c1", DataType::Int(display))],
+                    file_type: "CSV".to_string(),
+                    location: "foo.csv".into(),
+                    table_partition_cols: vec![],
+                    clustered_by: vec![],
+                    num_buckets: None,
+                    order_exprs: vec![],
+                    if_not_exists: false,
+                    temporary: false,
+                    unbounded: false,
+                    options: vec![(
+                        "format.compression".into(),
+                        Value::SingleQuotedString(compression.into()),
+                    )],
+                    constraints: vec![],
+                }));
             expect_parse_ok(sql, expected)?;
         }
 
         // positive case: it is ok for parquet files not to have columns specified
         let sql = "CREATE EXTERNAL TABLE t STORED AS PARQUET LOCATION 'foo.parquet'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![],
             file_type: "PARQUET".to_string(),
             location: "foo.parquet".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: it is ok for parquet files to be other than upper case
         let sql = "CREATE EXTERNAL TABLE t STORED AS parqueT LOCATION 'foo.parquet'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![],
             file_type: "PARQUET".to_string(),
             location: "foo.parquet".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: it is ok for avro files not to have columns specified
         let sql = "CREATE EXTERNAL TABLE t STORED AS AVRO LOCATION 'foo.avro'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![],
             file_type: "AVRO".to_string(),
             location: "foo.avro".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: it is ok for avro files not to have columns specified
         let sql =
             "CREATE EXTERNAL TABLE IF NOT EXISTS t STORED AS PARQUET LOCATION 'foo.parquet'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![],
             file_type: "PARQUET".to_string(),
             location: "foo.parquet".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: true,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: column definition allowed in 'partition by' clause
         let sql =
             "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV PARTITIONED BY (p1 int) LOCATION 'foo.csv'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![
                 make_column_def("c1", DataType::Int(None)),
@@ -1138,13 +1373,15 @@ mod tests {
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec!["p1".to_string()],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // negative case: mixed column defs and column names in `PARTITIONED BY` clause
@@ -1160,33 +1397,71 @@ mod tests {
             "CREATE EXTERNAL TABLE t(c1 int) STORED AS CSV PARTITIONED BY (c1, p1 int) LOCATION 'foo.csv'";
         expect_parse_error(sql, "sql parser error: Expected ',' or ')' after partition definition, found: int");
 
+        // positive case: bucketed table
+        let sql = "CREATE EXTERNAL TABLE t(c1 int, c2 int) STORED AS PARQUET CLUSTERED BY (c1, c2) INTO 8 BUCKETS LOCATION 'foo'";
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
+            name: name.clone(),
+            columns: vec![
+                make_column_def("c1", DataType::Int(None)),
+                make_column_def("c2", DataType::Int(None)),
+            ],
+            file_type: "PARQUET".to_string(),
+            location: "foo".into(),
+            table_partition_cols: vec![],
+            clustered_by: vec!["c1".to_string(), "c2".to_string()],
+            num_buckets: Some(8),
+            order_exprs: vec![],
+            if_not_exists: false,
+            temporary: false,
+            unbounded: false,
+            options: vec![],
+            constraints: vec![],
+        }));
+        expect_parse_ok(sql, expected)?;
+
+        // negative case: missing number of buckets
+        let sql =
+            "CREATE EXTERNAL TABLE t(c1 int) STORED AS PARQUET CLUSTERED BY (c1) LOCATION 'foo'";
+        expect_parse_error(sql, "sql parser error: Expected: INTO, found: LOCATION");
+
+        // negative case: CLUSTERED BY specified twice
+        let sql = "CREATE EXTERNAL TABLE t(c1 int) STORED AS PARQUET CLUSTERED BY (c1) INTO 2 BUCKETS CLUSTERED BY (c1) INTO 2 BUCKETS LOCATION 'foo'";
+        expect_parse_error(
+            sql,
+            "sql parser error: CLUSTERED BY specified more than once",
+        );
+
         // positive case: additional options (one entry) can be specified
         let sql =
             "CREATE EXTERNAL TABLE t STORED AS x OPTIONS ('k1' 'v1') LOCATION 'blahblah'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![],
             file_type: "X".to_string(),
             location: "blahblah".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
             unbounded: false,
             options: vec![("k1".into(), Value::SingleQuotedString("v1".into()))],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // positive case: additional options (multiple entries) can be specified
         let sql =
             "CREATE EXTERNAL TABLE t STORED AS x OPTIONS ('k1' 'v1', k2 v2) LOCATION 'blahblah'";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![],
             file_type: "X".to_string(),
             location: "blahblah".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![],
             if_not_exists: false,
             temporary: false,
@@ -1196,7 +1471,7 @@ mod tests {
                 ("k2".into(), Value::SingleQuotedString("v2".into())),
             ],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // Ordered Col
@@ -1221,35 +1496,38 @@ mod tests {
             (Some(true), Some(false)),
         ];
         for (sql, (asc, nulls_first)) in sqls.iter().zip(expected.into_iter()) {
-            let expected = Statement::CreateExternalTable(CreateExternalTable {
-                name: name.clone(),
-                columns: vec![make_column_def("c1", DataType::Int(None))],
-                file_type: "CSV".to_string(),
-                location: "foo.csv".into(),
-                table_partition_cols: vec![],
-                order_exprs: vec![vec![OrderByExpr {
-                    expr: Identifier(Ident {
-                        value: "c1".to_owned(),
-                        quote_style: None,
-                        span: Span::empty(),
-                    }),
-                    asc,
-                    nulls_first,
-                    with_fill: None,
-                }]],
-                if_not_exists: false,
-                temporary: false,
-                unbounded: false,
-                options: vec![],
-                constraints: vec![],
-            });
+            let expected =
+                Statement::CreateExternalTable(Box::new(CreateExternalTable {
+                    name: name.clone(),
+                    columns: vec![make_column_def("c1", DataType::Int(None))],
+                    file_type: "CSV".to_string(),
+                    location: "foo.csv".into(),
+                    table_partition_cols: vec![],
+                    clustered_by: vec![],
+                    num_buckets: None,
+                    order_exprs: vec![vec![OrderByExpr {
+                        expr: Identifier(Ident {
+                            value: "c1".to_owned(),
+                            quote_style: None,
+                            span: Span::empty(),
+                        }),
+                        asc,
+                        nulls_first,
+                        with_fill: None,
+                    }]],
+                    if_not_exists: false,
+                    temporary: false,
+                    unbounded: false,
+                    options: vec![],
+                    constraints: vec![],
+                }));
             expect_parse_ok(sql, expected)?;
         }
 
         // Ordered Col
         let sql = "CREATE EXTERNAL TABLE t(c1 int, c2 int) STORED AS CSV WITH ORDER (c1 ASC, c2 DESC NULLS FIRST) LOCATION 'foo.csv'";
         let display = None;
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![
                 make_column_def("c1", DataType::Int(display)),
@@ -1258,6 +1536,8 @@ mod tests {
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![vec![
                 OrderByExpr {
                     expr: Identifier(Ident {
@@ -1285,13 +1565,13 @@ mod tests {
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // Ordered Binary op
         let sql = "CREATE EXTERNAL TABLE t(c1 int, c2 int) STORED AS CSV WITH ORDER (c1 - c2 ASC) LOCATION 'foo.csv'";
         let display = None;
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![
                 make_column_def("c1", DataType::Int(display)),
@@ -1300,6 +1580,8 @@ mod tests {
             file_type: "CSV".to_string(),
             location: "foo.csv".into(),
             table_partition_cols: vec![],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![vec![OrderByExpr {
                 expr: Expr::BinaryOp {
                     left: Box::new(Identifier(Ident {
@@ -1323,7 +1605,7 @@ mod tests {
             unbounded: false,
             options: vec![],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // Most complete CREATE EXTERNAL TABLE statement possible
@@ -1338,7 +1620,7 @@ mod tests {
                      'ROW_GROUP_SIZE' '1024',
                      'TRUNCATE' 'NO',
                      'format.has_header' 'true')";
-        let expected = Statement::CreateExternalTable(CreateExternalTable {
+        let expected = Statement::CreateExternalTable(Box::new(CreateExternalTable {
             name: name.clone(),
             columns: vec![
                 make_column_def("c1", DataType::Int(None)),
@@ -1347,6 +1629,8 @@ mod tests {
             file_type: "PARQUET".to_string(),
             location: "foo.parquet".into(),
             table_partition_cols: vec!["c1".into()],
+            clustered_by: vec![],
+            num_buckets: None,
             order_exprs: vec![vec![OrderByExpr {
                 expr: Expr::BinaryOp {
                     left: Box::new(Identifier(Ident {
@@ -1388,7 +1672,7 @@ mod tests {
                 ),
             ],
             constraints: vec![],
-        });
+        }));
         expect_parse_ok(sql, expected)?;
 
         // For error cases, see: `create_external_table.slt`
@@ -1462,6 +1746,7 @@ mod tests {
             let expected = Statement::Explain(ExplainStatement {
                 analyze,
                 verbose,
+                format: None,
                 statement: Box::new(expected_copy),
             });
             assert_eq!(verified_stmt(sql), expected);
@@ -1469,6 +1754,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn explain_format() -> Result<(), ParserError> {
+        let cases = vec![
+            ("EXPLAIN FORMAT tree SELECT 1", false, false, "tree"),
+            ("EXPLAIN ANALYZE FORMAT json SELECT 1", true, false, "json"),
+            (
+                "EXPLAIN ANALYZE VERBOSE FORMAT indent SELECT 1",
+                true,
+                true,
+                "indent",
+            ),
+        ];
+        for (sql, analyze, verbose, format) in cases {
+            let expected = Statement::Explain(ExplainStatement {
+                analyze,
+                verbose,
+                format: Some(format.to_string()),
+                statement: Box::new(verified_stmt("SELECT 1")),
+            });
+            assert_eq!(verified_stmt(sql), expected);
+        }
+        Ok(())
+    }
+
     #[test]
     fn copy_to_query_to_table() -> Result<(), ParserError> {
         let statement = verified_stmt("SELECT 1");
@@ -1572,6 +1881,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn create_materialized_view() -> Result<(), ParserError> {
+        let sql = "CREATE MATERIALIZED VIEW mv AS SELECT 1";
+        let Statement::CreateMaterializedView(create) = verified_stmt(sql) else {
+            panic!("Expected CREATE MATERIALIZED VIEW");
+        };
+        assert_eq!(create.name, ObjectName(vec![Ident::new("mv")]));
+        assert!(create.columns.is_empty());
+        assert!(!create.or_replace && !create.if_not_exists);
+        assert_eq!((create.file_type, create.location), (None, None));
+
+        let sql = "CREATE OR REPLACE MATERIALIZED VIEW s.mv (a, b) STORED AS PARQUET LOCATION 'foo/' AS SELECT x, y FROM t";
+        let Statement::CreateMaterializedView(create) = verified_stmt(sql) else {
+            panic!("Expected CREATE MATERIALIZED VIEW");
+        };
+        assert_eq!(create.columns, vec![Ident::new("a"), Ident::new("b")]);
+        assert!(create.or_replace);
+        assert_eq!(create.file_type.as_deref(), Some("PARQUET"));
+        assert_eq!(create.location.as_deref(), Some("foo/"));
+
+        one_statement_parses_to(
+            "CREATE MATERIALIZED VIEW IF NOT EXISTS mv LOCATION 'foo/' STORED AS csv AS SELECT 1",
+            "CREATE MATERIALIZED VIEW IF NOT EXISTS mv STORED AS CSV LOCATION 'foo/' AS SELECT 1",
+        );
+
+        // plain views are still parsed by sqlparser
+        let sql = "CREATE OR REPLACE VIEW v AS SELECT 1";
+        assert!(matches!(verified_stmt(sql), Statement::Statement(_)));
+
+        expect_parse_error(
+            "CREATE MATERIALIZED VIEW mv LOCATION 'foo/' AS SELECT 1",
+            "STORED AS and LOCATION must be specified together",
+        );
+        expect_parse_error(
+            "CREATE MATERIALIZED VIEW mv STORED AS CSV STORED AS CSV AS SELECT 1",
+            "STORED AS specified more than once",
+        );
+        expect_parse_error(
+            "CREATE OR REPLACE MATERIALIZED VIEW IF NOT EXISTS mv AS SELECT 1",
+            "'IF NOT EXISTS' cannot coexist with 'REPLACE'",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_materialized_view() -> Result<(), ParserError> {
+        let expected = Statement::RefreshMaterializedView(RefreshMaterializedView {
+            name: ObjectName(vec![Ident::new("s"), Ident::new("mv")]),
+        });
+        assert_eq!(verified_stmt("REFRESH MATERIALIZED VIEW s.mv"), expected);
+        expect_parse_error("REFRESH VIEW mv", "Expected: MATERIALIZED");
+        Ok(())
+    }
+
     // For error cases, see: `copy.slt`
 
     fn object_name(name: &str) -> CopyToSource {
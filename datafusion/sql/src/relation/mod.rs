@@ -19,18 +19,67 @@ use std::sync::Arc;
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 
+use arrow::datatypes::{DataType, TimeUnit};
 use datafusion_common::tree_node::{Transformed, TreeNode};
 use datafusion_common::{
-    not_impl_err, plan_err, DFSchema, Diagnostic, Result, Span, TableReference,
+    not_impl_err, plan_err, DFSchema, Diagnostic, Result, ScalarValue, Span,
+    TableReference,
 };
 use datafusion_expr::builder::subquery_alias;
-use datafusion_expr::{expr::Unnest, Expr, LogicalPlan, LogicalPlanBuilder};
+use datafusion_expr::{
+    expr::Unnest, Cast, Expr, LogicalPlan, LogicalPlanBuilder, TableVersion,
+};
 use datafusion_expr::{Subquery, SubqueryAlias};
-use sqlparser::ast::{FunctionArg, FunctionArgExpr, Spanned, TableFactor};
+use sqlparser::ast::{
+    self, Expr as SQLExpr, FunctionArg, FunctionArgExpr, Spanned, TableFactor,
+};
 
 mod join;
 
 impl<S: ContextProvider> SqlToRel<'_, S> {
+    /// The version of a table read by `FOR SYSTEM_TIME AS OF <expr>`, where
+    /// `expr` is an integer version, or a timestamp
+    fn table_version(
+        &self,
+        expr: SQLExpr,
+        planner_context: &mut PlannerContext,
+    ) -> Result<TableVersion> {
+        let expr =
+            self.sql_expr_to_logical_expr(expr, &DFSchema::empty(), planner_context)?;
+        let value = match expr {
+            Expr::Literal(value) => Some(value),
+            Expr::Cast(Cast { expr, data_type }) => match *expr {
+                Expr::Literal(value) => Some(value.cast_to(&data_type)?),
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(value) = value else {
+            return plan_err!("FOR SYSTEM_TIME AS OF requires a constant");
+        };
+        let version = if value.data_type().is_integer() {
+            match value.cast_to(&DataType::UInt64)? {
+                ScalarValue::UInt64(Some(version)) => {
+                    Some(TableVersion::Version(version))
+                }
+                _ => None,
+            }
+        } else {
+            match value.cast_to(&DataType::Timestamp(TimeUnit::Nanosecond, None))? {
+                ScalarValue::TimestampNanosecond(Some(nanos), _) => {
+                    Some(TableVersion::Timestamp(nanos))
+                }
+                _ => None,
+            }
+        };
+        match version {
+            Some(version) => Ok(version),
+            None => plan_err!(
+                "FOR SYSTEM_TIME AS OF requires an integer version or a timestamp, got {value}"
+            ),
+        }
+    }
+
     /// Create a `LogicalPlan` that scans the named relation
     fn create_relation(
         &self,
@@ -40,7 +89,11 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         let relation_span = relation.span();
         let (plan, alias) = match relation {
             TableFactor::Table {
-                name, alias, args, ..
+                name,
+                alias,
+                args,
+                version,
+                ..
             } => {
                 if let Some(func_args) = args {
                     let tbl_func_name = name.0.first().unwrap().value.to_string();
@@ -72,6 +125,28 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     )?
                     .build()?;
                     (plan, alias)
+                } else if let Some(version) = version {
+                    let version = match version {
+                        ast::TableVersion::ForSystemTimeAsOf(expr) => {
+                            self.table_version(expr, planner_context)?
+                        }
+                        #[allow(unreachable_patterns)]
+                        version => {
+                            return not_impl_err!("Unsupported table version {version}")
+                        }
+                    };
+                    let table_ref = self.object_name_to_table_reference(name)?;
+                    if planner_context.get_cte(&table_ref.to_string()).is_some() {
+                        return plan_err!(
+                            "Cannot read the {version} of common table expression {table_ref}"
+                        );
+                    }
+                    let provider = self
+                        .context_provider
+                        .get_table_source_at_version(table_ref.clone(), &version)?;
+                    let plan =
+                        LogicalPlanBuilder::scan(table_ref, provider, None)?.build()?;
+                    (plan, alias)
                 } else {
                     // Normalize name and alias
                     let table_ref = self.object_name_to_table_reference(name)?;
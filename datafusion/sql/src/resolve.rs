@@ -146,6 +146,12 @@ fn visit_statement(statement: &DFStatement, visitor: &mut RelationVisitor) {
                 query.visit(visitor);
             }
         },
+        DFStatement::CreateMaterializedView(create) => {
+            create.query.visit(visitor);
+        }
+        DFStatement::RefreshMaterializedView(refresh) => {
+            visitor.insert_relation(&refresh.name);
+        }
         DFStatement::Explain(explain) => visit_statement(&explain.statement, visitor),
     }
 }
@@ -21,8 +21,8 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::parser::{
-    CopyToSource, CopyToStatement, CreateExternalTable, DFParser, ExplainStatement,
-    LexOrdering, Statement as DFStatement,
+    CopyToSource, CopyToStatement, CreateExternalTable, CreateMaterializedView, DFParser,
+    ExplainStatement, LexOrdering, RefreshMaterializedView, Statement as DFStatement,
 };
 use crate::planner::{
     object_name_to_qualifier, ContextProvider, PlannerContext, SqlToRel,
@@ -31,6 +31,7 @@ use crate::utils::normalize_ident;
 
 use arrow::datatypes::{DataType, Fields};
 use datafusion_common::error::_plan_err;
+use datafusion_common::format::ExplainFormat;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{
     exec_err, internal_err, not_impl_err, plan_datafusion_err, plan_err, schema_err,
@@ -46,10 +47,12 @@ use datafusion_expr::utils::expr_to_columns;
 use datafusion_expr::{
     cast, col, Analyze, CreateCatalog, CreateCatalogSchema,
     CreateExternalTable as PlanCreateExternalTable, CreateFunction, CreateFunctionBody,
-    CreateIndex as PlanCreateIndex, CreateMemoryTable, CreateView, Deallocate,
-    DescribeTable, DmlStatement, DropCatalogSchema, DropFunction, DropTable, DropView,
-    EmptyRelation, Execute, Explain, Expr, ExprSchemable, Filter, LogicalPlan,
-    LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare, SetVariable, SortExpr,
+    CreateIndex as PlanCreateIndex, CreateMaterializedView as PlanCreateMaterializedView,
+    CreateMemoryTable, CreateView, Deallocate, DescribeTable, DmlStatement,
+    DropCatalogSchema, DropFunction, DropTable, DropView, EmptyRelation, Execute,
+    Explain, Expr, ExprSchemable, Filter, LogicalPlan, LogicalPlanBuilder,
+    OperateFunctionArg, PlanType, Prepare,
+    RefreshMaterializedView as PlanRefreshMaterializedView, SetVariable, SortExpr,
     Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
     TransactionConclusion, TransactionEnd, TransactionIsolationLevel, TransactionStart,
     Volatility, WriteOp,
@@ -59,12 +62,13 @@ use sqlparser::ast::{
     ShowStatementOptions, SqliteOnConflict, TableObject, UpdateTableFromKind,
 };
 use sqlparser::ast::{
-    Assignment, AssignmentTarget, ColumnDef, CreateIndex, CreateTable,
+    AnalyzeFormat, Assignment, AssignmentTarget, ColumnDef, CreateIndex, CreateTable,
     CreateTableOptions, Delete, DescribeAlias, Expr as SQLExpr, FromTable, Ident, Insert,
     ObjectName, ObjectType, OneOrManyWithParens, Query, SchemaName, SetExpr,
     ShowCreateObject, ShowStatementFilter, Statement, TableConstraint, TableFactor,
     TableWithJoins, TransactionMode, UnaryOperator, Value,
 };
+use sqlparser::dialect::dialect_from_str;
 use sqlparser::parser::ParserError::ParserError;
 
 fn ident_to_string(ident: &Ident) -> String {
@@ -171,14 +175,21 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
     /// Generate a logical plan from an DataFusion SQL statement
     pub fn statement_to_plan(&self, statement: DFStatement) -> Result<LogicalPlan> {
         match statement {
-            DFStatement::CreateExternalTable(s) => self.external_table_to_plan(s),
+            DFStatement::CreateExternalTable(s) => self.external_table_to_plan(*s),
             DFStatement::Statement(s) => self.sql_statement_to_plan(*s),
             DFStatement::CopyTo(s) => self.copy_to_plan(s),
+            DFStatement::CreateMaterializedView(s) => {
+                self.create_materialized_view_to_plan(s)
+            }
+            DFStatement::RefreshMaterializedView(s) => {
+                self.refresh_materialized_view_to_plan(s)
+            }
             DFStatement::Explain(ExplainStatement {
                 verbose,
                 analyze,
+                format,
                 statement,
-            }) => self.explain_to_plan(verbose, analyze, *statement),
+            }) => self.explain_to_plan(verbose, analyze, format, *statement),
         }
     }
 
@@ -214,11 +225,24 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 verbose,
                 statement,
                 analyze,
-                format: _,
+                format,
                 describe_alias: _,
                 ..
             } => {
-                self.explain_to_plan(verbose, analyze, DFStatement::Statement(statement))
+                let format = match format {
+                    None => None,
+                    Some(AnalyzeFormat::TEXT) => Some("indent".to_string()),
+                    Some(AnalyzeFormat::JSON) => Some("json".to_string()),
+                    Some(format) => {
+                        return not_impl_err!("Unsupported EXPLAIN format: {format}")
+                    }
+                };
+                self.explain_to_plan(
+                    verbose,
+                    analyze,
+                    format,
+                    DFStatement::Statement(statement),
+                )
             }
             Statement::Query(query) => self.query_to_plan(*query, planner_context),
             Statement::ShowVariable { variable } => self.show_variable_to_plan(&variable),
@@ -499,9 +523,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 to,
                 params,
             } => {
-                if materialized {
-                    return not_impl_err!("Materialized views not supported")?;
-                }
                 if !cluster_by.is_empty() {
                     return not_impl_err!("Cluster by not supported")?;
                 }
@@ -511,7 +532,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 if with_no_schema_binding {
                     return not_impl_err!("With no schema binding not supported")?;
                 }
-                if if_not_exists {
+                if if_not_exists && !materialized {
                     return not_impl_err!("If not exists not supported")?;
                 }
                 if to.is_some() {
@@ -542,6 +563,8 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     query,
                     or_replace,
                     temporary,
+                    materialized,
+                    if_not_exists,
                     ..
                 } = stmt
                 else {
@@ -564,6 +587,25 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 let mut plan = self.query_to_plan(*query, &mut PlannerContext::new())?;
                 plan = self.apply_expr_alias(plan, columns)?;
 
+                if materialized {
+                    if temporary {
+                        return not_impl_err!(
+                            "Temporary materialized views not supported"
+                        );
+                    }
+                    return Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                        PlanCreateMaterializedView {
+                            name: self.object_name_to_table_reference(name)?,
+                            input: Arc::new(plan),
+                            if_not_exists,
+                            or_replace,
+                            definition: Some(sql),
+                            file_type: None,
+                            location: None,
+                        },
+                    )));
+                }
+
                 Ok(LogicalPlan::Ddl(DdlStatement::CreateView(CreateView {
                     name: self.object_name_to_table_reference(name)?,
                     input: Arc::new(plan),
@@ -1273,6 +1315,31 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
 
         let options_map = self.parse_options_map(statement.options, true)?;
 
+        // Cluster the rows by the expression of the `execution.cluster_by`
+        // option, such as `zorder(a, b)`, by sorting them by it
+        let input = match options_map.get("execution.cluster_by") {
+            Some(cluster_by) => {
+                let dialect = &self.context_provider.options().sql_parser.dialect;
+                let dialect = dialect_from_str(dialect).ok_or_else(|| {
+                    plan_datafusion_err!("Unsupported SQL dialect: {dialect}")
+                })?;
+                let cluster_by = DFParser::parse_sql_into_expr_with_dialect(
+                    cluster_by,
+                    dialect.as_ref(),
+                )?
+                .expr;
+                let cluster_by = self.sql_to_expr(
+                    cluster_by,
+                    &input_schema,
+                    &mut PlannerContext::new(),
+                )?;
+                LogicalPlanBuilder::from(input)
+                    .sort(vec![cluster_by.sort(true, false)])?
+                    .build()?
+            }
+            None => input,
+        };
+
         let maybe_file_type = if let Some(stored_as) = &statement.stored_as {
             if let Ok(ext_file_type) = self.context_provider.get_file_type(stored_as) {
                 Some(ext_file_type)
@@ -1378,6 +1445,51 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
     }
 
     /// Generate a logical plan from a CREATE EXTERNAL TABLE statement
+    /// Generate a logical plan from a `CREATE MATERIALIZED VIEW` statement
+    fn create_materialized_view_to_plan(
+        &self,
+        statement: CreateMaterializedView,
+    ) -> Result<LogicalPlan> {
+        let definition = Some(statement.to_string());
+        let CreateMaterializedView {
+            name,
+            columns,
+            query,
+            or_replace,
+            if_not_exists,
+            file_type,
+            location,
+        } = statement;
+
+        let mut plan = self.query_to_plan(*query, &mut PlannerContext::new())?;
+        plan = self.apply_expr_alias(plan, columns)?;
+
+        Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+            PlanCreateMaterializedView {
+                name: self.object_name_to_table_reference(name)?,
+                input: Arc::new(plan),
+                if_not_exists,
+                or_replace,
+                definition,
+                file_type,
+                location,
+            },
+        )))
+    }
+
+    /// Generate a logical plan from a `REFRESH MATERIALIZED VIEW` statement
+    fn refresh_materialized_view_to_plan(
+        &self,
+        statement: RefreshMaterializedView,
+    ) -> Result<LogicalPlan> {
+        Ok(LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(
+            PlanRefreshMaterializedView {
+                name: self.object_name_to_table_reference(statement.name)?,
+                schema: DFSchemaRef::new(DFSchema::empty()),
+            },
+        )))
+    }
+
     fn external_table_to_plan(
         &self,
         statement: CreateExternalTable,
@@ -1389,6 +1501,8 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             file_type,
             location,
             table_partition_cols,
+            clustered_by,
+            num_buckets,
             if_not_exists,
             temporary,
             order_exprs,
@@ -1442,6 +1556,8 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 location,
                 file_type,
                 table_partition_cols,
+                clustered_by,
+                num_buckets: num_buckets.map(|n| n as usize),
                 if_not_exists,
                 temporary,
                 definition,
@@ -1564,8 +1680,18 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         &self,
         verbose: bool,
         analyze: bool,
+        format: Option<String>,
         statement: DFStatement,
     ) -> Result<LogicalPlan> {
+        let explain_format = match format {
+            Some(format) => format.parse::<ExplainFormat>()?,
+            None => self
+                .context_provider
+                .options()
+                .explain
+                .format
+                .parse::<ExplainFormat>()?,
+        };
         let plan = self.statement_to_plan(statement)?;
         if matches!(plan, LogicalPlan::Explain(_)) {
             return plan_err!("Nested EXPLAINs are not supported");
@@ -1579,6 +1705,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 verbose,
                 input: plan,
                 schema,
+                explain_format,
             }))
         } else {
             let stringified_plans =
@@ -1589,6 +1716,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 stringified_plans,
                 schema,
                 logical_optimization_succeeded: false,
+                explain_format,
             }))
         }
     }
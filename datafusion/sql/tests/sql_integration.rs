@@ -49,7 +49,9 @@ use datafusion_functions_aggregate::{average::avg_udaf, grouping::grouping_udaf}
 use datafusion_functions_nested::make_array::make_array_udf;
 use datafusion_functions_window::rank::rank_udwf;
 use rstest::rstest;
-use sqlparser::dialect::{Dialect, GenericDialect, HiveDialect, MySqlDialect};
+use sqlparser::dialect::{
+    BigQueryDialect, Dialect, GenericDialect, HiveDialect, MySqlDialect,
+};
 
 mod cases;
 mod common;
@@ -3302,6 +3304,39 @@ fn group_by_ambiguous_name() {
     assert_eq!(err.strip_backtrace(), expected);
 }
 
+#[test]
+fn table_version() {
+    // `FOR SYSTEM_TIME AS OF` is parsed for the BigQuery and MsSql dialects
+    let cases = [
+        (
+            "select * from person for system_time as of 3",
+            "This feature is not implemented: Reading the version 3 of a table is not supported",
+        ),
+        (
+            "select * from person for system_time as of '1970-01-01T00:00:01'",
+            "This feature is not implemented: Reading the timestamp 1000000000 of a table is not supported",
+        ),
+        (
+            "select * from person for system_time as of timestamp '1970-01-01 00:00:02'",
+            "This feature is not implemented: Reading the timestamp 2000000000 of a table is not supported",
+        ),
+        (
+            "select * from person for system_time as of id",
+            "Error during planning: FOR SYSTEM_TIME AS OF requires a constant",
+        ),
+        (
+            "select * from person for system_time as of 1 + 2",
+            "Error during planning: FOR SYSTEM_TIME AS OF requires a constant",
+        ),
+    ];
+    for (sql, expected) in cases {
+        let err = logical_plan_with_dialect(sql, &BigQueryDialect {})
+            .unwrap_err()
+            .strip_backtrace();
+        assert!(err.starts_with(expected), "{sql}: {err}");
+    }
+}
+
 #[test]
 fn test_zero_offset_with_limit() {
     let sql = "select id from person where person.id > 100 LIMIT 5 OFFSET 0;";
@@ -129,6 +129,7 @@ pub async fn from_substrait_rel(
                             range: None,
                             statistics: None,
                             extensions: None,
+                            deletes: None,
                             metadata_size_hint: None,
                         };
 